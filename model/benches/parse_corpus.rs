@@ -0,0 +1,87 @@
+// SPDX-FileCopyrightText: Joonas Javanainen <joonas.javanainen@gmail.com>
+//
+// SPDX-License-Identifier: MIT
+
+//! Parses every label in the `data/` corpus with the parser families that accept it, once using
+//! the dispatch tables and once trying every member parser in order as a baseline.
+//!
+//! Run with `cargo bench -p gbhwdb-model`.
+
+use std::{
+    fs,
+    hint::black_box,
+    path::Path,
+    time::{Duration, Instant},
+};
+
+use gbhwdb_model::parser::{
+    audit,
+    registry::{self, ParserFamily},
+};
+
+const ROUNDS: u32 = 5;
+
+fn read_data(dir: &Path, labels: &mut Vec<String>) {
+    for entry in fs::read_dir(dir).unwrap() {
+        let path = entry.unwrap().path();
+        if path.is_dir() {
+            read_data(&path, labels);
+        } else if path.file_name().is_some_and(|name| name == "metadata.json") {
+            let value = serde_json::from_str(&fs::read_to_string(&path).unwrap()).unwrap();
+            audit::metadata_labels(&value, labels);
+        }
+    }
+}
+
+fn main() {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let mut labels = Vec::new();
+    read_data(&root.join("../data"), &mut labels);
+
+    let families = registry::families();
+    let jobs = labels
+        .iter()
+        .flat_map(|label| {
+            families
+                .iter()
+                .filter(|family| family.accepts(label))
+                .map(move |family| (family, label.as_str()))
+        })
+        .collect::<Vec<_>>();
+    println!(
+        "{} labels, {} label/family pairs, {} families",
+        labels.len(),
+        jobs.len(),
+        families.len()
+    );
+
+    let dispatch = measure("dispatch", &jobs, ParserFamily::accepts);
+    let baseline = measure("baseline", &jobs, ParserFamily::accepts_sequentially);
+    println!(
+        "dispatch is {:.1}x faster than the baseline",
+        baseline.as_secs_f64() / dispatch.as_secs_f64()
+    );
+}
+
+/// Returns the best time of all rounds
+fn measure(
+    name: &str,
+    jobs: &[(&ParserFamily, &str)],
+    accepts: impl Fn(&ParserFamily, &str) -> bool,
+) -> Duration {
+    let mut best = Duration::MAX;
+    for round in 1..=ROUNDS {
+        let start = Instant::now();
+        for (family, label) in jobs {
+            assert!(black_box(accepts(family, black_box(label))));
+        }
+        let elapsed = start.elapsed();
+        println!("{name} round {round}: {elapsed:?}");
+        best = best.min(elapsed);
+    }
+    println!(
+        "{name} best: {best:?} ({:?} per label)",
+        best / jobs.len().max(1) as u32
+    );
+    best
+}
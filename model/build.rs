@@ -0,0 +1,25 @@
+// SPDX-FileCopyrightText: Joonas Javanainen <joonas.javanainen@gmail.com>
+//
+// SPDX-License-Identifier: MIT
+
+use std::{env, fs, path::Path};
+
+/// Embeds every `config/parts/*.json` chip definition file, see `parser::definition`
+fn main() {
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("../config/parts");
+    println!("cargo::rerun-if-changed={}", dir.display());
+    let mut names = fs::read_dir(&dir)
+        .unwrap_or_else(|err| panic!("{}: {err}", dir.display()))
+        .map(|entry| entry.unwrap().file_name().into_string().unwrap())
+        .filter(|name| name.ends_with(".json"))
+        .collect::<Vec<_>>();
+    names.sort();
+    let mut files = String::from("&[\n");
+    for name in names {
+        let path = dir.join(&name);
+        files += &format!("    ({name:?}, include_str!({:?})),\n", path.display());
+    }
+    files += "]\n";
+    let out_dir = env::var_os("OUT_DIR").unwrap();
+    fs::write(Path::new(&out_dir).join("part_files.rs"), files).unwrap();
+}
@@ -3,29 +3,36 @@
 // SPDX-License-Identifier: MIT
 
 use crate::{
-    hash::{Crc32, Md5, Sha1, Sha256},
+    ParseError,
+    hash::{Crc32, Hashes, Md5, Sha1, Sha256},
+    input::cartridge::CartridgeBoard,
     parser::{
-        Crystal, GameMaskRom, GenericPart, LabelParser, Mapper, UNKNOWN_CHIP, UnknownChip,
-        agb_mask_rom_tsop_ii_44_3v3, analog, eeprom_sop_8_3v3, eeprom_tssop_8_5v,
-        flash_tsop_i_32_3v3, flash_tsop_i_40_5v, fram_sop_28_3v3, gb_mask_rom_glop_top_28_5v,
-        gb_mask_rom_qfp_44_5v, gb_mask_rom_sop_32_5v, gb_mask_rom_sop_44_5v,
-        gb_mask_rom_tsop_i_32_5v, gb_mask_rom_tsop_ii_44_5v, hex_inverter, huc1_qfp32, huc3_qfp48,
-        line_decoder, mbc1_glop_top, mbc1_sop24, mbc2_sop28, mbc3_qfp32, mbc5_qfp32, mbc6_qfp64,
-        mbc7_qfp56, mbc30_qfp32, mmm01_qfp32, rtc_crystal, rtc_sop_8, rtc_sop_20, sram_sop_28_3v3,
-        sram_sop_28_5v, sram_sop_32_5v, sram_tsop_i_28_5v, supervisor_reset, toshiba, unknown,
+        Crystal, GameMaskRom, GameRomType, GenericPart, LabelParser, Mapper, MapperType, Package,
+        PartDateCode, UNKNOWN_CHIP, UnknownChip, agb_mask_rom_tsop_ii_44_3v3, analog, battery,
+        eeprom_sop_8_3v3, eeprom_tssop_8_5v, flash_tsop_i_32_3v3, flash_tsop_i_40_5v,
+        fram_sop_28_3v3, gb_mask_rom_glop_top_28_5v, gb_mask_rom_qfp_44_5v, gb_mask_rom_sop_32_5v,
+        gb_mask_rom_sop_44_5v, gb_mask_rom_tsop_i_32_5v, gb_mask_rom_tsop_ii_44_5v, hex_inverter,
+        huc1_qfp32, huc3_qfp48, line_decoder, mbc1_glop_top, mbc1_sop24, mbc2_sop28, mbc3_qfp32,
+        mbc5_qfp32, mbc6_qfp64, mbc7_qfp56, mbc30_qfp32, mmm01_qfp32, rtc_crystal, rtc_sop_8,
+        rtc_sop_20, sram_sop_28_3v3, sram_sop_28_5v, sram_sop_32_5v, sram_tsop_i_28_5v,
+        supervisor_reset, toshiba, unknown,
     },
+    time::ProductionWindow,
 };
-use anyhow::Error;
+use anyhow::{Context as _, Error, anyhow, bail, ensure};
 use serde::{Deserialize, Serialize};
 use serde_json;
 use std::{
     collections::{BTreeMap, HashMap},
     fmt,
-    fs::File,
-    io::{BufReader, BufWriter},
+    fs::{self, File},
+    io::BufWriter,
+    iter,
     path::Path,
+    str::{self, FromStr},
     sync::OnceLock,
 };
+use time::macros::format_description;
 
 #[derive(Clone, Debug, Eq, PartialEq, Hash, Deserialize, Serialize)]
 pub struct GameConfig {
@@ -45,9 +52,31 @@ pub struct GameConfig {
     pub no_intro_id: String,
     #[serde(default, skip_serializing_if = "String::is_empty")]
     pub no_intro_clone_of: String,
+    /// Release region, e.g. "Japan" or "USA, Europe"
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub region: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub languages: Vec<Language>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub publisher: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub release_date: Option<ReleaseDate>,
+    /// Size of the game ROM in bytes
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rom_size: Option<u32>,
+    /// Size of the save RAM in bytes, one of `GameConfig::RAM_SIZES`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ram_size: Option<u32>,
+    /// Mapper type expected from the ROM header, e.g. "MBC3"
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mapper: Option<MapperType>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub save_type: Option<SaveType>,
 }
 
 impl GameConfig {
+    /// Save RAM sizes used by games: 512 B and 8 KiB EEPROM, 2-128 KiB SRAM, and 64 or 128 KiB flash
+    pub const RAM_SIZES: [u32; 6] = [512, 2048, 8192, 32768, 65536, 131072];
     pub fn is_variant_of(&self, other: &GameConfig) -> bool {
         let is_no_intro_clone = !self.no_intro_id.is_empty()
             && !other.no_intro_id.is_empty()
@@ -55,6 +84,206 @@ impl GameConfig {
                 || other.no_intro_id == self.no_intro_clone_of);
         self.platform == other.platform && (self.name == other.name || is_no_intro_clone)
     }
+    /// Returns true if the game has at least one hash, and all of its hashes match
+    pub fn matches_hashes(&self, hashes: &Hashes) -> bool {
+        let checks = [
+            self.crc32.map(|crc32| crc32 == hashes.crc32),
+            self.md5.map(|md5| md5 == hashes.md5),
+            self.sha1.map(|sha1| sha1 == hashes.sha1),
+            self.sha256.map(|sha256| sha256 == hashes.sha256),
+        ];
+        checks.iter().any(Option::is_some) && checks.into_iter().flatten().all(|matches| matches)
+    }
+}
+
+/// No-Intro language code, e.g. "En" or "Ja"
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Deserialize, Serialize)]
+#[serde(into = "String", try_from = "String")]
+pub struct Language([u8; 2]);
+
+impl Language {
+    pub fn as_str(&self) -> &str {
+        str::from_utf8(&self.0).unwrap_or_default()
+    }
+}
+
+impl FromStr for Language {
+    type Err = ParseError;
+
+    /// ```
+    /// use gbhwdb_model::config::cartridge::Language;
+    /// assert_eq!("Ja".parse::<Language>().unwrap().as_str(), "Ja");
+    /// assert!("ja".parse::<Language>().is_err());
+    /// assert!("Eng".parse::<Language>().is_err());
+    /// ```
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        match *text.as_bytes() {
+            [first, second] if first.is_ascii_uppercase() && second.is_ascii_lowercase() => {
+                Ok(Language([first, second]))
+            }
+            _ => Err(ParseError("invalid language code")),
+        }
+    }
+}
+
+impl TryFrom<String> for Language {
+    type Error = ParseError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl From<Language> for String {
+    fn from(language: Language) -> Self {
+        language.to_string()
+    }
+}
+
+impl fmt::Display for Language {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Release date, with only the year or month if the exact date is not known
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Deserialize, Serialize)]
+#[serde(into = "String", try_from = "String")]
+pub enum ReleaseDate {
+    Year(u16),
+    Month(u16, time::Month),
+    Day(time::Date),
+}
+
+impl FromStr for ReleaseDate {
+    type Err = ParseError;
+
+    /// Parses a date written as YYYY-MM-DD, YYYY-MM or YYYY.
+    ///
+    /// ```
+    /// use gbhwdb_model::config::cartridge::ReleaseDate;
+    /// let date = |text: &str| text.parse::<ReleaseDate>().map(|date| date.to_string());
+    /// assert_eq!(date("2000-10-15").unwrap(), "2000-10-15");
+    /// assert_eq!(date("2000-10").unwrap(), "2000-10");
+    /// assert_eq!(date("2000").unwrap(), "2000");
+    /// assert!(date("2001-02-29").is_err());
+    /// assert!(date("2000-13").is_err());
+    /// assert!(date("October 2000").is_err());
+    /// ```
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        const ERROR: ParseError = ParseError("invalid release date");
+        let number = |text: &str, digits: usize| {
+            if text.len() == digits && text.bytes().all(|b| b.is_ascii_digit()) {
+                text.parse::<u16>().map_err(|_| ERROR)
+            } else {
+                Err(ERROR)
+            }
+        };
+        match *text.split('-').collect::<Vec<_>>() {
+            [year] => Ok(ReleaseDate::Year(number(year, 4)?)),
+            [year, month] => {
+                let month = u8::try_from(number(month, 2)?).map_err(|_| ERROR)?;
+                let month = time::Month::try_from(month).map_err(|_| ERROR)?;
+                Ok(ReleaseDate::Month(number(year, 4)?, month))
+            }
+            [_, _, _] => time::Date::parse(text, format_description!("[year]-[month]-[day]"))
+                .map(ReleaseDate::Day)
+                .map_err(|_| ERROR),
+            _ => Err(ERROR),
+        }
+    }
+}
+
+impl TryFrom<String> for ReleaseDate {
+    type Error = ParseError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl From<ReleaseDate> for String {
+    fn from(date: ReleaseDate) -> Self {
+        date.to_string()
+    }
+}
+
+impl fmt::Display for ReleaseDate {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ReleaseDate::Year(year) => write!(f, "{year:04}"),
+            ReleaseDate::Month(year, month) => write!(f, "{year:04}-{:02}", *month as u8),
+            ReleaseDate::Day(date) => write!(
+                f,
+                "{:04}-{:02}-{:02}",
+                date.year(),
+                date.month() as u8,
+                date.day()
+            ),
+        }
+    }
+}
+
+/// Result of looking up a ROM dump in the game configs
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum RomIdentity<'a> {
+    /// The game with the same hashes
+    Game(&'a GameConfig),
+    /// No game has the same hashes, but these are the expected game and its variants
+    Variants(Vec<&'a GameConfig>),
+    Unknown,
+}
+
+/// Looks up a ROM dump by its hashes.
+///
+/// If no game matches, the expected ROM ID (e.g. from the cartridge label or the ROM header) is
+/// used to list the nearest variants.
+///
+/// ```
+/// use gbhwdb_model::config::cartridge::{GameConfig, RomIdentity, identify_rom};
+/// use gbhwdb_model::hash::Hashes;
+/// # use std::collections::BTreeMap;
+/// # fn game(rom_id: &str, name: &str, crc32: &str) -> (String, GameConfig) {
+/// #     let mut cfg: GameConfig = serde_json::from_value(serde_json::json!({
+/// #         "name": name, "rom_verified": false, "platform": "gb", "no_intro_id": "",
+/// #         "crc32": crc32,
+/// #     })).unwrap();
+/// #     cfg.rom_id = rom_id.to_owned();
+/// #     (rom_id.to_owned(), cfg)
+/// # }
+/// let hashes = Hashes::from_reader(&b"abc"[..]).unwrap();
+/// let cfgs = BTreeMap::from([
+///     game("DMG-AAAA-0", "Game (Japan)", "352441c2"),
+///     game("DMG-BBBB-0", "Other (Japan)", "00000000"),
+///     game("DMG-BBBB-1", "Other (Japan)", "11111111"),
+/// ]);
+/// assert_eq!(identify_rom(&cfgs, &hashes, None), RomIdentity::Game(&cfgs["DMG-AAAA-0"]));
+/// let hashes = Hashes::from_reader(&b"abcd"[..]).unwrap();
+/// assert_eq!(
+///     identify_rom(&cfgs, &hashes, Some("DMG-BBBB-1")),
+///     RomIdentity::Variants(vec![&cfgs["DMG-BBBB-1"], &cfgs["DMG-BBBB-0"]])
+/// );
+/// assert_eq!(identify_rom(&cfgs, &hashes, None), RomIdentity::Unknown);
+/// ```
+pub fn identify_rom<'a>(
+    cfgs: &'a BTreeMap<String, GameConfig>,
+    hashes: &Hashes,
+    expected: Option<&str>,
+) -> RomIdentity<'a> {
+    if let Some(cfg) = cfgs.values().find(|cfg| cfg.matches_hashes(hashes)) {
+        return RomIdentity::Game(cfg);
+    }
+    match expected.and_then(|rom_id| cfgs.get(rom_id)) {
+        Some(expected) => RomIdentity::Variants(
+            iter::once(expected)
+                .chain(
+                    cfgs.values()
+                        .filter(|cfg| cfg.rom_id != expected.rom_id && cfg.is_variant_of(expected)),
+                )
+                .collect(),
+        ),
+        None => RomIdentity::Unknown,
+    }
 }
 
 #[derive(Copy, Clone, Debug, Eq, PartialEq, Hash, Deserialize, Serialize)]
@@ -89,6 +318,14 @@ impl GamePlatform {
             GamePlatform::Gba => false,
         }
     }
+    /// Years during which cartridges for the platform were manufactured
+    pub const fn production_window(&self) -> ProductionWindow {
+        match self {
+            GamePlatform::Gb => ProductionWindow::new(1989, 2003),
+            GamePlatform::Gbc => ProductionWindow::new(1997, 2005),
+            GamePlatform::Gba => ProductionWindow::new(2000, 2008),
+        }
+    }
 }
 
 impl fmt::Display for GamePlatform {
@@ -101,734 +338,372 @@ impl fmt::Display for GamePlatform {
     }
 }
 
-#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+/// Type of the part that stores a game's saves.
+///
+/// Game Boy and Game Boy Color saves are either SRAM or EEPROM. Game Boy Advance games are
+/// identified by the save library linked into the game, see `rom_header::agb::detect_save_type`.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash, Deserialize, Serialize)]
+pub enum SaveType {
+    #[serde(rename = "eeprom")]
+    Eeprom,
+    #[serde(rename = "sram")]
+    Sram,
+    /// 64 KiB flash, identified by the older `FLASH_V` library
+    #[serde(rename = "flash")]
+    Flash,
+    /// 64 KiB flash
+    #[serde(rename = "flash512")]
+    Flash512,
+    /// 128 KiB flash
+    #[serde(rename = "flash1m")]
+    Flash1M,
+}
+
+impl SaveType {
+    pub const ALL: [SaveType; 5] = [
+        SaveType::Eeprom,
+        SaveType::Sram,
+        SaveType::Flash,
+        SaveType::Flash512,
+        SaveType::Flash1M,
+    ];
+    pub const fn name(&self) -> &'static str {
+        match self {
+            SaveType::Eeprom => "EEPROM",
+            SaveType::Sram => "SRAM",
+            SaveType::Flash => "Flash",
+            SaveType::Flash512 => "Flash 512 Kibit",
+            SaveType::Flash1M => "Flash 1 Mibit",
+        }
+    }
+    /// Returns true if the board has the part that stores this save type.
+    ///
+    /// SRAM saves can be stored in either SRAM or FRAM, or in the RAM built into MBC2. The parts of
+    /// the board revision are checked, if the label has one.
+    ///
+    /// ```
+    /// use gbhwdb_model::config::cartridge::{BoardLabel, SaveType};
+    /// let board = |label| BoardLabel::parse(label).unwrap();
+    /// assert!(SaveType::fits_board(Some(SaveType::Eeprom), &board("AGB-E03-01")));
+    /// assert!(SaveType::fits_board(Some(SaveType::Sram), &board("AGB-E11")));
+    /// assert!(!SaveType::fits_board(Some(SaveType::Flash1M), &board("AGB-E01-01")));
+    /// assert!(!SaveType::fits_board(None, &board("AGB-E02")));
+    /// assert!(SaveType::fits_board(Some(SaveType::Sram), &board("DMG-GDAN-03")));
+    /// ```
+    pub fn fits_board(save_type: Option<SaveType>, board: &BoardLabel) -> bool {
+        let has_ram = board.parts().any(|(_, part)| {
+            matches!(part, BoardPart::Ram(_)) || part.mapper_type() == Some(MapperType::Mbc2)
+        });
+        let has_eeprom = board
+            .parts()
+            .any(|(_, part)| matches!(part, BoardPart::Eeprom(_)));
+        let has_flash = board
+            .parts()
+            .any(|(_, part)| matches!(part, BoardPart::Flash(_)));
+        match save_type {
+            None => !has_ram && !has_eeprom && !has_flash,
+            Some(SaveType::Eeprom) => has_eeprom,
+            Some(SaveType::Sram) => has_ram,
+            Some(SaveType::Flash | SaveType::Flash512 | SaveType::Flash1M) => has_flash,
+        }
+    }
+}
+
+impl fmt::Display for SaveType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Deserialize)]
 pub enum BatteryType {
+    #[serde(rename = "CR1616")]
     Cr1616,
+    #[serde(rename = "CR2025")]
     Cr2025,
 }
 
-#[derive(Copy, Clone, Debug, Eq, PartialEq)]
-pub enum BoardConfig {
-    AgbArc,
-    AgbE01,
-    AgbE02,
-    AgbE03,
-    AgbE05,
-    AgbE06,
-    AgbE11,
-    AgbE12,
-    AgbE18,
-    AgbE24,
-    AgbY11,
-    Tama,
-    Aaac,
-    Bbac,
-    CgbA32,
-    DmgA02,
-    DmgA03,
-    DmgA04,
-    DmgA06,
-    DmgA07,
-    DmgA08,
-    DmgA09,
-    DmgA10,
-    DmgA11,
-    DmgA12,
-    DmgA14,
-    DmgA15,
-    DmgA16,
-    DmgA18,
-    DmgA40,
-    DmgA47,
-    DmgAaa,
-    DmgBba,
-    DmgBca,
-    DmgBean,
-    DmgBfan,
-    DmgDecn,
-    DmgDedn,
-    DmgDfcn,
-    DmgDgcu,
-    DmgGdan,
-    DmgKecn,
-    DmgKfcn,
-    DmgKfdn,
-    DmgKgdu,
-    DmgLfdn,
-    DmgMBfan,
-    DmgMcDfcn,
-    DmgMcSfcn,
-    DmgMheu,
-    DmgTedn,
-    DmgTfdn,
-    DmgUedt,
-    DmgUfdt,
-    DmgUgdu,
-    DmgZ01,
-    DmgZ02,
-    DmgZ03,
-    DmgZ04,
+/// Cartridge board layout, as defined in `config/boards.json`
+#[derive(Clone, Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct BoardDefinition {
+    /// Board label without the revision suffix, e.g. "DMG-BEAN"
+    pub label: String,
+    /// Other labels of the same layout, e.g. "DMG-BEAN(K)"
+    #[serde(default)]
+    pub aliases: Vec<String>,
+    /// Platforms of the games that can be found on the board
+    pub platforms: Vec<GamePlatform>,
+    #[serde(default)]
+    pub battery: Option<BatteryType>,
+    pub parts: BTreeMap<PartDesignator, PartDefinition>,
+    /// Part differences in specific board revisions (e.g. "02"), with null for parts that the
+    /// revision doesn't have
+    #[serde(default)]
+    pub revisions: BTreeMap<String, BTreeMap<PartDesignator, Option<PartDefinition>>>,
 }
 
-impl BoardConfig {
-    pub fn part(&self, designator: PartDesignator) -> Option<BoardPart> {
-        use PartDesignator as D;
+#[derive(Clone, Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct PartDefinition {
+    pub role: PartRole,
+    /// Name of the parser family in `gbhwdb_model::parser` (e.g. "mbc5_qfp32"), or the path of a
+    /// single parser (e.g. "toshiba::TOSHIBA_TAMA5")
+    pub parser: String,
+    /// Package of a game ROM part (e.g. "SOP-32"), which the parsed ROM type must match
+    #[serde(default)]
+    pub package: Option<Package>,
+    /// Mapper type of a mapper part (e.g. "MBC5")
+    #[serde(default)]
+    pub mapper: Option<MapperType>,
+    /// Package and chip type (e.g. "QFP-32 MBC5"), or a description of the component
+    #[serde(default)]
+    pub comment: Option<String>,
+}
 
-        match self {
-            BoardConfig::AgbArc => match designator {
-                // TSOP-II-44 ROM
-                D::U1 => Some(BoardPart::Rom(agb_mask_rom_tsop_ii_44_3v3())),
-                // SOP-28 FRAM
-                D::U2 => Some(BoardPart::Ram(fram_sop_28_3v3())),
-                _ => None,
-            },
-            BoardConfig::AgbE01 => match designator {
-                // TSOP-II-44 ROM
-                D::U1 => Some(BoardPart::Rom(agb_mask_rom_tsop_ii_44_3v3())),
-                _ => None,
-            },
-            BoardConfig::AgbE02 => match designator {
-                // TSOP-II-44 ROM
-                D::U1 => Some(BoardPart::Rom(agb_mask_rom_tsop_ii_44_3v3())),
-                // TSOP-I-32 Flash
-                D::U2 => Some(BoardPart::Flash(flash_tsop_i_32_3v3())),
-                _ => None,
-            },
-            BoardConfig::AgbE03 => match designator {
-                // TSOP-II-44 ROM
-                D::U1 => Some(BoardPart::Rom(agb_mask_rom_tsop_ii_44_3v3())),
-                // SOP-8 EEPROM
-                D::U2 => Some(BoardPart::Eeprom(eeprom_sop_8_3v3())),
-                _ => None,
-            },
-            BoardConfig::AgbE05 => match designator {
-                // TSOP-II-44 ROM
-                D::U1 => Some(BoardPart::Rom(agb_mask_rom_tsop_ii_44_3v3())),
-                // TSOP-I-32 Flash
-                D::U2 => Some(BoardPart::Flash(flash_tsop_i_32_3v3())),
-                // SOP-8 RTC
-                D::U3 => Some(BoardPart::Rtc(rtc_sop_8())),
-                D::X1 => Some(BoardPart::Crystal(rtc_crystal())),
-                _ => None,
-            },
-            BoardConfig::AgbE06 => match designator {
-                // TSOP-II-44 ROM
-                D::U1 => Some(BoardPart::Rom(agb_mask_rom_tsop_ii_44_3v3())),
-                // SOP-28 RAM
-                D::U2 => Some(BoardPart::Ram(sram_sop_28_3v3())),
-                // SOP-8 BU9803F
-                D::U3 => Some(BoardPart::SupervisorReset(supervisor_reset())),
-                _ => None,
-            },
-            BoardConfig::AgbE11 | BoardConfig::AgbY11 => match designator {
-                // TSOP-II-44 ROM
-                D::U1 => Some(BoardPart::Rom(agb_mask_rom_tsop_ii_44_3v3())),
-                // SOP-28 FRAM
-                D::U2 => Some(BoardPart::Ram(fram_sop_28_3v3())),
-                _ => None,
-            },
-            BoardConfig::AgbE12 => match designator {
-                // QFP-32
-                D::U1 => Some(BoardPart::Unknown(&UNKNOWN_CHIP)),
-                // TSOP-II-44 ROM
-                D::U2 => Some(BoardPart::Rom(agb_mask_rom_tsop_ii_44_3v3())),
-                // SOP-8 EEPROM
-                D::U3 => Some(BoardPart::Eeprom(eeprom_sop_8_3v3())),
-                D::U4 => Some(BoardPart::Accelerometer(&analog::ANALOG_ADXL202JE)),
-                _ => None,
-            },
-            BoardConfig::AgbE18 => match designator {
-                // TSOP-II-44 ROM
-                D::U1 => Some(BoardPart::Rom(agb_mask_rom_tsop_ii_44_3v3())),
-                // SOP-8 EEPROM
-                D::U2 => Some(BoardPart::Eeprom(eeprom_sop_8_3v3())),
-                // SOP-8 RTC
-                D::U3 => Some(BoardPart::Rtc(rtc_sop_8())),
-                D::U4 => Some(BoardPart::Unknown(&UNKNOWN_CHIP)),
-                D::U5 => Some(BoardPart::Unknown(&UNKNOWN_CHIP)),
-                D::X1 => Some(BoardPart::Crystal(rtc_crystal())),
-                _ => None,
-            },
-            BoardConfig::AgbE24 => match designator {
-                // TSOP-II-44 ROM
-                D::U1 => Some(BoardPart::Rom(agb_mask_rom_tsop_ii_44_3v3())),
-                // SOP-8 EEPROM
-                D::U2 => Some(BoardPart::Eeprom(eeprom_sop_8_3v3())),
-                _ => None,
-            },
-            BoardConfig::Tama => match designator {
-                // SOP-32 TAMA7
-                D::U1 => Some(BoardPart::Rom(&unknown::UNKNOWN_TAMA7)),
-                // SOP-28 TAMA5
-                D::U2 => Some(BoardPart::Mapper(&toshiba::TOSHIBA_TAMA5)),
-                // SOP-28 TAMA6
-                D::U3 => Some(BoardPart::Mcu(&toshiba::TOSHIBA_TAMA6)),
-                // SOP-20
-                D::U4 => Some(BoardPart::Rtc(rtc_sop_20())),
-                // SOP-8 M62021P
-                D::U5 => Some(BoardPart::SupervisorReset(supervisor_reset())),
-                D::X1 => Some(BoardPart::Crystal(rtc_crystal())),
-                _ => None,
-            },
-            BoardConfig::Aaac => match designator {
-                // glop top ROM, 28 pads
-                D::U1 => Some(BoardPart::Rom(gb_mask_rom_glop_top_28_5v())),
-                _ => None,
-            },
-            BoardConfig::Bbac => match designator {
-                // glop top ROM, 28 pads
-                D::U1 => Some(BoardPart::Rom(gb_mask_rom_glop_top_28_5v())),
-                // glop top MBC1B
-                D::U2 => Some(BoardPart::Mapper(mbc1_glop_top())),
-                _ => None,
-            },
-            BoardConfig::CgbA32 => match designator {
-                // QFP-64 MBC6
-                D::U1 => Some(BoardPart::Mapper(mbc6_qfp64())),
-                // SOP-32 ROM
-                D::U2 => Some(BoardPart::Rom(gb_mask_rom_sop_32_5v())),
-                // TSOP-I-40 Flash
-                D::U3 => Some(BoardPart::Flash(flash_tsop_i_40_5v())),
-                // SOP-28 RAM
-                D::U4 => Some(BoardPart::Ram(sram_sop_28_5v())),
-                // SOP-8 MM1134
-                D::U5 => Some(BoardPart::SupervisorReset(supervisor_reset())),
-                _ => None,
-            },
-            BoardConfig::DmgA02 => match designator {
-                // SOP-32 ROM
-                D::U1 => Some(BoardPart::Rom(gb_mask_rom_sop_32_5v())),
-                // QFP-32 MBC5
-                D::U2 => Some(BoardPart::Mapper(mbc5_qfp32())),
-                // SOP-28 RAM
-                D::U3 => Some(BoardPart::Ram(sram_sop_28_5v())),
-                // SOP-8 MM1134A
-                D::U4 => Some(BoardPart::SupervisorReset(supervisor_reset())),
-                _ => None,
-            },
-            BoardConfig::DmgA03 | BoardConfig::DmgA08 => match designator {
-                // TSOP-II-44 ROM
-                D::U1 => Some(BoardPart::Rom(gb_mask_rom_tsop_ii_44_5v())),
-                // QFP-32 MBC5
-                D::U2 => Some(BoardPart::Mapper(mbc5_qfp32())),
-                // SOP-28 RAM
-                D::U3 => Some(BoardPart::Ram(sram_sop_28_5v())),
-                // SOP-8 MM1134A
-                D::U4 => Some(BoardPart::SupervisorReset(supervisor_reset())),
-                _ => None,
-            },
-            BoardConfig::DmgA04 => match designator {
-                // TSOP-I-32 ROM
-                D::U1 => Some(BoardPart::Rom(gb_mask_rom_tsop_i_32_5v())),
-                // QFP-32 MBC5
-                D::U2 => Some(BoardPart::Mapper(mbc5_qfp32())),
-                // SOP-28 RAM
-                D::U3 => Some(BoardPart::Ram(sram_sop_28_5v())),
-                // SOP-8 MM1134A
-                D::U4 => Some(BoardPart::SupervisorReset(supervisor_reset())),
-                // MOT1 => motor
-                _ => None,
-            },
-            BoardConfig::DmgA06 => match designator {
-                // SOP-32 ROM
-                D::U1 => Some(BoardPart::Rom(gb_mask_rom_sop_32_5v())),
-                // QFP-32 MBC5
-                D::U2 => Some(BoardPart::Mapper(mbc5_qfp32())),
-                // SOP-28 RAM
-                D::U3 => Some(BoardPart::Ram(sram_sop_28_5v())),
-                // SOP-8 MM1134A
-                D::U4 => Some(BoardPart::SupervisorReset(supervisor_reset())),
-                _ => None,
-            },
-            BoardConfig::DmgA07 => match designator {
-                // SOP-32 ROM
-                D::U1 => Some(BoardPart::Rom(gb_mask_rom_sop_32_5v())),
-                // QFP-32 MBC5
-                D::U2 => Some(BoardPart::Mapper(mbc5_qfp32())),
-                _ => None,
-            },
-            BoardConfig::DmgA09 => match designator {
-                // TSOP-II-44 ROM
-                D::U1 => Some(BoardPart::Rom(gb_mask_rom_tsop_ii_44_5v())),
-                // QFP-32 MBC5
-                D::U2 => Some(BoardPart::Mapper(mbc5_qfp32())),
-                _ => None,
-            },
-            BoardConfig::DmgA10 => match designator {
-                // TSOP-I-32 ROM
-                D::U1 => Some(BoardPart::Rom(gb_mask_rom_tsop_i_32_5v())),
-                // QFP-32 MBC5
-                D::U2 => Some(BoardPart::Mapper(mbc5_qfp32())),
-                // MOT1 => motor
-                _ => None,
-            },
-            BoardConfig::DmgA11 => match designator {
-                // TSOP-II-44 ROM
-                D::U1 => Some(BoardPart::Rom(gb_mask_rom_tsop_ii_44_5v())),
-                // QFP-32 MBC5
-                D::U2 => Some(BoardPart::Mapper(mbc5_qfp32())),
-                // SOP-28 RAM
-                D::U3 => Some(BoardPart::Ram(sram_sop_28_5v())),
-                // SOP-8 MM1134A
-                D::U4 => Some(BoardPart::SupervisorReset(supervisor_reset())),
-                // MOT1 => motor
-                _ => None,
-            },
-            BoardConfig::DmgA12 => match designator {
-                // TSOP-II-44 ROM
-                D::U1 => Some(BoardPart::Rom(gb_mask_rom_tsop_ii_44_5v())),
-                // QFP-32 MBC5
-                D::U2 => Some(BoardPart::Mapper(mbc5_qfp32())),
-                // SOP-28 RAM
-                D::U3 => Some(BoardPart::Ram(sram_sop_28_5v())),
-                // SOP-8 MM1134A
-                D::U4 => Some(BoardPart::SupervisorReset(supervisor_reset())),
-                // MOT1 => motor
-                _ => None,
-            },
-            BoardConfig::DmgA14 => match designator {
-                // TSOP-II-44 ROM
-                D::U1 => Some(BoardPart::Rom(gb_mask_rom_tsop_ii_44_5v())),
-                // QFP-32 MBC5
-                D::U2 => Some(BoardPart::Mapper(mbc5_qfp32())),
-                // SOP-32 RAM
-                D::U3 => Some(BoardPart::Ram(sram_sop_32_5v())),
-                // SOP-8 MM1134A
-                D::U4 => Some(BoardPart::SupervisorReset(supervisor_reset())),
-                _ => None,
-            },
-            BoardConfig::DmgA15 => match designator {
-                // TSOP-II-44 ROM
-                D::U1 => Some(BoardPart::Rom(gb_mask_rom_tsop_ii_44_5v())),
-                // QFP-32 MBC5
-                D::U2 => Some(BoardPart::Mapper(mbc5_qfp32())),
-                // SOP-28 RAM
-                D::U3 => Some(BoardPart::Ram(sram_sop_28_5v())),
-                // SOP-8 MM1134
-                D::U4 => Some(BoardPart::SupervisorReset(supervisor_reset())),
-                // TSOP-II-44 ROM
-                D::U5 => Some(BoardPart::Rom(gb_mask_rom_tsop_ii_44_5v())),
-                // SSOP-8
-                D::U6 => Some(BoardPart::LineDecoder(line_decoder())),
-                _ => None,
-            },
-            BoardConfig::DmgA16 => match designator {
-                // SOP-32 ROM
-                D::U1 => Some(BoardPart::Rom(gb_mask_rom_sop_32_5v())),
-                // QFP-32 MBC5
-                D::U2 => Some(BoardPart::Mapper(mbc5_qfp32())),
-                // SOP-32 RAM
-                D::U3 => Some(BoardPart::Ram(sram_sop_32_5v())),
-                // SOP-8 MM1134A
-                D::U4 => Some(BoardPart::SupervisorReset(supervisor_reset())),
-                _ => None,
-            },
-            BoardConfig::DmgA18 => match designator {
-                // SOP-44 ROM
-                D::U1 => Some(BoardPart::Rom(gb_mask_rom_sop_44_5v())),
-                // QFP-32 MBC5
-                D::U2 => Some(BoardPart::Mapper(mbc5_qfp32())),
-                _ => None,
-            },
-            BoardConfig::DmgA40 => match designator {
-                // QFP-56 MBC7
-                D::U1 => Some(BoardPart::Mapper(mbc7_qfp56())),
-                // SOP-32 ROM
-                D::U2 => Some(BoardPart::Rom(gb_mask_rom_sop_32_5v())),
-                // TSSOP-8 EEPROM
-                D::U3 => Some(BoardPart::Eeprom(eeprom_tssop_8_5v())),
-                // QC-14 accelerometer
-                D::U4 => Some(BoardPart::Accelerometer(&analog::ANALOG_ADXL202JQC)),
-                _ => None,
-            },
-            BoardConfig::DmgA47 => match designator {
-                // QFP-56 MBC7
-                D::U1 => Some(BoardPart::Mapper(mbc7_qfp56())),
-                // TSOP-II-44 ROM
-                D::U2 => Some(BoardPart::Rom(gb_mask_rom_tsop_ii_44_5v())),
-                // TSSOP-8 EEPROM
-                D::U3 => Some(BoardPart::Eeprom(eeprom_tssop_8_5v())),
-                // QC-14 accelerometer
-                D::U4 => Some(BoardPart::Accelerometer(&analog::ANALOG_ADXL202JQC)),
-                _ => None,
-            },
-            BoardConfig::DmgAaa => match designator {
-                // QFP-44 ROM, LH53259-compatible pinout
-                D::U1 => Some(BoardPart::Rom(gb_mask_rom_qfp_44_5v())),
-                _ => None,
-            },
-            BoardConfig::DmgBba | BoardConfig::DmgBca => match designator {
-                // QFP-44 ROM
-                D::U1 => Some(BoardPart::Rom(gb_mask_rom_qfp_44_5v())),
-                // SOP-24 MBC1
-                D::U2 => Some(BoardPart::Mapper(mbc1_sop24())),
-                _ => None,
-            },
-            BoardConfig::DmgBean | BoardConfig::DmgBfan | BoardConfig::DmgMBfan => match designator
-            {
-                // SOP-32 ROM
-                D::U1 => Some(BoardPart::Rom(gb_mask_rom_sop_32_5v())),
-                // SOP-24 MBC1
-                D::U2 => Some(BoardPart::Mapper(mbc1_sop24())),
-                _ => None,
-            },
-            BoardConfig::DmgDecn
-            | BoardConfig::DmgDfcn
-            | BoardConfig::DmgDedn
-            | BoardConfig::DmgMcDfcn => {
-                match designator {
-                    // SOP-32 ROM
-                    D::U1 => Some(BoardPart::Rom(gb_mask_rom_sop_32_5v())),
-                    // SOP-24 MBC1
-                    D::U2 => Some(BoardPart::Mapper(mbc1_sop24())),
-                    // SOP-28 RAM
-                    D::U3 => Some(BoardPart::Ram(sram_sop_28_5v())),
-                    // SOP-8 26A
-                    D::U4 => Some(BoardPart::SupervisorReset(supervisor_reset())),
-                    _ => None,
+struct Board {
+    definition: BoardDefinition,
+    parts: Vec<(PartDesignator, BoardPart)>,
+    /// Parts of revisions that differ from the base layout
+    revisions: BTreeMap<String, Vec<(PartDesignator, BoardPart)>>,
+}
+
+struct Boards {
+    boards: Vec<Board>,
+    by_label: HashMap<String, usize>,
+}
+
+/// Parses board definitions from JSON, and checks that their parts and labels are valid
+fn load_boards(json: &str) -> Result<Boards, Error> {
+    let definitions: Vec<BoardDefinition> = serde_json::from_str(json)?;
+    let mut boards = Vec::with_capacity(definitions.len());
+    let mut by_label = HashMap::new();
+    for definition in definitions {
+        let index = boards.len();
+        for label in iter::once(&definition.label).chain(&definition.aliases) {
+            if let Some(other) = by_label.insert(label.clone(), index) {
+                let other = &boards[other];
+                bail!(
+                    "duplicate board label {label:?} in {} and {}",
+                    Board::label(other),
+                    definition.label
+                );
+            }
+        }
+        let resolve = |parts: &BTreeMap<PartDesignator, PartDefinition>, label: &str| {
+            parts
+                .iter()
+                .map(|(&designator, part)| {
+                    let board_part = BoardPart::resolve(part)
+                        .with_context(|| format!("{label} {}", designator.as_str()))?;
+                    Ok((designator, board_part))
+                })
+                .collect::<Result<Vec<_>, Error>>()
+        };
+        ensure!(
+            !definition.platforms.is_empty(),
+            "{}: the board has no platforms",
+            definition.label
+        );
+        let parts = resolve(&definition.parts, &definition.label)?;
+        let has_battery = definition
+            .parts
+            .values()
+            .any(|part| part.role == PartRole::Battery);
+        ensure!(
+            definition.battery.is_some() == has_battery,
+            "{}: the battery type and a battery part must be defined together",
+            definition.label
+        );
+        let mut revisions = BTreeMap::new();
+        for (revision, changes) in &definition.revisions {
+            let label = format!("{}-{revision}", definition.label);
+            ensure!(
+                is_board_revision(revision),
+                "{label}: revision must be a number"
+            );
+            let mut revision_parts = definition.parts.clone();
+            for (&designator, part) in changes {
+                match part {
+                    Some(part) => {
+                        revision_parts.insert(designator, part.clone());
+                    }
+                    None => {
+                        ensure!(
+                            revision_parts.remove(&designator).is_some(),
+                            "{label} {}: the board has no such part",
+                            designator.as_str()
+                        );
+                    }
                 }
             }
-            BoardConfig::DmgDgcu => match designator {
-                // TSOP-II-44 ROM
-                D::U1 => Some(BoardPart::Rom(gb_mask_rom_tsop_ii_44_5v())),
-                // SOP-24 MBC1
-                D::U2 => Some(BoardPart::Mapper(mbc1_sop24())),
-                // SOP-28 RAM
-                D::U3 => Some(BoardPart::Ram(sram_sop_28_5v())),
-                // SOP-8 MM1134A
-                D::U4 => Some(BoardPart::SupervisorReset(supervisor_reset())),
-                _ => None,
-            },
-            BoardConfig::DmgGdan => match designator {
-                // SOP-32 ROM
-                D::U1 => Some(BoardPart::Rom(gb_mask_rom_sop_32_5v())),
-                // SOP-28 MBC2
-                D::U2 => Some(BoardPart::Mapper(mbc2_sop28())),
-                // SOP-8 26A
-                D::U3 => Some(BoardPart::SupervisorReset(supervisor_reset())),
-                _ => None,
-            },
-            BoardConfig::DmgKecn => match designator {
-                // SOP-32 ROM
-                D::U1 => Some(BoardPart::Rom(gb_mask_rom_sop_32_5v())),
-                // QFP-32 MBC3
-                D::U2 => Some(BoardPart::Mapper(mbc3_qfp32())),
-                // SOP-28 RAM
-                D::U3 => Some(BoardPart::Ram(sram_sop_28_5v())),
-                // SOP-8 26A / MM1134
-                D::U4 => Some(BoardPart::SupervisorReset(supervisor_reset())),
-                D::X1 => Some(BoardPart::Crystal(rtc_crystal())),
-                _ => None,
-            },
-            BoardConfig::DmgKfcn | BoardConfig::DmgKfdn => match designator {
-                // SOP-32 ROM
-                D::U1 => Some(BoardPart::Rom(gb_mask_rom_sop_32_5v())),
-                // QFP-32 MBC3
-                D::U2 => Some(BoardPart::Mapper(mbc3_qfp32())),
-                // SOP-28 RAM
-                D::U3 => Some(BoardPart::Ram(sram_sop_28_5v())),
-                // SOP-8 MM1134
-                D::U4 => Some(BoardPart::SupervisorReset(supervisor_reset())),
-                D::X1 => Some(BoardPart::Crystal(rtc_crystal())),
-                _ => None,
-            },
-            BoardConfig::DmgKgdu => match designator {
-                // TSOP-II-44 ROM
-                D::U1 => Some(BoardPart::Rom(gb_mask_rom_tsop_ii_44_5v())),
-                // QFP-32 MBC3
-                D::U2 => Some(BoardPart::Mapper(mbc3_qfp32())),
-                // SOP-28 RAM
-                D::U3 => Some(BoardPart::Ram(sram_sop_28_5v())),
-                // SOP-8 MM1134
-                D::U4 => Some(BoardPart::SupervisorReset(supervisor_reset())),
-                D::X1 => Some(BoardPart::Crystal(rtc_crystal())),
-                _ => None,
-            },
-            BoardConfig::DmgLfdn => match designator {
-                // SOP-32 ROM
-                D::U1 => Some(BoardPart::Rom(gb_mask_rom_sop_32_5v())),
-                // QFP-32 MBC3
-                D::U2 => Some(BoardPart::Mapper(mbc3_qfp32())),
-                // SOP-28 RAM
-                D::U3 => Some(BoardPart::Ram(sram_sop_28_5v())),
-                // SOP-8 MM1134
-                D::U4 => Some(BoardPart::SupervisorReset(supervisor_reset())),
-                _ => None,
-            },
-            BoardConfig::DmgMcSfcn => match designator {
-                // SOP-32 ROM
-                D::U1 => Some(BoardPart::Rom(gb_mask_rom_sop_32_5v())),
-                // QFP-32 MMM01
-                D::U2 => Some(BoardPart::Mapper(mmm01_qfp32())),
-                // SOP-28 RAM
-                D::U3 => Some(BoardPart::Ram(sram_sop_28_5v())),
-                // SOP-8 26A
-                D::U4 => Some(BoardPart::SupervisorReset(supervisor_reset())),
-                _ => None,
-            },
-            BoardConfig::DmgMheu => match designator {
-                // TSOP-II-44 ROM
-                D::U1 => Some(BoardPart::Rom(gb_mask_rom_tsop_ii_44_5v())),
-                // QFP-32 MBC30
-                D::U2 => Some(BoardPart::Mapper(mbc30_qfp32())),
-                // SOP-32 RAM
-                D::U3 => Some(BoardPart::Ram(sram_sop_32_5v())),
-                // SOP-8 MM1134
-                D::U4 => Some(BoardPart::SupervisorReset(supervisor_reset())),
-                D::X1 => Some(BoardPart::Crystal(rtc_crystal())),
-                _ => None,
-            },
-            BoardConfig::DmgTedn => match designator {
-                // SOP-32 ROM
-                D::U1 => Some(BoardPart::Rom(gb_mask_rom_sop_32_5v())),
-                // QFP-32 HuC-1
-                D::U2 => Some(BoardPart::Mapper(huc1_qfp32())),
-                // SOP-28 RAM
-                D::U3 => Some(BoardPart::Ram(sram_sop_28_5v())),
-                // SOP-8 26A
-                D::U4 => Some(BoardPart::SupervisorReset(supervisor_reset())),
-                _ => None,
-            },
-            BoardConfig::DmgTfdn => match designator {
-                // SOP-32 ROM
-                D::U1 => Some(BoardPart::Rom(gb_mask_rom_sop_32_5v())),
-                // QFP-32 HuC-1
-                D::U2 => Some(BoardPart::Mapper(huc1_qfp32())),
-                // SOP-28 RAM
-                D::U3 => Some(BoardPart::Ram(sram_sop_28_5v())),
-                // SOP-8 1134A
-                D::U4 => Some(BoardPart::SupervisorReset(supervisor_reset())),
-                _ => None,
-            },
-            BoardConfig::DmgUedt => match designator {
-                // TSOP-I-32 ROM
-                D::U1 => Some(BoardPart::Rom(gb_mask_rom_tsop_i_32_5v())),
-                // QFP-48 HuC-3
-                D::U2 => Some(BoardPart::Mapper(huc3_qfp48())),
-                // TSOP-I-28 RAM
-                D::U3 => Some(BoardPart::Ram(sram_tsop_i_28_5v())),
-                // SOP-8 26A
-                D::U4 => Some(BoardPart::SupervisorReset(supervisor_reset())),
-                // TSSOP-14
-                D::U5 => Some(BoardPart::HexInverter(hex_inverter())),
-                D::X1 => Some(BoardPart::Crystal(rtc_crystal())),
-                _ => None,
-            },
-            BoardConfig::DmgUfdt => match designator {
-                // TSOP-I-32 ROM
-                D::U1 => Some(BoardPart::Rom(gb_mask_rom_tsop_i_32_5v())),
-                // QFP-48 HuC-3
-                D::U2 => Some(BoardPart::Mapper(huc3_qfp48())),
-                // TSOP-I-28 RAM
-                D::U3 => Some(BoardPart::Ram(sram_tsop_i_28_5v())),
-                // SOP-8 MM1134
-                D::U4 => Some(BoardPart::SupervisorReset(supervisor_reset())),
-                // TSSOP-14
-                D::U5 => Some(BoardPart::HexInverter(hex_inverter())),
-                D::X1 => Some(BoardPart::Crystal(rtc_crystal())),
-                _ => None,
-            },
-            BoardConfig::DmgUgdu => match designator {
-                // TSOP-II-44 ROM
-                D::U1 => Some(BoardPart::Rom(gb_mask_rom_tsop_ii_44_5v())),
-                // QFP-48 HuC-3
-                D::U2 => Some(BoardPart::Mapper(huc3_qfp48())),
-                // TSOP-I-28 RAM
-                D::U3 => Some(BoardPart::Ram(sram_tsop_i_28_5v())),
-                // SOP-8 MM1134
-                D::U4 => Some(BoardPart::SupervisorReset(supervisor_reset())),
-                // TSSOP-14
-                D::U5 => Some(BoardPart::HexInverter(hex_inverter())),
-                D::X1 => Some(BoardPart::Crystal(rtc_crystal())),
-                _ => None,
-            },
-            BoardConfig::DmgZ01 => match designator {
-                // TSOP-II-44 ROM
-                D::U1 => Some(BoardPart::Rom(gb_mask_rom_tsop_ii_44_5v())),
-                // QFP-32 MBC5
-                D::U2 => Some(BoardPart::Mapper(mbc5_qfp32())),
-                // SOP-28 RAM
-                D::U3 => Some(BoardPart::Ram(sram_sop_28_5v())),
-                // SOP-8 MM1134A
-                D::U4 => Some(BoardPart::SupervisorReset(supervisor_reset())),
-                // SSOP-8
-                D::U5 => Some(BoardPart::LineDecoder(line_decoder())),
-                _ => None,
-            },
-            BoardConfig::DmgZ02 => match designator {
-                // SOP-32 ROM
-                D::U1 => Some(BoardPart::Rom(gb_mask_rom_sop_32_5v())),
-                // QFP-32 MBC5
-                D::U2 => Some(BoardPart::Mapper(mbc5_qfp32())),
-                // SOP-28 RAM
-                D::U3 => Some(BoardPart::Ram(sram_sop_28_5v())),
-                // SOP-8 MM1134A
-                D::U4 => Some(BoardPart::SupervisorReset(supervisor_reset())),
-                _ => None,
-            },
-            BoardConfig::DmgZ03 => match designator {
-                // TSOP-II-44 ROM
-                D::U1 => Some(BoardPart::Rom(gb_mask_rom_tsop_ii_44_5v())),
-                // QFP-32 MBC5
-                D::U2 => Some(BoardPart::Mapper(mbc5_qfp32())),
-                // SOP-28 RAM
-                D::U3 => Some(BoardPart::Ram(sram_sop_28_5v())),
-                // SOP-8 MM1134A
-                D::U4 => Some(BoardPart::SupervisorReset(supervisor_reset())),
-                _ => None,
-            },
-            BoardConfig::DmgZ04 => match designator {
-                // TSOP-II-44 ROM
-                D::U1 => Some(BoardPart::Rom(gb_mask_rom_tsop_ii_44_5v())),
-                // QFP-32 MBC5
-                D::U2 => Some(BoardPart::Mapper(mbc5_qfp32())),
-                // SOP-28 RAM
-                D::U3 => Some(BoardPart::Ram(sram_sop_28_5v())),
-                // SOP-8 MM1134A
-                D::U4 => Some(BoardPart::SupervisorReset(supervisor_reset())),
-                // MOT1 => motor
-                _ => None,
-            },
+            revisions.insert(revision.clone(), resolve(&revision_parts, &label)?);
         }
+        boards.push(Board {
+            definition,
+            parts,
+            revisions,
+        });
     }
-    pub fn parts(&self) -> impl Iterator<Item = (PartDesignator, BoardPart)> + '_ {
-        PartDesignator::ALL.into_iter().filter_map(|designator| {
-            let part = self.part(designator)?;
-            Some((designator, part))
-        })
+    Ok(Boards { boards, by_label })
+}
+
+impl Board {
+    fn label(&self) -> &str {
+        &self.definition.label
+    }
+}
+
+fn is_board_revision(text: &str) -> bool {
+    !text.is_empty() && text.bytes().all(|b| b.is_ascii_digit())
+}
+
+/// Board definitions, embedded from `config/boards.json`
+const BOARDS_JSON: &str = include_str!("../../../config/boards.json");
+
+/// Returns the embedded board definitions, which are parsed and validated on first use
+fn boards() -> Result<&'static Boards, Error> {
+    static BOARDS: OnceLock<Result<Boards, Error>> = OnceLock::new();
+    BOARDS
+        .get_or_init(|| load_boards(BOARDS_JSON).context("config/boards.json"))
+        .as_ref()
+        .map_err(|err| anyhow!("{err:#}"))
+}
+
+/// Cartridge board layout, looked up from the definitions in `config/boards.json`
+#[derive(Copy, Clone)]
+pub struct BoardConfig(&'static Board);
+
+impl BoardConfig {
+    /// Checks that the embedded board definitions are valid.
+    ///
+    /// Lookups find no boards if they are invalid, so programs should call this before using
+    /// them.
+    pub fn validate() -> Result<(), Error> {
+        boards().map(|_| ())
+    }
+    /// Returns all board layouts, or nothing if the definitions are invalid
+    pub fn all() -> impl Iterator<Item = BoardConfig> {
+        boards()
+            .ok()
+            .into_iter()
+            .flat_map(|boards| &boards.boards)
+            .map(BoardConfig)
+    }
+    pub fn from_label(label: &str) -> Option<BoardConfig> {
+        BoardLabel::parse(label).map(|label| label.config)
+    }
+    pub fn definition(&self) -> &'static BoardDefinition {
+        &self.0.definition
+    }
+    pub fn part(&self, designator: PartDesignator) -> Option<BoardPart> {
+        self.parts()
+            .find(|&(candidate, _)| candidate == designator)
+            .map(|(_, part)| part)
+    }
+    /// Returns the parts of the base layout, which applies to all revisions without part
+    /// differences
+    pub fn parts(&self) -> impl Iterator<Item = (PartDesignator, BoardPart)> + 'static {
+        self.0.parts.iter().copied()
+    }
+    /// Returns the parts of a board revision
+    pub fn revision_parts(
+        &self,
+        revision: Option<&str>,
+    ) -> impl Iterator<Item = (PartDesignator, BoardPart)> + 'static {
+        let board: &'static Board = self.0;
+        revision
+            .and_then(|revision| board.revisions.get(revision))
+            .unwrap_or(&board.parts)
+            .iter()
+            .copied()
     }
     pub fn battery_type(&self) -> Option<BatteryType> {
-        match self {
-            BoardConfig::AgbArc => None,
-            BoardConfig::AgbE01 => None,
-            BoardConfig::AgbE02 => None,
-            BoardConfig::AgbE03 => None,
-            BoardConfig::AgbE05 => Some(BatteryType::Cr1616),
-            BoardConfig::AgbE06 => Some(BatteryType::Cr1616),
-            BoardConfig::AgbE11 => None,
-            BoardConfig::AgbE12 => None,
-            BoardConfig::AgbE18 => Some(BatteryType::Cr1616),
-            BoardConfig::AgbE24 => None,
-            BoardConfig::AgbY11 => None,
-            BoardConfig::Tama => None,
-            BoardConfig::Aaac => None,
-            BoardConfig::Bbac => None,
-            BoardConfig::CgbA32 => Some(BatteryType::Cr1616),
-            BoardConfig::DmgA02 => Some(BatteryType::Cr1616),
-            BoardConfig::DmgA03 => Some(BatteryType::Cr1616),
-            BoardConfig::DmgA04 => Some(BatteryType::Cr1616),
-            BoardConfig::DmgA06 => Some(BatteryType::Cr1616),
-            BoardConfig::DmgA07 => None,
-            BoardConfig::DmgA08 => Some(BatteryType::Cr1616),
-            BoardConfig::DmgA09 => None,
-            BoardConfig::DmgA10 => None,
-            BoardConfig::DmgA11 => Some(BatteryType::Cr1616),
-            BoardConfig::DmgA12 => Some(BatteryType::Cr1616),
-            BoardConfig::DmgA14 => Some(BatteryType::Cr2025),
-            BoardConfig::DmgA15 => Some(BatteryType::Cr1616),
-            BoardConfig::DmgA16 => Some(BatteryType::Cr2025),
-            BoardConfig::DmgA18 => None,
-            BoardConfig::DmgA40 => None,
-            BoardConfig::DmgA47 => None,
-            BoardConfig::DmgAaa => None,
-            BoardConfig::DmgBba => None,
-            BoardConfig::DmgBca => None,
-            BoardConfig::DmgBean => None,
-            BoardConfig::DmgBfan => None,
-            BoardConfig::DmgDecn => Some(BatteryType::Cr1616),
-            BoardConfig::DmgDedn => Some(BatteryType::Cr1616),
-            BoardConfig::DmgDfcn => Some(BatteryType::Cr1616),
-            BoardConfig::DmgDgcu => Some(BatteryType::Cr1616),
-            BoardConfig::DmgGdan => Some(BatteryType::Cr1616),
-            BoardConfig::DmgKecn => Some(BatteryType::Cr2025),
-            BoardConfig::DmgKfcn => Some(BatteryType::Cr2025),
-            BoardConfig::DmgKfdn => Some(BatteryType::Cr2025),
-            BoardConfig::DmgKgdu => Some(BatteryType::Cr2025),
-            BoardConfig::DmgLfdn => Some(BatteryType::Cr2025),
-            BoardConfig::DmgMBfan => None,
-            BoardConfig::DmgMcDfcn => Some(BatteryType::Cr1616),
-            BoardConfig::DmgMcSfcn => Some(BatteryType::Cr1616),
-            BoardConfig::DmgMheu => Some(BatteryType::Cr2025),
-            BoardConfig::DmgTedn => Some(BatteryType::Cr1616),
-            BoardConfig::DmgTfdn => Some(BatteryType::Cr1616),
-            BoardConfig::DmgUedt => Some(BatteryType::Cr2025),
-            BoardConfig::DmgUfdt => Some(BatteryType::Cr2025),
-            BoardConfig::DmgUgdu => Some(BatteryType::Cr2025),
-            BoardConfig::DmgZ01 => Some(BatteryType::Cr1616),
-            BoardConfig::DmgZ02 => Some(BatteryType::Cr1616),
-            BoardConfig::DmgZ03 => Some(BatteryType::Cr1616),
-            BoardConfig::DmgZ04 => Some(BatteryType::Cr1616),
-        }
+        self.0.definition.battery
     }
-    pub const fn label(&self) -> &'static str {
-        match self {
-            BoardConfig::AgbArc => "AGB-ARC",
-            BoardConfig::AgbE01 => "AGB-E01",
-            BoardConfig::AgbE02 => "AGB-E02",
-            BoardConfig::AgbE03 => "AGB-E03",
-            BoardConfig::AgbE05 => "AGB-E05",
-            BoardConfig::AgbE06 => "AGB-E06",
-            BoardConfig::AgbE11 => "AGB-E11",
-            BoardConfig::AgbE12 => "AGB-E12",
-            BoardConfig::AgbE18 => "AGB-E18",
-            BoardConfig::AgbE24 => "AGB-E24",
-            BoardConfig::AgbY11 => "AGB-Y11",
-            BoardConfig::Tama => "0200309E4",
-            BoardConfig::Aaac => "AAAC",
-            BoardConfig::Bbac => "BBAC",
-            BoardConfig::CgbA32 => "CGB-A32",
-            BoardConfig::DmgA02 => "DMG-A02",
-            BoardConfig::DmgA03 => "DMG-A03",
-            BoardConfig::DmgA04 => "DMG-A04",
-            BoardConfig::DmgA06 => "DMG-A06",
-            BoardConfig::DmgA07 => "DMG-A07",
-            BoardConfig::DmgA08 => "DMG-A08",
-            BoardConfig::DmgA09 => "DMG-A09",
-            BoardConfig::DmgA10 => "DMG-A10",
-            BoardConfig::DmgA11 => "DMG-A11",
-            BoardConfig::DmgA12 => "DMG-A12",
-            BoardConfig::DmgA14 => "DMG-A14",
-            BoardConfig::DmgA15 => "DMG-A15",
-            BoardConfig::DmgA16 => "DMG-A16",
-            BoardConfig::DmgA18 => "DMG-A18",
-            BoardConfig::DmgA40 => "DMG-A40",
-            BoardConfig::DmgA47 => "DMG-A47",
-            BoardConfig::DmgAaa => "DMG-AAA",
-            BoardConfig::DmgBba => "DMG-BBA",
-            BoardConfig::DmgBca => "DMG-BCA",
-            BoardConfig::DmgBean => "DMG-BEAN",
-            BoardConfig::DmgBfan => "DMG-BFAN",
-            BoardConfig::DmgDecn => "DMG-DECN",
-            BoardConfig::DmgDedn => "DMG-DEDN",
-            BoardConfig::DmgDfcn => "DMG-DFCN",
-            BoardConfig::DmgDgcu => "DMG-DGCU",
-            BoardConfig::DmgGdan => "DMG-GDAN",
-            BoardConfig::DmgKecn => "DMG-KECN",
-            BoardConfig::DmgKfcn => "DMG-KFCN",
-            BoardConfig::DmgKfdn => "DMG-KFDN",
-            BoardConfig::DmgKgdu => "DMG-KGDU",
-            BoardConfig::DmgLfdn => "DMG-LFDN",
-            BoardConfig::DmgMBfan => "DMG-M-BFAN",
-            BoardConfig::DmgMcDfcn => "DMG-MC-DFCN",
-            BoardConfig::DmgMcSfcn => "DMG-MC-SFCN",
-            BoardConfig::DmgMheu => "DMG-MHEU",
-            BoardConfig::DmgTedn => "DMG-TEDN",
-            BoardConfig::DmgTfdn => "DMG-TFDN",
-            BoardConfig::DmgUedt => "DMG-UEDT",
-            BoardConfig::DmgUfdt => "DMG-UFDT",
-            BoardConfig::DmgUgdu => "DMG-UGDU",
-            BoardConfig::DmgZ01 => "DMG-Z01",
-            BoardConfig::DmgZ02 => "DMG-Z02",
-            BoardConfig::DmgZ03 => "DMG-Z03",
-            BoardConfig::DmgZ04 => "DMG-Z04",
-        }
+    pub fn platforms(&self) -> &'static [GamePlatform] {
+        &self.0.definition.platforms
+    }
+    pub fn label(&self) -> &'static str {
+        self.0.label()
+    }
+}
+
+impl PartialEq for BoardConfig {
+    fn eq(&self, other: &Self) -> bool {
+        std::ptr::eq(self.0, other.0)
+    }
+}
+
+impl Eq for BoardConfig {}
+
+impl fmt::Debug for BoardConfig {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("BoardConfig").field(&self.label()).finish()
+    }
+}
+
+/// Cartridge board label split into the board layout, revision and any other text.
+///
+/// ```
+/// use gbhwdb_model::config::cartridge::BoardLabel;
+/// let label = BoardLabel::parse("DMG-BEAN(K)-02").unwrap();
+/// assert_eq!(label.config.label(), "DMG-BEAN");
+/// assert_eq!(label.revision.as_deref(), Some("02"));
+/// assert_eq!(label.suffix.as_deref(), Some("(K)"));
+/// let label = BoardLabel::parse("DMG-KECN-SP").unwrap();
+/// assert_eq!(label.revision, None);
+/// assert_eq!(label.suffix.as_deref(), Some("-SP"));
+/// assert_eq!(BoardLabel::parse("DMG-XXXX-01"), None);
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BoardLabel {
+    pub config: BoardConfig,
+    /// Revision number, e.g. "01" in "DMG-A07-01"
+    pub revision: Option<String>,
+    /// Text between the board name and the revision, e.g. "(K)" in "DMG-BEAN(K)-02", or "-SP" in
+    /// "DMG-KECN-SP"
+    pub suffix: Option<String>,
+}
+
+impl BoardLabel {
+    /// Returns None if the label matches no board, or if the board definitions are invalid
+    pub fn parse(label: &str) -> Option<BoardLabel> {
+        let boards = boards().ok()?;
+        let (key, revision) = match label.rsplit_once('-') {
+            Some((key, revision)) if boards.by_label.contains_key(key) => (key, Some(revision)),
+            _ => (label, None),
+        };
+        let config = BoardConfig(&boards.boards[*boards.by_label.get(key)?]);
+        let (rest, revision) = match revision {
+            Some(revision) if is_board_revision(revision) => (key, Some(revision)),
+            _ => (label, None),
+        };
+        let suffix = rest.strip_prefix(config.label()).unwrap_or(rest);
+        Some(BoardLabel {
+            config,
+            revision: revision.map(str::to_owned),
+            suffix: Some(suffix)
+                .filter(|suffix| !suffix.is_empty())
+                .map(str::to_owned),
+        })
+    }
+    pub fn part(&self, designator: PartDesignator) -> Option<BoardPart> {
+        self.parts()
+            .find(|&(candidate, _)| candidate == designator)
+            .map(|(_, part)| part)
+    }
+    /// Returns the parts of this board revision
+    pub fn parts(&self) -> impl Iterator<Item = (PartDesignator, BoardPart)> + 'static {
+        self.config.revision_parts(self.revision.as_deref())
     }
 }
 
+#[derive(Copy, Clone)]
 pub enum BoardPart {
     Unknown(&'static dyn LabelParser<UnknownChip>),
-    Rom(&'static dyn LabelParser<GameMaskRom>),
-    Mapper(&'static dyn LabelParser<Mapper>),
+    Rom(&'static dyn LabelParser<GameMaskRom>, Option<Package>),
+    Mapper(&'static dyn LabelParser<Mapper>, MapperType),
     Ram(&'static dyn LabelParser<GenericPart>),
     SupervisorReset(&'static dyn LabelParser<GenericPart>),
     Crystal(&'static dyn LabelParser<Crystal>),
@@ -839,14 +714,66 @@ pub enum BoardPart {
     HexInverter(&'static dyn LabelParser<GenericPart>),
     Mcu(&'static dyn LabelParser<GenericPart>),
     Rtc(&'static dyn LabelParser<GenericPart>),
+    Battery(&'static dyn LabelParser<PartDateCode>),
+    Motor(&'static dyn LabelParser<UnknownChip>),
+    IrLed(&'static dyn LabelParser<UnknownChip>),
+    Phototransistor(&'static dyn LabelParser<UnknownChip>),
+    LightSensor(&'static dyn LabelParser<UnknownChip>),
 }
 
 impl BoardPart {
+    /// Looks up the parser for a board part by role and parser name
+    fn resolve(definition: &PartDefinition) -> Result<BoardPart, Error> {
+        let PartDefinition {
+            role,
+            ref parser,
+            package,
+            mapper,
+            ..
+        } = *definition;
+        ensure!(
+            package.is_none() || role == PartRole::Rom,
+            "only a ROM part can have a package"
+        );
+        ensure!(
+            mapper.is_none() || role == PartRole::Mapper,
+            "only a mapper part can have a mapper type"
+        );
+        let part = match role {
+            PartRole::Unknown => unknown_parser(parser).map(BoardPart::Unknown),
+            PartRole::Rom => rom_parser(parser).map(|parser| BoardPart::Rom(parser, package)),
+            PartRole::Mapper => {
+                let mapper = mapper.context("a mapper part must have a mapper type")?;
+                mapper_parser(parser).map(|parser| BoardPart::Mapper(parser, mapper))
+            }
+            PartRole::Crystal => crystal_parser(parser).map(BoardPart::Crystal),
+            PartRole::Ram => generic_parser(parser).map(BoardPart::Ram),
+            PartRole::SupervisorReset => generic_parser(parser).map(BoardPart::SupervisorReset),
+            PartRole::Flash => generic_parser(parser).map(BoardPart::Flash),
+            PartRole::Eeprom => generic_parser(parser).map(BoardPart::Eeprom),
+            PartRole::Accelerometer => generic_parser(parser).map(BoardPart::Accelerometer),
+            PartRole::LineDecoder => generic_parser(parser).map(BoardPart::LineDecoder),
+            PartRole::HexInverter => generic_parser(parser).map(BoardPart::HexInverter),
+            PartRole::Mcu => generic_parser(parser).map(BoardPart::Mcu),
+            PartRole::Rtc => generic_parser(parser).map(BoardPart::Rtc),
+            PartRole::Battery => battery_parser(parser).map(BoardPart::Battery),
+            PartRole::Motor => unknown_parser(parser).map(BoardPart::Motor),
+            PartRole::IrLed => unknown_parser(parser).map(BoardPart::IrLed),
+            PartRole::Phototransistor => unknown_parser(parser).map(BoardPart::Phototransistor),
+            PartRole::LightSensor => unknown_parser(parser).map(BoardPart::LightSensor),
+        };
+        part.ok_or_else(|| {
+            anyhow!(
+                "unknown parser family {parser:?} for role {}",
+                role.display()
+            )
+        })
+    }
     pub fn role(&self) -> PartRole {
         match self {
             BoardPart::Unknown(_) => PartRole::Unknown,
-            BoardPart::Rom(_) => PartRole::Rom,
-            BoardPart::Mapper(_) => PartRole::Mapper,
+            BoardPart::Rom(..) => PartRole::Rom,
+            BoardPart::Mapper(..) => PartRole::Mapper,
             BoardPart::Ram(_) => PartRole::Ram,
             BoardPart::SupervisorReset(_) => PartRole::SupervisorReset,
             BoardPart::Crystal(_) => PartRole::Crystal,
@@ -857,94 +784,442 @@ impl BoardPart {
             BoardPart::HexInverter(_) => PartRole::HexInverter,
             BoardPart::Mcu(_) => PartRole::Mcu,
             BoardPart::Rtc(_) => PartRole::Rtc,
+            BoardPart::Battery(_) => PartRole::Battery,
+            BoardPart::Motor(_) => PartRole::Motor,
+            BoardPart::IrLed(_) => PartRole::IrLed,
+            BoardPart::Phototransistor(_) => PartRole::Phototransistor,
+            BoardPart::LightSensor(_) => PartRole::LightSensor,
+        }
+    }
+    /// Package of the game ROM accepted by the part, if the part is a game ROM with a known
+    /// package
+    pub fn rom_package(&self) -> Option<Package> {
+        match *self {
+            BoardPart::Rom(_, package) => package,
+            _ => None,
+        }
+    }
+    /// Mapper type accepted by the part, if the part is a mapper
+    pub fn mapper_type(&self) -> Option<MapperType> {
+        match *self {
+            BoardPart::Mapper(_, mapper_type) => Some(mapper_type),
+            _ => None,
         }
     }
 }
 
-fn create_map() -> HashMap<&'static str, BoardConfig> {
-    let mut m = HashMap::new();
-    m.insert("AGB-ARC", BoardConfig::AgbArc);
-    m.insert("AGB-E01", BoardConfig::AgbE01);
-    m.insert("AGB-E02", BoardConfig::AgbE02);
-    m.insert("AGB-E03", BoardConfig::AgbE03);
-    m.insert("AGB-E05", BoardConfig::AgbE05);
-    m.insert("AGB-E06", BoardConfig::AgbE06);
-    m.insert("AGB-E11", BoardConfig::AgbE11);
-    m.insert("AGB-E12", BoardConfig::AgbE12);
-    m.insert("AGB-E18", BoardConfig::AgbE18);
-    m.insert("AGB-E24", BoardConfig::AgbE24);
-    m.insert("AGB-Y11", BoardConfig::AgbY11);
-    m.insert("0200309E4-01", BoardConfig::Tama);
-    m.insert("AAAC S", BoardConfig::Aaac);
-    m.insert("BBAC S", BoardConfig::Bbac);
-    m.insert("CGB-A32", BoardConfig::CgbA32);
-    m.insert("DMG-A02", BoardConfig::DmgA02);
-    m.insert("DMG-A03", BoardConfig::DmgA03);
-    m.insert("DMG-A04", BoardConfig::DmgA04);
-    m.insert("DMG-A06", BoardConfig::DmgA06);
-    m.insert("DMG-A07", BoardConfig::DmgA07);
-    m.insert("DMG-A08", BoardConfig::DmgA08);
-    m.insert("DMG-A09", BoardConfig::DmgA09);
-    m.insert("DMG-A10", BoardConfig::DmgA10);
-    m.insert("DMG-A11", BoardConfig::DmgA11);
-    m.insert("DMG-A12", BoardConfig::DmgA12);
-    m.insert("DMG-A14", BoardConfig::DmgA14);
-    m.insert("DMG-A15", BoardConfig::DmgA15);
-    m.insert("DMG-A16", BoardConfig::DmgA16);
-    m.insert("DMG-A18", BoardConfig::DmgA18);
-    m.insert("DMG-A40", BoardConfig::DmgA40);
-    m.insert("DMG-A47", BoardConfig::DmgA47);
-    m.insert("DMG-AAA", BoardConfig::DmgAaa);
-    m.insert("DMG-BBA", BoardConfig::DmgBba);
-    m.insert("DMG-BCA", BoardConfig::DmgBca);
-    m.insert("DMG-BEAN", BoardConfig::DmgBean);
-    m.insert("DMG-BEAN(K)", BoardConfig::DmgBean);
-    m.insert("DMG-BFAN", BoardConfig::DmgBfan);
-    m.insert("DMG-DECN", BoardConfig::DmgDecn);
-    m.insert("DMG-DECN(K)", BoardConfig::DmgDecn);
-    m.insert("DMG-DEDN", BoardConfig::DmgDedn);
-    m.insert("DMG-DFCN", BoardConfig::DmgDfcn);
-    m.insert("DMG-DGCU", BoardConfig::DmgDgcu);
-    m.insert("DMG-GDAN", BoardConfig::DmgGdan);
-    m.insert("DMG-KECN", BoardConfig::DmgKecn);
-    m.insert("DMG-KFCN", BoardConfig::DmgKfcn);
-    m.insert("DMG-KFDN", BoardConfig::DmgKfdn);
-    m.insert("DMG-KGDU", BoardConfig::DmgKgdu);
-    m.insert("DMG-LFDN", BoardConfig::DmgLfdn);
-    m.insert("DMG-M-BFAN", BoardConfig::DmgMBfan);
-    m.insert("DMG-MC-DFCN", BoardConfig::DmgMcDfcn);
-    m.insert("DMG-MC-SFCN", BoardConfig::DmgMcSfcn);
-    m.insert("DMG-MHEU", BoardConfig::DmgMheu);
-    m.insert("DMG-TEDN", BoardConfig::DmgTedn);
-    m.insert("DMG-TFDN", BoardConfig::DmgTfdn);
-    m.insert("DMG-UEDT", BoardConfig::DmgUedt);
-    m.insert("DMG-UFDT", BoardConfig::DmgUfdt);
-    m.insert("DMG-UGDU", BoardConfig::DmgUgdu);
-    m.insert("DMG-Z01", BoardConfig::DmgZ01);
-    m.insert("DMG-Z02", BoardConfig::DmgZ02);
-    m.insert("DMG-Z03", BoardConfig::DmgZ03);
-    m.insert("DMG-Z04", BoardConfig::DmgZ04);
-    m
+/// Mismatch between a cartridge's game ROM chips, its board and the game
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum RomMismatch {
+    /// The ROM type has a package that doesn't fit the board
+    Package {
+        designator: PartDesignator,
+        rom_type: GameRomType,
+        expected: Package,
+    },
+    /// The combined density of the ROM chips doesn't match the game's ROM size
+    Size {
+        rom_types: Vec<GameRomType>,
+        /// Combined density in bits
+        density: u32,
+        /// Game ROM size in bytes
+        rom_size: u32,
+    },
 }
 
-impl BoardConfig {
-    pub fn from_label(label: &str) -> Option<BoardConfig> {
-        static MAP: OnceLock<HashMap<&'static str, BoardConfig>> = OnceLock::new();
-        let map = MAP.get_or_init(create_map);
-        label
-            .rfind(|c: char| c == '-')
-            .map(|pos| label.split_at(pos).0)
-            .and_then(|key| map.get(key).cloned())
-            .or_else(|| map.get(label).cloned())
+impl fmt::Display for RomMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            RomMismatch::Package {
+                designator,
+                rom_type,
+                expected,
+            } => write!(
+                f,
+                "{designator}: ROM type {rom_type} is {package}, but the board expects {expected}",
+                designator = designator.as_str(),
+                rom_type = rom_type.as_str(),
+                package = rom_type.package(),
+            ),
+            RomMismatch::Size {
+                rom_types,
+                density,
+                rom_size,
+            } => write!(
+                f,
+                "ROM type {rom_types} holds {density} KiB, but the game ROM is {rom_size} KiB",
+                rom_types = rom_types
+                    .iter()
+                    .map(|rom_type| rom_type.as_str())
+                    .collect::<Vec<_>>()
+                    .join(" + "),
+                density = density / 8 / 1024,
+                rom_size = rom_size / 1024,
+            ),
+        }
+    }
+}
+
+/// Checks parsed game ROM chips against the board they were found on and the game's ROM size.
+///
+/// ```
+/// use gbhwdb_model::config::cartridge::{BoardLabel, GameConfig, PartDesignator, check_roms};
+/// use gbhwdb_model::parser::{LabelParser, gb_mask_rom_sop_32_5v};
+/// let rom = gb_mask_rom_sop_32_5v().parse("DMG-AM6J-0 F1 M538011E-36 9085401").unwrap();
+/// # fn game(rom_size: u32) -> GameConfig {
+/// #     serde_json::from_value(serde_json::json!({
+/// #         "name": "", "rom_verified": false, "platform": "gb", "no_intro_id": "",
+/// #         "rom_size": rom_size,
+/// #     })).unwrap()
+/// # }
+/// let roms = [(PartDesignator::U1, rom)];
+/// let dmg_bean = BoardLabel::parse("DMG-BEAN-10").unwrap();
+/// assert!(check_roms(&dmg_bean, &roms, &game(1024 * 1024)).is_empty());
+/// // DMG-A03 takes a TSOP-II-44 ROM
+/// let dmg_a03 = BoardLabel::parse("DMG-A03-10").unwrap();
+/// assert_eq!(check_roms(&dmg_a03, &roms, &game(1024 * 1024)).len(), 1);
+/// assert_eq!(
+///     check_roms(&dmg_bean, &roms, &game(512 * 1024))[0].to_string(),
+///     "ROM type F1 holds 1024 KiB, but the game ROM is 512 KiB"
+/// );
+/// ```
+pub fn check_roms(
+    board: &BoardLabel,
+    roms: &[(PartDesignator, GameMaskRom)],
+    game: &GameConfig,
+) -> Vec<RomMismatch> {
+    let mut result = Vec::new();
+    for (designator, rom) in roms {
+        let designator = *designator;
+        let expected = board.part(designator).and_then(|part| part.rom_package());
+        if let Some(expected) = expected
+            && rom.rom_type.package() != expected
+        {
+            result.push(RomMismatch::Package {
+                designator,
+                rom_type: rom.rom_type,
+                expected,
+            });
+        }
+    }
+    if let (Some(density), Some(rom_size)) = (rom_density(roms), game.rom_size)
+        && u64::from(density) != u64::from(rom_size) * 8
+    {
+        result.push(RomMismatch::Size {
+            rom_types: roms.iter().map(|(_, rom)| rom.rom_type).collect(),
+            density,
+            rom_size,
+        });
+    }
+    result
+}
+
+/// Returns the combined density in bits of a cartridge's game ROM chips, if there are any and all
+/// of them have a known density
+pub fn rom_density(roms: &[(PartDesignator, GameMaskRom)]) -> Option<u32> {
+    if roms.is_empty() {
+        return None;
+    }
+    roms.iter().map(|(_, rom)| rom.rom_type.density()).sum()
+}
+
+/// How serious an inconsistency is
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum Severity {
+    /// Unusual, but possible on real hardware
+    Warning,
+    Error,
+}
+
+impl fmt::Display for Severity {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Severity::Warning => write!(f, "warning"),
+            Severity::Error => write!(f, "error"),
+        }
+    }
+}
+
+/// Inconsistency between a game, the board it was found on and the board's mapper
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Inconsistency {
+    /// The board is not used for games of the platform
+    Platform {
+        platform: GamePlatform,
+        board: BoardConfig,
+    },
+    /// The game expects a mapper, but the board doesn't have one
+    MissingMapper { expected: MapperType },
+    /// The board doesn't have the part that stores the game's saves
+    SaveType {
+        save_type: SaveType,
+        board: BoardConfig,
+    },
+    /// The mapper chip, or the mapper the board takes if the chip is unknown, differs from the
+    /// one the game expects
+    Mapper {
+        expected: MapperType,
+        actual: MapperType,
+    },
+}
+
+impl Inconsistency {
+    pub const fn severity(&self) -> Severity {
+        match self {
+            // MBC30 is MBC3 with a larger ROM and RAM address space
+            Inconsistency::Mapper {
+                expected: MapperType::Mbc3,
+                actual: MapperType::Mbc30,
+            } => Severity::Warning,
+            Inconsistency::Platform { .. }
+            | Inconsistency::MissingMapper { .. }
+            | Inconsistency::SaveType { .. }
+            | Inconsistency::Mapper { .. } => Severity::Error,
+        }
+    }
+}
+
+impl fmt::Display for Inconsistency {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Inconsistency::Platform { platform, board } => write!(
+                f,
+                "{board} is not used for {platform} games",
+                board = board.label(),
+                platform = platform.name(),
+            ),
+            Inconsistency::MissingMapper { expected } => write!(
+                f,
+                "the game expects {expected}, but the board has no mapper",
+                expected = expected.name(),
+            ),
+            Inconsistency::SaveType { save_type, board } => write!(
+                f,
+                "{board} has no part for {save_type} saves",
+                board = board.label(),
+            ),
+            Inconsistency::Mapper { expected, actual } => write!(
+                f,
+                "the game expects {expected}, but the mapper is {actual}",
+                expected = expected.name(),
+                actual = actual.name(),
+            ),
+        }
+    }
+}
+
+/// Checks a game against the board it was found on and the parsed mapper chip, if any.
+///
+/// The mapper and save type are only checked if the game config has them, and against the parts of
+/// the board revision.
+///
+/// ```
+/// use gbhwdb_model::config::cartridge::{
+///     BoardLabel, GameConfig, SaveType, Severity, check_consistency,
+/// };
+/// use gbhwdb_model::parser::{LabelParser, mbc5_qfp32};
+/// # fn game(platform: &str, mapper: &str) -> GameConfig {
+/// #     serde_json::from_value(serde_json::json!({
+/// #         "name": "", "rom_verified": false, "platform": platform, "no_intro_id": "",
+/// #         "mapper": mapper,
+/// #     })).unwrap()
+/// # }
+/// let mbc5 = mbc5_qfp32().parse("MBC5 LZ9GB31 AL23 A").unwrap();
+/// let dmg_a07 = BoardLabel::parse("DMG-A07-01").unwrap();
+/// assert!(check_consistency(&game("gbc", "MBC5"), &dmg_a07, Some(&mbc5)).is_empty());
+/// let issues = check_consistency(&game("gba", "MBC5"), &dmg_a07, Some(&mbc5));
+/// assert_eq!(issues[0].to_string(), "DMG-A07 is not used for Game Boy Advance games");
+/// let issues = check_consistency(&game("gbc", "MBC3"), &dmg_a07, None);
+/// assert_eq!(issues[0].to_string(), "the game expects MBC3, but the mapper is MBC5");
+/// assert_eq!(issues[0].severity(), Severity::Error);
+/// let mut game = game("gbc", "MBC5");
+/// game.save_type = Some(SaveType::Sram);
+/// let issues = check_consistency(&game, &dmg_a07, None);
+/// assert_eq!(issues[0].to_string(), "DMG-A07 has no part for SRAM saves");
+/// ```
+pub fn check_consistency(
+    game: &GameConfig,
+    board: &BoardLabel,
+    mapper: Option<&Mapper>,
+) -> Vec<Inconsistency> {
+    let mut result = Vec::new();
+    if !board.config.platforms().contains(&game.platform) {
+        result.push(Inconsistency::Platform {
+            platform: game.platform,
+            board: board.config,
+        });
+    }
+    if let Some(expected) = game.mapper {
+        let actual = mapper
+            .map(|mapper| mapper.kind.mapper_type())
+            .or_else(|| board.parts().find_map(|(_, part)| part.mapper_type()));
+        match actual {
+            None => result.push(Inconsistency::MissingMapper { expected }),
+            Some(actual) if actual != expected => {
+                result.push(Inconsistency::Mapper { expected, actual })
+            }
+            Some(_) => (),
+        }
+    }
+    if let Some(save_type) = game.save_type
+        && !SaveType::fits_board(Some(save_type), board)
+    {
+        result.push(Inconsistency::SaveType {
+            save_type,
+            board: board.config,
+        });
+    }
+    result
+}
+
+/// Game ROM chips and mapper chip parsed from a cartridge submission
+#[derive(Clone, Debug, Default)]
+pub struct BoardChips {
+    pub roms: Vec<(PartDesignator, GameMaskRom)>,
+    pub mapper: Option<Mapper>,
+}
+
+/// Parses the game ROM chips and the mapper chip of a cartridge submission with the parsers of the
+/// board it was found on.
+///
+/// Empty and unparseable labels are skipped, because reporting them is up to the label parsing
+/// itself. The result is meant for `check_roms` and `check_consistency`.
+///
+/// ```
+/// use gbhwdb_model::config::cartridge::{BoardLabel, PartDesignator, parse_board_chips};
+/// use gbhwdb_model::input::{Part, cartridge::CartridgeBoard};
+/// let board = CartridgeBoard {
+///     label: String::from("DMG-BEAN-10"),
+///     u1: Part {
+///         label: String::from("DMG-AM6J-0 F1 M538011E-36 9085401"),
+///         outlier: false,
+///     },
+///     ..CartridgeBoard::default()
+/// };
+/// let chips = parse_board_chips(&BoardLabel::parse(&board.label).unwrap(), &board);
+/// assert_eq!(chips.roms.len(), 1);
+/// assert_eq!(chips.roms[0].0, PartDesignator::U1);
+/// assert!(chips.mapper.is_none());
+/// ```
+pub fn parse_board_chips(board: &BoardLabel, submission: &CartridgeBoard) -> BoardChips {
+    let label = |designator| {
+        submission
+            .part(designator)
+            .map(|part| part.label.as_str())
+            .filter(|label| !label.is_empty())
+    };
+    let mut chips = BoardChips::default();
+    for (designator, part) in board.parts() {
+        match part {
+            BoardPart::Rom(parser, _) => {
+                if let Some(rom) = label(designator).and_then(|label| parser.parse(label).ok()) {
+                    chips.roms.push((designator, rom));
+                }
+            }
+            BoardPart::Mapper(parser, _) if chips.mapper.is_none() => {
+                chips.mapper = label(designator).and_then(|label| parser.parse(label).ok());
+            }
+            _ => (),
+        }
+    }
+    chips
+}
+
+fn unknown_parser(name: &str) -> Option<&'static dyn LabelParser<UnknownChip>> {
+    match name {
+        "UNKNOWN_CHIP" => Some(&UNKNOWN_CHIP),
+        _ => None,
+    }
+}
+
+fn battery_parser(name: &str) -> Option<&'static dyn LabelParser<PartDateCode>> {
+    match name {
+        "battery" => Some(battery()),
+        _ => None,
     }
 }
 
+fn rom_parser(name: &str) -> Option<&'static dyn LabelParser<GameMaskRom>> {
+    Some(match name {
+        "agb_mask_rom_tsop_ii_44_3v3" => agb_mask_rom_tsop_ii_44_3v3(),
+        "gb_mask_rom_glop_top_28_5v" => gb_mask_rom_glop_top_28_5v(),
+        "gb_mask_rom_qfp_44_5v" => gb_mask_rom_qfp_44_5v(),
+        "gb_mask_rom_sop_32_5v" => gb_mask_rom_sop_32_5v(),
+        "gb_mask_rom_sop_44_5v" => gb_mask_rom_sop_44_5v(),
+        "gb_mask_rom_tsop_i_32_5v" => gb_mask_rom_tsop_i_32_5v(),
+        "gb_mask_rom_tsop_ii_44_5v" => gb_mask_rom_tsop_ii_44_5v(),
+        "unknown::UNKNOWN_TAMA7" => &unknown::UNKNOWN_TAMA7,
+        _ => return None,
+    })
+}
+
+fn mapper_parser(name: &str) -> Option<&'static dyn LabelParser<Mapper>> {
+    Some(match name {
+        "huc1_qfp32" => huc1_qfp32(),
+        "huc3_qfp48" => huc3_qfp48(),
+        "mbc1_glop_top" => mbc1_glop_top(),
+        "mbc1_sop24" => mbc1_sop24(),
+        "mbc2_sop28" => mbc2_sop28(),
+        "mbc3_qfp32" => mbc3_qfp32(),
+        "mbc30_qfp32" => mbc30_qfp32(),
+        "mbc5_qfp32" => mbc5_qfp32(),
+        "mbc6_qfp64" => mbc6_qfp64(),
+        "mbc7_qfp56" => mbc7_qfp56(),
+        "mmm01_qfp32" => mmm01_qfp32(),
+        "toshiba::TOSHIBA_TAMA5" => &toshiba::TOSHIBA_TAMA5,
+        _ => return None,
+    })
+}
+
+fn crystal_parser(name: &str) -> Option<&'static dyn LabelParser<Crystal>> {
+    match name {
+        "rtc_crystal" => Some(rtc_crystal()),
+        _ => None,
+    }
+}
+
+fn generic_parser(name: &str) -> Option<&'static dyn LabelParser<GenericPart>> {
+    Some(match name {
+        "analog::ANALOG_ADXL202JE" => &analog::ANALOG_ADXL202JE,
+        "analog::ANALOG_ADXL202JQC" => &analog::ANALOG_ADXL202JQC,
+        "eeprom_sop_8_3v3" => eeprom_sop_8_3v3(),
+        "eeprom_tssop_8_5v" => eeprom_tssop_8_5v(),
+        "flash_tsop_i_32_3v3" => flash_tsop_i_32_3v3(),
+        "flash_tsop_i_40_5v" => flash_tsop_i_40_5v(),
+        "fram_sop_28_3v3" => fram_sop_28_3v3(),
+        "hex_inverter" => hex_inverter(),
+        "line_decoder" => line_decoder(),
+        "rtc_sop_8" => rtc_sop_8(),
+        "rtc_sop_20" => rtc_sop_20(),
+        "sram_sop_28_3v3" => sram_sop_28_3v3(),
+        "sram_sop_28_5v" => sram_sop_28_5v(),
+        "sram_sop_32_5v" => sram_sop_32_5v(),
+        "sram_tsop_i_28_5v" => sram_tsop_i_28_5v(),
+        "supervisor_reset" => supervisor_reset(),
+        "toshiba::TOSHIBA_TAMA6" => &toshiba::TOSHIBA_TAMA6,
+        _ => return None,
+    })
+}
+
+/// Loads and validates the game configs from a file, usually `config/games.json`
 pub fn load_cfgs<P: AsRef<Path>>(path: P) -> Result<BTreeMap<String, GameConfig>, Error> {
-    let file = File::open(path)?;
-    let file = BufReader::new(file);
-    let mut cfgs: BTreeMap<String, GameConfig> = serde_json::from_reader(file)?;
+    let path = path.as_ref();
+    fs::read_to_string(path)
+        .map_err(Error::from)
+        .and_then(|json| parse_cfgs(&json))
+        .with_context(|| path.display().to_string())
+}
+
+fn parse_cfgs(json: &str) -> Result<BTreeMap<String, GameConfig>, Error> {
+    let mut cfgs: BTreeMap<String, GameConfig> = serde_json::from_str(json)?;
     for (rom_id, cfg) in cfgs.iter_mut() {
         cfg.rom_id.clone_from(rom_id);
+        if let Some(ram_size) = cfg.ram_size {
+            ensure!(
+                GameConfig::RAM_SIZES.contains(&ram_size),
+                "{rom_id}: invalid RAM size {ram_size}"
+            );
+        }
     }
     Ok(cfgs)
 }
@@ -959,7 +1234,8 @@ pub fn write_cfgs<P: AsRef<Path>>(
     Ok(())
 }
 
-#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord, Deserialize)]
+#[serde(rename_all = "snake_case")]
 pub enum PartRole {
     Unknown,
     Rom,
@@ -974,6 +1250,11 @@ pub enum PartRole {
     HexInverter,
     Mcu,
     Rtc,
+    Battery,
+    Motor,
+    IrLed,
+    Phototransistor,
+    LightSensor,
 }
 
 impl PartRole {
@@ -992,12 +1273,27 @@ impl PartRole {
             PartRole::HexInverter => "Hex inverter",
             PartRole::Mcu => "Microcontroller",
             PartRole::Rtc => "RTC",
+            PartRole::Battery => "Battery",
+            PartRole::Motor => "Motor",
+            PartRole::IrLed => "IR LED",
+            PartRole::Phototransistor => "Phototransistor",
+            PartRole::LightSensor => "Light sensor",
         }
     }
 }
 
 #[derive(
-    Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, strum::VariantArray, strum::EnumString,
+    Copy,
+    Clone,
+    Debug,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    Deserialize,
+    strum::VariantArray,
+    strum::EnumString,
 )]
 pub enum PartDesignator {
     U1,
@@ -1008,10 +1304,20 @@ pub enum PartDesignator {
     U6,
     U7,
     X1,
+    /// Battery
+    BT1,
+    /// Diode or LED
+    D1,
+    /// Transistor or phototransistor
+    Q1,
+    /// Light sensor
+    LS1,
+    /// Motor
+    MOT1,
 }
 
 impl PartDesignator {
-    pub const ALL: [PartDesignator; 8] = [
+    pub const ALL: [PartDesignator; 13] = [
         PartDesignator::U1,
         PartDesignator::U2,
         PartDesignator::U3,
@@ -1020,6 +1326,11 @@ impl PartDesignator {
         PartDesignator::U6,
         PartDesignator::U7,
         PartDesignator::X1,
+        PartDesignator::BT1,
+        PartDesignator::D1,
+        PartDesignator::Q1,
+        PartDesignator::LS1,
+        PartDesignator::MOT1,
     ];
     pub const fn as_str(&self) -> &'static str {
         match self {
@@ -1031,6 +1342,11 @@ impl PartDesignator {
             PartDesignator::U6 => "U6",
             PartDesignator::U7 => "U7",
             PartDesignator::X1 => "X1",
+            PartDesignator::BT1 => "BT1",
+            PartDesignator::D1 => "D1",
+            PartDesignator::Q1 => "Q1",
+            PartDesignator::LS1 => "LS1",
+            PartDesignator::MOT1 => "MOT1",
         }
     }
     pub const fn as_lower_str(&self) -> &'static str {
@@ -1043,6 +1359,293 @@ impl PartDesignator {
             PartDesignator::U6 => "u6",
             PartDesignator::U7 => "u7",
             PartDesignator::X1 => "x1",
+            PartDesignator::BT1 => "bt1",
+            PartDesignator::D1 => "d1",
+            PartDesignator::Q1 => "q1",
+            PartDesignator::LS1 => "ls1",
+            PartDesignator::MOT1 => "mot1",
+        }
+    }
+}
+
+#[test]
+fn test_board_parsers_match_registry_roles() {
+    use crate::parser::registry;
+
+    BoardConfig::validate().unwrap();
+    for board in BoardConfig::all() {
+        for (designator, part) in &board.definition().parts {
+            if let Some(family) = registry::families()
+                .iter()
+                .find(|family| family.id == part.parser)
+            {
+                assert_eq!(
+                    family.role,
+                    part.role.into(),
+                    "{} {}: {} is not a {} family",
+                    board.label(),
+                    designator.as_str(),
+                    part.parser,
+                    part.role.display()
+                );
+                if let Some(package) = part.package {
+                    assert_eq!(
+                        family.package,
+                        Some(package),
+                        "{} {}: {} is not a {package} family",
+                        board.label(),
+                        designator.as_str(),
+                        part.parser,
+                    );
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn test_game_config_optional_fields() {
+    let minimal = serde_json::json!({
+        "name": "Tetris (World) (Rev 1)",
+        "rom_verified": true,
+        "platform": "gb",
+        "no_intro_id": "0863",
+    });
+    let cfg: GameConfig = serde_json::from_value(minimal.clone()).unwrap();
+    assert_eq!(cfg.region, None);
+    assert!(cfg.languages.is_empty());
+    assert_eq!(cfg.save_type, None);
+    assert_eq!(serde_json::to_value(&cfg).unwrap(), minimal);
+
+    let full = serde_json::json!({
+        "name": "Pokemon - Gold Version (USA, Europe)",
+        "rom_verified": true,
+        "platform": "gbc",
+        "no_intro_id": "0185",
+        "region": "USA, Europe",
+        "languages": ["En"],
+        "publisher": "Nintendo",
+        "release_date": "2000-10-15",
+        "rom_size": 2097152,
+        "ram_size": 32768,
+        "mapper": "MBC3",
+        "save_type": "sram",
+    });
+    let cfg: GameConfig = serde_json::from_value(full.clone()).unwrap();
+    assert_eq!(cfg.languages, ["En".parse().unwrap()]);
+    assert_eq!(
+        cfg.release_date,
+        Some(ReleaseDate::Day(
+            time::Date::from_calendar_date(2000, time::Month::October, 15).unwrap()
+        ))
+    );
+    assert_eq!(cfg.mapper, Some(MapperType::Mbc3));
+    assert_eq!(cfg.save_type, Some(SaveType::Sram));
+    assert_eq!(serde_json::to_value(&cfg).unwrap(), full);
+}
+
+#[test]
+fn test_parse_cfgs_errors() {
+    let parse = |fields: &str| {
+        let json = format!(
+            r#"{{"DMG-TEST-0": {{"name": "", "rom_verified": false, "platform": "gb", "no_intro_id": ""{fields}}}}}"#
+        );
+        match parse_cfgs(&json) {
+            Ok(_) => panic!("{json} parsed without errors"),
+            Err(err) => format!("{err:#}"),
+        }
+    };
+    let error = parse(r#", "ram_size": 4096"#);
+    assert!(
+        error.contains("DMG-TEST-0: invalid RAM size 4096"),
+        "{error}"
+    );
+    let error = parse(r#", "release_date": "2000-02-30""#);
+    assert!(error.contains("invalid release date"), "{error}");
+    let error = parse(r#", "languages": ["English"]"#);
+    assert!(error.contains("invalid language code"), "{error}");
+}
+
+#[test]
+fn test_board_mapper_types_match_parser_examples() {
+    use crate::parser::registry;
+
+    BoardConfig::validate().unwrap();
+    for board in BoardConfig::all() {
+        for (designator, part) in board.parts() {
+            let BoardPart::Mapper(parser, mapper_type) = part else {
+                continue;
+            };
+            let name = &board.definition().parts[&designator].parser;
+            let examples = registry::families()
+                .iter()
+                .filter(|family| family.id == name)
+                .flat_map(|family| &family.parsers)
+                .flat_map(|parser| parser.examples);
+            for label in examples {
+                assert_eq!(
+                    parser.parse(label).map(|mapper| mapper.kind.mapper_type()),
+                    Ok(mapper_type),
+                    "{} {}: {label}",
+                    board.label(),
+                    designator.as_str()
+                );
+            }
         }
     }
 }
+
+#[test]
+fn test_load_boards_errors() {
+    let load = |json: &str| match load_boards(json) {
+        Ok(_) => panic!("{json} loaded without errors"),
+        Err(err) => format!("{err:#}"),
+    };
+    let error = load(
+        r#"[{"label": "DMG-TEST", "platforms": ["gb"], "parts": {"U1": {"role": "rom", "parser": "mbc5_qfp32"}}}]"#,
+    );
+    assert!(
+        error.contains("DMG-TEST U1: unknown parser family \"mbc5_qfp32\" for role ROM"),
+        "{error}"
+    );
+    let error = load(
+        r#"[{"label": "DMG-TEST", "aliases": ["DMG-TEST(K)"], "platforms": ["gb"], "parts": {}}, {"label": "DMG-TEST(K)", "platforms": ["gb"], "parts": {}}]"#,
+    );
+    assert!(
+        error.contains("duplicate board label \"DMG-TEST(K)\" in DMG-TEST and DMG-TEST(K)"),
+        "{error}"
+    );
+    let error = load(
+        r#"[{"label": "DMG-TEST", "platforms": ["gb"], "parts": {"J1": {"role": "unknown", "parser": "UNKNOWN_CHIP"}}}]"#,
+    );
+    assert!(error.contains("unknown variant `J1`"), "{error}");
+    let error = load(
+        r#"[{"label": "DMG-TEST", "platforms": ["gb"], "parts": {"U1": {"role": "cpu", "parser": "dmg_soc_qfp_80"}}}]"#,
+    );
+    assert!(error.contains("unknown variant `cpu`"), "{error}");
+    let error = load(
+        r#"[{"label": "DMG-TEST", "platforms": ["gb"], "parts": {"U2": {"role": "mapper", "parser": "mbc1_sop24"}}}]"#,
+    );
+    assert!(
+        error.contains("DMG-TEST U2: a mapper part must have a mapper type"),
+        "{error}"
+    );
+    let error = load(
+        r#"[{"label": "DMG-TEST", "platforms": ["gb"], "parts": {"U2": {"role": "ram", "parser": "sram_sop_28_5v", "package": "SOP-28"}}}]"#,
+    );
+    assert!(
+        error.contains("DMG-TEST U2: only a ROM part can have a package"),
+        "{error}"
+    );
+    let error =
+        load(r#"[{"label": "DMG-TEST", "platforms": ["gb"], "battery": "CR2025", "parts": {}}]"#);
+    assert!(
+        error.contains("DMG-TEST: the battery type and a battery part must be defined together"),
+        "{error}"
+    );
+    let error = load(
+        r#"[{"label": "DMG-TEST", "platforms": ["gb"], "parts": {}, "revisions": {"02": {"U3": null}}}]"#,
+    );
+    assert!(
+        error.contains("DMG-TEST-02 U3: the board has no such part"),
+        "{error}"
+    );
+    let error = load(r#"[{"label": "DMG-TEST", "platforms": [], "parts": {}}]"#);
+    assert!(
+        error.contains("DMG-TEST: the board has no platforms"),
+        "{error}"
+    );
+}
+
+#[test]
+fn test_board_revision_parts() {
+    let boards = load_boards(
+        r#"[{
+            "label": "DMG-TEST",
+            "platforms": ["gb"],
+            "parts": {
+                "U1": {"role": "rom", "parser": "gb_mask_rom_sop_32_5v"},
+                "U2": {"role": "mapper", "parser": "mbc1_sop24", "mapper": "MBC1"}
+            },
+            "revisions": {
+                "02": {
+                    "U2": null,
+                    "U3": {"role": "ram", "parser": "sram_sop_28_5v"}
+                }
+            }
+        }]"#,
+    )
+    .unwrap();
+    let board = BoardConfig(Box::leak(Box::new(boards)).boards.first().unwrap());
+    let roles = |revision| {
+        board
+            .revision_parts(revision)
+            .map(|(designator, part)| (designator, part.role()))
+            .collect::<Vec<_>>()
+    };
+    use PartDesignator::*;
+    assert_eq!(
+        roles(Some("01")),
+        [(U1, PartRole::Rom), (U2, PartRole::Mapper)]
+    );
+    assert_eq!(roles(None), roles(Some("01")));
+    assert_eq!(
+        roles(Some("02")),
+        [(U1, PartRole::Rom), (U3, PartRole::Ram)]
+    );
+}
+
+#[test]
+fn test_checks_use_revision_parts() {
+    let boards = load_boards(
+        r#"[{
+            "label": "DMG-TEST",
+            "platforms": ["gb"],
+            "parts": {
+                "U1": {"role": "rom", "parser": "gb_mask_rom_sop_32_5v"},
+                "U2": {"role": "mapper", "parser": "mbc1_sop24", "mapper": "MBC1"},
+                "U3": {"role": "ram", "parser": "sram_sop_28_5v"}
+            },
+            "revisions": {
+                "02": {
+                    "U2": {"role": "mapper", "parser": "mbc5_qfp32", "mapper": "MBC5"},
+                    "U3": null
+                }
+            }
+        }]"#,
+    )
+    .unwrap();
+    let config = BoardConfig(Box::leak(Box::new(boards)).boards.first().unwrap());
+    let board = |revision: Option<&str>| BoardLabel {
+        config,
+        revision: revision.map(str::to_owned),
+        suffix: None,
+    };
+    assert!(SaveType::fits_board(
+        Some(SaveType::Sram),
+        &board(Some("01"))
+    ));
+    assert!(!SaveType::fits_board(
+        Some(SaveType::Sram),
+        &board(Some("02"))
+    ));
+    assert!(SaveType::fits_board(None, &board(Some("02"))));
+
+    let game: GameConfig = serde_json::from_value(serde_json::json!({
+        "name": "", "rom_verified": false, "platform": "gb", "no_intro_id": "",
+        "mapper": "MBC1", "save_type": "sram",
+    }))
+    .unwrap();
+    assert!(check_consistency(&game, &board(None), None).is_empty());
+    let issues = check_consistency(&game, &board(Some("02")), None)
+        .iter()
+        .map(|issue| issue.to_string())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        issues,
+        [
+            "the game expects MBC1, but the mapper is MBC5",
+            "DMG-TEST has no part for SRAM saves"
+        ]
+    );
+}
@@ -2,7 +2,11 @@
 //
 // SPDX-License-Identifier: MIT
 
-use std::str;
+use sha2::Digest as _;
+use std::{
+    io::{self, Read},
+    str,
+};
 
 fn parse_hash<const N: usize>(text: &str) -> Option<[u8; N]> {
     if text.len() != N * 2 {
@@ -84,3 +88,69 @@ impl_hash!(pub struct Crc32([u8; 4]), "CRC-32");
 impl_hash!(pub struct Md5([u8; 16]), "MD5");
 impl_hash!(pub struct Sha1([u8; 20]), "SHA-1");
 impl_hash!(pub struct Sha256([u8; 32]), "SHA-256");
+
+/// All hashes of a ROM dump
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Hashes {
+    pub crc32: Crc32,
+    pub md5: Md5,
+    pub sha1: Sha1,
+    pub sha256: Sha256,
+}
+
+impl Hashes {
+    /// Computes the hashes of everything read from the reader.
+    ///
+    /// ```
+    /// use gbhwdb_model::hash::Hashes;
+    /// let hashes = Hashes::from_reader(&b"abc"[..]).unwrap();
+    /// assert_eq!(hashes.crc32.to_string(), "352441c2");
+    /// assert_eq!(hashes.md5.to_string(), "900150983cd24fb0d6963f7d28e17f72");
+    /// assert_eq!(hashes.sha1.to_string(), "a9993e364706816aba3e25717850c26c9cd0d89d");
+    /// assert_eq!(
+    ///     hashes.sha256.to_string(),
+    ///     "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+    /// );
+    /// ```
+    pub fn from_reader<R: Read>(mut reader: R) -> io::Result<Hashes> {
+        let mut hasher = Hasher::default();
+        io::copy(&mut reader, &mut hasher)?;
+        Ok(hasher.finish())
+    }
+}
+
+/// Computes all hashes in one pass over the data
+#[derive(Clone, Default)]
+pub struct Hasher {
+    crc32: crc32fast::Hasher,
+    md5: md5::Md5,
+    sha1: sha1::Sha1,
+    sha256: sha2::Sha256,
+}
+
+impl Hasher {
+    pub fn update(&mut self, data: &[u8]) {
+        self.crc32.update(data);
+        self.md5.update(data);
+        self.sha1.update(data);
+        self.sha256.update(data);
+    }
+    pub fn finish(self) -> Hashes {
+        Hashes {
+            crc32: Crc32(self.crc32.finalize().to_be_bytes()),
+            md5: Md5(self.md5.finalize().into()),
+            sha1: Sha1(self.sha1.finalize().into()),
+            sha256: Sha256(self.sha256.finalize().into()),
+        }
+    }
+}
+
+impl io::Write for Hasher {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.update(buf);
+        Ok(buf.len())
+    }
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
@@ -33,7 +33,7 @@ impl SubmissionMetadata for AgsConsole {
         &self.slug
     }
 
-    fn identifier(&self) -> SubmissionIdentifier {
+    fn identifier(&self) -> SubmissionIdentifier<'_> {
         SubmissionIdentifier::new(&self.shell.serial, self.index)
     }
 
@@ -44,7 +44,7 @@ impl SubmissionMetadata for AgsConsole {
     fn update_identifier(&mut self, contributor_slug: &str, index: u16) {
         if self.shell.serial.is_empty() {
             self.slug = format!("{}-{}", contributor_slug, index);
-            self.index = Some(index as u16);
+            self.index = Some(index);
         } else {
             self.slug = self.shell.serial.clone();
             self.index = None;
@@ -10,7 +10,7 @@ use crate::parser::Manufacturer;
 use crate::{
     ParseError, SubmissionIdentifier, SubmissionMetadata,
     config::cartridge::PartDesignator,
-    hash::{Crc32, Md5, Sha1, Sha256},
+    hash::{Crc32, Hashes, Md5, Sha1, Sha256},
     input::{Part, is_not_outlier},
     time::Month,
 };
@@ -39,7 +39,7 @@ impl SubmissionMetadata for Cartridge {
         &self.slug
     }
 
-    fn identifier(&self) -> SubmissionIdentifier {
+    fn identifier(&self) -> SubmissionIdentifier<'_> {
         SubmissionIdentifier::Index(self.index)
     }
 
@@ -117,25 +117,56 @@ pub struct CartridgeBoard {
     pub u7: Part,
     #[serde(skip_serializing_if = "Part::is_unknown", default)]
     pub x1: Part,
+    #[serde(skip_serializing_if = "Part::is_unknown", default)]
+    pub d1: Part,
+    #[serde(skip_serializing_if = "Part::is_unknown", default)]
+    pub q1: Part,
+    #[serde(skip_serializing_if = "Part::is_unknown", default)]
+    pub ls1: Part,
+    #[serde(skip_serializing_if = "Part::is_unknown", default)]
+    pub mot1: Part,
+    /// Battery (BT1)
     #[serde(skip_serializing_if = "CartridgeBattery::is_unknown", default)]
     pub battery: CartridgeBattery,
     #[serde(skip_serializing_if = "is_not_outlier", default)]
     pub outlier: bool,
 }
 
+/// Returns the part with the given designator.
+///
+/// # Panics
+///
+/// Panics for the battery (BT1), which is not a plain part because it also records the
+/// manufacturer. Use the `battery` field, or `CartridgeBoard::part` which returns None for it.
 impl Index<PartDesignator> for CartridgeBoard {
     type Output = Part;
 
     fn index(&self, index: PartDesignator) -> &Self::Output {
-        match index {
-            PartDesignator::U1 => &self.u1,
-            PartDesignator::U2 => &self.u2,
-            PartDesignator::U3 => &self.u3,
-            PartDesignator::U4 => &self.u4,
-            PartDesignator::U5 => &self.u5,
-            PartDesignator::U6 => &self.u6,
-            PartDesignator::U7 => &self.u7,
-            PartDesignator::X1 => &self.x1,
+        self.part(index)
+            .unwrap_or_else(|| panic!("{index:?} is not a plain part"))
+    }
+}
+
+impl CartridgeBoard {
+    /// Returns the part with the given designator, or None for the battery (BT1).
+    ///
+    /// The battery is not a plain part, because it also records the manufacturer, so it is only
+    /// available in the `battery` field.
+    pub fn part(&self, designator: PartDesignator) -> Option<&Part> {
+        match designator {
+            PartDesignator::U1 => Some(&self.u1),
+            PartDesignator::U2 => Some(&self.u2),
+            PartDesignator::U3 => Some(&self.u3),
+            PartDesignator::U4 => Some(&self.u4),
+            PartDesignator::U5 => Some(&self.u5),
+            PartDesignator::U6 => Some(&self.u6),
+            PartDesignator::U7 => Some(&self.u7),
+            PartDesignator::X1 => Some(&self.x1),
+            PartDesignator::BT1 => None,
+            PartDesignator::D1 => Some(&self.d1),
+            PartDesignator::Q1 => Some(&self.q1),
+            PartDesignator::LS1 => Some(&self.ls1),
+            PartDesignator::MOT1 => Some(&self.mot1),
         }
     }
 }
@@ -178,6 +209,20 @@ pub struct CartridgeDump {
     pub sha256: Option<Sha256>,
 }
 
+impl CartridgeDump {
+    pub fn new(tool: String, date: Date, hashes: &Hashes) -> CartridgeDump {
+        CartridgeDump {
+            tool,
+            log: String::new(),
+            date,
+            crc32: Some(hashes.crc32),
+            md5: Some(hashes.md5),
+            sha1: Some(hashes.sha1),
+            sha256: Some(hashes.sha256),
+        }
+    }
+}
+
 impl std::error::Error for ParseError {}
 
 mod date_format {
@@ -259,6 +304,9 @@ fn test_deserialize() {
                 "x1": {
                     "label": "KDS"
                 },
+                "mot1": {
+                    "label": "MOT1"
+                },
                 "battery": {
                     "manufacturer": "Panasonic",
                     "label": "98-11"
@@ -318,6 +366,13 @@ fn test_deserialize() {
                     label: "KDS".to_owned(),
                     outlier: false,
                 },
+                d1: Part::default(),
+                q1: Part::default(),
+                ls1: Part::default(),
+                mot1: Part {
+                    label: "MOT1".to_owned(),
+                    outlier: false,
+                },
                 battery: CartridgeBattery {
                     manufacturer: Some(Manufacturer::Panasonic),
                     label: "98-11".to_owned(),
@@ -341,6 +396,9 @@ fn test_deserialize() {
             })
         }
     );
+    assert_eq!(cart.board[PartDesignator::X1].label, "KDS");
+    assert_eq!(cart.board[PartDesignator::MOT1].label, "MOT1");
+    assert_eq!(cart.board.part(PartDesignator::BT1), None);
 }
 
 #[test]
@@ -384,6 +442,10 @@ fn test_deserialize_minimal() {
                 u6: Part::default(),
                 u7: Part::default(),
                 x1: Part::default(),
+                d1: Part::default(),
+                q1: Part::default(),
+                ls1: Part::default(),
+                mot1: Part::default(),
                 battery: CartridgeBattery::default(),
                 outlier: false
             },
@@ -35,7 +35,7 @@ impl SubmissionMetadata for GbsConsole {
         &self.slug
     }
 
-    fn identifier(&self) -> SubmissionIdentifier {
+    fn identifier(&self) -> SubmissionIdentifier<'_> {
         SubmissionIdentifier::Index(self.index)
     }
 
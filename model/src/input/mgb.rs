@@ -38,7 +38,7 @@ impl SubmissionMetadata for MgbConsole {
         &self.slug
     }
 
-    fn identifier(&self) -> SubmissionIdentifier {
+    fn identifier(&self) -> SubmissionIdentifier<'_> {
         SubmissionIdentifier::new(&self.shell.serial, self.index)
     }
 
@@ -49,7 +49,7 @@ impl SubmissionMetadata for MgbConsole {
     fn update_identifier(&mut self, contributor_slug: &str, index: u16) {
         if self.shell.serial.is_empty() {
             self.slug = format!("{}-{}", contributor_slug, index);
-            self.index = Some(index as u16);
+            self.index = Some(index);
         } else {
             self.slug = self.shell.serial.clone();
             self.index = None;
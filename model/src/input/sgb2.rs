@@ -34,7 +34,7 @@ impl SubmissionMetadata for Sgb2Console {
         &self.slug
     }
 
-    fn identifier(&self) -> SubmissionIdentifier {
+    fn identifier(&self) -> SubmissionIdentifier<'_> {
         SubmissionIdentifier::Index(self.index)
     }
 
@@ -6,10 +6,13 @@ use std::fmt;
 
 use serde::{Deserialize, Serialize};
 
+use crate::time::ProductionWindow;
+
 pub mod config;
 pub mod hash;
 pub mod input;
 pub mod parser;
+pub mod rom_header;
 pub mod time;
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Deserialize, Serialize)]
@@ -91,6 +94,21 @@ impl Console {
             Console::Oxy => "Game Boy Micro",
         }
     }
+    /// Years during which the console and its parts were manufactured
+    pub const fn production_window(&self) -> ProductionWindow {
+        match self {
+            Console::Dmg => ProductionWindow::new(1989, 1999),
+            Console::Sgb => ProductionWindow::new(1993, 1998),
+            Console::Mgb => ProductionWindow::new(1994, 2001),
+            Console::Mgl => ProductionWindow::new(1997, 1999),
+            Console::Sgb2 => ProductionWindow::new(1997, 1999),
+            Console::Cgb => ProductionWindow::new(1997, 2002),
+            Console::Agb => ProductionWindow::new(2000, 2005),
+            Console::Ags => ProductionWindow::new(2002, 2008),
+            Console::Gbs => ProductionWindow::new(2002, 2008),
+            Console::Oxy => ProductionWindow::new(2004, 2008),
+        }
+    }
 }
 
 impl fmt::Display for Console {
@@ -111,6 +129,24 @@ impl fmt::Display for ParseError {
 #[macro_use]
 pub(crate) mod macros {
     macro_rules! multi_parser {
+        ($t:ty, definitions: $family:literal, $($m:expr),* $(,)?) => {{
+            static PARSERS: std::sync::OnceLock<Vec<&'static dyn crate::parser::LabelParser<$t>>> =
+                std::sync::OnceLock::new();
+            static PARSER: std::sync::OnceLock<crate::parser::MultiParser<$t>> =
+                std::sync::OnceLock::new();
+            PARSER.get_or_init(|| {
+                crate::parser::MultiParser::new(&PARSERS.get_or_init(|| {
+                    let mut parsers: Vec<&'static dyn crate::parser::LabelParser<$t>> = vec![$($m),*];
+                    match crate::parser::definition::parsers::<$t>($family) {
+                        Ok(defined) => parsers.extend(defined.into_iter().map(|parser| {
+                            parser as &'static dyn crate::parser::LabelParser<$t>
+                        })),
+                        Err(err) => log::error!("{}: {err:#}", $family),
+                    }
+                    parsers
+                }))
+            })
+        }};
         ($t:ty, $($m:expr),* $(,)?) => {{
             static PARSERS: std::sync::OnceLock<Vec<&'static dyn crate::parser::LabelParser<$t>>> =
                 std::sync::OnceLock::new();
@@ -129,7 +165,7 @@ pub trait SubmissionMetadata {
 
     fn contributor(&self) -> &str;
     fn slug(&self) -> &str;
-    fn identifier(&self) -> SubmissionIdentifier;
+    fn identifier(&self) -> SubmissionIdentifier<'_>;
 
     fn set_contributor(&mut self, contributor: &str);
     fn update_identifier(&mut self, contributor_slug: &str, index: u16);
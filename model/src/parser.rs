@@ -3,25 +3,30 @@
 // SPDX-License-Identifier: MIT
 
 use log::warn;
-use nom::bytes::streaming::tag;
 use nom::combinator::fail;
 use nom::sequence::separated_pair;
 use nom::{IResult, Parser as _, combinator::all_consuming};
-use nom_language::error::VerboseError;
+use nom_language::error::{VerboseError, VerboseErrorKind};
 use serde::{Deserialize, Serialize};
 use stamp::{CgbStamp, DmgStamp};
+use std::{collections::HashMap, fmt, ops::RangeInclusive, str::FromStr, sync::OnceLock};
+use strum::VariantArray as _;
+use time::Date;
 
-use crate::parser::for_nom::{month2, year2};
+use crate::parser::for_nom::{month2, tag, year2};
 use crate::{
+    ParseError,
     macros::multi_parser,
-    time::{Month, Week},
+    time::{Jun, Month, Week},
 };
 
 pub mod amic;
 pub mod analog;
 pub mod atmel;
+pub mod audit;
 pub mod bsi;
 pub mod crosslink;
+pub mod definition;
 pub mod fujitsu;
 pub mod hudson;
 pub mod hynix;
@@ -39,6 +44,7 @@ pub mod motorola;
 pub mod nec;
 pub mod oki;
 pub mod panasonic;
+pub mod registry;
 pub mod rohm;
 pub mod samsung;
 pub mod sanyo;
@@ -52,20 +58,434 @@ pub mod ti;
 pub mod toshiba;
 pub mod unknown;
 pub mod victronix;
-pub mod winbond;
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub enum PartDateCode {
     Year { year: Year },
     YearMonth { year: Year, month: Month },
     YearWeek { year: Year, week: Week },
+    YearMonthJun { year: Year, month: Month, jun: Jun },
+    YearMonthDay { year: Year, month: Month, day: u8 },
+}
+
+impl PartDateCode {
+    pub fn year(&self) -> Year {
+        match *self {
+            PartDateCode::Year { year }
+            | PartDateCode::YearMonth { year, .. }
+            | PartDateCode::YearWeek { year, .. }
+            | PartDateCode::YearMonthJun { year, .. }
+            | PartDateCode::YearMonthDay { year, .. } => year,
+        }
+    }
+    /// Returns the inclusive range of calendar dates covered by the date code
+    ///
+    /// The year must be the full year, because a date code may only include a partial one.
+    /// Weeks of year are ISO 8601 weeks. Returns None if the date code is not a valid date in the
+    /// given year.
+    ///
+    /// ```
+    /// use gbhwdb_model::{parser::{PartDateCode, Year}, time::{Jun, Month}};
+    /// use time::macros::date;
+    /// let date_code = PartDateCode::YearMonthJun {
+    ///     year: Year::Partial(2),
+    ///     month: Month::February,
+    ///     jun: Jun::Third,
+    /// };
+    /// assert_eq!(date_code.date_range(1992), Some(date!(1992-02-21)..=date!(1992-02-29)));
+    /// let date_code = PartDateCode::YearWeek {
+    ///     year: Year::Full(1998),
+    ///     week: 1.try_into().unwrap(),
+    /// };
+    /// assert_eq!(date_code.date_range(1998), Some(date!(1997-12-29)..=date!(1998-01-04)));
+    /// ```
+    pub fn date_range(&self, year: u16) -> Option<RangeInclusive<Date>> {
+        let days = |month: Month, days: RangeInclusive<u8>| {
+            let month = ::time::Month::from(month);
+            let last_day = (*days.end()).min(month.length(i32::from(year)));
+            let start = Date::from_calendar_date(i32::from(year), month, *days.start()).ok()?;
+            let end = Date::from_calendar_date(i32::from(year), month, last_day).ok()?;
+            Some(start..=end)
+        };
+        let months = |months: RangeInclusive<Month>| {
+            let start = days(*months.start(), 1..=1)?;
+            let end = days(*months.end(), 31..=31)?;
+            Some(*start.start()..=*end.end())
+        };
+        match *self {
+            PartDateCode::Year { .. } => months(Month::January..=Month::December),
+            PartDateCode::YearMonth { month, .. } => days(month, 1..=31),
+            PartDateCode::YearWeek { week, .. } => {
+                let week = u8::from(week);
+                let start =
+                    Date::from_iso_week_date(i32::from(year), week, ::time::Weekday::Monday);
+                let end = Date::from_iso_week_date(i32::from(year), week, ::time::Weekday::Sunday);
+                Some(start.ok()?..=end.ok()?)
+            }
+            PartDateCode::YearMonthJun { month, jun, .. } => days(month, jun.range(year, month)),
+            PartDateCode::YearMonthDay { month, day, .. } => days(month, day..=day),
+        }
+    }
 }
 
-#[derive(Clone, Debug, Eq, PartialEq)]
+impl ParsedChip for PartDateCode {
+    fn manufacturer(&self) -> Option<Manufacturer> {
+        None
+    }
+}
+
+/// A chip identified by its part number
+///
+/// Package, voltage, density, organization and access time are filled in when the parser knows
+/// them from the part number. Lot codes, revisions and the country of origin are captured from
+/// the rest of the label when present.
+///
+/// ```
+/// use gbhwdb_model::parser::{self, Country, KIBIT, LabelParser, Organization, Package, Voltage};
+/// let part = parser::sharp::SHARP_LH52256CT.parse("LH52256CT-10LL SHARP JAPAN 9842 7 SS").unwrap();
+/// assert_eq!(part.package, Some(Package::TsopI28));
+/// assert_eq!(part.voltage, Some(Voltage::V5));
+/// assert_eq!(part.density, Some(256 * KIBIT));
+/// assert_eq!(part.organization, Some(Organization::new(32 * 1024, 8)));
+/// assert_eq!(part.access_time, Some(100));
+/// assert_eq!(part.lot_code.as_deref(), Some("7 SS"));
+/// assert_eq!(part.origin_country, Some(Country::Japan));
+///
+/// let part = parser::sharp::SHARP_IR3E02.parse("DMG-REG IR3E02 9527 CB").unwrap();
+/// assert_eq!(part.lot_code.as_deref(), Some("CB"));
+/// ```
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
 pub struct GenericPart {
     pub kind: String,
     pub manufacturer: Option<Manufacturer>,
     pub date_code: Option<PartDateCode>,
+    /// Lot, assembly or trace codes printed on the label, separated by spaces if there are several
+    pub lot_code: Option<String>,
+    /// Die or mask revision marking
+    pub revision: Option<String>,
+    pub origin_country: Option<Country>,
+    pub package: Option<Package>,
+    pub voltage: Option<Voltage>,
+    /// Memory density in bits
+    pub density: Option<u32>,
+    pub organization: Option<Organization>,
+    /// Access time in nanoseconds
+    pub access_time: Option<u16>,
+}
+
+impl ParsedChip for GenericPart {
+    fn manufacturer(&self) -> Option<Manufacturer> {
+        self.manufacturer
+    }
+}
+
+#[derive(
+    Copy, Clone, Debug, Eq, PartialEq, Hash, Ord, PartialOrd, Deserialize, strum::VariantArray,
+)]
+pub enum Package {
+    #[serde(rename = "Glop top")]
+    GlopTop,
+    #[serde(rename = "SOP-8")]
+    Sop8,
+    #[serde(rename = "TSSOP-8")]
+    Tssop8,
+    #[serde(rename = "LCC-8")]
+    Lcc8,
+    #[serde(rename = "CERPAK-14")]
+    Cerpak14,
+    #[serde(rename = "SSOP-18")]
+    Ssop18,
+    #[serde(rename = "SOP-20")]
+    Sop20,
+    #[serde(rename = "SOP-24")]
+    Sop24,
+    #[serde(rename = "SOP-28")]
+    Sop28,
+    #[serde(rename = "SOP-32")]
+    Sop32,
+    #[serde(rename = "SOP-44")]
+    Sop44,
+    #[serde(rename = "TSOP-I-28")]
+    TsopI28,
+    #[serde(rename = "TSOP-I-32")]
+    TsopI32,
+    #[serde(rename = "TSOP-I-40")]
+    TsopI40,
+    #[serde(rename = "TSOP-I-48")]
+    TsopI48,
+    #[serde(rename = "TSOP-II-44")]
+    TsopIi44,
+    #[serde(rename = "QFP-32")]
+    Qfp32,
+    #[serde(rename = "QFP-44")]
+    Qfp44,
+    #[serde(rename = "QFP-48")]
+    Qfp48,
+    #[serde(rename = "QFP-56")]
+    Qfp56,
+    #[serde(rename = "QFP-64")]
+    Qfp64,
+    #[serde(rename = "QFP-80")]
+    Qfp80,
+    #[serde(rename = "QFP-128")]
+    Qfp128,
+    #[serde(rename = "QFP-156")]
+    Qfp156,
+    #[serde(rename = "BGA")]
+    Bga,
+}
+
+impl Package {
+    pub const fn name(&self) -> &'static str {
+        match self {
+            Package::GlopTop => "Glop top",
+            Package::Sop8 => "SOP-8",
+            Package::Tssop8 => "TSSOP-8",
+            Package::Lcc8 => "LCC-8",
+            Package::Cerpak14 => "CERPAK-14",
+            Package::Ssop18 => "SSOP-18",
+            Package::Sop20 => "SOP-20",
+            Package::Sop24 => "SOP-24",
+            Package::Sop28 => "SOP-28",
+            Package::Sop32 => "SOP-32",
+            Package::Sop44 => "SOP-44",
+            Package::TsopI28 => "TSOP-I-28",
+            Package::TsopI32 => "TSOP-I-32",
+            Package::TsopI40 => "TSOP-I-40",
+            Package::TsopI48 => "TSOP-I-48",
+            Package::TsopIi44 => "TSOP-II-44",
+            Package::Qfp32 => "QFP-32",
+            Package::Qfp44 => "QFP-44",
+            Package::Qfp48 => "QFP-48",
+            Package::Qfp56 => "QFP-56",
+            Package::Qfp64 => "QFP-64",
+            Package::Qfp80 => "QFP-80",
+            Package::Qfp128 => "QFP-128",
+            Package::Qfp156 => "QFP-156",
+            Package::Bga => "BGA",
+        }
+    }
+}
+
+impl fmt::Display for Package {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// Country of origin printed on a chip label (e.g. "JAPAN")
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash, Ord, PartialOrd, strum::VariantArray)]
+pub enum Country {
+    Japan,
+    Korea,
+    Taiwan,
+    UnitedStates,
+}
+
+impl Country {
+    pub const fn name(&self) -> &'static str {
+        match self {
+            Country::Japan => "Japan",
+            Country::Korea => "Korea",
+            Country::Taiwan => "Taiwan",
+            Country::UnitedStates => "United States",
+        }
+    }
+    /// Returns the country as it is printed on labels
+    pub const fn label(&self) -> &'static str {
+        match self {
+            Country::Japan => "JAPAN",
+            Country::Korea => "KOREA",
+            Country::Taiwan => "TAIWAN",
+            Country::UnitedStates => "USA",
+        }
+    }
+}
+
+impl fmt::Display for Country {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// Supply voltage range in millivolts
+///
+/// ```
+/// use gbhwdb_model::parser::Voltage;
+/// assert_eq!(Voltage::V5.to_string(), "5V");
+/// assert_eq!(Voltage::range(2_700, 5_500).to_string(), "2.7-5.5V");
+/// assert_eq!("3.3V".parse(), Ok(Voltage::V3_3));
+/// assert_eq!("4.5-5.5V".parse(), Ok(Voltage::range(4_500, 5_500)));
+/// assert!("5.5-4.5V".parse::<Voltage>().is_err());
+/// ```
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash, Ord, PartialOrd, Deserialize)]
+#[serde(try_from = "String")]
+pub struct Voltage {
+    pub min: u16,
+    pub max: u16,
+}
+
+impl Voltage {
+    pub const V3_3: Voltage = Voltage::nominal(3_300);
+    pub const V5: Voltage = Voltage::nominal(5_000);
+    pub const fn nominal(mv: u16) -> Voltage {
+        Voltage { min: mv, max: mv }
+    }
+    pub const fn range(min: u16, max: u16) -> Voltage {
+        Voltage { min, max }
+    }
+    /// Returns true if the given supply voltage is within the range
+    pub fn supports(&self, mv: u16) -> bool {
+        (self.min..=self.max).contains(&mv)
+    }
+}
+
+impl FromStr for Voltage {
+    type Err = ParseError;
+
+    /// Parses a voltage like "5V", "3.3V" or "4.5-5.5V"
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        const ERROR: ParseError = ParseError("invalid voltage");
+        fn millivolts(text: &str) -> Option<u16> {
+            let (volts, fraction) = text.split_once('.').unwrap_or((text, ""));
+            if fraction.len() > 3 || !fraction.chars().all(|ch| ch.is_ascii_digit()) {
+                return None;
+            }
+            let fraction = format!("{fraction:0<3}").parse::<u16>().ok()?;
+            volts
+                .parse::<u16>()
+                .ok()?
+                .checked_mul(1000)?
+                .checked_add(fraction)
+        }
+        let range = text.strip_suffix('V').ok_or(ERROR)?;
+        let voltage = match range.split_once('-') {
+            Some((min, max)) => millivolts(min).zip(millivolts(max)),
+            None => millivolts(range).map(|mv| (mv, mv)),
+        };
+        match voltage {
+            Some((min, max)) if min <= max => Ok(Voltage::range(min, max)),
+            _ => Err(ERROR),
+        }
+    }
+}
+
+impl TryFrom<String> for Voltage {
+    type Error = ParseError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl fmt::Display for Voltage {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fn volts(mv: u16) -> String {
+            let text = format!("{}.{:03}", mv / 1000, mv % 1000);
+            text.trim_end_matches('0').trim_end_matches('.').to_owned()
+        }
+        if self.min == self.max {
+            write!(f, "{}V", volts(self.min))
+        } else {
+            write!(f, "{}-{}V", volts(self.min), volts(self.max))
+        }
+    }
+}
+
+/// Memory organization as words x bits per word
+///
+/// ```
+/// use gbhwdb_model::parser::Organization;
+/// assert_eq!(Organization::new(128 * 1024, 16).to_string(), "128K x 16");
+/// assert_eq!(Organization::new(128 * 1024, 16).density(), 2 * 1024 * 1024);
+/// assert_eq!("32K x 8".parse(), Ok(Organization::new(32 * 1024, 8)));
+/// assert!("32K".parse::<Organization>().is_err());
+/// ```
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash, Ord, PartialOrd, Deserialize)]
+#[serde(try_from = "String")]
+pub struct Organization {
+    pub words: u32,
+    pub width: u8,
+}
+
+impl Organization {
+    pub const fn new(words: u32, width: u8) -> Organization {
+        Organization { words, width }
+    }
+    /// Returns the total density in bits
+    pub const fn density(&self) -> u32 {
+        self.words * self.width as u32
+    }
+}
+
+impl FromStr for Organization {
+    type Err = ParseError;
+
+    /// Parses a memory organization like "32K x 8"
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let organization = text.split_once(" x ").and_then(|(words, width)| {
+            let (words, scale) = if let Some(words) = words.strip_suffix('M') {
+                (words, 1024 * 1024)
+            } else if let Some(words) = words.strip_suffix('K') {
+                (words, 1024)
+            } else {
+                (words, 1)
+            };
+            Some(Organization::new(
+                words.parse::<u32>().ok()?.checked_mul(scale)?,
+                width.parse().ok()?,
+            ))
+        });
+        organization.ok_or(ParseError("invalid memory organization"))
+    }
+}
+
+impl TryFrom<String> for Organization {
+    type Error = ParseError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl fmt::Display for Organization {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.words.is_multiple_of(1024 * 1024) {
+            write!(f, "{}M x {}", self.words / (1024 * 1024), self.width)
+        } else if self.words.is_multiple_of(1024) {
+            write!(f, "{}K x {}", self.words / 1024, self.width)
+        } else {
+            write!(f, "{} x {}", self.words, self.width)
+        }
+    }
+}
+
+pub const KIBIT: u32 = 1024;
+pub const MIBIT: u32 = 1024 * 1024;
+
+/// Decodes a two-digit speed grade into an access time in nanoseconds
+///
+/// The slow grades "10", "12" and "15" are in units of 10 ns (e.g. "10" is 100 ns), and grades
+/// from "50" upwards are in nanoseconds (e.g. "70" is 70 ns). Other grades are ambiguous between
+/// manufacturers, so they are not decoded.
+///
+/// ```
+/// # use gbhwdb_model::parser::{self, LabelParser};
+/// let part = parser::sram_sop_28_5v().parse("LH5164AN-10L SHARP JAPAN 9933 3 EB").unwrap();
+/// assert_eq!(part.access_time, Some(100));
+/// ```
+fn speed_grade(code: &str) -> Option<u16> {
+    match code.parse::<u16>().ok()? {
+        grade @ (10 | 12 | 15) => Some(grade * 10),
+        grade @ 50..100 => Some(grade),
+        _ => None,
+    }
+}
+
+/// Decodes the speed grade that follows a dash in a part number (e.g. "LH5164AN-10L")
+fn dash_speed_grade(kind: &str) -> Option<u16> {
+    let (_, suffix) = kind.rsplit_once('-')?;
+    speed_grade(suffix.get(..2)?)
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -73,6 +493,17 @@ pub struct Crystal {
     pub manufacturer: Option<Manufacturer>,
     pub frequency: u32,
     pub date_code: Option<PartDateCode>,
+    /// Lot, assembly or trace codes printed on the label, separated by spaces if there are several
+    pub lot_code: Option<String>,
+    /// Die or mask revision marking
+    pub revision: Option<String>,
+    pub origin_country: Option<Country>,
+}
+
+impl ParsedChip for Crystal {
+    fn manufacturer(&self) -> Option<Manufacturer> {
+        self.manufacturer
+    }
 }
 
 impl Crystal {
@@ -102,6 +533,9 @@ impl Crystal {
     Debug,
     Eq,
     PartialEq,
+    Ord,
+    PartialOrd,
+    Hash,
     Deserialize,
     Serialize,
     strum::VariantArray,
@@ -194,6 +628,327 @@ impl Manufacturer {
             Manufacturer::Winbond => "Winbond",
         }
     }
+    /// Returns known aliases, markings, country and date code formats of the manufacturer
+    ///
+    /// ```
+    /// use gbhwdb_model::parser::{Country, DateCodeFormat, Manufacturer};
+    /// let info = Manufacturer::Lgs.info();
+    /// assert!(info.aliases.contains(&"LG Semicon"));
+    /// assert_eq!(info.country, Some(Country::Korea));
+    /// assert_eq!(info.date_code_formats, &[DateCodeFormat::Year2Week2]);
+    /// ```
+    pub const fn info(&self) -> ManufacturerInfo {
+        use Country::*;
+        use DateCodeFormat::*;
+        const fn info(
+            aliases: &'static [&'static str],
+            markings: &'static [&'static str],
+            country: Option<Country>,
+            date_code_formats: &'static [DateCodeFormat],
+        ) -> ManufacturerInfo {
+            ManufacturerInfo {
+                aliases,
+                markings,
+                country,
+                date_code_formats,
+            }
+        }
+        match self {
+            Manufacturer::Amic => info(&["AMIC"], &["AMIC"], Some(Taiwan), &[Year2Week2]),
+            Manufacturer::Analog => info(&["ADI"], &[], Some(UnitedStates), &[Year2Week2]),
+            Manufacturer::Atmel => info(&[], &[], Some(UnitedStates), &[Year2Week2]),
+            Manufacturer::AtT => info(
+                &["AT&T Microelectronics"],
+                &["AT&T"],
+                Some(UnitedStates),
+                &[],
+            ),
+            Manufacturer::Bsi => info(
+                &["Brilliance Semiconductor"],
+                &["BSI"],
+                Some(Taiwan),
+                &[Year2Week2],
+            ),
+            Manufacturer::Crosslink => info(&["Xlink"], &["Xlink"], None, &[Year1Week2]),
+            Manufacturer::Fujitsu => info(&[], &[], Some(Japan), &[Year2Week2]),
+            Manufacturer::Hudson => info(&["Hudson Soft"], &[], Some(Japan), &[Year2Week2]),
+            Manufacturer::Hynix => info(
+                &["Hynix Semiconductor", "SK Hynix"],
+                &["hynix", "Hynix", "HYNIX"],
+                Some(Korea),
+                &[Year2Week2],
+            ),
+            Manufacturer::Hyundai => info(
+                &["Hyundai Electronics"],
+                &["HYUNDAI"],
+                Some(Korea),
+                &[Year2Week2],
+            ),
+            Manufacturer::Kds => info(
+                &["KDS", "Daishinku Corp."],
+                &["KDS", "KDSI"],
+                Some(Japan),
+                &[Year2Week2, Year1Month1Abc, Month1AbcYear1],
+            ),
+            Manufacturer::Kinseki => info(&[], &["KSS"], Some(Japan), &[Year1Month1Abc]),
+            Manufacturer::Lgs => info(
+                &["LG Semicon", "LGS", "GoldStar"],
+                &["LGS"],
+                Some(Korea),
+                &[Year2Week2],
+            ),
+            Manufacturer::LsiLogic => info(&[], &["LSI LOGIC"], Some(UnitedStates), &[Year1Week2]),
+            Manufacturer::Macronix => info(&["MXIC"], &[], Some(Taiwan), &[Year2Week2]),
+            Manufacturer::Magnachip => info(
+                &["MagnaChip Semiconductor"],
+                &["MAGNACHIP"],
+                Some(Korea),
+                &[],
+            ),
+            Manufacturer::Mani => info(&[], &["MANI"], None, &[]),
+            Manufacturer::Maxell => info(&["Hitachi Maxell"], &[], Some(Japan), &[]),
+            Manufacturer::Mitsubishi => info(&[], &[], Some(Japan), &[]),
+            Manufacturer::Mitsumi => info(&[], &["MITSUMI"], Some(Japan), &[Year1Week2]),
+            Manufacturer::MoselVitelic => info(
+                &["Mosel Vitelic"],
+                &["MOSEL-VITELIC"],
+                Some(Taiwan),
+                &[Year1Week2],
+            ),
+            Manufacturer::Motorola => info(&[], &[], Some(UnitedStates), &[Year2Week2]),
+            Manufacturer::Nec => info(&[], &["NEC"], Some(Japan), &[Year2Week2]),
+            Manufacturer::Oki => info(&["Oki Electric"], &["OKI"], Some(Japan), &[Year1Week2]),
+            Manufacturer::Panasonic => info(
+                &["Matsushita"],
+                &["Panasonic"],
+                Some(Japan),
+                &[Year1Week2, Year2Week2],
+            ),
+            Manufacturer::Rohm => info(&[], &[], Some(Japan), &[Year1Week2, Year1Month1_123Abc]),
+            Manufacturer::Samsung => info(&["SEC"], &["SEC"], Some(Korea), &[]),
+            Manufacturer::Sanyo => info(&[], &["SANYO"], Some(Japan), &[Year1Month1Abc]),
+            Manufacturer::Seiko => info(&["SII"], &[], Some(Japan), &[Year1Month1_123Xyz]),
+            Manufacturer::Sharp => info(
+                &[],
+                &["SHARP"],
+                Some(Japan),
+                &[Year1Week2, Year2Week2, Year1Month2, Year2Month2Day2],
+            ),
+            Manufacturer::Smsc => info(&["SMSC"], &["STANDARD MICRO"], Some(UnitedStates), &[]),
+            Manufacturer::Sony => info(&[], &[], Some(Japan), &[]),
+            Manufacturer::Sst => info(
+                &["Silicon Storage Technology"],
+                &[],
+                Some(UnitedStates),
+                &[Year2Week2],
+            ),
+            Manufacturer::StMicro => info(&["ST", "SGS-Thomson"], &[], None, &[Year1Week2]),
+            Manufacturer::Tdk => info(&[], &["TDK"], Some(Japan), &[]),
+            Manufacturer::TexasInstruments => {
+                info(&["TI"], &[], Some(UnitedStates), &[Year1Month1_123Abc])
+            }
+            Manufacturer::Toshiba => info(&[], &["TOSHIBA"], Some(Japan), &[Year2Week2]),
+            Manufacturer::Victronix => info(&[], &["Victronix"], None, &[Year2Week2]),
+            Manufacturer::Winbond => info(&[], &["Winbond"], Some(Taiwan), &[Year1Week2]),
+        }
+    }
+    /// Infers the manufacturer from a marking printed on the label
+    ///
+    /// ```
+    /// use gbhwdb_model::parser::Manufacturer;
+    /// assert_eq!(Manufacturer::from_markings("HYUNDAI GM76C256CLLFW70 0047 KOREA"), Some(Manufacturer::Hyundai));
+    /// assert_eq!(Manufacturer::from_markings("LCS5 040"), None);
+    /// ```
+    pub fn from_markings(label: &str) -> Option<Manufacturer> {
+        let label = format!(" {} ", normalize_label(label).canonical.replace('\n', " "));
+        Manufacturer::VARIANTS.iter().copied().find(|manufacturer| {
+            manufacturer
+                .info()
+                .markings
+                .iter()
+                .any(|marking| label.contains(&format!(" {marking} ")))
+        })
+    }
+    /// Returns the company history links of the manufacturer and its related companies, in
+    /// chronological order
+    ///
+    /// ```
+    /// use gbhwdb_model::parser::Manufacturer;
+    /// let lineage = Manufacturer::Hynix.lineage();
+    /// assert_eq!(lineage.len(), 3);
+    /// assert_eq!(lineage[1].to_string(), "Hyundai renamed to Hynix (2001)");
+    /// ```
+    pub fn lineage(&self) -> Vec<&'static LineageLink> {
+        let mut related = vec![*self];
+        let mut links = Vec::new();
+        while let Some(link) = LINEAGE.iter().find(|link| {
+            !links.contains(link) && (related.contains(&link.from) || related.contains(&link.to))
+        }) {
+            links.push(link);
+            for manufacturer in [link.from, link.to] {
+                if !related.contains(&manufacturer) {
+                    related.push(manufacturer);
+                }
+            }
+        }
+        links.sort_by_key(|link| link.year);
+        links
+    }
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct ManufacturerInfo {
+    /// Other names the company or brand is known by
+    pub aliases: &'static [&'static str],
+    /// Logo or brand text printed on chip labels
+    pub markings: &'static [&'static str],
+    /// Country of the headquarters
+    pub country: Option<Country>,
+    /// Date code formats seen on the labels of the manufacturer
+    pub date_code_formats: &'static [DateCodeFormat],
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum LineageEvent {
+    Renamed,
+    MergedInto,
+    SpunOff,
+    AcquiredBy,
+}
+
+/// A change in company history between two manufacturers
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct LineageLink {
+    pub from: Manufacturer,
+    pub to: Manufacturer,
+    pub event: LineageEvent,
+    pub year: u16,
+}
+
+impl fmt::Display for LineageLink {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let (from, to) = (self.from.name(), self.to.name());
+        match self.event {
+            LineageEvent::Renamed => write!(f, "{from} renamed to {to}")?,
+            LineageEvent::MergedInto => write!(f, "{from} merged into {to}")?,
+            LineageEvent::SpunOff => write!(f, "{to} spun off from {from}")?,
+            LineageEvent::AcquiredBy => write!(f, "{from} acquired by {to}")?,
+        }
+        write!(f, " ({})", self.year)
+    }
+}
+
+pub const LINEAGE: &[LineageLink] = &[
+    LineageLink {
+        from: Manufacturer::Lgs,
+        to: Manufacturer::Hyundai,
+        event: LineageEvent::MergedInto,
+        year: 1999,
+    },
+    LineageLink {
+        from: Manufacturer::Hyundai,
+        to: Manufacturer::Hynix,
+        event: LineageEvent::Renamed,
+        year: 2001,
+    },
+    LineageLink {
+        from: Manufacturer::Hynix,
+        to: Manufacturer::Magnachip,
+        event: LineageEvent::SpunOff,
+        year: 2004,
+    },
+    LineageLink {
+        from: Manufacturer::Oki,
+        to: Manufacturer::Rohm,
+        event: LineageEvent::AcquiredBy,
+        year: 2008,
+    },
+];
+
+/// Date code layout printed on chip labels, named after the parser that reads it
+#[derive(Copy, Clone, Debug, Eq, PartialEq, strum::VariantArray)]
+pub enum DateCodeFormat {
+    /// e.g. "946" for week 46 of 1999 (or 1989, 2009)
+    Year1Week2,
+    /// e.g. "9946" for week 46 of 1999
+    Year2Week2,
+    /// e.g. "911" for November 1999 (or 1989, 2009)
+    Year1Month2,
+    /// e.g. "991124" for 24th of November 1999
+    Year2Month2Day2,
+    /// e.g. "9L" for November 1999, using A-M without I for months
+    Year1Month1Abc,
+    /// e.g. "9B" for November 1999, using 1-9 and A-C for months
+    Year1Month1_123Abc,
+    /// e.g. "9Y" for November 1999, using 1-9 and X-Z for months
+    Year1Month1_123Xyz,
+    /// e.g. "L9" for November 1999, using A-M without I for months
+    Month1AbcYear1,
+}
+
+impl DateCodeFormat {
+    /// Returns the name used for the format in chip definition files
+    pub const fn name(&self) -> &'static str {
+        match self {
+            DateCodeFormat::Year1Week2 => "year1_week2",
+            DateCodeFormat::Year2Week2 => "year2_week2",
+            DateCodeFormat::Year1Month2 => "year1_month2",
+            DateCodeFormat::Year2Month2Day2 => "year2_month2_day2",
+            DateCodeFormat::Year1Month1Abc => "year1_month1_abc",
+            DateCodeFormat::Year1Month1_123Abc => "year1_month1_123abc",
+            DateCodeFormat::Year1Month1_123Xyz => "year1_month1_123xyz",
+            DateCodeFormat::Month1AbcYear1 => "month1_abc_year1",
+        }
+    }
+    pub fn from_name(name: &str) -> Option<DateCodeFormat> {
+        DateCodeFormat::VARIANTS
+            .iter()
+            .copied()
+            .find(|format| format.name() == name)
+    }
+    /// Formats a date code, or returns None if it cannot be written in this format
+    ///
+    /// ```
+    /// use gbhwdb_model::{parser::{DateCodeFormat, PartDateCode, Year}, time::Month};
+    /// let date_code = PartDateCode::YearMonth { year: Year::Partial(9), month: Month::November };
+    /// assert_eq!(DateCodeFormat::Year1Month1Abc.format(date_code).as_deref(), Some("9L"));
+    /// assert_eq!(DateCodeFormat::Year1Week2.format(date_code), None);
+    /// ```
+    pub fn format(self, date_code: PartDateCode) -> Option<String> {
+        use for_format::*;
+        match self {
+            DateCodeFormat::Year1Week2 => year1_week2(date_code),
+            DateCodeFormat::Year2Week2 => year2_week2(date_code),
+            DateCodeFormat::Year1Month2 => year1_month2(date_code),
+            DateCodeFormat::Year2Month2Day2 => year2_month2_day2(date_code),
+            DateCodeFormat::Year1Month1Abc => year1_month1(date_code, month1_abc),
+            DateCodeFormat::Year1Month1_123Abc => year1_month1(date_code, month1_123abc),
+            DateCodeFormat::Year1Month1_123Xyz => year1_month1(date_code, month1_123xyz),
+            DateCodeFormat::Month1AbcYear1 => match date_code {
+                PartDateCode::YearMonth { year, month } => {
+                    Some(format!("{}{}", month1_abc(month), year1(year)?))
+                }
+                _ => None,
+            },
+        }
+    }
+    fn parse(self, input: &str) -> IResult<&str, PartDateCode, VerboseError<&str>> {
+        use for_nom::*;
+        match self {
+            DateCodeFormat::Year1Week2 => year1_week2(input),
+            DateCodeFormat::Year2Week2 => year2_week2(input),
+            DateCodeFormat::Year1Month2 => year1_month2(input),
+            DateCodeFormat::Year2Month2Day2 => year2_month2_day2(input),
+            DateCodeFormat::Year1Month1Abc => year1_month1_abc(input),
+            DateCodeFormat::Year1Month1_123Abc => year1_month1_123abc(input),
+            DateCodeFormat::Year1Month1_123Xyz => (year1, month1_123xyz)
+                .map(|(year, month)| PartDateCode::YearMonth { year, month })
+                .parse(input),
+            DateCodeFormat::Month1AbcYear1 => (month1_abc, year1)
+                .map(|(month, year)| PartDateCode::YearMonth { year, month })
+                .parse(input),
+        }
+    }
 }
 
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
@@ -202,20 +957,42 @@ pub enum Year {
     Partial(u8),
 }
 
+// Date code fields are always decimal, and are parsed with an explicit radix to say so
+#[allow(clippy::from_str_radix_10)]
 mod for_nom {
     use nom::{
-        IResult, Parser,
+        Err, IResult, Needed, Parser,
         branch::alt,
-        bytes::streaming::{tag, take},
+        bytes::streaming::take,
         character::streaming::{anychar, char, satisfy},
-        combinator::{map_opt, recognize},
+        combinator::{map_opt, recognize, value},
         error::ParseError,
         multi::fold_many_m_n,
     };
 
-    use super::{PartDateCode, Year};
+    use super::{Country, PartDateCode, Year};
     use crate::time::{Month, Week};
 
+    /// Streaming tag that reports a mismatch at the first differing character.
+    ///
+    /// Behaves like `nom::bytes::streaming::tag`, but the error points to the exact position where
+    /// the input diverges from the tag, which makes parse failures much easier to diagnose.
+    pub fn tag<'a, 't, E: ParseError<&'a str>>(
+        tag: &'t str,
+    ) -> impl Fn(&'a str) -> IResult<&'a str, &'a str, E> + use<'a, 't, E> {
+        move |input: &'a str| {
+            let mut offset = 0;
+            for expected in tag.chars() {
+                match input[offset..].chars().next() {
+                    Some(ch) if ch == expected => offset += ch.len_utf8(),
+                    Some(_) => return Err(Err::Error(E::from_char(&input[offset..], expected))),
+                    None => return Err(Err::Incomplete(Needed::new(tag.len() - offset))),
+                }
+            }
+            Ok((&input[offset..], &input[..offset]))
+        }
+    }
+
     pub fn dmg_rom_code<'a, E: ParseError<&'a str>>()
     -> impl Parser<&'a str, Output = &'a str, Error = E> {
         recognize((
@@ -325,11 +1102,11 @@ mod for_nom {
             .parse(input)
     }
 
-    pub fn year2_month2<'a, E: ParseError<&'a str>>(
+    pub fn year2_month2_day2<'a, E: ParseError<&'a str>>(
         input: &'a str,
     ) -> IResult<&'a str, PartDateCode, E> {
-        (year2, month2)
-            .map(|(year, month)| PartDateCode::YearMonth { year, month })
+        (year2, month2, day2)
+            .map(|(year, month, day)| PartDateCode::YearMonthDay { year, month, day })
             .parse(input)
     }
 
@@ -445,6 +1222,25 @@ mod for_nom {
         .parse(input)
     }
 
+    /// Parses a two-digit day of the month (01-31).
+    ///
+    /// Some labels print 00 when the day is unknown (e.g. Sharp LCD screens), and their parsers
+    /// handle that case separately instead of accepting it here.
+    pub fn day2<'a, E: ParseError<&'a str>>(input: &'a str) -> IResult<&'a str, u8, E> {
+        map_opt(take(2_usize), |text| {
+            u8::from_str_radix(text, 10)
+                .ok()
+                .filter(|day| (1..=31).contains(day))
+        })
+        .parse(input)
+    }
+
+    pub fn country<'a, E: ParseError<&'a str>>(
+        country: Country,
+    ) -> impl Parser<&'a str, Output = Country, Error = E> {
+        value(country, tag(country.label()))
+    }
+
     fn line_sep<'a, E: ParseError<&'a str>>(input: &'a str) -> IResult<&'a str, char, E> {
         alt((char(' '), char('\n'))).parse(input)
     }
@@ -485,56 +1281,728 @@ mod for_nom {
     }
 }
 
+/// Inverses of the date code parsers in `for_nom`, used by label formatters
+mod for_format {
+    use super::{PartDateCode, Year};
+    use crate::time::Month;
+
+    pub fn year1(year: Year) -> Option<String> {
+        match year {
+            Year::Partial(year) => Some(year.to_string()),
+            Year::Full(_) => None,
+        }
+    }
+
+    pub fn year2(year: Year) -> Option<String> {
+        match year {
+            Year::Full(year) => Some(format!("{:02}", year % 100)),
+            Year::Partial(_) => None,
+        }
+    }
+
+    pub fn year1_week2(date_code: PartDateCode) -> Option<String> {
+        match date_code {
+            PartDateCode::YearWeek { year, week } => {
+                Some(format!("{}{:02}", year1(year)?, u8::from(week)))
+            }
+            _ => None,
+        }
+    }
+
+    pub fn year1_month2(date_code: PartDateCode) -> Option<String> {
+        match date_code {
+            PartDateCode::YearMonth {
+                year: Year::Partial(year),
+                month,
+            } => Some(format!("{year}{:02}", u8::from(month))),
+            _ => None,
+        }
+    }
+
+    pub fn year2_week2(date_code: PartDateCode) -> Option<String> {
+        match date_code {
+            PartDateCode::YearWeek { year, week } => {
+                Some(format!("{}{:02}", year2(year)?, u8::from(week)))
+            }
+            _ => None,
+        }
+    }
+
+    pub fn year2_month2_day2(date_code: PartDateCode) -> Option<String> {
+        match date_code {
+            PartDateCode::YearMonthDay { year, month, day } => {
+                Some(format!("{}{:02}{day:02}", year2(year)?, u8::from(month)))
+            }
+            _ => None,
+        }
+    }
+
+    /// Formats a year and month date code with the given month encoding
+    pub fn year1_month1(date_code: PartDateCode, month1: impl Fn(Month) -> char) -> Option<String> {
+        match date_code {
+            PartDateCode::YearMonth { year, month } => {
+                Some(format!("{}{}", year1(year)?, month1(month)))
+            }
+            _ => None,
+        }
+    }
+
+    /// Formats a month and year date code with the given month encoding
+    pub fn month1_year1(date_code: PartDateCode, month1: impl Fn(Month) -> char) -> Option<String> {
+        match date_code {
+            PartDateCode::YearMonth { year, month } => {
+                Some(format!("{}{}", month1(month), year1(year)?))
+            }
+            _ => None,
+        }
+    }
+
+    pub fn month1_123abc(month: Month) -> char {
+        b"123456789ABC"[usize::from(u8::from(month) - 1)].into()
+    }
+
+    pub fn month1_123xyz(month: Month) -> char {
+        b"123456789XYZ"[usize::from(u8::from(month) - 1)].into()
+    }
+
+    pub fn month1_123ond(month: Month) -> char {
+        b"123456789OND"[usize::from(u8::from(month) - 1)].into()
+    }
+
+    pub fn month1_abc(month: Month) -> char {
+        b"ABCDEFGHJKLM"[usize::from(u8::from(month) - 1)].into()
+    }
+}
+
 pub trait LabelParser<T>: Send + Sync {
-    fn parse(&self, label: &str) -> Result<T, String>;
+    fn parse(&self, label: &str) -> Result<T, ParseFailure> {
+        self.parse_normalized(label).map(|(_, chip)| chip)
+    }
+    /// Normalizes and parses the label, and returns the canonical form that was accepted
+    ///
+    /// If the canonical label is rejected, the word where parsing stopped is uppercased and the
+    /// label is parsed again, because lot codes are often written down in the wrong case.
+    ///
+    /// ```
+    /// use gbhwdb_model::parser::{self, LabelParser, Normalization};
+    /// let (label, _) = parser::sanyo::SANYO_LE26FV10
+    ///     .parse_normalized("LE26FV10N1TS -10 3mu50")
+    ///     .unwrap();
+    /// assert_eq!(label.canonical, "LE26FV10N1TS -10 3MU50");
+    /// assert_eq!(label.applied, [Normalization::LetterCase]);
+    /// ```
+    fn parse_normalized(&self, label: &str) -> Result<(NormalizedLabel, T), ParseFailure> {
+        self.parse_named(label)
+            .map(|(label, _, chip)| (label, chip))
+    }
+    /// Like `parse_normalized`, but also returns the name of the parser that accepted the label
+    ///
+    /// ```
+    /// use gbhwdb_model::parser::{self, LabelParser};
+    /// let (_, name, _) = parser::gb_mask_rom_sop_32_5v()
+    ///     .parse_named("DMG-AM6J-0 F1 M538011E-36 9085401")
+    ///     .unwrap();
+    /// assert_eq!(name, "OKI MSM538011");
+    /// ```
+    fn parse_named(&self, label: &str) -> Result<(NormalizedLabel, &'static str, T), ParseFailure> {
+        let mut label = normalize_label(label);
+        let failure = match self.parse_canonical_named(&label.canonical) {
+            Ok((name, chip)) => return Ok((label, name, chip)),
+            Err(failure) => failure,
+        };
+        let mut canonical = label.canonical.clone();
+        let mut offset = failure.closest().map(|candidate| candidate.offset);
+        while let Some(uppercased) = offset.and_then(|offset| uppercase_word_at(&canonical, offset))
+        {
+            canonical = uppercased;
+            match self.parse_canonical_named(&canonical) {
+                Ok((name, chip)) => {
+                    label.canonical = canonical;
+                    label.applied.push(Normalization::LetterCase);
+                    return Ok((label, name, chip));
+                }
+                Err(failure) => offset = failure.closest().map(|candidate| candidate.offset),
+            }
+        }
+        Err(failure)
+    }
+    /// Returns the name and output of every parser that accepts the label
+    fn parse_all(&self, label: &str) -> Vec<(&'static str, T)> {
+        self.parse_all_canonical(&normalize_label(label).canonical)
+    }
+    /// Parses a label that is already in canonical form, see `normalize_label`
+    fn parse_canonical(&self, label: &str) -> Result<T, ParseFailure> {
+        self.parse_canonical_named(label).map(|(_, chip)| chip)
+    }
+    /// Parses a label that is already in canonical form, and returns the name of the parser that
+    /// accepted it
+    fn parse_canonical_named(&self, label: &str) -> Result<(&'static str, T), ParseFailure>;
+    /// Returns the name and output of every parser that accepts the canonical label
+    fn parse_all_canonical(&self, label: &str) -> Vec<(&'static str, T)>;
+    /// Returns the individual chip parsers this parser is made of
+    fn members(&self) -> Vec<&NomParser<T>>;
+    /// Returns the indices of the members that a canonical label is dispatched to
+    fn candidates(&self, label: &str) -> Vec<usize>;
+}
+
+/// Parser output that may identify the manufacturer of the chip
+pub trait ParsedChip {
+    fn manufacturer(&self) -> Option<Manufacturer>;
+}
+
+/// Describes why a label could not be parsed by any of the candidate parsers
+///
+/// ```
+/// use gbhwdb_model::parser::{self, LabelParser};
+/// let failure = parser::sharp::SHARP_LH5164AN
+///     .parse("LH5164AN-15L SHARP JAPAN 9933 3 EB")
+///     .unwrap_err();
+/// let closest = failure.closest().unwrap();
+/// assert_eq!(closest.parser, "Sharp LH5164AN");
+/// assert_eq!(closest.offset, 10);
+/// assert_eq!(closest.expected, "'0'");
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ParseFailure {
+    /// Canonical form of the label, see `normalize_label`
+    pub label: String,
+    pub candidates: Vec<CandidateFailure>,
+}
+
+/// Failure of a single parser
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CandidateFailure {
+    pub parser: &'static str,
+    /// Byte offset in the canonical label where the parser gave up
+    pub offset: usize,
+    pub expected: String,
+}
+
+impl ParseFailure {
+    /// Returns the candidate that got furthest in the label
+    pub fn closest(&self) -> Option<&CandidateFailure> {
+        self.candidates
+            .iter()
+            .rev()
+            .max_by_key(|candidate| candidate.offset)
+    }
+}
+
+impl fmt::Display for ParseFailure {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "no parser matched {:?}", self.label)?;
+        for candidate in &self.candidates {
+            let rest = self.label.get(candidate.offset..).unwrap_or_default();
+            write!(
+                f,
+                "\n  {}: expected {} at offset {} ({:?})",
+                candidate.parser, candidate.expected, candidate.offset, rest
+            )?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for ParseFailure {}
+
+/// A normalization applied to a label before parsing
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Normalization {
+    /// Leading/trailing whitespace was removed, or a run of whitespace was collapsed
+    Whitespace,
+    /// Fullwidth characters were replaced with their ASCII equivalents
+    Fullwidth,
+    /// `(C)` or `(c)` was replaced with `©`
+    CopyrightSign,
+    /// Stray punctuation was removed from the start or end of the label
+    Punctuation,
+    /// Lowercase letters were uppercased in a word the parser did not accept otherwise
+    LetterCase,
 }
 
-pub struct NomParser<T> {
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct NormalizedLabel {
+    pub canonical: String,
+    /// Normalizations that changed the label, in the order they were applied
+    pub applied: Vec<Normalization>,
+}
+
+const STRAY_PUNCTUATION: &[char] = &['.', ',', ';', ':', '\'', '"', '`'];
+
+/// Converts a label to the canonical form expected by the parsers
+///
+/// A run of whitespace becomes a single newline if it contains one, and a single space otherwise.
+///
+/// ```
+/// use gbhwdb_model::parser::{self, Normalization};
+/// let label = parser::normalize_label("SYS-SGB-2  (c) 1994 Nintendo\r\n９４２９ R77.");
+/// assert_eq!(label.canonical, "SYS-SGB-2 © 1994 Nintendo\n9429 R77");
+/// assert_eq!(
+///     label.applied,
+///     [
+///         Normalization::Fullwidth,
+///         Normalization::CopyrightSign,
+///         Normalization::Whitespace,
+///         Normalization::Punctuation
+///     ]
+/// );
+/// ```
+///
+/// All parsers normalize labels before parsing them:
+///
+/// ```
+/// use gbhwdb_model::parser::{self, LabelParser};
+/// assert!(parser::sharp::SHARP_LH5164AN.parse(" LH5164AN-10L  SHARP\nJAPAN 9933 3 EB ").is_ok());
+/// ```
+pub fn normalize_label(label: &str) -> NormalizedLabel {
+    let mut applied = Vec::new();
+
+    let mut text = label
+        .chars()
+        .map(|ch| match ch {
+            '\u{FF01}'..='\u{FF5E}' => char::from_u32(ch as u32 - 0xFEE0).unwrap_or(ch),
+            '\u{3000}' => ' ',
+            _ => ch,
+        })
+        .collect::<String>();
+    if text != label {
+        applied.push(Normalization::Fullwidth);
+    }
+
+    if text.contains("(C)") || text.contains("(c)") {
+        text = text.replace("(C)", "©").replace("(c)", "©");
+        applied.push(Normalization::CopyrightSign);
+    }
+
+    let mut collapsed = String::with_capacity(text.len());
+    let mut separator = None;
+    for ch in text.chars() {
+        if ch == '\n' {
+            separator = Some('\n');
+        } else if ch.is_whitespace() {
+            separator = separator.or(Some(' '));
+        } else {
+            if let Some(separator) = separator.take().filter(|_| !collapsed.is_empty()) {
+                collapsed.push(separator);
+            }
+            collapsed.push(ch);
+        }
+    }
+    if collapsed != text {
+        applied.push(Normalization::Whitespace);
+    }
+
+    let trimmed =
+        collapsed.trim_matches(|ch: char| STRAY_PUNCTUATION.contains(&ch) || ch.is_whitespace());
+    if trimmed.len() != collapsed.len() {
+        applied.push(Normalization::Punctuation);
+    }
+
+    NormalizedLabel {
+        canonical: trimmed.to_owned(),
+        applied,
+    }
+}
+
+/// Uppercases the word containing the byte offset, or returns None if it has no lowercase letters
+fn uppercase_word_at(label: &str, offset: usize) -> Option<String> {
+    let start = label
+        .get(..offset)?
+        .rfind(char::is_whitespace)
+        .map_or(0, |index| index + 1);
+    let end = label[offset..]
+        .find(char::is_whitespace)
+        .map_or(label.len(), |index| offset + index);
+    let word = &label[start..end];
+    word.contains(|ch: char| ch.is_ascii_lowercase()).then(|| {
+        format!(
+            "{}{}{}",
+            &label[..start],
+            word.to_ascii_uppercase(),
+            &label[end..]
+        )
+    })
+}
+
+type ParseFn<T> = dyn Fn(&str) -> IResult<&str, T, VerboseError<&str>> + Send + Sync;
+type FormatFn<T> = dyn Fn(&T) -> Option<String> + Send + Sync;
+
+pub struct NomParser<T: 'static> {
     pub name: &'static str,
-    f: fn(label: &str) -> IResult<&str, T, VerboseError<&str>>,
+    /// Known-good labels, usually copied from the doc-test examples
+    pub examples: &'static [&'static str],
+    /// Literal text that every accepted label starts with one of, or empty if the label starts
+    /// with a variable field (e.g. a date code)
+    prefixes: &'static [&'static str],
+    f: &'static ParseFn<T>,
+    /// Inverse of `f`, if the parsed output retains everything printed on the label
+    format: Option<&'static FormatFn<T>>,
+}
+
+impl<T> NomParser<T> {
+    /// Returns true if the parser can synthesize labels from parsed parts
+    pub fn can_format(&self) -> bool {
+        self.format.is_some()
+    }
+    /// Returns false if the label can't be accepted, because it doesn't start with a prefix
+    fn may_accept(&self, label: &str) -> bool {
+        self.prefixes.is_empty() || self.prefixes.iter().any(|prefix| label.starts_with(prefix))
+    }
+    fn failure(&self, label: &str, err: nom::Err<VerboseError<&str>>) -> CandidateFailure {
+        let deepest = match err {
+            nom::Err::Error(err) | nom::Err::Failure(err) => {
+                err.errors.into_iter().min_by_key(|(input, _)| input.len())
+            }
+            nom::Err::Incomplete(_) => None,
+        };
+        let (offset, expected) = match deepest {
+            Some((input, kind)) => {
+                let expected = match kind {
+                    VerboseErrorKind::Char(ch) => format!("{ch:?}"),
+                    VerboseErrorKind::Context(context) => context.to_owned(),
+                    VerboseErrorKind::Nom(kind) => kind.description().to_lowercase(),
+                };
+                (label.len() - input.len(), expected)
+            }
+            None => (label.len(), String::from("more input")),
+        };
+        CandidateFailure {
+            parser: self.name,
+            offset,
+            expected,
+        }
+    }
 }
 
 impl<T> LabelParser<T> for NomParser<T> {
-    fn parse(&self, label: &str) -> Result<T, String> {
+    fn parse_canonical_named(&self, label: &str) -> Result<(&'static str, T), ParseFailure> {
         match all_consuming(self.f).parse(label) {
-            Ok((_, chip)) => Ok(chip),
-            Err(err) => Err(format!("{label}:{err:?}")),
+            Ok((_, chip)) => Ok((self.name, chip)),
+            Err(err) => Err(ParseFailure {
+                candidates: vec![self.failure(label, err)],
+                label: label.to_owned(),
+            }),
         }
     }
+    fn parse_all_canonical(&self, label: &str) -> Vec<(&'static str, T)> {
+        self.parse_canonical_named(label).into_iter().collect()
+    }
+    fn members(&self) -> Vec<&NomParser<T>> {
+        vec![self]
+    }
+    fn candidates(&self, label: &str) -> Vec<usize> {
+        self.may_accept(label).then_some(0).into_iter().collect()
+    }
 }
 
 #[derive(Clone)]
 pub struct MultiParser<T: 'static> {
     parsers: &'static [&'static dyn LabelParser<T>],
+    dispatch: OnceLock<DispatchTable>,
 }
 
 impl<T> MultiParser<T> {
     pub const fn new(parsers: &'static [&'static dyn LabelParser<T>]) -> Self {
-        MultiParser { parsers }
+        MultiParser {
+            parsers,
+            dispatch: OnceLock::new(),
+        }
+    }
+    fn dispatch(&self) -> &DispatchTable {
+        self.dispatch
+            .get_or_init(|| DispatchTable::new(self.parsers))
+    }
+}
+
+/// Lookup table from the first character of a canonical label to the parsers that can accept it
+///
+/// Built from the literal prefixes of the member parsers. A parser without prefixes is a candidate
+/// for every label.
+#[derive(Clone, Debug)]
+struct DispatchTable {
+    by_first_char: HashMap<char, Vec<DispatchEntry>>,
+    /// Parsers without prefixes, for labels whose first character starts no prefix
+    unprefixed: Vec<DispatchEntry>,
+}
+
+#[derive(Clone, Debug)]
+struct DispatchEntry {
+    index: usize,
+    /// Prefixes of all member parsers, or empty if any member has none
+    prefixes: Vec<&'static str>,
+}
+
+impl DispatchTable {
+    fn new<T: 'static>(parsers: &[&dyn LabelParser<T>]) -> Self {
+        let entries = parsers
+            .iter()
+            .enumerate()
+            .map(|(index, parser)| {
+                let members = parser.members();
+                let prefixes = if members.iter().any(|member| member.prefixes.is_empty()) {
+                    Vec::new()
+                } else {
+                    members
+                        .iter()
+                        .flat_map(|member| member.prefixes.iter().copied())
+                        .collect()
+                };
+                DispatchEntry { index, prefixes }
+            })
+            .collect::<Vec<_>>();
+        let mut by_first_char = HashMap::<char, Vec<DispatchEntry>>::new();
+        for entry in &entries {
+            for ch in entry
+                .prefixes
+                .iter()
+                .filter_map(|prefix| prefix.chars().next())
+            {
+                by_first_char.entry(ch).or_default();
+            }
+        }
+        for (ch, bucket) in &mut by_first_char {
+            bucket.extend(
+                entries
+                    .iter()
+                    .filter(|entry| {
+                        entry.prefixes.is_empty()
+                            || entry.prefixes.iter().any(|prefix| prefix.starts_with(*ch))
+                    })
+                    .cloned(),
+            );
+        }
+        DispatchTable {
+            by_first_char,
+            unprefixed: entries
+                .into_iter()
+                .filter(|entry| entry.prefixes.is_empty())
+                .collect(),
+        }
+    }
+    /// Returns the indices of the parsers that can accept the label, in their original order
+    fn candidates<'a>(&'a self, label: &'a str) -> impl Iterator<Item = usize> + 'a {
+        label
+            .chars()
+            .next()
+            .and_then(|ch| self.by_first_char.get(&ch))
+            .unwrap_or(&self.unprefixed)
+            .iter()
+            .filter(move |entry| {
+                entry.prefixes.is_empty()
+                    || entry
+                        .prefixes
+                        .iter()
+                        .any(|prefix| label.starts_with(prefix))
+            })
+            .map(|entry| entry.index)
     }
 }
 
 impl<T> LabelParser<T> for MultiParser<T> {
-    fn parse(&self, label: &str) -> Result<T, String> {
-        let mut iter = self.parsers.iter();
-        while let Some(parser) = iter.next() {
-            if let Ok(m) = parser.parse(label) {
-                if iter.any(|parser| parser.parse(label).is_ok()) {
+    fn parse_canonical_named(&self, label: &str) -> Result<(&'static str, T), ParseFailure> {
+        for index in self.dispatch().candidates(label) {
+            if let Ok(m) = self.parsers[index].parse_canonical_named(label) {
+                if audit::is_enabled()
+                    && self.parsers[index + 1..]
+                        .iter()
+                        .any(|parser| parser.parse_canonical(label).is_ok())
+                {
                     warn!("Warning: multiple matches for {}", label);
                 }
                 return Ok(m);
             }
         }
-        Err(format!("no match for {label}"))
+        // Slow path: try everything, so failures list every candidate and a parser missing from
+        // the dispatch table still gets a chance
+        let mut candidates = Vec::new();
+        for parser in self.parsers {
+            match parser.parse_canonical_named(label) {
+                Ok(m) => {
+                    warn!("Warning: dispatch table missed a match for {}", label);
+                    return Ok(m);
+                }
+                Err(failure) => candidates.extend(failure.candidates),
+            }
+        }
+        Err(ParseFailure {
+            label: label.to_owned(),
+            candidates,
+        })
+    }
+    fn parse_all_canonical(&self, label: &str) -> Vec<(&'static str, T)> {
+        self.parsers
+            .iter()
+            .flat_map(|parser| parser.parse_all_canonical(label))
+            .collect()
+    }
+    fn members(&self) -> Vec<&NomParser<T>> {
+        self.parsers
+            .iter()
+            .flat_map(|parser| parser.members())
+            .collect()
+    }
+    fn candidates(&self, label: &str) -> Vec<usize> {
+        let offsets = self
+            .parsers
+            .iter()
+            .scan(0, |offset, parser| {
+                let start = *offset;
+                *offset += parser.members().len();
+                Some(start)
+            })
+            .collect::<Vec<_>>();
+        self.dispatch()
+            .candidates(label)
+            .flat_map(|index| {
+                let offset = offsets[index];
+                self.parsers[index]
+                    .candidates(label)
+                    .into_iter()
+                    .map(move |member| offset + member)
+            })
+            .collect()
+    }
+}
+
+/// Synthesizes the canonical label of a parsed part
+///
+/// Fields are separated by single spaces, because parsing does not preserve line breaks. A label
+/// is only returned if it parses back to an identical part, so the parsers act as the
+/// specification of the label formats.
+///
+/// ```
+/// use gbhwdb_model::parser::{self, GenericPart, LabelFormatter, LabelParser};
+/// let part = parser::dmg_reg().parse("DMG-REG IR3E02 9527 CB").unwrap();
+/// assert_eq!(parser::dmg_reg().format(&part).as_deref(), Some("DMG-REG IR3E02 9527 CB"));
+///
+/// let part = GenericPart {
+///     lot_code: Some(String::from("A")),
+///     ..part
+/// };
+/// assert_eq!(parser::dmg_reg().format(&part).as_deref(), Some("DMG-REG IR3E02 9527 A"));
+///
+/// let part = GenericPart {
+///     lot_code: None,
+///     ..part
+/// };
+/// assert_eq!(parser::dmg_reg().format(&part), None);
+/// ```
+pub trait LabelFormatter<T> {
+    /// Returns the label of the part, or None if no member parser can format it
+    fn format(&self, part: &T) -> Option<String>;
+}
+
+impl<T: PartialEq + 'static, P: LabelParser<T> + ?Sized> LabelFormatter<T> for P {
+    fn format(&self, part: &T) -> Option<String> {
+        self.members().into_iter().find_map(|parser| {
+            let label = (parser.format?)(part)?;
+            (parser.parse(&label).ok().as_ref() == Some(part)).then_some(label)
+        })
     }
 }
 
-#[derive(Copy, Clone, Debug)]
-pub struct UnknownChip;
+/// A known label format that resembles a label that could not be parsed
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Suggestion {
+    pub parser: &'static str,
+    pub example: &'static str,
+    /// Edit distance between the label and the example, ignoring differences between digits
+    pub distance: usize,
+    /// True if the label starts with the same part number prefix (e.g. `LH52`, `MBC5`, `DMG-`)
+    pub prefix_match: bool,
+}
+
+impl fmt::Display for Suggestion {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:?} ({})", self.example, self.parser)
+    }
+}
 
+/// Minimum common prefix length for a prefix match
+const SUGGESTION_PREFIX_LEN: usize = 4;
+
+/// Suggests the closest known label formats from the given parser family
+///
+/// Date codes and other numbers vary between labels, so digit-to-digit substitutions are free.
+/// Typical typos, such as `O` instead of `0` or a missing space, cost one edit each.
+///
+/// ```
+/// use gbhwdb_model::parser;
+/// let suggestions = parser::suggest("LH5164AN-1OL SHARP JAPAN 9933 3 EB", parser::sram_sop_28_5v());
+/// assert_eq!(suggestions[0].parser, "Sharp LH5164AN");
+/// assert_eq!(suggestions[0].distance, 1);
+/// ```
+pub fn suggest<T: 'static>(label: &str, family: &dyn LabelParser<T>) -> Vec<Suggestion> {
+    let max_distance = (label.chars().count() / 5).max(2);
+    let mut suggestions = family
+        .members()
+        .into_iter()
+        .filter_map(|parser| {
+            parser
+                .examples
+                .iter()
+                .map(|&example| Suggestion {
+                    parser: parser.name,
+                    example,
+                    distance: label_distance(label, example),
+                    prefix_match: label
+                        .chars()
+                        .zip(example.chars())
+                        .take_while(|(a, b)| a == b)
+                        .count()
+                        >= SUGGESTION_PREFIX_LEN,
+                })
+                .min_by_key(|suggestion| suggestion.distance)
+        })
+        .filter(|suggestion| suggestion.prefix_match || suggestion.distance <= max_distance)
+        .collect::<Vec<_>>();
+    suggestions.sort_by_key(|suggestion| (suggestion.distance, !suggestion.prefix_match));
+    suggestions
+}
+
+fn label_distance(a: &str, b: &str) -> usize {
+    let b = b.chars().collect::<Vec<_>>();
+    let mut prev = (0..=b.len()).collect::<Vec<_>>();
+    let mut row = vec![0; b.len() + 1];
+    for (i, ca) in a.chars().enumerate() {
+        row[0] = i + 1;
+        for (j, &cb) in b.iter().enumerate() {
+            let substitution = if ca == cb || (ca.is_ascii_digit() && cb.is_ascii_digit()) {
+                0
+            } else {
+                1
+            };
+            row[j + 1] = (prev[j] + substitution)
+                .min(prev[j + 1] + 1)
+                .min(row[j] + 1);
+        }
+        std::mem::swap(&mut prev, &mut row);
+    }
+    prev[b.len()]
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct UnknownChip {
+    /// Manufacturer inferred from markings on the label
+    pub manufacturer: Option<Manufacturer>,
+}
+
+/// Accepts any label, and infers the manufacturer from its markings if possible
+///
+/// ```
+/// use gbhwdb_model::parser::{LabelParser, Manufacturer, UNKNOWN_CHIP};
+/// let chip = UNKNOWN_CHIP.parse("TOSHIBA TC7W00F 9A").unwrap();
+/// assert_eq!(chip.manufacturer, Some(Manufacturer::Toshiba));
+/// ```
 pub static UNKNOWN_CHIP: NomParser<UnknownChip> = NomParser {
     name: "Unknown Chip",
-    f: |_| Ok(("", UnknownChip)),
+    examples: &[],
+    prefixes: &[],
+    f: &|input| {
+        let manufacturer = Manufacturer::from_markings(input);
+        Ok(("", UnknownChip { manufacturer }))
+    },
+    format: None,
 };
 
 pub fn mgb_amp() -> &'static impl LabelParser<GenericPart> {
@@ -791,28 +2259,65 @@ pub fn agb_soc_bga() -> &'static impl LabelParser<GenericPart> {
     &sharp::SHARP_CPU_AGB_E
 }
 
-#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Deserialize, strum::VariantArray)]
 pub enum GameRomType {
-    GlopTop, // 256 Kibit / 32 KiBit, gloptop
-    A0,      // 256 Kibit / 32 KiB,  QFP
-    B0,      // 512 Kibit / 64 KiB,  QFP
-    B1,      // 512 Kibit / 64 KiB,  SOP
-    C1,      // 1 Mibit   / 128 KiB, SOP
-    D1,      // 2 Mibit   / 256 KiB, SOP
-    E,       // 4 Mibit   / 512 KiB, TSOP-I
-    E1,      // 4 Mibit   / 512 KiB, SOP
-    F,       // 8 Mibit   / 1 MiB,   TSOP-I
-    F1,      // 8 Mibit   / 1 MiB,   SOP
-    F2,      // 8 Mibit   / 1 MiB,   TSOP-II
-    G1,      // 16 Mibit  / 2 MiB,   SOP
-    G2,      // 16 Mibit  / 2 MiB,   TSOP-II
-    H2,      // 32 Mibit  / 4 MiB,   TSOP-II
-    I2,      // 64 Mibit  / 8 MiB,   TSOP-II
-    J2,      // 128 Mibit / 16 MiB,  TSOP-II
-    K2,      // 256 Mibit / 32 MiB,  TSOP-II
+    #[serde(skip_deserializing)]
+    GlopTop, // 256/512 Kibit, gloptop
+    A0, // 256 Kibit / 32 KiB,  QFP
+    B0, // 512 Kibit / 64 KiB,  QFP
+    B1, // 512 Kibit / 64 KiB,  SOP
+    C1, // 1 Mibit   / 128 KiB, SOP
+    D1, // 2 Mibit   / 256 KiB, SOP
+    E,  // 4 Mibit   / 512 KiB, TSOP-I
+    E1, // 4 Mibit   / 512 KiB, SOP
+    F,  // 8 Mibit   / 1 MiB,   TSOP-I
+    F1, // 8 Mibit   / 1 MiB,   SOP
+    F2, // 8 Mibit   / 1 MiB,   TSOP-II
+    G1, // 16 Mibit  / 2 MiB,   SOP
+    G2, // 16 Mibit  / 2 MiB,   TSOP-II
+    H2, // 32 Mibit  / 4 MiB,   TSOP-II
+    I2, // 64 Mibit  / 8 MiB,   TSOP-II
+    J2, // 128 Mibit / 16 MiB,  TSOP-II
+    K2, // 256 Mibit / 32 MiB,  TSOP-II
 }
 
 impl GameRomType {
+    pub const fn package(&self) -> Package {
+        match self {
+            GameRomType::GlopTop => Package::GlopTop,
+            GameRomType::A0 | GameRomType::B0 => Package::Qfp44,
+            GameRomType::B1
+            | GameRomType::C1
+            | GameRomType::D1
+            | GameRomType::E1
+            | GameRomType::F1 => Package::Sop32,
+            GameRomType::G1 => Package::Sop44,
+            GameRomType::E | GameRomType::F => Package::TsopI32,
+            GameRomType::F2
+            | GameRomType::G2
+            | GameRomType::H2
+            | GameRomType::I2
+            | GameRomType::J2
+            | GameRomType::K2 => Package::TsopIi44,
+        }
+    }
+    /// Returns the density in bits, if the ROM type implies one
+    pub const fn density(&self) -> Option<u32> {
+        match self {
+            GameRomType::GlopTop => None,
+            GameRomType::A0 => Some(256 * KIBIT),
+            GameRomType::B0 | GameRomType::B1 => Some(512 * KIBIT),
+            GameRomType::C1 => Some(MIBIT),
+            GameRomType::D1 => Some(2 * MIBIT),
+            GameRomType::E | GameRomType::E1 => Some(4 * MIBIT),
+            GameRomType::F | GameRomType::F1 | GameRomType::F2 => Some(8 * MIBIT),
+            GameRomType::G1 | GameRomType::G2 => Some(16 * MIBIT),
+            GameRomType::H2 => Some(32 * MIBIT),
+            GameRomType::I2 => Some(64 * MIBIT),
+            GameRomType::J2 => Some(128 * MIBIT),
+            GameRomType::K2 => Some(256 * MIBIT),
+        }
+    }
     pub fn as_str(&self) -> &'static str {
         match self {
             GameRomType::GlopTop => "",
@@ -836,13 +2341,59 @@ impl GameRomType {
     }
 }
 
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub enum MaskCode {
     Nec(String),
     Oki(String),
     Sharp(String),
 }
 
+impl MaskCode {
+    pub const fn manufacturer(&self) -> Manufacturer {
+        match self {
+            MaskCode::Nec(_) => Manufacturer::Nec,
+            MaskCode::Oki(_) => Manufacturer::Oki,
+            MaskCode::Sharp(_) => Manufacturer::Sharp,
+        }
+    }
+    pub fn as_str(&self) -> &str {
+        match self {
+            MaskCode::Nec(code) | MaskCode::Oki(code) | MaskCode::Sharp(code) => code,
+        }
+    }
+}
+
+/// Mask code split into its parts.
+///
+/// Two ROM chips with equal decoded mask codes were made from the same mask and contain the same
+/// data.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct DecodedMaskCode {
+    pub manufacturer: Manufacturer,
+    /// Chip part number, e.g. "μPD23C4001EJGW", "M538011E" or "LH5359"
+    pub chip: String,
+    /// Identifier of the mask within the chip part number, e.g. "J79", "15" or "UZ"
+    pub mask_id: String,
+    /// Revision of the game data, taken from the last digit of the ROM id (e.g. 1 in "DMG-AYWJ-1")
+    pub rom_id_revision: u8,
+}
+
+impl fmt::Display for DecodedMaskCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.manufacturer {
+            Manufacturer::Sharp => write!(f, "{}{}", self.chip, self.mask_id),
+            _ => write!(f, "{}-{}", self.chip, self.mask_id),
+        }
+    }
+}
+
+/// ```
+/// use gbhwdb_model::parser::{self, LabelParser, MIBIT, Package, Voltage};
+/// let rom = parser::oki::OKI_MR26V3210.parse("AGB-TCHK-1 H2 R26V3210F-087 244A239").unwrap();
+/// assert_eq!(rom.package, Some(Package::TsopIi44));
+/// assert_eq!(rom.voltage, Some(Voltage::V3_3));
+/// assert_eq!(rom.density, Some(32 * MIBIT));
+/// ```
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct GameMaskRom {
     pub rom_id: String,
@@ -851,6 +2402,86 @@ pub struct GameMaskRom {
     pub chip_type: Option<String>,
     pub mask_code: Option<MaskCode>,
     pub date_code: Option<PartDateCode>,
+    /// Lot, assembly or trace codes printed on the label, separated by spaces if there are several
+    pub lot_code: Option<String>,
+    /// Die or mask revision marking
+    pub revision: Option<String>,
+    pub origin_country: Option<Country>,
+    pub package: Option<Package>,
+    pub voltage: Option<Voltage>,
+    /// Memory density in bits
+    pub density: Option<u32>,
+    pub organization: Option<Organization>,
+    /// Access time in nanoseconds
+    pub access_time: Option<u16>,
+}
+
+impl GameMaskRom {
+    /// Splits the mask code into the chip part number and the mask identifier, and adds the
+    /// revision of the game data from the ROM id.
+    ///
+    /// NEC and OKI labels print a dash between the part number and the mask identifier, and NEC
+    /// codes printed as `N-`, `UPD23C` or `23C` are normalized to the same μPD23C part number.
+    /// Sharp labels print the mask code as one string (e.g. `LH5359UZ`), where the last two
+    /// characters are the mask identifier and the rest is the part number prefix.
+    ///
+    /// ```
+    /// use gbhwdb_model::parser::{self, LabelParser, Manufacturer};
+    /// let rev0 = parser::nec::NEC_UPD23C4001E.parse("DMG-AYWJ-0 E1 N-4001EJGW-J79 9747E7019").unwrap();
+    /// let rev1 = parser::nec::NEC_UPD23C4001E.parse("DMG-AYWJ-1 E1 N-4001EJGW-J82 9804E7012").unwrap();
+    /// let (rev0, rev1) = (rev0.decode_mask_code().unwrap(), rev1.decode_mask_code().unwrap());
+    /// assert_eq!((rev0.chip.as_str(), rev0.mask_id.as_str()), ("μPD23C4001EJGW", "J79"));
+    /// assert_eq!((rev0.rom_id_revision, rev1.rom_id_revision), (0, 1));
+    /// assert_eq!(rev1.to_string(), "μPD23C4001EJGW-J82");
+    ///
+    /// let nec = parser::nec::NEC_UPD23C1001E.parse("NEC JAPAN DMG-SAJ-0 C1 UPD23C1001EGW-J01 9010E9702").unwrap();
+    /// let nec = nec.decode_mask_code().unwrap();
+    /// assert_eq!((nec.chip.as_str(), nec.mask_id.as_str()), ("μPD23C1001EGW", "J01"));
+    ///
+    /// let oki = parser::oki::OKI_MSM538011_SOP_32.parse("DMG-APSJ-2 F1 M538011E-19 8475406").unwrap();
+    /// let oki = oki.decode_mask_code().unwrap();
+    /// assert_eq!(oki.manufacturer, Manufacturer::Oki);
+    /// assert_eq!((oki.chip.as_str(), oki.mask_id.as_str()), ("M538011E", "19"));
+    /// assert_eq!(oki.rom_id_revision, 2);
+    ///
+    /// let sharp = parser::sharp::SHARP_LH5332XXX.parse("DMG-AYKJ-0 S LH5S5WT1 JAPAN H2 SP 9926 D").unwrap();
+    /// let sharp = sharp.decode_mask_code().unwrap();
+    /// assert_eq!((sharp.chip.as_str(), sharp.mask_id.as_str()), ("LH5S5W", "T1"));
+    /// assert_eq!(sharp.to_string(), "LH5S5WT1");
+    /// ```
+    pub fn decode_mask_code(&self) -> Option<DecodedMaskCode> {
+        let mask_code = self.mask_code.as_ref()?;
+        let (chip, mask_id) = match mask_code {
+            MaskCode::Nec(code) => {
+                let (chip, mask_id) = code.rsplit_once('-')?;
+                let chip = ["N-", "UPD23C", "23C", "μPD23C"]
+                    .iter()
+                    .find_map(|prefix| chip.strip_prefix(prefix))?;
+                (format!("μPD23C{chip}"), mask_id)
+            }
+            MaskCode::Oki(code) => {
+                let (chip, mask_id) = code.rsplit_once('-')?;
+                (String::from(chip), mask_id)
+            }
+            MaskCode::Sharp(code) => {
+                let (chip, mask_id) = code.split_at_checked(code.len().checked_sub(2)?)?;
+                (String::from(chip), mask_id)
+            }
+        };
+        let rom_id_revision = self.rom_id.rsplit_once('-')?.1.parse().ok()?;
+        (!chip.is_empty() && !mask_id.is_empty()).then(|| DecodedMaskCode {
+            manufacturer: mask_code.manufacturer(),
+            chip,
+            mask_id: String::from(mask_id),
+            rom_id_revision,
+        })
+    }
+}
+
+impl ParsedChip for GameMaskRom {
+    fn manufacturer(&self) -> Option<Manufacturer> {
+        self.manufacturer
+    }
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -860,11 +2491,19 @@ pub struct MaskRom {
     pub chip_type: Option<String>,
     pub mask_code: Option<MaskCode>,
     pub date_code: Option<PartDateCode>,
+    pub lot_code: Option<String>,
+}
+
+impl ParsedChip for MaskRom {
+    fn manufacturer(&self) -> Option<Manufacturer> {
+        self.manufacturer
+    }
 }
 
 pub fn agb_mask_rom_tsop_ii_44_3v3() -> &'static impl LabelParser<GameMaskRom> {
     multi_parser!(
         GameMaskRom,
+        definitions: "agb_mask_rom_tsop_ii_44_3v3",
         &magnachip::MAGNACHIP_AC23V32101,
         &magnachip::MAGNACHIP_AC23V64101,
         &magnachip::MAGNACHIP_AC23V128111,
@@ -893,6 +2532,7 @@ pub fn agb_mask_rom_tsop_ii_44_3v3() -> &'static impl LabelParser<GameMaskRom> {
 pub fn gb_mask_rom_glop_top_28_5v() -> &'static impl LabelParser<GameMaskRom> {
     multi_parser!(
         GameMaskRom,
+        definitions: "gb_mask_rom_glop_top_28_5v",
         &sharp::SHARP_MASK_ROM_GLOP_TOP_28_256_KIBIT,
         &sharp::SHARP_MASK_ROM_GLOP_TOP_28_512_KIBIT
     )
@@ -901,6 +2541,7 @@ pub fn gb_mask_rom_glop_top_28_5v() -> &'static impl LabelParser<GameMaskRom> {
 pub fn gb_mask_rom_sop_32_5v() -> &'static impl LabelParser<GameMaskRom> {
     multi_parser!(
         GameMaskRom,
+        definitions: "gb_mask_rom_sop_32_5v",
         &sharp::SHARP_MASK_ROM_SOP_32_1_MIBIT,
         &sharp::SHARP_LH53514Z,
         &sharp::SHARP_LH53517Z,
@@ -938,6 +2579,7 @@ pub fn gb_mask_rom_sop_44_5v() -> &'static impl LabelParser<GameMaskRom> {
 pub fn gb_mask_rom_tsop_i_32_5v() -> &'static impl LabelParser<GameMaskRom> {
     multi_parser!(
         GameMaskRom,
+        definitions: "gb_mask_rom_tsop_i_32_5v",
         &sharp::SHARP_LH534XXXS,
         &sharp::SHARP_LH538XXXS,
         &oki::OKI_MSM538011_TSOP_I_32,
@@ -948,6 +2590,7 @@ pub fn gb_mask_rom_tsop_i_32_5v() -> &'static impl LabelParser<GameMaskRom> {
 pub fn gb_mask_rom_tsop_ii_44_5v() -> &'static impl LabelParser<GameMaskRom> {
     multi_parser!(
         GameMaskRom,
+        definitions: "gb_mask_rom_tsop_ii_44_5v",
         &sharp::SHARP_LH5316XXX,
         &sharp::SHARP_LH5332XXX,
         &macronix::MACRONIX_MX23C1603,
@@ -961,6 +2604,7 @@ pub fn gb_mask_rom_tsop_ii_44_5v() -> &'static impl LabelParser<GameMaskRom> {
 pub fn gb_mask_rom_qfp_44_5v() -> &'static impl LabelParser<GameMaskRom> {
     multi_parser!(
         GameMaskRom,
+        definitions: "gb_mask_rom_qfp_44_5v",
         &sharp::SHARP_LH53259M,
         &sharp::SHARP_LH53515M,
         &oki::OKI_MASK_ROM_QFP_44_512_KIBIT,
@@ -1064,35 +2708,78 @@ impl MapperChip {
     }
 }
 
-#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash, Deserialize, Serialize)]
 pub enum MapperType {
+    #[serde(rename = "MBC1")]
     Mbc1,
+    #[serde(rename = "MBC2")]
     Mbc2,
+    #[serde(rename = "MBC3")]
     Mbc3,
+    #[serde(rename = "MBC30")]
     Mbc30,
+    #[serde(rename = "MBC5")]
     Mbc5,
+    #[serde(rename = "MBC6")]
     Mbc6,
+    #[serde(rename = "MBC7")]
     Mbc7,
+    #[serde(rename = "HuC-1")]
     Huc1,
+    #[serde(rename = "HuC-3")]
     Huc3,
+    #[serde(rename = "MMM01")]
     Mmm01,
+    #[serde(rename = "TAMA5")]
     Tama5,
 }
 
+impl MapperType {
+    pub const fn name(&self) -> &'static str {
+        match self {
+            MapperType::Mbc1 => "MBC1",
+            MapperType::Mbc2 => "MBC2",
+            MapperType::Mbc3 => "MBC3",
+            MapperType::Mbc30 => "MBC30",
+            MapperType::Mbc5 => "MBC5",
+            MapperType::Mbc6 => "MBC6",
+            MapperType::Mbc7 => "MBC7",
+            MapperType::Huc1 => "HuC-1",
+            MapperType::Huc3 => "HuC-3",
+            MapperType::Mmm01 => "MMM01",
+            MapperType::Tama5 => "TAMA5",
+        }
+    }
+}
+
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct Mapper {
     pub kind: MapperChip,
     pub manufacturer: Option<Manufacturer>,
     pub date_code: Option<PartDateCode>,
+    /// Lot, assembly or trace codes printed on the label, separated by spaces if there are several
+    pub lot_code: Option<String>,
+    /// Die or mask revision marking
+    pub revision: Option<String>,
+    pub origin_country: Option<Country>,
+}
+
+impl ParsedChip for Mapper {
+    fn manufacturer(&self) -> Option<Manufacturer> {
+        self.manufacturer
+    }
 }
 
 pub fn mbc1_glop_top() -> &'static impl LabelParser<Mapper> {
     static MBC1_GLOP_TOP: NomParser<Mapper> = NomParser {
         name: "MBC1 glop top",
-        f: |input| {
+        examples: &[],
+        prefixes: &[],
+        f: &|input| {
             // No label -> can't parse anything
             fail().parse(input)
         },
+        format: None,
     };
     &MBC1_GLOP_TOP
 }
@@ -1169,6 +2856,7 @@ pub fn huc3_qfp48() -> &'static impl LabelParser<Mapper> {
 pub fn sram_sop_28_5v() -> &'static impl LabelParser<GenericPart> {
     multi_parser!(
         GenericPart,
+        definitions: "sram_sop_28_5v",
         // 256 Kibit / 32 KiB
         &bsi::BSI_BS62LV256,
         &hynix::HYNIX_HY62WT08081,
@@ -1180,8 +2868,6 @@ pub fn sram_sop_28_5v() -> &'static impl LabelParser<GenericPart> {
         &sanyo::SANYO_LC35256,
         &sharp::SHARP_LH52256CN,
         &sharp::SHARP_LH52256CVN,
-        &winbond::WINBOND_W24257S,
-        &winbond::WINBOND_W24258S,
         // 64 Kibit / 8 KiB
         &crosslink::CROSSLINK_LH5268AN,
         &crosslink::CROSSLINK_LH52A64N,
@@ -1207,13 +2893,13 @@ pub fn sram_sop_28_5v() -> &'static impl LabelParser<GenericPart> {
         &sharp::SHARP_LH52A64N,
         &panasonic::PANASONIC_MN4464,
         &victronix::VICTRONIX_VN4464,
-        &winbond::WINBOND_W2465S,
     )
 }
 
 pub fn sram_sop_28_3v3() -> &'static impl LabelParser<GenericPart> {
     multi_parser!(
         GenericPart,
+        definitions: "sram_sop_28_3v3",
         // 64 Kibit / 8 KiB
         &sanyo::SANYO_LC3564,
         // 256 Kibit / 32 KiB
@@ -1222,13 +2908,13 @@ pub fn sram_sop_28_3v3() -> &'static impl LabelParser<GenericPart> {
         &lgs::HYUNDAI_GM76V256,
         &sanyo::SANYO_LC35256,
         &sharp::SHARP_LH52256CVN,
-        &winbond::WINBOND_W24258S,
     )
 }
 
 pub fn sram_sop_32_5v() -> &'static impl LabelParser<GenericPart> {
     multi_parser!(
         GenericPart,
+        definitions: "sram_sop_32_5v",
         // 1 Mibit / 128 KiB
         &hyundai::HYUNDAI_HY628100,
     )
@@ -1237,6 +2923,7 @@ pub fn sram_sop_32_5v() -> &'static impl LabelParser<GenericPart> {
 pub fn sram_tsop_i_28_3v3() -> &'static impl LabelParser<GenericPart> {
     multi_parser!(
         GenericPart,
+        definitions: "sram_tsop_i_28_3v3",
         // 256 Kibit / 32 KiB
         &sharp::SHARP_LH51D256T,
         &sharp::SHARP_LH52CV256JT,
@@ -1247,6 +2934,7 @@ pub fn sram_tsop_i_28_3v3() -> &'static impl LabelParser<GenericPart> {
 pub fn sram_tsop_i_28_5v() -> &'static impl LabelParser<GenericPart> {
     multi_parser!(
         GenericPart,
+        definitions: "sram_tsop_i_28_5v",
         // 256 Kibit / 32 KiB
         &sharp::SHARP_LH52256CT,
         &sharp::SHARP_LH52256CVT
@@ -1256,6 +2944,7 @@ pub fn sram_tsop_i_28_5v() -> &'static impl LabelParser<GenericPart> {
 pub fn sram_tsop_i_48() -> &'static impl LabelParser<GenericPart> {
     multi_parser!(
         GenericPart,
+        definitions: "sram_tsop_i_48",
         // 2 Mibit / 256 KiB / 128x16
         &nec::NEC_UPD442012A_X,
         &nec::NEC_UPD442012L_X,
@@ -1272,11 +2961,51 @@ pub fn sram_tsop_i_48() -> &'static impl LabelParser<GenericPart> {
 pub fn battery() -> &'static impl LabelParser<PartDateCode> {
     static BATTERY: NomParser<PartDateCode> = NomParser {
         name: "CRxxxx battery",
-        f: |input| {
+        examples: &["97-11"],
+        prefixes: &[],
+        f: &|input| {
             separated_pair(year2, tag("-"), month2)
                 .map(|(year, month)| PartDateCode::YearMonth { year, month })
                 .parse(input)
         },
+        format: Some(&|date_code| match *date_code {
+            PartDateCode::YearMonth { year, month } => Some(format!(
+                "{}-{:02}",
+                for_format::year2(year)?,
+                u8::from(month)
+            )),
+            _ => None,
+        }),
     };
     &BATTERY
 }
+
+#[test]
+fn test_dispatch_table_covers_examples() {
+    for family in registry::families() {
+        for (index, parser) in family.parsers.iter().enumerate() {
+            for example in parser.examples {
+                let label = normalize_label(example).canonical;
+                assert!(
+                    family.candidates(&label).contains(&index),
+                    "{} is not a candidate for {example} in {}",
+                    parser.name,
+                    family.id
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn test_manufacturer_markings_are_unique() {
+    for manufacturer in Manufacturer::VARIANTS {
+        for marking in manufacturer.info().markings {
+            assert_eq!(
+                Manufacturer::from_markings(marking),
+                Some(*manufacturer),
+                "{marking}"
+            );
+        }
+    }
+}
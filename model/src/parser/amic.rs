@@ -4,14 +4,13 @@
 
 use nom::{
     Parser as _,
-    bytes::streaming::tag,
     character::streaming::{char, one_of},
     combinator::{opt, recognize},
 };
 
 use super::{
-    GenericPart,
-    for_nom::{alnum_uppers, uppers},
+    GenericPart, MIBIT, Organization, Voltage, dash_speed_grade, for_format,
+    for_nom::{alnum_uppers, tag, uppers},
 };
 use crate::parser::{Manufacturer, NomParser, for_nom::year2_week2};
 
@@ -26,7 +25,9 @@ use crate::parser::{Manufacturer, NomParser, for_nom::year2_week2};
 /// ```
 pub static AMIC_LP62S16128: NomParser<GenericPart> = NomParser {
     name: "AMIC LP62S16128",
-    f: |input| {
+    examples: &["AMIC LP62S16128BW-70LLTF P4060473FB 0540A"],
+    prefixes: &["AMIC "],
+    f: &|input| {
         let package = Package::TsopI48;
         (
             tag("AMIC "),
@@ -44,13 +45,31 @@ pub static AMIC_LP62S16128: NomParser<GenericPart> = NomParser {
             char(' '),
             year2_week2.and(uppers(1)),
         )
-            .map(|(_, kind, _, _, _, (date_code, _))| GenericPart {
-                kind: String::from(kind),
-                manufacturer: Some(Manufacturer::Amic),
-                date_code: Some(date_code),
-            })
+            .map(
+                |(_, kind, _, lot_code, _, (date_code, suffix))| GenericPart {
+                    kind: String::from(kind),
+                    manufacturer: Some(Manufacturer::Amic),
+                    date_code: Some(date_code),
+                    lot_code: Some(format!("{lot_code} {suffix}")),
+                    revision: None,
+                    origin_country: None,
+                    package: Some(package.into()),
+                    voltage: Some(Voltage::range(2_700, 3_600)),
+                    density: Some(2 * MIBIT),
+                    organization: Some(Organization::new(128 * 1024, 16)),
+                    access_time: dash_speed_grade(kind),
+                },
+            )
             .parse(input)
     },
+    format: Some(&|part| {
+        let (lot_code, suffix) = part.lot_code.as_deref()?.split_once(' ')?;
+        Some(format!(
+            "AMIC {kind} {lot_code} {date_code}{suffix}",
+            kind = part.kind,
+            date_code = for_format::year2_week2(part.date_code?)?,
+        ))
+    }),
 };
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
@@ -67,3 +86,11 @@ impl Package {
         // U: TFBGA-48
     }
 }
+
+impl From<Package> for super::Package {
+    fn from(package: Package) -> Self {
+        match package {
+            Package::TsopI48 => super::Package::TsopI48,
+        }
+    }
+}
@@ -2,13 +2,11 @@
 //
 // SPDX-License-Identifier: MIT
 
-use nom::{
-    Parser as _, bytes::streaming::tag, character::streaming::one_of, combinator::recognize,
-};
+use nom::{Parser as _, character::streaming::one_of, combinator::recognize};
 
 use super::{
-    GenericPart, Manufacturer, NomParser,
-    for_nom::{digits, lines3, uppers, year2_week2},
+    GenericPart, Manufacturer, NomParser, Package, Voltage, for_format,
+    for_nom::{digits, lines3, tag, uppers, year2_week2},
 };
 
 /// Analog ADXL202JQC accelerometer (14-lead CERPAK, 3-5.25V)
@@ -19,7 +17,9 @@ use super::{
 /// ```
 pub static ANALOG_ADXL202JQC: NomParser<GenericPart> = NomParser {
     name: "Analog ADXL202JQC",
-    f: |input| {
+    examples: &["2738109451 0028 ADXL202JQC"],
+    prefixes: &[],
+    f: &|input| {
         lines3(
             digits(10),
             year2_week2,
@@ -29,13 +29,25 @@ pub static ANALOG_ADXL202JQC: NomParser<GenericPart> = NomParser {
                 tag("QC"),    // package
             )),
         )
-        .map(|(_, date_code, kind)| GenericPart {
+        .map(|(lot_code, date_code, kind)| GenericPart {
             kind: String::from(kind),
             manufacturer: Some(Manufacturer::Analog),
             date_code: Some(date_code),
+            lot_code: Some(String::from(lot_code)),
+            package: Some(Package::Cerpak14),
+            voltage: Some(Voltage::range(3_000, 5_250)),
+            ..GenericPart::default()
         })
         .parse(input)
     },
+    format: Some(&|part| {
+        Some(format!(
+            "{lot_code} {date_code} {kind}",
+            lot_code = part.lot_code.as_ref()?,
+            date_code = for_format::year2_week2(part.date_code?)?,
+            kind = part.kind,
+        ))
+    }),
 };
 
 /// Analog ADXL202JE accelerometer (8-lead LCC, 3-5.25V)
@@ -46,7 +58,9 @@ pub static ANALOG_ADXL202JQC: NomParser<GenericPart> = NomParser {
 /// ```
 pub static ANALOG_ADXL202JE: NomParser<GenericPart> = NomParser {
     name: "Analog ADXL202JE",
-    f: |input| {
+    examples: &["06245 202JE 0501A"],
+    prefixes: &[],
+    f: &|input| {
         lines3(
             digits(5),
             recognize((
@@ -54,13 +68,24 @@ pub static ANALOG_ADXL202JE: NomParser<GenericPart> = NomParser {
                 one_of("JA"), // temperature
                 tag("E"),     // package
             )),
-            digits(4).and(uppers(1)),
+            recognize(digits(4).and(uppers(1))),
         )
-        .map(|(_, kind, _)| GenericPart {
+        .map(|(lot_code, kind, code)| GenericPart {
             kind: format!("ADXL{kind}"),
             manufacturer: Some(Manufacturer::Analog),
             date_code: None,
+            lot_code: Some(format!("{lot_code} {code}")),
+            package: Some(Package::Lcc8),
+            voltage: Some(Voltage::range(3_000, 5_250)),
+            ..GenericPart::default()
         })
         .parse(input)
     },
+    format: Some(&|part| {
+        let (lot_code, code) = part.lot_code.as_deref()?.split_once(' ')?;
+        Some(format!(
+            "{lot_code} {kind} {code}",
+            kind = part.kind.strip_prefix("ADXL")?
+        ))
+    }),
 };
@@ -4,12 +4,14 @@
 
 use nom::{
     Parser as _,
-    bytes::streaming::tag,
     character::streaming::{char, one_of},
     combinator::recognize,
 };
 
-use super::{GenericPart, for_nom::lines3};
+use super::{
+    GenericPart, KIBIT, Organization, Package, Voltage, for_format,
+    for_nom::{lines3, tag},
+};
 use crate::parser::{Manufacturer, NomParser, for_nom::year2_week2};
 
 /// Atmel AT29LV512 flash (TSOP-I-32, 3.0-3.6V)
@@ -20,7 +22,9 @@ use crate::parser::{Manufacturer, NomParser, for_nom::year2_week2};
 /// ```
 pub static ATMEL_AT29LV512: NomParser<GenericPart> = NomParser {
     name: "Atmel AT29LV512",
-    f: |input| {
+    examples: &["AT29LV512 15TC 0114"],
+    prefixes: &["AT29LV512"],
+    f: &|input| {
         lines3(
             tag("AT29LV512"),
             recognize((
@@ -34,7 +38,22 @@ pub static ATMEL_AT29LV512: NomParser<GenericPart> = NomParser {
             kind: format!("{kind}-{attrs}"),
             manufacturer: Some(Manufacturer::Atmel),
             date_code: Some(date_code),
+            lot_code: None,
+            revision: None,
+            origin_country: None,
+            package: Some(Package::TsopI32),
+            voltage: Some(Voltage::range(3_000, 3_600)),
+            density: Some(512 * KIBIT),
+            organization: Some(Organization::new(64 * 1024, 8)),
+            access_time: Some(150),
         })
         .parse(input)
     },
+    format: Some(&|part| {
+        let (kind, attrs) = part.kind.split_once('-')?;
+        Some(format!(
+            "{kind} {attrs} {date_code}",
+            date_code = for_format::year2_week2(part.date_code?)?,
+        ))
+    }),
 };
@@ -0,0 +1,188 @@
+// SPDX-FileCopyrightText: Joonas Javanainen <joonas.javanainen@gmail.com>
+//
+// SPDX-License-Identifier: MIT
+
+//! Ambiguity audit for parser families.
+//!
+//! A label is ambiguous if more than one parser in a family accepts it, and the parsers disagree
+//! about the result. `MultiParser` silently picks the first match, so a new parser can shadow an
+//! existing one without anyone noticing.
+//!
+//! The audit also checks that the dispatch table of every family sends accepted labels to the
+//! parsers that accept them. A miss still parses via the slow path, so it only shows up as a
+//! warning at runtime.
+
+use std::{
+    fmt,
+    sync::atomic::{AtomicBool, Ordering},
+};
+
+use super::registry;
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+
+/// Enables or disables the ambiguity check in `MultiParser::parse`
+///
+/// When enabled, every successful parse also runs the remaining parsers of the family and logs a
+/// warning if any of them accepts the label too. This multiplies the parsing cost, so it is off
+/// by default.
+pub fn set_enabled(enabled: bool) {
+    ENABLED.store(enabled, Ordering::Relaxed);
+}
+
+/// Returns true if the ambiguity check in `MultiParser::parse` is enabled
+pub fn is_enabled() -> bool {
+    ENABLED.load(Ordering::Relaxed)
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Ambiguity {
+    pub family: &'static str,
+    pub label: String,
+    /// Parser name and debug-formatted output of every matching parser
+    pub matches: Vec<(&'static str, String)>,
+}
+
+impl fmt::Display for Ambiguity {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}: ambiguous label {:?}", self.family, self.label)?;
+        for (parser, output) in &self.matches {
+            write!(f, "\n  {parser}: {output}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Runs the label through every parser family, and returns all ambiguous matches
+pub fn find_ambiguities(label: &str) -> Vec<Ambiguity> {
+    registry::families()
+        .iter()
+        .filter_map(|family| {
+            let matches = family.parse_all(label);
+            matches
+                .iter()
+                .any(|(_, output)| *output != matches[0].1)
+                .then(|| Ambiguity {
+                    family: family.id,
+                    label: label.to_owned(),
+                    matches,
+                })
+        })
+        .collect()
+}
+
+/// Runs the label through every parser family, and returns the member parsers that accept it
+/// even though the dispatch table doesn't consider them candidates
+pub fn find_dispatch_misses(label: &str) -> Vec<String> {
+    registry::families()
+        .iter()
+        .flat_map(|family| {
+            let candidates = family.candidates(label);
+            family
+                .parse_all(label)
+                .into_iter()
+                .filter(move |(name, _)| {
+                    !candidates
+                        .iter()
+                        .any(|&index| family.parsers[index].name == *name)
+                })
+                .map(move |(name, _)| {
+                    format!(
+                        "{}: {label:?} is accepted by {name}, but not dispatched to it",
+                        family.id
+                    )
+                })
+        })
+        .collect()
+}
+
+/// Extracts example labels from the doc-tests of parser source code
+pub fn doc_test_labels(source: &str) -> Vec<String> {
+    source
+        .lines()
+        .filter(|line| line.trim_start().starts_with("///"))
+        .flat_map(|line| line.split(".parse(\"").skip(1))
+        .filter_map(|text| text.split_once("\")").map(|(label, _)| label.to_owned()))
+        .collect()
+}
+
+/// Extracts labels and stamps from a submission metadata JSON value
+pub fn metadata_labels(value: &serde_json::Value, labels: &mut Vec<String>) {
+    match value {
+        serde_json::Value::Object(map) => {
+            for (key, value) in map {
+                match value {
+                    serde_json::Value::String(text)
+                        if !text.is_empty() && (key == "label" || key.starts_with("stamp")) =>
+                    {
+                        labels.push(text.clone())
+                    }
+                    _ => metadata_labels(value, labels),
+                }
+            }
+        }
+        serde_json::Value::Array(values) => {
+            for value in values {
+                metadata_labels(value, labels);
+            }
+        }
+        _ => (),
+    }
+}
+
+#[test]
+fn test_no_ambiguities() {
+    use std::{fs, path::Path};
+
+    fn read_data(dir: &Path, labels: &mut Vec<String>) {
+        for entry in fs::read_dir(dir).unwrap() {
+            let path = entry.unwrap().path();
+            if path.is_dir() {
+                read_data(&path, labels);
+            } else if path.file_name().is_some_and(|name| name == "metadata.json") {
+                let value = serde_json::from_str(&fs::read_to_string(&path).unwrap()).unwrap();
+                metadata_labels(&value, labels);
+            }
+        }
+    }
+
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let mut labels = Vec::new();
+    read_data(&root.join("../data"), &mut labels);
+    labels.extend(
+        registry::families()
+            .iter()
+            .flat_map(|family| &family.parsers)
+            .flat_map(|parser| parser.examples)
+            .map(|&label| label.to_owned()),
+    );
+    let mut sources = vec![root.join("src/parser.rs")];
+    for entry in fs::read_dir(root.join("src/parser")).unwrap() {
+        sources.push(entry.unwrap().path());
+    }
+    let mut doc_labels = Vec::new();
+    for path in sources {
+        doc_labels.extend(doc_test_labels(&fs::read_to_string(path).unwrap()));
+    }
+    // Doc-tests must keep literal labels, otherwise this test silently stops covering them
+    assert!(
+        !doc_labels.is_empty(),
+        "no labels found in parser doc-tests"
+    );
+    labels.extend(doc_labels);
+    labels.sort();
+    labels.dedup();
+
+    let ambiguities = labels
+        .iter()
+        .flat_map(|label| find_ambiguities(label))
+        .map(|ambiguity| ambiguity.to_string())
+        .collect::<Vec<_>>();
+    assert!(ambiguities.is_empty(), "{}", ambiguities.join("\n"));
+
+    let misses = labels
+        .iter()
+        .flat_map(|label| find_dispatch_misses(label))
+        .collect::<Vec<_>>();
+    assert!(misses.is_empty(), "{}", misses.join("\n"));
+}
@@ -5,14 +5,15 @@
 use nom::{
     Parser as _,
     branch::alt,
-    bytes::streaming::tag,
     character::streaming::{char, one_of},
     combinator::{opt, recognize},
+    sequence::preceded,
 };
 
 use super::{
-    GenericPart,
-    for_nom::{alnum_uppers, digits, uppers},
+    Country, GenericPart, KIBIT, MIBIT, Organization, Voltage, for_format,
+    for_nom::{alnum_uppers, country, digits, tag, uppers},
+    speed_grade,
 };
 use crate::parser::{Manufacturer, NomParser, for_nom::year2_week2};
 
@@ -30,7 +31,14 @@ use crate::parser::{Manufacturer, NomParser, for_nom::year2_week2};
 /// ```
 pub static BSI_BS62LV256: NomParser<GenericPart> = NomParser {
     name: "BSI BS62LV256",
-    f: |input| {
+    examples: &[
+        "BSI BS62LV256SC-70 S2827V52155 A0106 TAIWAN",
+        "BSI BS62LV256SC-70 S2828W11075.1 F0231 TAIWAN",
+        "BSI BS62LV256SCG70 S2828CA30125.A D05502 TAIWAN",
+        "BSI BS62LV256SC-70 S2828W13088.1N F0318 TAIWAN",
+    ],
+    prefixes: &["BSI "],
+    f: &|input| {
         let package = Package::Sop28;
         (
             tag("BSI "),
@@ -42,23 +50,42 @@ pub static BSI_BS62LV256: NomParser<GenericPart> = NomParser {
                 alt((tag("55"), tag("70"))), // speed
             )),
             char(' '),
-            (
+            recognize((
                 alt((tag("S2827"), tag("S2828"))),
                 opt(alt((tag("CA"), uppers(1)))),
                 digits(5),
                 opt(char('.').and(alnum_uppers(1)).and(opt(uppers(1)))),
-            ),
+            )),
             char(' '),
             (uppers(1), year2_week2, opt(digits(1))),
-            tag(" TAIWAN"),
+            preceded(char(' '), country(Country::Taiwan)),
         )
-            .map(|(_, kind, _, _, _, (_, date_code, _), _)| GenericPart {
-                kind: String::from(kind),
-                manufacturer: Some(Manufacturer::Bsi),
-                date_code: Some(date_code),
-            })
+            .map(
+                |(_, kind, _, lot_code, _, (prefix, date_code, suffix), origin_country)| {
+                    GenericPart {
+                        kind: String::from(kind),
+                        manufacturer: Some(Manufacturer::Bsi),
+                        date_code: Some(date_code),
+                        lot_code: Some(
+                            [Some(lot_code), Some(prefix), suffix]
+                                .into_iter()
+                                .flatten()
+                                .collect::<Vec<_>>()
+                                .join(" "),
+                        ),
+                        revision: None,
+                        origin_country: Some(origin_country),
+                        package: Some(package.into()),
+                        voltage: Some(Voltage::range(2_400, 5_500)),
+                        density: Some(256 * KIBIT),
+                        organization: Some(Organization::new(32 * 1024, 8)),
+                        access_time: kind.get(kind.len() - 2..).and_then(speed_grade),
+                    }
+                },
+            )
             .parse(input)
     },
+    format: Some(&bsi_label),
 };
 
 /// BSI BS616LV2018 SRAM (TSOP-I-48, 2.4-3.6V, 2 Mibit / 256 KiB / 128x16)
@@ -73,7 +100,12 @@ pub static BSI_BS62LV256: NomParser<GenericPart> = NomParser {
 /// ```
 pub static BSI_BS616LV2018: NomParser<GenericPart> = NomParser {
     name: "BSI BS616LV2018",
-    f: |input| {
+    examples: &[
+        "BSI BS616LV2018TC-70 S31686-2FY24092.1 L0314 TAIWAN",
+        "BSI BS616LV2018TC-70 S31686-2FY10121.1 L0230 TAIWAN",
+    ],
+    prefixes: &["BSI "],
+    f: &|input| {
         let package = Package::TsopI48;
         (
             tag("BSI "),
@@ -85,18 +117,29 @@ pub static BSI_BS616LV2018: NomParser<GenericPart> = NomParser {
                 tag("70"),     // speed
             )),
             char(' '),
-            (tag("S31686-2FY"), digits(5), tag(".1")),
+            recognize((tag("S31686-2FY"), digits(5), tag(".1"))),
             char(' '),
             (uppers(1), year2_week2),
-            tag(" TAIWAN"),
+            preceded(char(' '), country(Country::Taiwan)),
         )
-            .map(|(_, kind, _, _, _, (_, date_code), _)| GenericPart {
-                kind: String::from(kind),
-                manufacturer: Some(Manufacturer::Bsi),
-                date_code: Some(date_code),
-            })
+            .map(
+                |(_, kind, _, lot_code, _, (prefix, date_code), origin_country)| GenericPart {
+                    kind: String::from(kind),
+                    manufacturer: Some(Manufacturer::Bsi),
+                    date_code: Some(date_code),
+                    lot_code: Some(format!("{lot_code} {prefix}")),
+                    revision: None,
+                    origin_country: Some(origin_country),
+                    package: Some(package.into()),
+                    voltage: Some(Voltage::range(2_400, 3_600)),
+                    density: Some(2 * MIBIT),
+                    organization: Some(Organization::new(128 * 1024, 16)),
+                    access_time: Some(70),
+                },
+            )
             .parse(input)
     },
+    format: Some(&bsi_label),
 };
 
 /// BSI BS616LV2019 SRAM (TSOP-I-48, 2.4-3.6V, 2 Mibit / 256 KiB / 128x16)
@@ -112,7 +155,13 @@ pub static BSI_BS616LV2018: NomParser<GenericPart> = NomParser {
 /// ```
 pub static BSI_BS616LV2019: NomParser<GenericPart> = NomParser {
     name: "BSI BS616LV2019",
-    f: |input| {
+    examples: &[
+        "BSI BS616LV2019TC-70 S31687FZ26013.1 L0335 TAIWAN",
+        "BSI BS616LV2019TC-70 S31687FZ27050.1 L0336 TAIWAN",
+        "BSI BS616LV2019TC-70 S31687FZ31012.1 L0410 TAIWAN",
+    ],
+    prefixes: &["BSI "],
+    f: &|input| {
         let package = Package::TsopI48;
         (
             tag("BSI "),
@@ -124,20 +173,45 @@ pub static BSI_BS616LV2019: NomParser<GenericPart> = NomParser {
                 alt((tag("55"), tag("70"))), // speed
             )),
             char(' '),
-            (tag("S31687FZ"), digits(5), tag(".1")),
+            recognize((tag("S31687FZ"), digits(5), tag(".1"))),
             char(' '),
             (uppers(1), year2_week2),
-            tag(" TAIWAN"),
+            preceded(char(' '), country(Country::Taiwan)),
         )
-            .map(|(_, kind, _, _, _, (_, date_code), _)| GenericPart {
-                kind: String::from(kind),
-                manufacturer: Some(Manufacturer::Bsi),
-                date_code: Some(date_code),
-            })
+            .map(
+                |(_, kind, _, lot_code, _, (prefix, date_code), origin_country)| GenericPart {
+                    kind: String::from(kind),
+                    manufacturer: Some(Manufacturer::Bsi),
+                    date_code: Some(date_code),
+                    lot_code: Some(format!("{lot_code} {prefix}")),
+                    revision: None,
+                    origin_country: Some(origin_country),
+                    package: Some(package.into()),
+                    voltage: Some(Voltage::range(2_400, 3_600)),
+                    density: Some(2 * MIBIT),
+                    organization: Some(Organization::new(128 * 1024, 16)),
+                    access_time: kind.get(kind.len() - 2..).and_then(speed_grade),
+                },
+            )
             .parse(input)
     },
+    format: Some(&bsi_label),
 };
 
+fn bsi_label(part: &GenericPart) -> Option<String> {
+    let mut lot_code = part.lot_code.as_deref()?.split(' ');
+    Some(format!(
+        "BSI {kind} {lot} {prefix}{date_code}{suffix} {country}",
+        kind = part.kind,
+        lot = lot_code.next()?,
+        prefix = lot_code.next()?,
+        date_code = for_format::year2_week2(part.date_code?)?,
+        suffix = lot_code.next().unwrap_or_default(),
+        country = part.origin_country?.label(),
+    ))
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
 enum Package {
     Sop28,
     TsopI48,
@@ -151,3 +225,12 @@ impl Package {
         }
     }
 }
+
+impl From<Package> for super::Package {
+    fn from(package: Package) -> Self {
+        match package {
+            Package::Sop28 => super::Package::Sop28,
+            Package::TsopI48 => super::Package::TsopI48,
+        }
+    }
+}
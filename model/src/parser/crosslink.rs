@@ -3,13 +3,16 @@
 // SPDX-License-Identifier: MIT
 
 use nom::{
-    IResult, Parser as _, bytes::streaming::tag, combinator::opt, error::ParseError,
-    sequence::terminated,
+    IResult, Parser as _,
+    combinator::{opt, recognize},
+    error::ParseError,
+    sequence::separated_pair,
 };
 
 use super::{
-    GenericPart, Manufacturer, NomParser, PartDateCode,
-    for_nom::{alnum_uppers, digits, lines4, uppers, week2, year1},
+    Country, GenericPart, KIBIT, Manufacturer, NomParser, Organization, Package, PartDateCode,
+    Voltage, dash_speed_grade, for_format,
+    for_nom::{alnum_uppers, country, digits, lines4, tag, uppers, week2, year1},
 };
 
 /// Crosslink LH52A64N (SOP-28, 5V, 64 Kibit / 8 KiB)
@@ -20,23 +23,37 @@ use super::{
 /// ```
 pub static CROSSLINK_LH52A64N: NomParser<GenericPart> = NomParser {
     name: "Crosslink LH52A64N",
-    f: |input| {
+    examples: &["LH52A64N-YL Xlink JAPAN H432 0U C"],
+    prefixes: &["LH52A64N-YL"],
+    f: &|input| {
         lines4(
             tag("LH52A64N-YL"),
             tag("Xlink"),
-            tag("JAPAN"),
-            terminated(
+            country(Country::Japan),
+            separated_pair(
                 date_code,
-                (tag(" "), digits(1), alnum_uppers(1), tag(" "), uppers(1)),
+                tag(" "),
+                recognize((digits(1), alnum_uppers(1), tag(" "), uppers(1))),
             ),
         )
-        .map(|(kind, _, _, date_code)| GenericPart {
-            kind: String::from(kind),
-            manufacturer: Some(Manufacturer::Crosslink),
-            date_code: Some(date_code),
-        })
+        .map(
+            |(kind, _, origin_country, (date_code, lot_code))| GenericPart {
+                kind: String::from(kind),
+                manufacturer: Some(Manufacturer::Crosslink),
+                date_code: Some(date_code),
+                lot_code: Some(String::from(lot_code)),
+                revision: None,
+                origin_country: Some(origin_country),
+                package: Some(Package::Sop28),
+                voltage: Some(Voltage::V5),
+                density: Some(64 * KIBIT),
+                organization: Some(Organization::new(8 * 1024, 8)),
+                access_time: dash_speed_grade(kind),
+            },
+        )
         .parse(input)
     },
+    format: Some(&crosslink_label),
 };
 
 /// Crosslink LH5268AN (SOP-28, 5V, 64 Kibit / 8 KiB)
@@ -47,25 +64,49 @@ pub static CROSSLINK_LH52A64N: NomParser<GenericPart> = NomParser {
 /// ```
 pub static CROSSLINK_LH5268AN: NomParser<GenericPart> = NomParser {
     name: "Crosslink LH5268AN",
-    f: |input| {
+    examples: &["LH5268ANF-10YLL Xlink JAPAN H429 0Y BB"],
+    prefixes: &["LH5268ANF-10YLL"],
+    f: &|input| {
         lines4(
             tag("LH5268ANF-10YLL"),
             tag("Xlink"),
-            tag("JAPAN"),
-            terminated(
+            country(Country::Japan),
+            separated_pair(
                 date_code,
-                (tag(" "), digits(1), alnum_uppers(1), tag(" "), uppers(2)),
+                tag(" "),
+                recognize((digits(1), alnum_uppers(1), tag(" "), uppers(2))),
             ),
         )
-        .map(|(kind, _, _, date_code)| GenericPart {
-            kind: String::from(kind),
-            manufacturer: Some(Manufacturer::Crosslink),
-            date_code: Some(date_code),
-        })
+        .map(
+            |(kind, _, origin_country, (date_code, lot_code))| GenericPart {
+                kind: String::from(kind),
+                manufacturer: Some(Manufacturer::Crosslink),
+                date_code: Some(date_code),
+                lot_code: Some(String::from(lot_code)),
+                revision: None,
+                origin_country: Some(origin_country),
+                package: Some(Package::Sop28),
+                voltage: Some(Voltage::V5),
+                density: Some(64 * KIBIT),
+                organization: Some(Organization::new(8 * 1024, 8)),
+                access_time: dash_speed_grade(kind),
+            },
+        )
         .parse(input)
     },
+    format: Some(&crosslink_label),
 };
 
+fn crosslink_label(part: &GenericPart) -> Option<String> {
+    Some(format!(
+        "{kind} Xlink {country} H{date_code} {lot_code}",
+        kind = part.kind,
+        country = part.origin_country?.label(),
+        date_code = for_format::year1_week2(part.date_code?)?,
+        lot_code = part.lot_code.as_ref()?,
+    ))
+}
+
 fn date_code<'a, E: ParseError<&'a str>>(input: &'a str) -> IResult<&'a str, PartDateCode, E> {
     (tag("H"), year1, opt(tag(" ")), week2)
         .map(|(_, year, _, week)| PartDateCode::YearWeek { year, week })
@@ -0,0 +1,818 @@
+// SPDX-FileCopyrightText: Joonas Javanainen <joonas.javanainen@gmail.com>
+//
+// SPDX-License-Identifier: MIT
+
+//! Chip label parsers defined in data files instead of Rust.
+//!
+//! Simple chips, whose labels are just literal text, a date code and some fixed-width fields, can
+//! be described in `config/parts/*.json`. Every definition is compiled into a `NomParser` on first
+//! use, and appended to the parser families it lists.
+//!
+//! The `label` pattern is literal text with `{name:spec}` placeholders:
+//!
+//! - `{date:year1_week2}` is a date code, using the name of a `DateCodeFormat`
+//! - `{country:JAPAN|KOREA}` is a country of origin, and `{country}` accepts any known country
+//! - `{rom_id:dmg|cgb}` is a game ROM code (`dmg`, `cgb` and/or `agb`)
+//! - `{lot:digits(4)}` is a fixed-width field of `digits`, `uppers`, `alphas` or `alnum_uppers`,
+//!   and `{lot:uppers(2)+'-'+digits(4)}` is a sequence of them and quoted literal text
+//! - `{speed:70|85|10}` is one of the alternatives, which are tried in order
+//!
+//! A space matches a space or a line break. Fields named `lot`, `revision` and `speed` fill in the
+//! lot code, revision and access time of the part, fields named `_` are discarded, and any field
+//! can be referenced in the `kind` template as `{name}`. Labels can be formatted back from parsed
+//! parts if no field is discarded.
+//!
+//! ```
+//! use gbhwdb_model::parser::{self, LabelFormatter, LabelParser, Voltage};
+//! let part = parser::sram_sop_28_5v().parse("Winbond W24257S-70LL 046QB202858301AC").unwrap();
+//! assert_eq!(part.kind, "W24257S-70LL");
+//! assert_eq!(part.voltage, Some(Voltage::range(4_500, 5_500)));
+//! assert_eq!(part.access_time, Some(70));
+//! assert_eq!(part.lot_code.as_deref(), Some("QB202858301AC"));
+//! assert_eq!(
+//!     parser::sram_sop_28_5v().format(&part).as_deref(),
+//!     Some("Winbond W24257S-70LL 046QB202858301AC")
+//! );
+//! ```
+
+use anyhow::{Context as _, Error, anyhow, bail, ensure};
+use nom::{
+    Err, IResult, Parser as _,
+    branch::alt,
+    character::streaming::char,
+    combinator::all_consuming,
+    error::{ErrorKind, ParseError},
+};
+use nom_language::error::VerboseError;
+use serde::Deserialize;
+use std::sync::OnceLock;
+use strum::VariantArray as _;
+
+use super::{
+    Country, DateCodeFormat, FormatFn, GameMaskRom, GameRomType, GenericPart, Manufacturer,
+    NomParser, Organization, Package, ParseFn, PartDateCode, Voltage,
+    for_nom::{agb_rom_code, cgb_rom_code, dmg_rom_code, satisfy_m_n, tag},
+    normalize_label, speed_grade,
+};
+
+/// Data files with chip definitions, relative to `config/parts`
+///
+/// The list is generated by the build script, so every JSON file in the directory is included.
+const FILES: &[(&str, &str)] = include!(concat!(env!("OUT_DIR"), "/part_files.rs"));
+
+type PResult<'a, O> = IResult<&'a str, O, VerboseError<&'a str>>;
+
+#[derive(Clone, Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Definition {
+    pub name: String,
+    pub manufacturer: Option<Manufacturer>,
+    /// Names of the parser families in `gbhwdb_model::parser` the chip belongs to
+    pub families: Vec<String>,
+    /// Label pattern with `{name:spec}` placeholders
+    pub label: String,
+    /// Template for the chip type (or the chip type of a mask ROM), with `{name}` placeholders
+    #[serde(default)]
+    pub kind: Option<String>,
+    /// Game ROM type (e.g. "C1"), only for mask ROMs
+    #[serde(default)]
+    pub rom_type: Option<GameRomType>,
+    /// Package name (e.g. "SOP-28")
+    #[serde(default)]
+    pub package: Option<Package>,
+    /// Supply voltage (e.g. "5V" or "4.5-5.5V")
+    #[serde(default)]
+    pub voltage: Option<Voltage>,
+    /// Memory organization (e.g. "32K x 8")
+    #[serde(default)]
+    pub organization: Option<Organization>,
+    pub examples: Vec<String>,
+}
+
+impl Definition {
+    fn compile_pattern(&self) -> Result<Compiled, Error> {
+        let pattern = Pattern::compile(&self.label)?;
+        let kind = self
+            .kind
+            .as_deref()
+            .map(|kind| Template::compile(kind, &pattern))
+            .transpose()?;
+        let compiled = Compiled {
+            pattern,
+            kind,
+            manufacturer: self.manufacturer,
+            rom_type: self.rom_type,
+            package: self.package,
+            voltage: self.voltage,
+            organization: self.organization,
+        };
+        ensure!(!self.examples.is_empty(), "no examples");
+        for example in &self.examples {
+            let label = normalize_label(example).canonical;
+            all_consuming(|input| compiled.pattern.parse(input))
+                .parse(&label)
+                .map_err(|_| anyhow!("example {example:?} does not match the label pattern"))?;
+        }
+        Ok(compiled)
+    }
+}
+
+/// Parses chip definitions from JSON, and checks that they compile and match their examples
+pub fn load(json: &str) -> Result<Vec<Definition>, Error> {
+    let definitions: Vec<Definition> = serde_json::from_str(json)?;
+    for definition in &definitions {
+        definition
+            .compile_pattern()
+            .with_context(|| definition.name.clone())?;
+    }
+    Ok(definitions)
+}
+
+/// Returns all chip definitions from the data files
+pub fn definitions() -> Result<&'static [Definition], Error> {
+    static DEFINITIONS: OnceLock<Result<Vec<Definition>, Error>> = OnceLock::new();
+    DEFINITIONS
+        .get_or_init(|| {
+            let mut definitions = Vec::new();
+            for (name, json) in FILES {
+                definitions.extend(load(json).with_context(|| format!("config/parts/{name}"))?);
+            }
+            Ok(definitions)
+        })
+        .as_deref()
+        .map_err(|err| anyhow!("{err:#}"))
+}
+
+/// Returns every chip definition with its compiled label pattern
+fn compiled() -> Result<&'static [(&'static Definition, Compiled)], Error> {
+    static COMPILED: OnceLock<Result<Vec<(&'static Definition, Compiled)>, Error>> =
+        OnceLock::new();
+    COMPILED
+        .get_or_init(|| {
+            definitions()?
+                .iter()
+                .map(|definition| Ok((definition, definition.compile_pattern()?)))
+                .collect()
+        })
+        .as_deref()
+        .map_err(|err| anyhow!("{err:#}"))
+}
+
+/// Returns the parsers of the definitions that belong to the given parser family
+pub(crate) fn parsers<T: Defined>(family: &str) -> Result<Vec<&'static NomParser<T>>, Error> {
+    let compiled = compiled()?;
+    compiled
+        .iter()
+        .zip(T::cache().parsers(compiled))
+        .filter(|((definition, _), _)| definition.families.iter().any(|id| id == family))
+        .map(|((definition, compiled), parser)| {
+            T::check(compiled).with_context(|| definition.name.clone())?;
+            Ok(parser)
+        })
+        .collect()
+}
+
+/// Parsers built from the chip definitions for one output type
+///
+/// Built in two steps, because the parsers refer to the functions and data stored in the first
+/// step.
+pub(crate) struct Cache<T: 'static> {
+    functions: OnceLock<Vec<Functions<T>>>,
+    parsers: OnceLock<Vec<NomParser<T>>>,
+}
+
+struct Functions<T: 'static> {
+    examples: Vec<&'static str>,
+    prefixes: Vec<&'static str>,
+    parse: Box<ParseFn<T>>,
+    format: Option<Box<FormatFn<T>>>,
+}
+
+impl<T: Defined> Cache<T> {
+    const fn new() -> Self {
+        Cache {
+            functions: OnceLock::new(),
+            parsers: OnceLock::new(),
+        }
+    }
+    /// Returns a parser for every definition, whether or not it can build the output type
+    fn parsers(
+        &'static self,
+        compiled: &'static [(&'static Definition, Compiled)],
+    ) -> &'static [NomParser<T>] {
+        let functions = self.functions.get_or_init(|| {
+            compiled
+                .iter()
+                .map(|(definition, compiled)| Functions {
+                    examples: definition.examples.iter().map(String::as_str).collect(),
+                    prefixes: compiled.pattern.prefixes(),
+                    parse: parse_fn(move |input| {
+                        let (input, captures) = compiled.pattern.parse(input)?;
+                        Ok((input, T::build(compiled, &captures)))
+                    }),
+                    format: compiled.can_format().then(|| {
+                        let format: Box<FormatFn<T>> =
+                            Box::new(move |part: &T| compiled.format(part.values()?));
+                        format
+                    }),
+                })
+                .collect()
+        });
+        self.parsers.get_or_init(|| {
+            compiled
+                .iter()
+                .zip(functions)
+                .map(|((definition, _), functions)| NomParser {
+                    name: &definition.name,
+                    examples: &functions.examples,
+                    prefixes: &functions.prefixes,
+                    f: &*functions.parse,
+                    format: functions.format.as_deref(),
+                })
+                .collect()
+        })
+    }
+}
+
+fn parse_fn<T>(f: impl Fn(&str) -> PResult<'_, T> + Send + Sync + 'static) -> Box<ParseFn<T>> {
+    Box::new(f)
+}
+
+/// Parser output that can be built from a chip definition
+pub(crate) trait Defined: Sized + 'static {
+    fn cache() -> &'static Cache<Self>;
+    /// Checks that the definition has everything the output needs
+    fn check(compiled: &Compiled) -> Result<(), Error>;
+    fn build(compiled: &Compiled, captures: &Captures) -> Self;
+    /// Returns the values needed to format the label, except for fields of the `kind` template
+    fn values(&self) -> Option<Values<'_>>;
+}
+
+impl Defined for GenericPart {
+    fn cache() -> &'static Cache<Self> {
+        static CACHE: Cache<GenericPart> = Cache::new();
+        &CACHE
+    }
+    fn check(compiled: &Compiled) -> Result<(), Error> {
+        ensure!(compiled.kind.is_some(), "missing kind");
+        ensure!(
+            compiled.rom_type.is_none(),
+            "ROM type given for a generic part"
+        );
+        Ok(())
+    }
+    fn build(compiled: &Compiled, captures: &Captures) -> Self {
+        GenericPart {
+            kind: compiled
+                .kind
+                .as_ref()
+                .map(|kind| kind.render(captures))
+                .unwrap_or_default(),
+            manufacturer: compiled.manufacturer,
+            date_code: captures.date_code,
+            lot_code: captures.get("lot").map(String::from),
+            revision: captures.get("revision").map(String::from),
+            origin_country: captures.origin_country,
+            package: compiled.package,
+            voltage: compiled.voltage,
+            density: compiled
+                .organization
+                .map(|organization| organization.density()),
+            organization: compiled.organization,
+            access_time: captures.get("speed").and_then(speed_grade),
+        }
+    }
+    fn values(&self) -> Option<Values<'_>> {
+        Some(Values {
+            kind: Some(&self.kind),
+            date_code: self.date_code,
+            origin_country: self.origin_country,
+            rom_id: None,
+            lot_code: self.lot_code.as_deref(),
+            revision: self.revision.as_deref(),
+        })
+    }
+}
+
+impl Defined for GameMaskRom {
+    fn cache() -> &'static Cache<Self> {
+        static CACHE: Cache<GameMaskRom> = Cache::new();
+        &CACHE
+    }
+    fn check(compiled: &Compiled) -> Result<(), Error> {
+        ensure!(compiled.rom_type.is_some(), "missing ROM type");
+        ensure!(
+            compiled
+                .pattern
+                .segments
+                .iter()
+                .any(|segment| matches!(segment, Segment::RomId(_))),
+            "missing {{rom_id}} in the label pattern"
+        );
+        Ok(())
+    }
+    fn build(compiled: &Compiled, captures: &Captures) -> Self {
+        let rom_type = compiled.rom_type.unwrap_or(GameRomType::GlopTop);
+        GameMaskRom {
+            rom_id: captures.rom_id.map(String::from).unwrap_or_default(),
+            rom_type,
+            manufacturer: compiled.manufacturer,
+            chip_type: compiled.kind.as_ref().map(|kind| kind.render(captures)),
+            mask_code: None,
+            date_code: captures.date_code,
+            lot_code: captures.get("lot").map(String::from),
+            revision: captures.get("revision").map(String::from),
+            origin_country: captures.origin_country,
+            package: compiled.package.or(Some(rom_type.package())),
+            voltage: compiled.voltage,
+            density: compiled
+                .organization
+                .map(|organization| organization.density())
+                .or(rom_type.density()),
+            organization: compiled.organization,
+            access_time: captures.get("speed").and_then(speed_grade),
+        }
+    }
+    fn values(&self) -> Option<Values<'_>> {
+        if self.mask_code.is_some() {
+            return None;
+        }
+        Some(Values {
+            kind: self.chip_type.as_deref(),
+            date_code: self.date_code,
+            origin_country: self.origin_country,
+            rom_id: Some(&self.rom_id),
+            lot_code: self.lot_code.as_deref(),
+            revision: self.revision.as_deref(),
+        })
+    }
+}
+
+/// Parsed values a label is formatted from
+pub(crate) struct Values<'a> {
+    kind: Option<&'a str>,
+    date_code: Option<PartDateCode>,
+    origin_country: Option<Country>,
+    rom_id: Option<&'a str>,
+    lot_code: Option<&'a str>,
+    revision: Option<&'a str>,
+}
+
+pub(crate) struct Compiled {
+    pattern: Pattern,
+    kind: Option<Template>,
+    manufacturer: Option<Manufacturer>,
+    rom_type: Option<GameRomType>,
+    package: Option<Package>,
+    voltage: Option<Voltage>,
+    organization: Option<Organization>,
+}
+
+#[derive(Default)]
+pub(crate) struct Captures<'a> {
+    fields: Vec<(&'a str, &'a str)>,
+    date_code: Option<PartDateCode>,
+    origin_country: Option<Country>,
+    rom_id: Option<&'a str>,
+}
+
+impl Compiled {
+    /// Returns true if every field of the label can be recovered from a parsed part
+    fn can_format(&self) -> bool {
+        self.pattern.field_names().all(|name| {
+            matches!(name, "lot" | "revision")
+                || self.kind.as_ref().is_some_and(|kind| kind.has_field(name))
+        })
+    }
+    fn format(&self, values: Values) -> Option<String> {
+        let kind_fields = match (&self.kind, values.kind) {
+            (Some(template), Some(kind)) => template.captures(kind)?,
+            (None, _) => Vec::new(),
+            (Some(_), None) => return None,
+        };
+        let field = |name: &str| match name {
+            "lot" => values.lot_code,
+            "revision" => values.revision,
+            _ => kind_fields
+                .iter()
+                .find(|(field, _)| *field == name)
+                .map(|(_, text)| *text),
+        };
+        let mut label = String::new();
+        for segment in &self.pattern.segments {
+            match segment {
+                Segment::Literal(text) => label.push_str(text),
+                Segment::Separator => label.push(' '),
+                Segment::Date(format) => label.push_str(&format.format(values.date_code?)?),
+                Segment::Country(countries) => {
+                    let country = values.origin_country?;
+                    if !countries.contains(&country) {
+                        return None;
+                    }
+                    label.push_str(country.label());
+                }
+                Segment::RomId(_) => label.push_str(values.rom_id?),
+                Segment::Chars { name, .. } | Segment::Alternatives { name, .. } => {
+                    label.push_str(field(name)?)
+                }
+            }
+        }
+        Some(label)
+    }
+}
+
+impl<'a> Captures<'a> {
+    fn get(&self, name: &str) -> Option<&'a str> {
+        self.fields
+            .iter()
+            .find(|(field, _)| *field == name)
+            .map(|(_, text)| *text)
+    }
+}
+
+struct Pattern {
+    segments: Vec<Segment>,
+}
+
+enum Segment {
+    Literal(String),
+    Separator,
+    Date(DateCodeFormat),
+    Country(Vec<Country>),
+    RomId(Vec<RomCode>),
+    Chars {
+        name: String,
+        items: Vec<CharItem>,
+    },
+    Alternatives {
+        name: String,
+        alternatives: Vec<String>,
+    },
+}
+
+impl Pattern {
+    fn compile(label: &str) -> Result<Pattern, Error> {
+        let mut segments = Vec::new();
+        let mut rest = label;
+        while !rest.is_empty() {
+            if let Some(body) = rest.strip_prefix('{') {
+                let (placeholder, tail) = body
+                    .split_once('}')
+                    .ok_or_else(|| anyhow!("unterminated placeholder in {label:?}"))?;
+                segments.push(Segment::compile(placeholder)?);
+                rest = tail;
+            } else if let Some(tail) = rest.strip_prefix(' ') {
+                segments.push(Segment::Separator);
+                rest = tail;
+            } else {
+                let end = rest.find(['{', '}', ' ']).unwrap_or(rest.len());
+                ensure!(end > 0, "unexpected '}}' in {label:?}");
+                segments.push(Segment::Literal(rest[..end].to_owned()));
+                rest = &rest[end..];
+            }
+        }
+        Ok(Pattern { segments })
+    }
+    /// Returns the literal text that matching labels start with, or nothing if the first segment
+    /// is a variable field
+    fn prefixes(&self) -> Vec<&str> {
+        match self.segments.first() {
+            Some(Segment::Literal(text)) => vec![text.as_str()],
+            Some(Segment::Country(countries)) => {
+                countries.iter().map(|country| country.label()).collect()
+            }
+            Some(Segment::RomId(codes)) => codes.iter().map(|code| code.prefix()).collect(),
+            _ => Vec::new(),
+        }
+    }
+    fn field_names(&self) -> impl Iterator<Item = &str> {
+        self.segments.iter().filter_map(|segment| match segment {
+            Segment::Chars { name, .. } | Segment::Alternatives { name, .. } => Some(name.as_str()),
+            _ => None,
+        })
+    }
+    fn parse<'a>(&'a self, mut input: &'a str) -> PResult<'a, Captures<'a>> {
+        let mut captures = Captures::default();
+        for segment in &self.segments {
+            input = match segment {
+                Segment::Literal(text) => tag(text).parse(input)?.0,
+                Segment::Separator => alt((char(' '), char('\n'))).parse(input)?.0,
+                Segment::Date(format) => {
+                    let (input, date_code) = format.parse(input)?;
+                    captures.date_code = Some(date_code);
+                    input
+                }
+                Segment::Country(countries) => {
+                    let (input, country) = first_of(input, countries, |country, input| {
+                        tag(country.label()).map(|_| *country).parse(input)
+                    })?;
+                    captures.origin_country = Some(country);
+                    input
+                }
+                Segment::RomId(codes) => {
+                    let (input, rom_id) = first_of(input, codes, |code, input| match code {
+                        RomCode::Dmg => dmg_rom_code().parse(input),
+                        RomCode::Cgb => cgb_rom_code().parse(input),
+                        RomCode::Agb => agb_rom_code().parse(input),
+                    })?;
+                    captures.rom_id = Some(rom_id);
+                    input
+                }
+                Segment::Chars { name, items } => {
+                    let mut rest = input;
+                    for item in items {
+                        rest = match item {
+                            CharItem::Class(class, count) => {
+                                satisfy_m_n(*count, *count, |ch| class.matches(ch))
+                                    .parse(rest)?
+                                    .0
+                            }
+                            CharItem::Literal(text) => tag(text).parse(rest)?.0,
+                        };
+                    }
+                    captures
+                        .fields
+                        .push((name, &input[..input.len() - rest.len()]));
+                    rest
+                }
+                Segment::Alternatives { name, alternatives } => {
+                    let (input, text) =
+                        first_of(input, alternatives, |text, input| tag(text).parse(input))?;
+                    captures.fields.push((name, text));
+                    input
+                }
+            };
+        }
+        Ok((input, captures))
+    }
+}
+
+impl Segment {
+    fn compile(placeholder: &str) -> Result<Segment, Error> {
+        let (name, spec) = placeholder.split_once(':').unwrap_or((placeholder, ""));
+        ensure!(
+            !name.is_empty()
+                && name
+                    .chars()
+                    .all(|ch| ch == '_' || ch.is_ascii_lowercase() || ch.is_ascii_digit()),
+            "invalid placeholder name {name:?}"
+        );
+        Ok(match name {
+            "date" => Segment::Date(
+                DateCodeFormat::from_name(spec)
+                    .ok_or_else(|| anyhow!("unknown date code format {spec:?}"))?,
+            ),
+            "country" if spec.is_empty() => Segment::Country(Country::VARIANTS.to_vec()),
+            "country" => Segment::Country(
+                spec.split('|')
+                    .map(|label| {
+                        Country::VARIANTS
+                            .iter()
+                            .copied()
+                            .find(|country| country.label() == label)
+                            .ok_or_else(|| anyhow!("unknown country {label:?}"))
+                    })
+                    .collect::<Result<_, _>>()?,
+            ),
+            "rom_id" => Segment::RomId(
+                spec.split('|')
+                    .map(RomCode::from_name)
+                    .collect::<Result<_, _>>()?,
+            ),
+            _ => {
+                ensure!(!spec.is_empty(), "missing spec for {{{name}}}");
+                match spec
+                    .split('+')
+                    .map(CharItem::from_spec)
+                    .collect::<Option<Vec<_>>>()
+                {
+                    Some(items) => Segment::Chars {
+                        name: name.to_owned(),
+                        items,
+                    },
+                    None => Segment::Alternatives {
+                        name: name.to_owned(),
+                        alternatives: spec.split('|').map(String::from).collect(),
+                    },
+                }
+            }
+        })
+    }
+}
+
+/// Runs the parser for every item in order, and returns the first match
+fn first_of<'a, I: Copy, O>(
+    input: &'a str,
+    items: impl IntoIterator<Item = I>,
+    mut f: impl FnMut(I, &'a str) -> PResult<'a, O>,
+) -> PResult<'a, O> {
+    let mut error: Option<VerboseError<&'a str>> = None;
+    for item in items {
+        match f(item, input) {
+            Err(Err::Error(err)) => {
+                error = Some(match error {
+                    Some(error) => error.or(err),
+                    None => err,
+                })
+            }
+            result => return result,
+        }
+    }
+    Err(Err::Error(error.unwrap_or_else(|| {
+        VerboseError::from_error_kind(input, ErrorKind::Alt)
+    })))
+}
+
+#[derive(Copy, Clone)]
+enum RomCode {
+    Dmg,
+    Cgb,
+    Agb,
+}
+
+impl RomCode {
+    fn from_name(name: &str) -> Result<RomCode, Error> {
+        Ok(match name {
+            "dmg" => RomCode::Dmg,
+            "cgb" => RomCode::Cgb,
+            "agb" => RomCode::Agb,
+            _ => bail!("unknown ROM code {name:?}"),
+        })
+    }
+    fn prefix(&self) -> &'static str {
+        match self {
+            RomCode::Dmg => "DMG-",
+            RomCode::Cgb => "CGB-",
+            RomCode::Agb => "AGB-",
+        }
+    }
+}
+
+enum CharItem {
+    Class(CharClass, usize),
+    Literal(String),
+}
+
+impl CharItem {
+    /// Parses a spec like `digits(4)` or `'-'`
+    fn from_spec(spec: &str) -> Option<CharItem> {
+        match spec
+            .strip_prefix('\'')
+            .and_then(|spec| spec.strip_suffix('\''))
+        {
+            Some(text) if !text.is_empty() => Some(CharItem::Literal(text.to_owned())),
+            Some(_) => None,
+            None => CharClass::from_spec(spec).map(|(class, count)| CharItem::Class(class, count)),
+        }
+    }
+}
+
+#[derive(Copy, Clone)]
+enum CharClass {
+    Digits,
+    Uppers,
+    Alphas,
+    AlnumUppers,
+}
+
+impl CharClass {
+    /// Parses a spec like `digits(4)`
+    fn from_spec(spec: &str) -> Option<(CharClass, usize)> {
+        let (class, count) = spec.strip_suffix(')')?.split_once('(')?;
+        let class = match class {
+            "digits" => CharClass::Digits,
+            "uppers" => CharClass::Uppers,
+            "alphas" => CharClass::Alphas,
+            "alnum_uppers" => CharClass::AlnumUppers,
+            _ => return None,
+        };
+        Some((class, count.parse().ok().filter(|&count| count > 0)?))
+    }
+    fn matches(self, ch: char) -> bool {
+        match self {
+            CharClass::Digits => ch.is_ascii_digit(),
+            CharClass::Uppers => ch.is_ascii_uppercase(),
+            CharClass::Alphas => ch.is_ascii_alphabetic(),
+            CharClass::AlnumUppers => ch.is_ascii_digit() || ch.is_ascii_uppercase(),
+        }
+    }
+}
+
+/// Chip type template, e.g. `W24257S-{speed}LL`
+struct Template {
+    parts: Vec<TemplatePart>,
+}
+
+enum TemplatePart {
+    Literal(String),
+    Field(String),
+}
+
+impl Template {
+    fn compile(template: &str, pattern: &Pattern) -> Result<Template, Error> {
+        let mut parts = Vec::new();
+        let mut rest = template;
+        while let Some((literal, tail)) = rest.split_once('{') {
+            let (name, tail) = tail
+                .split_once('}')
+                .ok_or_else(|| anyhow!("unterminated placeholder in {template:?}"))?;
+            ensure!(
+                name != "_" && pattern.field_names().any(|field| field == name),
+                "{{{name}}} is not a field of the label pattern"
+            );
+            if !literal.is_empty() {
+                parts.push(TemplatePart::Literal(literal.to_owned()));
+            }
+            parts.push(TemplatePart::Field(name.to_owned()));
+            rest = tail;
+        }
+        if !rest.is_empty() {
+            parts.push(TemplatePart::Literal(rest.to_owned()));
+        }
+        Ok(Template { parts })
+    }
+    fn has_field(&self, name: &str) -> bool {
+        self.parts
+            .iter()
+            .any(|part| matches!(part, TemplatePart::Field(field) if field == name))
+    }
+    /// Matches a rendered template, and returns the text of every field
+    ///
+    /// A field extends to the next occurrence of the literal text that follows it.
+    fn captures<'a>(&'a self, mut text: &'a str) -> Option<Vec<(&'a str, &'a str)>> {
+        let mut fields = Vec::new();
+        let mut parts = self.parts.iter().peekable();
+        while let Some(part) = parts.next() {
+            match part {
+                TemplatePart::Literal(literal) => text = text.strip_prefix(literal.as_str())?,
+                TemplatePart::Field(name) => {
+                    let end = match parts.peek() {
+                        Some(TemplatePart::Literal(literal)) => text.find(literal.as_str())?,
+                        Some(TemplatePart::Field(_)) => return None,
+                        None => text.len(),
+                    };
+                    fields.push((name.as_str(), &text[..end]));
+                    text = &text[end..];
+                }
+            }
+        }
+        text.is_empty().then_some(fields)
+    }
+    fn render(&self, captures: &Captures) -> String {
+        self.parts
+            .iter()
+            .map(|part| match part {
+                TemplatePart::Literal(text) => text,
+                TemplatePart::Field(name) => captures.get(name).unwrap_or_default(),
+            })
+            .collect()
+    }
+}
+
+#[test]
+fn test_definitions_belong_to_families() {
+    use super::registry;
+
+    for definition in definitions().unwrap() {
+        for id in &definition.families {
+            let family = registry::families()
+                .iter()
+                .find(|family| family.id == id)
+                .unwrap_or_else(|| panic!("{}: unknown family {id}", definition.name));
+            for example in &definition.examples {
+                assert!(
+                    family
+                        .parse_all(example)
+                        .iter()
+                        .any(|(name, _)| *name == definition.name),
+                    "{}: {example} is not parsed by {id}",
+                    definition.name
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn test_load_errors() {
+    let load = |definition: &str| format!("{:#}", load(&format!("[{definition}]")).unwrap_err());
+    let error = load(
+        r#"{"name": "Test", "families": [], "label": "T{x:1|2}", "kind": "T{y}", "examples": ["T1"]}"#,
+    );
+    assert!(error.contains("{y} is not a field"), "{error}");
+    let error =
+        load(r#"{"name": "Test", "families": [], "label": "T {date:year9}", "examples": ["T 1"]}"#);
+    assert!(error.contains("unknown date code format"), "{error}");
+    let error = load(
+        r#"{"name": "Test", "families": [], "label": "T {date:year1_week2}", "examples": ["T 9953"]}"#,
+    );
+    assert!(error.contains("does not match"), "{error}");
+    let error = load(
+        r#"{"name": "Test", "families": [], "label": "T", "voltage": "5", "examples": ["T"]}"#,
+    );
+    assert!(error.contains("invalid voltage"), "{error}");
+    let error = load(
+        r#"{"name": "Test", "families": [], "label": "T", "package": "SOP-99", "examples": ["T"]}"#,
+    );
+    assert!(error.contains("unknown variant"), "{error}");
+}
@@ -5,7 +5,6 @@
 use nom::{
     Parser,
     branch::alt,
-    bytes::streaming::tag,
     character::{complete::one_of, streaming::char},
     combinator::{opt, recognize},
     error::ParseError,
@@ -13,12 +12,13 @@ use nom::{
 };
 
 use crate::parser::{
-    Manufacturer, NomParser,
-    for_nom::{digits, uppers, year2_week2},
+    Manufacturer, NomParser, for_format,
+    for_nom::{country, digits, tag, uppers, year2_week2},
 };
 
 use super::{
-    GameMaskRom, GameRomType, GenericPart, MaskRom,
+    Country, GameMaskRom, GameRomType, GenericPart, KIBIT, MIBIT, MaskRom, Organization, Package,
+    Voltage, dash_speed_grade,
     for_nom::{alnum_uppers, cgb_rom_code, dmg_rom_code},
 };
 
@@ -31,23 +31,46 @@ use super::{
 /// ```
 pub static FUJITSU_MB85R256: NomParser<GenericPart> = NomParser {
     name: "Fujitsu MB85R256",
-    f: |input| {
+    examples: &["JAPAN MB85R256A 0412 M88", "JAPAN MB85R256S 0511 M22 E1"],
+    prefixes: &["JAPAN"],
+    f: &|input| {
         (
-            tag("JAPAN "),
+            terminated(country(Country::Japan), char(' ')),
             recognize(tag("MB85R256").and(opt(one_of("AS")))),
             char(' '),
             year2_week2,
             char(' '),
-            uppers(1).and(digits(2)),
-            opt(nom::bytes::complete::tag(" E1")),
+            recognize(
+                uppers(1)
+                    .and(digits(2))
+                    .and(opt(nom::bytes::complete::tag(" E1"))),
+            ),
         )
-            .map(|(_, kind, _, date_code, _, _, _)| GenericPart {
-                kind: String::from(kind),
-                manufacturer: Some(Manufacturer::Fujitsu),
-                date_code: Some(date_code),
-            })
+            .map(
+                |(origin_country, kind, _, date_code, _, lot_code)| GenericPart {
+                    kind: String::from(kind),
+                    manufacturer: Some(Manufacturer::Fujitsu),
+                    date_code: Some(date_code),
+                    lot_code: Some(String::from(lot_code)),
+                    origin_country: Some(origin_country),
+                    package: Some(Package::Sop28),
+                    voltage: Some(Voltage::range(3_000, 3_600)),
+                    density: Some(256 * KIBIT),
+                    organization: Some(Organization::new(32 * 1024, 8)),
+                    ..GenericPart::default()
+                },
+            )
             .parse(input)
     },
+    format: Some(&|part| {
+        Some(format!(
+            "{country} {kind} {date_code} {lot_code}",
+            country = part.origin_country?.label(),
+            kind = part.kind,
+            date_code = for_format::year2_week2(part.date_code?)?,
+            lot_code = part.lot_code.as_ref()?,
+        ))
+    }),
 };
 
 /// Fujitsu MB82D12160 SRAM (TSOP-I-48, 3.3V, 2 Mibit / 256 KiB / 128x16)
@@ -58,53 +81,95 @@ pub static FUJITSU_MB85R256: NomParser<GenericPart> = NomParser {
 /// ```
 pub static FUJITSU_MB82D12160: NomParser<GenericPart> = NomParser {
     name: "Fujitsu MB82D12160",
-    f: |input| {
+    examples: &["JAPAN 82D12160-10FN 0238 M88N"],
+    prefixes: &["JAPAN"],
+    f: &|input| {
         (
-            tag("JAPAN "),
+            terminated(country(Country::Japan), char(' ')),
             tag("82D12160-10FN"),
             char(' '),
             year2_week2,
             char(' '),
-            uppers(1).and(digits(2)).and(uppers(1)),
+            recognize(uppers(1).and(digits(2)).and(uppers(1))),
         )
-            .map(|(_, kind, _, date_code, _, _)| GenericPart {
-                kind: format!("MB{kind}"),
-                manufacturer: Some(Manufacturer::Fujitsu),
-                date_code: Some(date_code),
-            })
+            .map(
+                |(origin_country, kind, _, date_code, _, lot_code)| GenericPart {
+                    kind: format!("MB{kind}"),
+                    manufacturer: Some(Manufacturer::Fujitsu),
+                    date_code: Some(date_code),
+                    lot_code: Some(String::from(lot_code)),
+                    revision: None,
+                    origin_country: Some(origin_country),
+                    package: Some(Package::TsopI48),
+                    voltage: Some(Voltage::V3_3),
+                    density: Some(2 * MIBIT),
+                    organization: Some(Organization::new(128 * 1024, 16)),
+                    access_time: dash_speed_grade(kind),
+                },
+            )
             .parse(input)
     },
+    format: Some(&|part| {
+        Some(format!(
+            "{country} {kind} {date_code} {lot_code}",
+            country = part.origin_country?.label(),
+            kind = part.kind.strip_prefix("MB")?,
+            date_code = for_format::year2_week2(part.date_code?)?,
+            lot_code = part.lot_code.as_ref()?,
+        ))
+    }),
 };
 
 fn mask_rom<'a, E: ParseError<&'a str>>(
     rom_type: GameRomType,
 ) -> impl Parser<&'a str, Output = GameMaskRom, Error = E> {
     (
-        tag("JAPAN "),
+        terminated(country(Country::Japan), char(' ')),
         alt((dmg_rom_code(), cgb_rom_code())),
         char(' '),
         tag(rom_type.as_str()),
         char(' '),
-        digits(1).and(alnum_uppers(1)).and(alnum_uppers(1)), // mask_code?
+        recognize(digits(1).and(alnum_uppers(1)).and(alnum_uppers(1))), // mask_code?
         char(' '),
         tag("AK"),
         char(' '),
         year2_week2,
         char(' '),
-        uppers(1).and(digits(2)),
+        recognize(uppers(1).and(digits(2))),
     )
         .map(
-            move |(_, rom_id, _, _, _, _, _, _, _, date_code, _, _)| GameMaskRom {
-                rom_id: String::from(rom_id),
-                rom_type,
-                manufacturer: Some(Manufacturer::Fujitsu),
-                chip_type: None,
-                mask_code: None,
-                date_code: Some(date_code),
+            move |(origin_country, rom_id, _, _, _, code, _, _, _, date_code, _, lot_code)| {
+                GameMaskRom {
+                    rom_id: String::from(rom_id),
+                    rom_type,
+                    manufacturer: Some(Manufacturer::Fujitsu),
+                    chip_type: None,
+                    mask_code: None,
+                    date_code: Some(date_code),
+                    lot_code: Some(format!("{code} {lot_code}")),
+                    revision: None,
+                    origin_country: Some(origin_country),
+                    package: Some(rom_type.package()),
+                    voltage: Some(Voltage::V5),
+                    density: rom_type.density(),
+                    organization: None,
+                    access_time: None,
+                }
             },
         )
 }
 
+fn mask_rom_label(rom: &GameMaskRom) -> Option<String> {
+    let (code, lot_code) = rom.lot_code.as_ref()?.split_once(' ')?;
+    Some(format!(
+        "{country} {rom_id} {rom_type} {code} AK {date_code} {lot_code}",
+        country = rom.origin_country?.label(),
+        rom_id = rom.rom_id,
+        rom_type = rom.rom_type.as_str(),
+        date_code = for_format::year2_week2(rom.date_code?)?,
+    ))
+}
+
 /// Fujitsu mask ROM (SOP-32, 5V, 2 Mibit / 256 KiB)
 ///
 /// ```
@@ -113,7 +178,10 @@ fn mask_rom<'a, E: ParseError<&'a str>>(
 /// ```
 pub static FUJITSU_MASK_ROM_SOP_32_2_MIBIT: NomParser<GameMaskRom> = NomParser {
     name: "Fujitsu mask ROM",
-    f: |input| mask_rom(GameRomType::D1).parse(input),
+    examples: &["JAPAN DMG-GKX-0 D1 1P0 AK 9328 R09"],
+    prefixes: &["JAPAN"],
+    f: &|input| mask_rom(GameRomType::D1).parse(input),
+    format: Some(&mask_rom_label),
 };
 
 /// Fujitsu mask ROM (SOP-32, 5V, 4 Mibit / 512 KiB)
@@ -125,7 +193,13 @@ pub static FUJITSU_MASK_ROM_SOP_32_2_MIBIT: NomParser<GameMaskRom> = NomParser {
 /// ```
 pub static FUJITSU_MASK_ROM_SOP_32_4_MIBIT: NomParser<GameMaskRom> = NomParser {
     name: "Fujitsu mask ROM",
-    f: |input| mask_rom(GameRomType::E1).parse(input),
+    examples: &[
+        "JAPAN DMG-WJA-0 E1 3NH AK 9401 R17",
+        "JAPAN DMG-ZLJ-0 E1 58T AK 9321 R25",
+    ],
+    prefixes: &["JAPAN"],
+    f: &|input| mask_rom(GameRomType::E1).parse(input),
+    format: Some(&mask_rom_label),
 };
 
 /// Fujitsu SGB mask ROM
@@ -136,20 +210,31 @@ pub static FUJITSU_MASK_ROM_SOP_32_4_MIBIT: NomParser<GameMaskRom> = NomParser {
 /// ```
 pub static FUJITSU_SGB_ROM: NomParser<MaskRom> = NomParser {
     name: "Fujitsu SGB ROM",
-    f: |input| {
+    examples: &["SYS-SGB-2 © 1994 Nintendo 9429 R77"],
+    prefixes: &["SYS-SGB-2"],
+    f: &|input| {
         (
             terminated(tag("SYS-SGB-2"), tag(" © 1994 Nintendo ")),
             year2_week2,
             char(' '),
-            uppers(1).and(digits(2)),
+            recognize(uppers(1).and(digits(2))),
         )
-            .map(|(rom_id, date_code, _, _)| MaskRom {
+            .map(|(rom_id, date_code, _, lot_code)| MaskRom {
                 rom_id: String::from(rom_id),
                 manufacturer: Some(Manufacturer::Fujitsu),
                 chip_type: None,
                 mask_code: None,
                 date_code: Some(date_code),
+                lot_code: Some(String::from(lot_code)),
             })
             .parse(input)
     },
+    format: Some(&|rom| {
+        Some(format!(
+            "{rom_id} © 1994 Nintendo {date_code} {lot_code}",
+            rom_id = rom.rom_id,
+            date_code = for_format::year2_week2(rom.date_code?)?,
+            lot_code = rom.lot_code.as_ref()?,
+        ))
+    }),
 };
@@ -2,11 +2,11 @@
 //
 // SPDX-License-Identifier: MIT
 
-use nom::{IResult, Parser as _, bytes::streaming::tag, error::ParseError, sequence::terminated};
+use nom::{IResult, Parser as _, error::ParseError, sequence::separated_pair};
 
 use super::{
-    Manufacturer, Mapper, MapperChip, NomParser, PartDateCode,
-    for_nom::{lines4, uppers, year2_week2},
+    Manufacturer, Mapper, MapperChip, NomParser, PartDateCode, for_format,
+    for_nom::{lines4, tag, uppers, year2_week2},
 };
 
 /// Hudson HuC-1 mapper (QFP-32)
@@ -17,15 +17,26 @@ use super::{
 /// ```
 pub static HUDSON_HUC1: NomParser<Mapper> = NomParser {
     name: "Hudson HuC-1",
-    f: |input| {
-        lines4(tag("HuC-1"), tag("© HUDSON"), tag("Nintendo"), date_code)
-            .map(|(_, _, _, date_code)| Mapper {
-                kind: MapperChip::Huc1,
-                manufacturer: Some(Manufacturer::Hudson),
-                date_code: Some(date_code),
-            })
-            .parse(input)
+    examples: &["HuC-1 © HUDSON Nintendo 9752 A"],
+    prefixes: &["HuC-1"],
+    f: &|input| {
+        lines4(
+            tag("HuC-1"),
+            tag("© HUDSON"),
+            tag("Nintendo"),
+            date_and_lot_code,
+        )
+        .map(|(_, _, _, (date_code, lot_code))| Mapper {
+            kind: MapperChip::Huc1,
+            manufacturer: Some(Manufacturer::Hudson),
+            date_code: Some(date_code),
+            lot_code: Some(String::from(lot_code)),
+            revision: None,
+            origin_country: None,
+        })
+        .parse(input)
     },
+    format: Some(&|mapper| hudson_label("HuC-1", mapper)),
 };
 
 /// Hudson HuC-1A mapper (QFP-32)
@@ -36,15 +47,26 @@ pub static HUDSON_HUC1: NomParser<Mapper> = NomParser {
 /// ```
 pub static HUDSON_HUC1A: NomParser<Mapper> = NomParser {
     name: "Hudson HuC-1A",
-    f: |input| {
-        lines4(tag("HuC1A"), tag("© HUDSON"), tag("Nintendo"), date_code)
-            .map(|(_, _, _, date_code)| Mapper {
-                kind: MapperChip::Huc1A,
-                manufacturer: Some(Manufacturer::Hudson),
-                date_code: Some(date_code),
-            })
-            .parse(input)
+    examples: &["HuC1A © HUDSON Nintendo 9845 A"],
+    prefixes: &["HuC1A"],
+    f: &|input| {
+        lines4(
+            tag("HuC1A"),
+            tag("© HUDSON"),
+            tag("Nintendo"),
+            date_and_lot_code,
+        )
+        .map(|(_, _, _, (date_code, lot_code))| Mapper {
+            kind: MapperChip::Huc1A,
+            manufacturer: Some(Manufacturer::Hudson),
+            date_code: Some(date_code),
+            lot_code: Some(String::from(lot_code)),
+            revision: None,
+            origin_country: None,
+        })
+        .parse(input)
     },
+    format: Some(&|mapper| hudson_label("HuC1A", mapper)),
 };
 
 /// Hudson HuC-3 mapper (QFP-48)
@@ -55,17 +77,38 @@ pub static HUDSON_HUC1A: NomParser<Mapper> = NomParser {
 /// ```
 pub static HUDSON_HUC3: NomParser<Mapper> = NomParser {
     name: "Hudson HuC-3",
-    f: |input| {
-        lines4(tag("HuC-3"), tag("© HUDSON"), tag("Nintendo"), date_code)
-            .map(|(_, _, _, date_code)| Mapper {
-                kind: MapperChip::Huc3,
-                manufacturer: Some(Manufacturer::Hudson),
-                date_code: Some(date_code),
-            })
-            .parse(input)
+    examples: &["HuC-3 © HUDSON Nintendo 9943 A"],
+    prefixes: &["HuC-3"],
+    f: &|input| {
+        lines4(
+            tag("HuC-3"),
+            tag("© HUDSON"),
+            tag("Nintendo"),
+            date_and_lot_code,
+        )
+        .map(|(_, _, _, (date_code, lot_code))| Mapper {
+            kind: MapperChip::Huc3,
+            manufacturer: Some(Manufacturer::Hudson),
+            date_code: Some(date_code),
+            lot_code: Some(String::from(lot_code)),
+            revision: None,
+            origin_country: None,
+        })
+        .parse(input)
     },
+    format: Some(&|mapper| hudson_label("HuC-3", mapper)),
 };
 
-fn date_code<'a, E: ParseError<&'a str>>(input: &'a str) -> IResult<&'a str, PartDateCode, E> {
-    terminated(year2_week2, tag(" ").and(uppers(1))).parse(input)
+fn date_and_lot_code<'a, E: ParseError<&'a str>>(
+    input: &'a str,
+) -> IResult<&'a str, (PartDateCode, &'a str), E> {
+    separated_pair(year2_week2, tag(" "), uppers(1)).parse(input)
+}
+
+fn hudson_label(model: &str, mapper: &Mapper) -> Option<String> {
+    Some(format!(
+        "{model} © HUDSON Nintendo {date_code} {lot_code}",
+        date_code = for_format::year2_week2(mapper.date_code?)?,
+        lot_code = mapper.lot_code.as_ref()?,
+    ))
 }
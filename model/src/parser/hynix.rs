@@ -5,7 +5,6 @@
 use nom::{
     IResult, Parser,
     branch::alt,
-    bytes::streaming::tag,
     character::streaming::{char, one_of},
     combinator::{opt, recognize, value},
     error::ParseError,
@@ -13,8 +12,10 @@ use nom::{
 };
 
 use super::{
-    GameMaskRom, GameRomType, GenericPart, PartDateCode,
-    for_nom::{agb_rom_code, digits, lines3, lines4, uppers, year2_week2},
+    Country, GameMaskRom, GameRomType, GenericPart, KIBIT, MIBIT, Organization, Package,
+    PartDateCode, Voltage, for_format,
+    for_nom::{agb_rom_code, country, digits, lines3, lines4, tag, uppers, year2_week2},
+    speed_grade,
 };
 use crate::parser::{Manufacturer, NomParser};
 
@@ -29,9 +30,11 @@ use crate::parser::{Manufacturer, NomParser};
 /// ```
 pub static HYNIX_HY62LF16206: NomParser<GenericPart> = NomParser {
     name: "Hynix HY62LF16206",
-    f: |input| {
+    examples: &["Hynix KOREA HY62LF16206A 0223A LT12C"],
+    prefixes: &["Hynix"],
+    f: &|input| {
         lines3(
-            separated_pair(tag("Hynix"), char(' '), tag("KOREA")),
+            separated_pair(tag("Hynix"), char(' '), country(Country::Korea)),
             recognize(tag("HY62LF16206").and(opt(one_of("AB")))),
             separated_pair(
                 date_code.and(process_code),
@@ -45,14 +48,35 @@ pub static HYNIX_HY62LF16206: NomParser<GenericPart> = NomParser {
             ),
         )
         .map(
-            |(_, kind, ((date_code, _), (power, package, speed, temp)))| GenericPart {
+            |(
+                (_, origin_country),
+                kind,
+                ((date_code, revision), (power, package, speed, temp)),
+            )| GenericPart {
                 kind: format!("{kind}-{power}{package}{speed}{temp}"),
                 manufacturer: Some(Manufacturer::Hynix),
                 date_code: Some(date_code),
+                lot_code: None,
+                revision: Some(String::from(revision)),
+                origin_country: Some(origin_country),
+                package: Some(Package::TsopI48),
+                voltage: Some(Voltage::range(2_300, 2_700)),
+                density: Some(2 * MIBIT),
+                organization: Some(Organization::new(128 * 1024, 16)),
+                access_time: speed_grade(speed),
             },
         )
         .parse(input)
     },
+    format: Some(&|part| {
+        let (kind, suffix) = part.kind.split_once('-')?;
+        Some(format!(
+            "Hynix {country} {kind} {date_code}{revision} {suffix}",
+            country = part.origin_country?.label(),
+            date_code = for_format::year2_week2(part.date_code?)?,
+            revision = part.revision.as_ref()?,
+        ))
+    }),
 };
 
 /// Hynix HY62WT08081 SRAM (SOP-28, 2.7-5.5V, 256 Kibit / 32 KiB)
@@ -66,7 +90,9 @@ pub static HYNIX_HY62LF16206: NomParser<GenericPart> = NomParser {
 /// ```
 pub static HYNIX_HY62WT08081: NomParser<GenericPart> = NomParser {
     name: "Hynix HY62WT08081",
-    f: |input| {
+    examples: &["hynix 0231A HY62WT081ED70C KOREA"],
+    prefixes: &["hynix"],
+    f: &|input| {
         lines3(
             separated_pair(tag("hynix"), char(' '), date_code.and(process_code)),
             (
@@ -75,17 +101,36 @@ pub static HYNIX_HY62WT08081: NomParser<GenericPart> = NomParser {
                 alt((tag("50"), tag("70"))),         // speed
                 alt((tag("C"), tag("E"), tag("I"))), // temperature
             ),
-            tag("KOREA"),
+            country(Country::Korea),
         )
         .map(
-            |((_, (date_code, _)), (kind, power, speed, temp), _)| GenericPart {
-                kind: format!("{kind}{power}{speed}{temp}"),
-                manufacturer: Some(Manufacturer::Hynix),
-                date_code: Some(date_code),
+            |((_, (date_code, revision)), (kind, power, speed, temp), origin_country)| {
+                GenericPart {
+                    kind: format!("{kind}{power}{speed}{temp}"),
+                    manufacturer: Some(Manufacturer::Hynix),
+                    date_code: Some(date_code),
+                    lot_code: None,
+                    revision: Some(String::from(revision)),
+                    origin_country: Some(origin_country),
+                    package: Some(Package::Sop28),
+                    voltage: Some(Voltage::range(2_700, 5_500)),
+                    density: Some(256 * KIBIT),
+                    organization: Some(Organization::new(32 * 1024, 8)),
+                    access_time: speed_grade(speed),
+                }
             },
         )
         .parse(input)
     },
+    format: Some(&|part| {
+        Some(format!(
+            "hynix {date_code}{revision} {kind} {country}",
+            date_code = for_format::year2_week2(part.date_code?)?,
+            revision = part.revision.as_ref()?,
+            kind = part.kind.replacen("HY62WT08081", "HY62WT081", 1),
+            country = part.origin_country?.label(),
+        ))
+    }),
 };
 
 fn ac23v<'a, E: ParseError<&'a str>>(
@@ -96,18 +141,36 @@ fn ac23v<'a, E: ParseError<&'a str>>(
         tag("HYNIX"),
         tag(chip_type),
         separated_pair(agb_rom_code(), char(' '), tag(rom_type.as_str())),
-        alt((tag("NL"), tag("ZBR"))).and(digits(4)),
+        recognize(alt((tag("NL"), tag("ZBR"))).and(digits(4))),
     )
-    .map(move |(_, kind, (rom_id, _), _)| GameMaskRom {
+    .map(move |(_, kind, (rom_id, _), lot_code)| GameMaskRom {
         rom_id: String::from(rom_id),
         rom_type,
         manufacturer: Some(Manufacturer::Hynix),
         chip_type: Some(String::from(kind)),
         mask_code: None,
         date_code: None,
+        lot_code: Some(String::from(lot_code)),
+        revision: None,
+        origin_country: None,
+        package: Some(rom_type.package()),
+        voltage: Some(Voltage::V3_3),
+        density: rom_type.density(),
+        organization: None,
+        access_time: None,
     })
 }
 
+fn ac23v_label(rom: &GameMaskRom) -> Option<String> {
+    Some(format!(
+        "HYNIX {kind} {rom_id} {rom_type} {lot_code}",
+        kind = rom.chip_type.as_ref()?,
+        rom_id = rom.rom_id,
+        rom_type = rom.rom_type.as_str(),
+        lot_code = rom.lot_code.as_ref()?,
+    ))
+}
+
 /// Hynix AC23V32101 AGB mask ROM (TSOP-II-44, 3.3V, 32 Mibit / 4 MiB)
 ///
 /// ```
@@ -116,7 +179,10 @@ fn ac23v<'a, E: ParseError<&'a str>>(
 /// ```
 pub static HYNIX_AC23V32101: NomParser<GameMaskRom> = NomParser {
     name: "Hynix AC23V32101",
-    f: |input| ac23v("AC23V32101", GameRomType::H2).parse(input),
+    examples: &["HYNIX AC23V32101 AGB-BAUE-0 H2 ZBR4079"],
+    prefixes: &["HYNIX"],
+    f: &|input| ac23v("AC23V32101", GameRomType::H2).parse(input),
+    format: Some(&ac23v_label),
 };
 
 /// Hynix AC23V64101 AGB mask ROM (TSOP-II-44, 3.3V, 64 Mibit / 8 MiB)
@@ -127,7 +193,10 @@ pub static HYNIX_AC23V32101: NomParser<GameMaskRom> = NomParser {
 /// ```
 pub static HYNIX_AC23V64101: NomParser<GameMaskRom> = NomParser {
     name: "Hynix AC23V64101",
-    f: |input| ac23v("AC23V64101", GameRomType::I2).parse(input),
+    examples: &["HYNIX AC23V64101 AGB-AZLP-0 I2 ZBR1467"],
+    prefixes: &["HYNIX"],
+    f: &|input| ac23v("AC23V64101", GameRomType::I2).parse(input),
+    format: Some(&ac23v_label),
 };
 
 /// Hynix AC23V128111 AGB mask ROM (TSOP-II-44, 3.3V, 128 Mibit / 16 MiB)
@@ -138,7 +207,10 @@ pub static HYNIX_AC23V64101: NomParser<GameMaskRom> = NomParser {
 /// ```
 pub static HYNIX_AC23V128111: NomParser<GameMaskRom> = NomParser {
     name: "Hynix AC23V128111",
-    f: |input| ac23v("AC23V128111", GameRomType::J2).parse(input),
+    examples: &["HYNIX AC23V128111 AGB-AY7E-0 J2 NL0013"],
+    prefixes: &["HYNIX"],
+    f: &|input| ac23v("AC23V128111", GameRomType::J2).parse(input),
+    format: Some(&ac23v_label),
 };
 
 fn date_code<'a, E: ParseError<&'a str>>(input: &'a str) -> IResult<&'a str, PartDateCode, E> {
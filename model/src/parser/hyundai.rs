@@ -3,13 +3,18 @@
 // SPDX-License-Identifier: MIT
 
 use nom::{
-    IResult, Parser, branch::alt, bytes::streaming::tag, character::streaming::char,
-    combinator::value, error::ParseError,
+    IResult, Parser,
+    branch::alt,
+    character::streaming::char,
+    combinator::value,
+    error::ParseError,
+    sequence::{delimited, preceded},
 };
 
 use super::{
-    GenericPart, PartDateCode,
-    for_nom::{uppers, year2_week2},
+    Country, GenericPart, KIBIT, MIBIT, Organization, PartDateCode, Voltage, Year, for_format,
+    for_nom::{country, tag, uppers, year2_week2},
+    speed_grade,
 };
 use crate::parser::{Manufacturer, NomParser};
 
@@ -21,9 +26,11 @@ use crate::parser::{Manufacturer, NomParser};
 /// ```
 pub static HYUNDAI_HY628100: NomParser<GenericPart> = NomParser {
     name: "Hyundai HY628100",
-    f: |input| {
+    examples: &["HYUNDAI KOREA HY628100B 0041A LLG-70"],
+    prefixes: &["HYUNDAI "],
+    f: &|input| {
         (
-            tag("HYUNDAI KOREA "),
+            delimited(tag("HYUNDAI "), country(Country::Korea), char(' ')),
             alt((tag("HY628100A"), tag("HY628100B"), tag("HY628100"))),
             char(' '),
             date_code.and(process_code),
@@ -36,14 +43,40 @@ pub static HYUNDAI_HY628100: NomParser<GenericPart> = NomParser {
             ),
         )
             .map(
-                |(_, kind, _, (date_code, _), _, (power, package, _, speed))| GenericPart {
-                    kind: format!("{kind}{power}{package}-{speed}", package = package.code()),
-                    manufacturer: Some(Manufacturer::Hyundai),
-                    date_code: Some(date_code),
+                |(
+                    origin_country,
+                    kind,
+                    _,
+                    (date_code, revision),
+                    _,
+                    (power, package, _, speed),
+                )| {
+                    GenericPart {
+                        kind: format!("{kind}{power}{package}-{speed}", package = package.code()),
+                        manufacturer: Some(Manufacturer::Hyundai),
+                        date_code: Some(date_code),
+                        lot_code: None,
+                        revision: Some(String::from(revision)),
+                        origin_country: Some(origin_country),
+                        package: Some(super::Package::Sop32),
+                        voltage: Some(Voltage::range(4_500, 5_500)),
+                        density: Some(MIBIT),
+                        organization: Some(Organization::new(128 * 1024, 8)),
+                        access_time: speed_grade(speed),
+                    }
                 },
             )
             .parse(input)
     },
+    format: Some(&|part| {
+        let (kind, suffix) = part.kind.split_at(part.kind.find('L')?);
+        Some(format!(
+            "HYUNDAI {country} {kind} {date_code}{revision} {suffix}",
+            country = part.origin_country?.label(),
+            date_code = for_format::year2_week2(part.date_code?)?,
+            revision = part.revision.as_ref()?,
+        ))
+    }),
 };
 
 /// Hyundai HY6264 SRAM (SOP-28, 4.5-5.5V, 64 Kibit / 8 KiB)
@@ -55,7 +88,12 @@ pub static HYUNDAI_HY628100: NomParser<GenericPart> = NomParser {
 /// ```
 pub static HYUNDAI_HY6264: NomParser<GenericPart> = NomParser {
     name: "Hyundai HY6264",
-    f: |input| {
+    examples: &[
+        "HYUNDAI HY6264ALLJ-10 9327B KOREA",
+        "HY6264A LLJ-10 9902B KOREA",
+    ],
+    prefixes: &["HYUNDAI ", "HY6264"],
+    f: &|input| {
         // 1992-1994
         let old_format = (
             tag("HYUNDAI "),
@@ -68,13 +106,29 @@ pub static HYUNDAI_HY6264: NomParser<GenericPart> = NomParser {
             ),
             char(' '),
             date_code.and(process_code),
-            tag(" KOREA"),
+            preceded(char(' '), country(Country::Korea)),
         )
             .map(
-                |(_, (kind, power, package, _, speed), _, (date_code, _), _)| GenericPart {
-                    kind: format!("{kind}{power}{package}-{speed}", package = package.code()),
-                    manufacturer: Some(Manufacturer::Hyundai),
-                    date_code: Some(date_code),
+                |(
+                    _,
+                    (kind, power, package, _, speed),
+                    _,
+                    (date_code, revision),
+                    origin_country,
+                )| {
+                    GenericPart {
+                        kind: format!("{kind}{power}{package}-{speed}", package = package.code()),
+                        manufacturer: Some(Manufacturer::Hyundai),
+                        date_code: Some(date_code),
+                        lot_code: None,
+                        revision: Some(String::from(revision)),
+                        origin_country: Some(origin_country),
+                        package: Some(super::Package::Sop28),
+                        voltage: Some(Voltage::range(4_500, 5_500)),
+                        density: Some(64 * KIBIT),
+                        organization: Some(Organization::new(8 * 1024, 8)),
+                        access_time: speed_grade(speed),
+                    }
                 },
             );
         // 1994-
@@ -89,17 +143,56 @@ pub static HYUNDAI_HY6264: NomParser<GenericPart> = NomParser {
             ),
             char(' '),
             date_code.and(process_code),
-            tag(" KOREA"),
+            preceded(char(' '), country(Country::Korea)),
         )
             .map(
-                |(kind, _, (power, package, _, speed), _, (date_code, _), _)| GenericPart {
-                    kind: format!("{kind}{power}{package}-{speed}", package = package.code()),
-                    manufacturer: Some(Manufacturer::Hyundai),
-                    date_code: Some(date_code),
+                |(
+                    kind,
+                    _,
+                    (power, package, _, speed),
+                    _,
+                    (date_code, revision),
+                    origin_country,
+                )| {
+                    GenericPart {
+                        kind: format!("{kind}{power}{package}-{speed}", package = package.code()),
+                        manufacturer: Some(Manufacturer::Hyundai),
+                        date_code: Some(date_code),
+                        lot_code: None,
+                        revision: Some(String::from(revision)),
+                        origin_country: Some(origin_country),
+                        package: Some(super::Package::Sop28),
+                        voltage: Some(Voltage::range(4_500, 5_500)),
+                        density: Some(64 * KIBIT),
+                        organization: Some(Organization::new(8 * 1024, 8)),
+                        access_time: speed_grade(speed),
+                    }
                 },
             );
         alt((new_format, old_format)).parse(input)
     },
+    format: Some(&|part| {
+        let date_code = part.date_code?;
+        let PartDateCode::YearWeek {
+            year: Year::Full(year),
+            ..
+        } = date_code
+        else {
+            return None;
+        };
+        let trailer = format!(
+            "{date_code}{revision} {country}",
+            date_code = for_format::year2_week2(date_code)?,
+            revision = part.revision.as_ref()?,
+            country = part.origin_country?.label(),
+        );
+        if year < 1994 {
+            Some(format!("HYUNDAI {kind} {trailer}", kind = part.kind))
+        } else {
+            let (kind, suffix) = part.kind.split_at(part.kind.find('L')?);
+            Some(format!("{kind} {suffix} {trailer}"))
+        }
+    }),
 };
 
 fn date_code<'a, E: ParseError<&'a str>>(input: &'a str) -> IResult<&'a str, PartDateCode, E> {
@@ -130,3 +223,12 @@ impl Package {
         }
     }
 }
+
+impl From<Package> for super::Package {
+    fn from(package: Package) -> Self {
+        match package {
+            Package::Sop28 => super::Package::Sop28,
+            Package::Sop32 => super::Package::Sop32,
+        }
+    }
+}
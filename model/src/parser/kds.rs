@@ -3,13 +3,12 @@
 // SPDX-License-Identifier: MIT
 
 use nom::{
-    IResult, Parser as _, branch::alt, bytes::streaming::tag, combinator::opt, error::ParseError,
-    sequence::terminated,
+    IResult, Parser as _, branch::alt, combinator::opt, error::ParseError, sequence::terminated,
 };
 
 use super::{
-    Crystal, Manufacturer, NomParser, PartDateCode,
-    for_nom::{lines2, month1_abc, uppers, year1, year1_month1_abc, year2_week2},
+    Crystal, Manufacturer, NomParser, PartDateCode, for_format,
+    for_nom::{lines2, month1_abc, tag, uppers, year1, year1_month1_abc, year2_week2},
 };
 
 /// ```
@@ -18,15 +17,24 @@ use super::{
 /// ```
 pub static KDS_32_KIHZ: NomParser<Crystal> = NomParser {
     name: "KDS 32 KiHz",
-    f: |input| {
+    examples: &["KDS1H"],
+    prefixes: &["KDS"],
+    f: &|input| {
         (tag("KDS"), year1_month1_abc)
             .map(|(_, date_code)| Crystal {
                 manufacturer: Some(Manufacturer::Kds),
                 frequency: Crystal::FREQ_32_KIHZ,
                 date_code: Some(date_code),
+                lot_code: None,
+                revision: None,
+                origin_country: None,
             })
             .parse(input)
     },
+    format: Some(&|crystal| {
+        for_format::year1_month1(crystal.date_code?, for_format::month1_abc)
+            .map(|date_code| format!("KDS{date_code}"))
+    }),
 };
 
 /// ```
@@ -37,7 +45,9 @@ pub static KDS_32_KIHZ: NomParser<Crystal> = NomParser {
 /// ```
 pub static KDS_4_MIHZ_OLD: NomParser<Crystal> = NomParser {
     name: "KDS 4 MiHz",
-    f: |input| {
+    examples: &["KDS9807 4.194", "KDS 9803 4.194", "KDS 6F 4.194"],
+    prefixes: &["KDS"],
+    f: &|input| {
         lines2(
             terminated(tag("KDS"), opt(nom::character::complete::char(' ')))
                 .and(alt((year1_month1_abc, year2_week2))),
@@ -47,9 +57,13 @@ pub static KDS_4_MIHZ_OLD: NomParser<Crystal> = NomParser {
             manufacturer: Some(Manufacturer::Kds),
             frequency: Crystal::FREQ_4_MIHZ,
             date_code: Some(date_code),
+            lot_code: None,
+            revision: None,
+            origin_country: None,
         })
         .parse(input)
     },
+    format: None,
 };
 
 /// ```
@@ -58,15 +72,26 @@ pub static KDS_4_MIHZ_OLD: NomParser<Crystal> = NomParser {
 /// ```
 pub static KDS_4_MIHZ_NEW: NomParser<Crystal> = NomParser {
     name: "KDS 4 MiHz",
-    f: |input| {
+    examples: &["KDS 0102 4.194"],
+    prefixes: &["KDS "],
+    f: &|input| {
         lines2(tag("KDS ").and(year2_week2), tag("4.194"))
             .map(|((_, date_code), _)| Crystal {
                 manufacturer: Some(Manufacturer::Kds),
                 frequency: Crystal::FREQ_4_MIHZ,
                 date_code: Some(date_code),
+                lot_code: None,
+                revision: None,
+                origin_country: None,
             })
             .parse(input)
     },
+    format: Some(&|crystal| {
+        Some(format!(
+            "KDS {} 4.194",
+            for_format::year2_week2(crystal.date_code?)?
+        ))
+    }),
 };
 
 /// ```
@@ -75,15 +100,26 @@ pub static KDS_4_MIHZ_NEW: NomParser<Crystal> = NomParser {
 /// ```
 pub static KDS_4_MIHZ_AGS: NomParser<Crystal> = NomParser {
     name: "KDS 4 MiHz",
-    f: |input| {
+    examples: &["KDSI 0549 4.194"],
+    prefixes: &["KDSI "],
+    f: &|input| {
         lines2(tag("KDSI ").and(year2_week2), tag("4.194"))
             .map(|((_, date_code), _)| Crystal {
                 manufacturer: Some(Manufacturer::Kds),
                 frequency: Crystal::FREQ_4_MIHZ,
                 date_code: Some(date_code),
+                lot_code: None,
+                revision: None,
+                origin_country: None,
             })
             .parse(input)
     },
+    format: Some(&|crystal| {
+        Some(format!(
+            "KDSI {} 4.194",
+            for_format::year2_week2(crystal.date_code?)?
+        ))
+    }),
 };
 
 /// ```
@@ -92,15 +128,26 @@ pub static KDS_4_MIHZ_AGS: NomParser<Crystal> = NomParser {
 /// ```
 pub static KDS_8_MIHZ: NomParser<Crystal> = NomParser {
     name: "KDS 8 MiHz",
-    f: |input| {
+    examples: &["KDS 9841 8.388"],
+    prefixes: &["KDS "],
+    f: &|input| {
         lines2(tag("KDS ").and(year2_week2), tag("8.388"))
             .map(|((_, date_code), _)| Crystal {
                 manufacturer: Some(Manufacturer::Kds),
                 frequency: Crystal::FREQ_8_MIHZ,
                 date_code: Some(date_code),
+                lot_code: None,
+                revision: None,
+                origin_country: None,
             })
             .parse(input)
     },
+    format: Some(&|crystal| {
+        Some(format!(
+            "KDS {} 8.388",
+            for_format::year2_week2(crystal.date_code?)?
+        ))
+    }),
 };
 
 /// ```
@@ -109,16 +156,25 @@ pub static KDS_8_MIHZ: NomParser<Crystal> = NomParser {
 /// ```
 pub static KDS_D419_OLD: NomParser<Crystal> = NomParser {
     name: "KDS D419",
-    f: |input| {
+    examples: &["D419A2"],
+    prefixes: &["D419"],
+    f: &|input| {
         tag("D419")
             .and(month1_abc_year1)
             .map(|(_, date_code)| Crystal {
                 manufacturer: Some(Manufacturer::Kds),
                 frequency: Crystal::FREQ_4_MIHZ,
                 date_code: Some(date_code),
+                lot_code: None,
+                revision: None,
+                origin_country: None,
             })
             .parse(input)
     },
+    format: Some(&|crystal| {
+        for_format::month1_year1(crystal.date_code?, for_format::month1_abc)
+            .map(|date_code| format!("D419{date_code}"))
+    }),
 };
 
 /// ```
@@ -127,16 +183,22 @@ pub static KDS_D419_OLD: NomParser<Crystal> = NomParser {
 /// ```
 pub static KDS_D419_NEW: NomParser<Crystal> = NomParser {
     name: "KDS D419",
-    f: |input| {
+    examples: &["D419J3I"],
+    prefixes: &["D419"],
+    f: &|input| {
         tag("D419")
             .and(month1_abc_year1.and(uppers(1)))
-            .map(|(_, (date_code, _))| Crystal {
+            .map(|(_, (date_code, lot_code))| Crystal {
                 manufacturer: Some(Manufacturer::Kds),
                 frequency: Crystal::FREQ_4_MIHZ,
                 date_code: Some(date_code),
+                lot_code: Some(String::from(lot_code)),
+                revision: None,
+                origin_country: None,
             })
             .parse(input)
     },
+    format: Some(&|crystal| d_series_label("D419", crystal)),
 };
 
 /// ```
@@ -145,16 +207,22 @@ pub static KDS_D419_NEW: NomParser<Crystal> = NomParser {
 /// ```
 pub static KDS_D838: NomParser<Crystal> = NomParser {
     name: "KDS D838",
-    f: |input| {
+    examples: &["D838K0I"],
+    prefixes: &["D838"],
+    f: &|input| {
         tag("D838")
             .and(month1_abc_year1.and(uppers(1)))
-            .map(|(_, (date_code, _))| Crystal {
+            .map(|(_, (date_code, lot_code))| Crystal {
                 manufacturer: Some(Manufacturer::Kds),
                 frequency: Crystal::FREQ_8_MIHZ,
                 date_code: Some(date_code),
+                lot_code: Some(String::from(lot_code)),
+                revision: None,
+                origin_country: None,
             })
             .parse(input)
     },
+    format: Some(&|crystal| d_series_label("D838", crystal)),
 };
 
 /// ```
@@ -163,16 +231,25 @@ pub static KDS_D838: NomParser<Crystal> = NomParser {
 /// ```
 pub static KDS_D209: NomParser<Crystal> = NomParser {
     name: "KDS D209",
-    f: |input| {
+    examples: &["D209A8"],
+    prefixes: &["D209"],
+    f: &|input| {
         tag("D209")
             .and(month1_abc_year1)
             .map(|(_, date_code)| Crystal {
                 manufacturer: Some(Manufacturer::Kds),
                 frequency: Crystal::FREQ_20_MIHZ,
                 date_code: Some(date_code),
+                lot_code: None,
+                revision: None,
+                origin_country: None,
             })
             .parse(input)
     },
+    format: Some(&|crystal| {
+        for_format::month1_year1(crystal.date_code?, for_format::month1_abc)
+            .map(|date_code| format!("D209{date_code}"))
+    }),
 };
 
 fn month1_abc_year1<'a, E: ParseError<&'a str>>(
@@ -182,3 +259,11 @@ fn month1_abc_year1<'a, E: ParseError<&'a str>>(
         .map(|(month, year)| PartDateCode::YearMonth { year, month })
         .parse(input)
 }
+
+fn d_series_label(model: &str, crystal: &Crystal) -> Option<String> {
+    Some(format!(
+        "{model}{date_code}{lot_code}",
+        date_code = for_format::month1_year1(crystal.date_code?, for_format::month1_abc)?,
+        lot_code = crystal.lot_code.as_ref()?,
+    ))
+}
@@ -2,14 +2,11 @@
 //
 // SPDX-License-Identifier: MIT
 
-use nom::{
-    Parser as _, branch::alt, bytes::streaming::tag, character::streaming::char,
-    sequence::delimited,
-};
+use nom::{Parser as _, branch::alt, character::streaming::char, sequence::preceded};
 
 use super::{
-    Crystal, Manufacturer, NomParser,
-    for_nom::{lines2, uppers, year1_month1_abc},
+    Crystal, Manufacturer, NomParser, for_format,
+    for_nom::{lines2, tag, uppers, year1_month1_abc},
 };
 
 /// ```
@@ -19,24 +16,30 @@ use super::{
 /// ```
 pub static KINSEKI_4_MIHZ: NomParser<Crystal> = NomParser {
     name: "Kinseki 4 MiHz",
-    f: |input| {
+    examples: &["4194 KSS 0KF", "4194 KSS1A"],
+    prefixes: &["4194"],
+    f: &|input| {
         lines2(
             tag("4194"),
             (
                 tag("KSS"),
                 alt((
-                    delimited(char(' '), year1_month1_abc, uppers(1)),
-                    year1_month1_abc,
+                    preceded(char(' '), year1_month1_abc).and(uppers(1).map(Some)),
+                    year1_month1_abc.map(|date_code| (date_code, None)),
                 )),
             ),
         )
-        .map(|(_, (_, date_code))| Crystal {
+        .map(|(_, (_, (date_code, lot_code)))| Crystal {
             manufacturer: Some(Manufacturer::Kinseki),
             frequency: Crystal::FREQ_4_MIHZ,
             date_code: Some(date_code),
+            lot_code: lot_code.map(String::from),
+            revision: None,
+            origin_country: None,
         })
         .parse(input)
     },
+    format: Some(&|crystal| kss_label("4194", crystal)),
 };
 
 /// ```
@@ -46,42 +49,65 @@ pub static KINSEKI_4_MIHZ: NomParser<Crystal> = NomParser {
 /// ```
 pub static KINSEKI_8_MIHZ: NomParser<Crystal> = NomParser {
     name: "Kinseki 8 MiHz",
-    f: |input| {
+    examples: &["8388 KSS 1CF", "8388 KSS9J"],
+    prefixes: &["8388"],
+    f: &|input| {
         lines2(
             tag("8388"),
             (
                 tag("KSS"),
                 alt((
-                    delimited(char(' '), year1_month1_abc, uppers(1)),
-                    year1_month1_abc,
+                    preceded(char(' '), year1_month1_abc).and(uppers(1).map(Some)),
+                    year1_month1_abc.map(|date_code| (date_code, None)),
                 )),
             ),
         )
-        .map(|(_, (_, date_code))| Crystal {
+        .map(|(_, (_, (date_code, lot_code)))| Crystal {
             manufacturer: Some(Manufacturer::Kinseki),
             frequency: Crystal::FREQ_8_MIHZ,
             date_code: Some(date_code),
+            lot_code: lot_code.map(String::from),
+            revision: None,
+            origin_country: None,
         })
         .parse(input)
     },
+    format: Some(&|crystal| kss_label("8388", crystal)),
 };
 
+fn kss_label(frequency: &str, crystal: &Crystal) -> Option<String> {
+    let date_code = for_format::year1_month1(crystal.date_code?, for_format::month1_abc)?;
+    match &crystal.lot_code {
+        Some(lot_code) => Some(format!("{frequency} KSS {date_code}{lot_code}")),
+        None => Some(format!("{frequency} KSS{date_code}")),
+    }
+}
+
 /// ```
 /// use gbhwdb_model::parser::{self, LabelParser};
 /// assert!(parser::kinseki::KINSEKI_20_MIHZ.parse("KSS20V 8A").is_ok());
 /// ```
 pub static KINSEKI_20_MIHZ: NomParser<Crystal> = NomParser {
     name: "Kinseki 20 MiHz",
-    f: |input| {
+    examples: &["KSS20V 8A"],
+    prefixes: &["KSS20V "],
+    f: &|input| {
         tag("KSS20V ")
             .and(year1_month1_abc)
             .map(|(_, date_code)| Crystal {
                 manufacturer: Some(Manufacturer::Kinseki),
                 frequency: Crystal::FREQ_20_MIHZ,
                 date_code: Some(date_code),
+                lot_code: None,
+                revision: None,
+                origin_country: None,
             })
             .parse(input)
     },
+    format: Some(&|crystal| {
+        for_format::year1_month1(crystal.date_code?, for_format::month1_abc)
+            .map(|date_code| format!("KSS20V {date_code}"))
+    }),
 };
 
 /// ```
@@ -90,14 +116,23 @@ pub static KINSEKI_20_MIHZ: NomParser<Crystal> = NomParser {
 /// ```
 pub static KINSEKI_32_MIHZ: NomParser<Crystal> = NomParser {
     name: "Kinseki 32 MiHz",
-    f: |input| {
+    examples: &["33WKSS6DT"],
+    prefixes: &["33WKSS"],
+    f: &|input| {
         tag("33WKSS")
             .and(year1_month1_abc.and(char('T')))
             .map(|(_, (date_code, _))| Crystal {
                 manufacturer: Some(Manufacturer::Kinseki),
                 frequency: Crystal::FREQ_32_MIHZ,
                 date_code: Some(date_code),
+                lot_code: None,
+                revision: None,
+                origin_country: None,
             })
             .parse(input)
     },
+    format: Some(&|crystal| {
+        for_format::year1_month1(crystal.date_code?, for_format::month1_abc)
+            .map(|date_code| format!("33WKSS{date_code}T"))
+    }),
 };
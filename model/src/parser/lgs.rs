@@ -5,12 +5,16 @@
 use nom::{
     Parser as _,
     branch::alt,
-    bytes::streaming::tag,
     character::streaming::{char, one_of},
     combinator::{opt, recognize},
+    sequence::preceded,
 };
 
-use super::{GenericPart, Manufacturer, NomParser, for_nom::year2_week2};
+use super::{
+    Country, GenericPart, KIBIT, Manufacturer, NomParser, Organization, Voltage, for_format,
+    for_nom::{country, tag, year2_week2},
+    speed_grade,
+};
 
 /// LGS GM76C256 SRAM (SOP-28, 5V, 256 Kibit / 32 KiB)
 ///
@@ -20,7 +24,9 @@ use super::{GenericPart, Manufacturer, NomParser, for_nom::year2_week2};
 /// ```
 pub static LGS_GM76C256: NomParser<GenericPart> = NomParser {
     name: "LGS GM76C256",
-    f: |input| {
+    examples: &["LGS GM76C256CLLFW70 0047 KOREA"],
+    prefixes: &["LGS "],
+    f: &|input| {
         let package = Package::Sop;
         (
             tag("LGS "),
@@ -32,17 +38,26 @@ pub static LGS_GM76C256: NomParser<GenericPart> = NomParser {
             ),
             char(' '),
             year2_week2,
-            tag(" KOREA"),
+            preceded(char(' '), country(Country::Korea)),
         )
             .map(
-                |(_, (kind, power, package, speed), _, date_code, _)| GenericPart {
+                |(_, (kind, power, package, speed), _, date_code, origin_country)| GenericPart {
                     kind: format!("{kind}{power}{package}{speed}"),
                     manufacturer: Some(Manufacturer::Lgs),
                     date_code: Some(date_code),
+                    lot_code: None,
+                    revision: None,
+                    origin_country: Some(origin_country),
+                    package: Some(Package::Sop.into()),
+                    voltage: Some(Voltage::V5),
+                    density: Some(256 * KIBIT),
+                    organization: Some(Organization::new(32 * 1024, 8)),
+                    access_time: speed_grade(speed),
                 },
             )
             .parse(input)
     },
+    format: Some(&|part| gm76_label("LGS", part)),
 };
 
 /// Hyundai GM76C256 SRAM (SOP-28, 5V, 256 Kibit / 32 KiB)
@@ -55,7 +70,9 @@ pub static LGS_GM76C256: NomParser<GenericPart> = NomParser {
 /// ```
 pub static HYUNDAI_GM76C256: NomParser<GenericPart> = NomParser {
     name: "Hyundai GM76C256",
-    f: |input| {
+    examples: &["HYUNDAI GM76C256CLLFW70 0047 KOREA"],
+    prefixes: &["HYUNDAI "],
+    f: &|input| {
         let package = Package::Sop;
         (
             tag("HYUNDAI "),
@@ -67,17 +84,26 @@ pub static HYUNDAI_GM76C256: NomParser<GenericPart> = NomParser {
             ),
             char(' '),
             year2_week2,
-            tag(" KOREA"),
+            preceded(char(' '), country(Country::Korea)),
         )
             .map(
-                |(_, (kind, power, package, speed), _, date_code, _)| GenericPart {
+                |(_, (kind, power, package, speed), _, date_code, origin_country)| GenericPart {
                     kind: format!("{kind}{power}{package}{speed}"),
                     manufacturer: Some(Manufacturer::Hyundai),
                     date_code: Some(date_code),
+                    lot_code: None,
+                    revision: None,
+                    origin_country: Some(origin_country),
+                    package: Some(Package::Sop.into()),
+                    voltage: Some(Voltage::V5),
+                    density: Some(256 * KIBIT),
+                    organization: Some(Organization::new(32 * 1024, 8)),
+                    access_time: speed_grade(speed),
                 },
             )
             .parse(input)
     },
+    format: Some(&|part| gm76_label("HYUNDAI", part)),
 };
 
 /// Hyundai GM76V256 SRAM (SOP-28, 2.5-3.6V, 256 Kibit / 32 KiB)
@@ -90,7 +116,9 @@ pub static HYUNDAI_GM76C256: NomParser<GenericPart> = NomParser {
 /// ```
 pub static HYUNDAI_GM76V256: NomParser<GenericPart> = NomParser {
     name: "Hyundai GM76V256",
-    f: |input| {
+    examples: &["HYUNDAI GM76V256CLLFW10 0115 KOREA"],
+    prefixes: &["HYUNDAI "],
+    f: &|input| {
         let package = Package::Sop;
         (
             tag("HYUNDAI "),
@@ -102,19 +130,37 @@ pub static HYUNDAI_GM76V256: NomParser<GenericPart> = NomParser {
             ),
             char(' '),
             year2_week2,
-            tag(" KOREA"),
+            preceded(char(' '), country(Country::Korea)),
         )
             .map(
-                |(_, (kind, power, package, speed), _, date_code, _)| GenericPart {
+                |(_, (kind, power, package, speed), _, date_code, origin_country)| GenericPart {
                     kind: format!("{kind}{power}{package}{speed}"),
                     manufacturer: Some(Manufacturer::Hyundai),
                     date_code: Some(date_code),
+                    lot_code: None,
+                    revision: None,
+                    origin_country: Some(origin_country),
+                    package: Some(Package::Sop.into()),
+                    voltage: Some(Voltage::range(2_500, 3_600)),
+                    density: Some(256 * KIBIT),
+                    organization: Some(Organization::new(32 * 1024, 8)),
+                    access_time: speed_grade(speed),
                 },
             )
             .parse(input)
     },
+    format: Some(&|part| gm76_label("HYUNDAI", part)),
 };
 
+fn gm76_label(manufacturer: &str, part: &GenericPart) -> Option<String> {
+    Some(format!(
+        "{manufacturer} {kind} {date_code} {country}",
+        kind = part.kind,
+        date_code = for_format::year2_week2(part.date_code?)?,
+        country = part.origin_country?.label(),
+    ))
+}
+
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 enum Package {
     Sop,
@@ -127,3 +173,11 @@ impl Package {
         }
     }
 }
+
+impl From<Package> for super::Package {
+    fn from(package: Package) -> Self {
+        match package {
+            Package::Sop => super::Package::Sop28,
+        }
+    }
+}
@@ -2,53 +2,97 @@
 //
 // SPDX-License-Identifier: MIT
 
-use nom::{Parser, bytes::streaming::tag, combinator::opt, error::ParseError, sequence::delimited};
+use nom::{
+    Parser,
+    combinator::{opt, recognize},
+    error::ParseError,
+    sequence::{preceded, separated_pair},
+};
 
 use super::{
-    GenericPart, Manufacturer, NomParser, PartDateCode,
-    for_nom::{alnum_uppers, digits, lines4, uppers, week2, year1},
+    Country, GenericPart, KIBIT, Manufacturer, NomParser, Organization, Package, PartDateCode,
+    Voltage, dash_speed_grade, for_format,
+    for_nom::{alnum_uppers, country, digits, lines4, tag, uppers, week2, year1},
 };
 
 fn lh51_sop28<'a, E: ParseError<&'a str>>(
     kind: &'static str,
+    density: u32,
 ) -> impl Parser<&'a str, Output = GenericPart, Error = E> {
     lines4(
         tag(kind),
         tag("LSI LOGIC"),
-        tag("JAPAN"),
-        delimited(
+        country(Country::Japan),
+        preceded(
             tag("D"),
-            (year1, opt(tag(" ")), week2)
-                .map(|(year, _, week)| PartDateCode::YearWeek { year, week }),
-            (tag(" "), digits(1), tag(" "), uppers(2)),
+            separated_pair(
+                (year1, opt(tag(" ")), week2)
+                    .map(|(year, _, week)| PartDateCode::YearWeek { year, week }),
+                tag(" "),
+                recognize((digits(1), tag(" "), uppers(2))),
+            ),
         ),
     )
-    .map(|(kind, _, _, date_code)| GenericPart {
-        kind: String::from(kind),
-        manufacturer: Some(Manufacturer::LsiLogic),
-        date_code: Some(date_code),
-    })
+    .map(
+        move |(kind, _, origin_country, (date_code, lot_code))| GenericPart {
+            kind: String::from(kind),
+            manufacturer: Some(Manufacturer::LsiLogic),
+            date_code: Some(date_code),
+            lot_code: Some(String::from(lot_code)),
+            revision: None,
+            origin_country: Some(origin_country),
+            package: Some(Package::Sop28),
+            voltage: Some(Voltage::V5),
+            density: Some(density),
+            organization: Some(Organization::new(density / 8, 8)),
+            access_time: dash_speed_grade(kind),
+        },
+    )
 }
 
 fn lh52_sop28<'a, E: ParseError<&'a str>>(
     kind: &'static str,
+    density: u32,
 ) -> impl Parser<&'a str, Output = GenericPart, Error = E> {
     lines4(
         tag(kind),
         tag("LSI LOGIC"),
-        tag("JAPAN"),
-        delimited(
+        country(Country::Japan),
+        preceded(
             tag("D"),
-            (year1, opt(tag(" ")), week2)
-                .map(|(year, _, week)| PartDateCode::YearWeek { year, week }),
-            (tag(" "), digits(1), alnum_uppers(1), tag(" "), uppers(1)),
+            separated_pair(
+                (year1, opt(tag(" ")), week2)
+                    .map(|(year, _, week)| PartDateCode::YearWeek { year, week }),
+                tag(" "),
+                recognize((digits(1), alnum_uppers(1), tag(" "), uppers(1))),
+            ),
         ),
     )
-    .map(|(kind, _, _, date_code)| GenericPart {
-        kind: String::from(kind),
-        manufacturer: Some(Manufacturer::LsiLogic),
-        date_code: Some(date_code),
-    })
+    .map(
+        move |(kind, _, origin_country, (date_code, lot_code))| GenericPart {
+            kind: String::from(kind),
+            manufacturer: Some(Manufacturer::LsiLogic),
+            date_code: Some(date_code),
+            lot_code: Some(String::from(lot_code)),
+            revision: None,
+            origin_country: Some(origin_country),
+            package: Some(Package::Sop28),
+            voltage: Some(Voltage::V5),
+            density: Some(density),
+            organization: Some(Organization::new(density / 8, 8)),
+            access_time: dash_speed_grade(kind),
+        },
+    )
+}
+
+fn lsi_logic_label(part: &GenericPart) -> Option<String> {
+    Some(format!(
+        "{kind} LSI LOGIC {country} D{date_code} {lot_code}",
+        kind = part.kind,
+        country = part.origin_country?.label(),
+        date_code = for_format::year1_week2(part.date_code?)?,
+        lot_code = part.lot_code.as_ref()?,
+    ))
 }
 
 /// LSI Logic LH5264N4T SRAM (SOP-28, 5V)
@@ -61,7 +105,10 @@ fn lh52_sop28<'a, E: ParseError<&'a str>>(
 /// ```
 pub static LSI_LOGIC_LH5264N4T: NomParser<GenericPart> = NomParser {
     name: "LSI Logic LH5264N4T",
-    f: |input| lh52_sop28("LH5264N4T").parse(input),
+    examples: &["LH5264N4T LSI LOGIC JAPAN D222 24 C"],
+    prefixes: &["LH5264N4T"],
+    f: &|input| lh52_sop28("LH5264N4T", 64 * KIBIT).parse(input),
+    format: Some(&lsi_logic_label),
 };
 
 /// LSI Logic LH5264TN SRAM (SOP-28, 5V)
@@ -74,7 +121,10 @@ pub static LSI_LOGIC_LH5264N4T: NomParser<GenericPart> = NomParser {
 /// ```
 pub static LSI_LOGIC_LH5264TN: NomParser<GenericPart> = NomParser {
     name: "LSI Logic LH5264TN",
-    f: |input| lh52_sop28("LH5264TN-TL").parse(input),
+    examples: &["LH5264TN-TL LSI LOGIC JAPAN D220 53 C"],
+    prefixes: &["LH5264TN-TL"],
+    f: &|input| lh52_sop28("LH5264TN-TL", 64 * KIBIT).parse(input),
+    format: Some(&lsi_logic_label),
 };
 
 /// LSI Logic LH52A64N SRAM (SOP-28, 5V, 64 Kibit / 8 KiB)
@@ -88,7 +138,13 @@ pub static LSI_LOGIC_LH5264TN: NomParser<GenericPart> = NomParser {
 /// ```
 pub static LSI_LOGIC_LH52A64N: NomParser<GenericPart> = NomParser {
     name: "LSI Logic LH52A64N",
-    f: |input| lh52_sop28("LH52A64N-TL").parse(input),
+    examples: &[
+        "LH52A64N-TL LSI LOGIC JAPAN D404 0U C",
+        "LH52A64N-TL LSI LOGIC JAPAN D4 06 05 C",
+    ],
+    prefixes: &["LH52A64N-TL"],
+    f: &|input| lh52_sop28("LH52A64N-TL", 64 * KIBIT).parse(input),
+    format: None,
 };
 
 /// LSI Logic LH52B256N SRAM (SOP-28, 5V, 256 Kibit / 32 KiB)
@@ -101,7 +157,10 @@ pub static LSI_LOGIC_LH52A64N: NomParser<GenericPart> = NomParser {
 /// ```
 pub static LSI_LOGIC_LH52B256N: NomParser<GenericPart> = NomParser {
     name: "LSI Logic LH52B256N",
-    f: |input| lh52_sop28("LH52B256NA-10TLL").parse(input),
+    examples: &["LH52B256NA-10TLL LSI LOGIC JAPAN D344 03 B"],
+    prefixes: &["LH52B256NA-10TLL"],
+    f: &|input| lh52_sop28("LH52B256NA-10TLL", 256 * KIBIT).parse(input),
+    format: Some(&lsi_logic_label),
 };
 
 /// LSI Logic LH5168N SRAM (SOP-28, 5V, 64 Kibit / 8 KiB)
@@ -114,5 +173,8 @@ pub static LSI_LOGIC_LH52B256N: NomParser<GenericPart> = NomParser {
 /// ```
 pub static LSI_LOGIC_LH5168N: NomParser<GenericPart> = NomParser {
     name: "LSI Logic LH5168N",
-    f: |input| lh51_sop28("LH5168NFB-10TL").parse(input),
+    examples: &["LH5168NFB-10TL LSI LOGIC JAPAN D242 7 BC"],
+    prefixes: &["LH5168NFB-10TL"],
+    f: &|input| lh51_sop28("LH5168NFB-10TL", 64 * KIBIT).parse(input),
+    format: Some(&lsi_logic_label),
 };
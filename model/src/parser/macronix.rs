@@ -5,15 +5,18 @@
 use nom::{
     IResult, Parser,
     branch::alt,
-    bytes::streaming::tag,
     character::streaming::{char, satisfy},
     combinator::{opt, recognize},
     error::ParseError,
+    sequence::preceded,
 };
 
 use super::{
-    GameMaskRom, GameRomType, GenericPart, Manufacturer, NomParser, PartDateCode,
-    for_nom::{self, agb_rom_code, alnum_uppers, cgb_rom_code, digits, dmg_rom_code, uppers},
+    Country, GameMaskRom, GameRomType, GenericPart, MIBIT, Manufacturer, NomParser, Organization,
+    Package, PartDateCode, Voltage, dash_speed_grade, for_format,
+    for_nom::{
+        self, agb_rom_code, alnum_uppers, cgb_rom_code, country, digits, dmg_rom_code, tag, uppers,
+    },
 };
 
 /// Macronix MX29F008 flash (TSOP-I-40, 4.5-5.5V)
@@ -24,7 +27,9 @@ use super::{
 /// ```
 pub static MACRONIX_MX29F008: NomParser<GenericPart> = NomParser {
     name: "Macronix MX29F008",
-    f: |input| {
+    examples: &["E991012 29F008TC-14 21534 TAIWAN"],
+    prefixes: &[],
+    f: &|input| {
         (
             (
                 assembly_vendor_code,
@@ -35,15 +40,33 @@ pub static MACRONIX_MX29F008: NomParser<GenericPart> = NomParser {
             tag("29F008TC-14"),
             char(' '),
             lot_code_old,
-            tag(" TAIWAN"),
+            preceded(char(' '), country(Country::Taiwan)),
         )
-            .map(|((_, date_code, _), _, kind, _, _, _)| GenericPart {
-                kind: format!("MX{kind}"),
-                manufacturer: Some(Manufacturer::Macronix),
-                date_code: Some(date_code),
-            })
+            .map(
+                |((vendor, date_code, body), _, kind, _, lot_code, origin_country)| GenericPart {
+                    kind: format!("MX{kind}"),
+                    manufacturer: Some(Manufacturer::Macronix),
+                    date_code: Some(date_code),
+                    lot_code: Some(format!("{vendor}{body} {lot_code}")),
+                    revision: None,
+                    origin_country: Some(origin_country),
+                    package: Some(Package::TsopI40),
+                    voltage: Some(Voltage::range(4_500, 5_500)),
+                    density: Some(8 * MIBIT),
+                    organization: Some(Organization::new(1024 * 1024, 8)),
+                    access_time: dash_speed_grade(kind),
+                },
+            )
             .parse(input)
     },
+    format: Some(&|part| {
+        let (trace_code, lot_code) = trace_code_label(part.date_code, part.lot_code.as_deref())?;
+        Some(format!(
+            "{trace_code} {kind} {lot_code} {country}",
+            kind = part.kind.strip_prefix("MX")?,
+            country = part.origin_country?.label(),
+        ))
+    }),
 };
 
 /// Macronix MX29L010 flash (TSOP-I-32, 3.3V)
@@ -57,26 +80,49 @@ pub static MACRONIX_MX29F008: NomParser<GenericPart> = NomParser {
 /// ```
 pub static MACRONIX_MX29L010: NomParser<GenericPart> = NomParser {
     name: "Macronix MX29L010",
-    f: |input| {
+    examples: &[
+        "B063857G MX29L010TC-15A1 1H4751",
+        "E032457 MX29L010TC-15A1 1E8980",
+        "E023057 MX29L010TC-15 1E0290",
+        "E040257 MX29L010TC-15A1 1F468900A0",
+    ],
+    prefixes: &[],
+    f: &|input| {
         (
             (
                 assembly_vendor_code,
                 date_code,
-                tag("57"),     // digits 3 and 4 of "product body" (?)
-                opt(tag("G")), // green package?
+                recognize((
+                    tag("57"),     // digits 3 and 4 of "product body" (?)
+                    opt(tag("G")), // green package?
+                )),
             ),
             char(' '),
             alt((tag("MX29L010TC-15A1"), tag("MX29L010TC-15"))),
             char(' '),
             lot_code_new,
         )
-            .map(|((_, date_code, _, _), _, kind, _, _)| GenericPart {
-                kind: String::from(kind),
-                manufacturer: Some(Manufacturer::Macronix),
-                date_code: Some(date_code),
-            })
+            .map(
+                |((vendor, date_code, body), _, kind, _, lot_code)| GenericPart {
+                    kind: String::from(kind),
+                    manufacturer: Some(Manufacturer::Macronix),
+                    date_code: Some(date_code),
+                    lot_code: Some(format!("{vendor}{body} {lot_code}")),
+                    revision: None,
+                    origin_country: None,
+                    package: Some(Package::TsopI32),
+                    voltage: Some(Voltage::V3_3),
+                    density: Some(MIBIT),
+                    organization: Some(Organization::new(128 * 1024, 8)),
+                    access_time: dash_speed_grade(kind),
+                },
+            )
             .parse(input)
     },
+    format: Some(&|part| {
+        let (trace_code, lot_code) = trace_code_label(part.date_code, part.lot_code.as_deref())?;
+        Some(format!("{trace_code} {kind} {lot_code}", kind = part.kind))
+    }),
 };
 
 fn agb_mx23l<'a, E: ParseError<&'a str>>(
@@ -87,9 +133,11 @@ fn agb_mx23l<'a, E: ParseError<&'a str>>(
     (
         assembly_vendor_code,
         date_code,
-        tag(product_body),
-        tag("-M"),
-        opt(tag("G")), // green package?
+        recognize((
+            tag(product_body),
+            tag("-M"),
+            opt(tag("G")), // green package?
+        )),
         char(' '),
         tag(chip_type),
         char(' '),
@@ -100,13 +148,21 @@ fn agb_mx23l<'a, E: ParseError<&'a str>>(
         lot_code_new,
     )
         .map(
-            move |(_, date_code, _, _, _, _, kind, _, rom_id, _, _, _, _)| GameMaskRom {
+            move |(vendor, date_code, body, _, kind, _, rom_id, _, _, _, lot_code)| GameMaskRom {
                 rom_id: String::from(rom_id),
                 rom_type,
                 manufacturer: Some(Manufacturer::Macronix),
                 chip_type: Some(String::from(kind)),
                 mask_code: None,
                 date_code: Some(date_code),
+                lot_code: Some(format!("{vendor}{body} {lot_code}")),
+                revision: None,
+                origin_country: None,
+                package: Some(rom_type.package()),
+                voltage: Some(Voltage::V3_3),
+                density: rom_type.density(),
+                organization: None,
+                access_time: None,
             },
         )
 }
@@ -119,7 +175,10 @@ fn agb_mx23l<'a, E: ParseError<&'a str>>(
 /// ```
 pub static MACRONIX_MX23L8006: NomParser<GameMaskRom> = NomParser {
     name: "Macronix MX23L8006",
-    f: |input| agb_mx23l("MX23L8006-12B", "21", GameRomType::F2).parse(input),
+    examples: &["M042021-M MX23L8006-12B AGB-FBMP-0 F2 2K151900"],
+    prefixes: &[],
+    f: &|input| agb_mx23l("MX23L8006-12B", "21", GameRomType::F2).parse(input),
+    format: Some(&mx23_label),
 };
 
 /// Macronix MX23L3206 AGB mask ROM (TSOP-II-44, 3.3V, 4 MiB)
@@ -131,7 +190,13 @@ pub static MACRONIX_MX23L8006: NomParser<GameMaskRom> = NomParser {
 /// ```
 pub static MACRONIX_MX23L3206: NomParser<GameMaskRom> = NomParser {
     name: "Macronix MX23L3206",
-    f: |input| agb_mx23l("MX23L3206-12B", "21", GameRomType::H2).parse(input),
+    examples: &[
+        "M043821-M MX23L3206-12B AGB-BP9E-0 H2 2K194300",
+        "S064421-MG MX23L3206-12B AGB-BG7E-0 H2 2T341304",
+    ],
+    prefixes: &[],
+    f: &|input| agb_mx23l("MX23L3206-12B", "21", GameRomType::H2).parse(input),
+    format: Some(&mx23_label),
 };
 
 /// Macronix MX23L3406 AGB mask ROM (TSOP-II-44, 3.3V, 8 MiB)
@@ -142,7 +207,10 @@ pub static MACRONIX_MX23L3206: NomParser<GameMaskRom> = NomParser {
 /// ```
 pub static MACRONIX_MX23L3406: NomParser<GameMaskRom> = NomParser {
     name: "Macronix MX23L3406",
-    f: |input| agb_mx23l("MX23L3406-12C", "46", GameRomType::I2).parse(input),
+    examples: &["S035046-M MX23L3406-12C AGB-BBRX-0 I2 2I904402"],
+    prefixes: &[],
+    f: &|input| agb_mx23l("MX23L3406-12C", "46", GameRomType::I2).parse(input),
+    format: Some(&mx23_label),
 };
 
 /// Macronix MX23L6406 AGB mask ROM (TSOP-II-44, 3.3V, 8 MiB)
@@ -154,7 +222,12 @@ pub static MACRONIX_MX23L3406: NomParser<GameMaskRom> = NomParser {
 /// ```
 pub static MACRONIX_MX23L6406: NomParser<GameMaskRom> = NomParser {
     name: "Macronix MX23L6406",
-    f: |input| {
+    examples: &[
+        "M022807-M MX23L6406-12B1 AGB-AGSF-0 I2 2E825103",
+        "S051746-MG MX23L6406-12C AGB-BRKP-0 I2 2L261801",
+    ],
+    prefixes: &[],
+    f: &|input| {
         alt((
             agb_mx23l("MX23L6406-12B", "07", GameRomType::I2),
             agb_mx23l("MX23L6406-12B1", "07", GameRomType::I2),
@@ -162,6 +235,7 @@ pub static MACRONIX_MX23L6406: NomParser<GameMaskRom> = NomParser {
         ))
         .parse(input)
     },
+    format: Some(&mx23_label),
 };
 
 /// Macronix MX23L6407 AGB mask ROM (TSOP-II-44, 3.3V, 8 MiB)
@@ -173,13 +247,19 @@ pub static MACRONIX_MX23L6406: NomParser<GameMaskRom> = NomParser {
 /// ```
 pub static MACRONIX_MX23L6407: NomParser<GameMaskRom> = NomParser {
     name: "Macronix MX23L6407",
-    f: |input| {
+    examples: &[
+        "S024358-M MX23L6407-12C AGB-AXPJ-0 I2 2G447800",
+        "M053257-MG MX23L6407-12C1 AGB-KYGP-0 I2 2M219701A1",
+    ],
+    prefixes: &[],
+    f: &|input| {
         alt((
             agb_mx23l("MX23L6407-12C", "58", GameRomType::I2),
             agb_mx23l("MX23L6407-12C1", "57", GameRomType::I2),
         ))
         .parse(input)
     },
+    format: Some(&mx23_label),
 };
 
 /// Macronix MX23L12806 AGB mask ROM (TSOP-II-44, 3.3V, 16 MiB)
@@ -191,7 +271,13 @@ pub static MACRONIX_MX23L6407: NomParser<GameMaskRom> = NomParser {
 /// ```
 pub static MACRONIX_MX23L12806: NomParser<GameMaskRom> = NomParser {
     name: "Macronix MX23L12806",
-    f: |input| agb_mx23l("MX23L12806-12C", "38", GameRomType::J2).parse(input),
+    examples: &[
+        "E033938-M MX23L12806-12C AGB-BPPP-0 J2 2F478700",
+        "S052638-MG MX23L12806-12C AGB-BPRS-0 J2 2M396503A1",
+    ],
+    prefixes: &[],
+    f: &|input| agb_mx23l("MX23L12806-12C", "38", GameRomType::J2).parse(input),
+    format: Some(&mx23_label),
 };
 
 /// Macronix MX23L12807 AGB mask ROM (TSOP-II-44, 3.3V, 16 MiB)
@@ -203,7 +289,13 @@ pub static MACRONIX_MX23L12806: NomParser<GameMaskRom> = NomParser {
 /// ```
 pub static MACRONIX_MX23L12807: NomParser<GameMaskRom> = NomParser {
     name: "Macronix MX23L12807",
-    f: |input| agb_mx23l("MX23L12807-12C", "58", GameRomType::J2).parse(input),
+    examples: &[
+        "E055058-MG MX23L12807-12C AGB-BPES-0 J2 2N422000A1",
+        "N032358-M MX23L12807-12C AGB-AXVS-0 J2 2H552600",
+    ],
+    prefixes: &[],
+    f: &|input| agb_mx23l("MX23L12807-12C", "58", GameRomType::J2).parse(input),
+    format: Some(&mx23_label),
 };
 
 /// Macronix MX23L25607 AGB mask ROM (TSOP-II-44, 3.3V, 32 MiB)
@@ -215,13 +307,19 @@ pub static MACRONIX_MX23L12807: NomParser<GameMaskRom> = NomParser {
 /// ```
 pub static MACRONIX_MX23L25607: NomParser<GameMaskRom> = NomParser {
     name: "Macronix MX23L25607",
-    f: |input| {
+    examples: &[
+        "E053953-MG MX23L25607-12D1 AGB-BE8P-0 K2 2N007800",
+        "M064053-MG MX23L25607-12D2 AGB-BH3E-0 K2 2T151000",
+    ],
+    prefixes: &[],
+    f: &|input| {
         alt((
             agb_mx23l("MX23L25607-12D1", "53", GameRomType::K2),
             agb_mx23l("MX23L25607-12D2", "53", GameRomType::K2),
         ))
         .parse(input)
     },
+    format: Some(&mx23_label),
 };
 
 fn dmg_mx23c_old<'a, E: ParseError<&'a str>>(
@@ -239,17 +337,24 @@ fn dmg_mx23c_old<'a, E: ParseError<&'a str>>(
         char(' '),
         tag(rom_type.as_str()),
         char(' '),
-        lot_code_old,
-        uppers(1),
+        recognize(lot_code_old.and(uppers(1))),
     )
         .map(
-            move |(_, date_code, _, _, kind, _, rom_id, _, _, _, _, _)| GameMaskRom {
+            move |(vendor, date_code, suffix, _, kind, _, rom_id, _, _, _, lot_code)| GameMaskRom {
                 rom_id: String::from(rom_id),
                 rom_type,
                 manufacturer: Some(Manufacturer::Macronix),
                 chip_type: Some(String::from(kind)),
                 mask_code: None,
                 date_code: Some(date_code),
+                lot_code: Some(format!("{vendor}{suffix} {lot_code}")),
+                revision: None,
+                origin_country: None,
+                package: Some(rom_type.package()),
+                voltage: Some(Voltage::V5),
+                density: rom_type.density(),
+                organization: None,
+                access_time: None,
             },
         )
 }
@@ -262,27 +367,42 @@ fn gb_mx23c<'a, E: ParseError<&'a str>>(
     (
         assembly_vendor_code,
         date_code,
-        tag(product_body),
-        tag("-M"),
-        opt(tag("G")), // green package?
+        recognize((
+            tag(product_body),
+            tag("-M"),
+            opt(tag("G")), // green package?
+        )),
         char(' '),
         tag(chip_type),
         char(' '),
         alt((dmg_rom_code(), cgb_rom_code())),
-        opt((char(' '), digits(2))),
+        opt(preceded(char(' '), digits(2))),
         char(' '),
         tag(rom_type.as_str()),
         char(' '),
         lot_code_new,
     )
         .map(
-            move |(_, date_code, _, _, _, _, kind, _, rom_id, _, _, _, _, _)| GameMaskRom {
-                rom_id: String::from(rom_id),
-                rom_type,
-                manufacturer: Some(Manufacturer::Macronix),
-                chip_type: Some(String::from(kind)),
-                mask_code: None,
-                date_code: Some(date_code),
+            move |(vendor, date_code, body, _, kind, _, rom_id, extra, _, _, _, lot_code)| {
+                GameMaskRom {
+                    rom_id: String::from(rom_id),
+                    rom_type,
+                    manufacturer: Some(Manufacturer::Macronix),
+                    chip_type: Some(String::from(kind)),
+                    mask_code: None,
+                    date_code: Some(date_code),
+                    lot_code: Some(match extra {
+                        Some(extra) => format!("{vendor}{body} {extra} {lot_code}"),
+                        None => format!("{vendor}{body} {lot_code}"),
+                    }),
+                    revision: None,
+                    origin_country: None,
+                    package: Some(rom_type.package()),
+                    voltage: Some(Voltage::V5),
+                    density: rom_type.density(),
+                    organization: None,
+                    access_time: None,
+                }
             },
         )
 }
@@ -296,13 +416,19 @@ fn gb_mx23c<'a, E: ParseError<&'a str>>(
 /// ```
 pub static MACRONIX_MX23C4002: NomParser<GameMaskRom> = NomParser {
     name: "Macronix MX23C4002",
-    f: |input| {
+    examples: &[
+        "J9720-M MX23C4002-20 DMG-ATAJ-0 E1 43282F",
+        "C983938-M MX23C4002-20 DMG-AD3E-1 E1 1P0221Y3",
+    ],
+    prefixes: &[],
+    f: &|input| {
         alt((
             dmg_mx23c_old("MX23C4002-20", GameRomType::E1),
             gb_mx23c("MX23C4002-20", "38", GameRomType::E1),
         ))
         .parse(input)
     },
+    format: Some(&mx23_label),
 };
 
 /// Macronix MX23C8003 mask ROM (SOP-32, 4.5-5.5V, 8 Mibit / 1 MiB)
@@ -313,7 +439,10 @@ pub static MACRONIX_MX23C4002: NomParser<GameMaskRom> = NomParser {
 /// ```
 pub static MACRONIX_MX23C8003: NomParser<GameMaskRom> = NomParser {
     name: "Macronix MX23C8003",
-    f: |input| gb_mx23c("MX23C8003-20", "49", GameRomType::F1).parse(input),
+    examples: &["S010649-M MX23C8003-20 DMG-BMAP-0 F1 1C3876A1"],
+    prefixes: &[],
+    f: &|input| gb_mx23c("MX23C8003-20", "49", GameRomType::F1).parse(input),
+    format: Some(&mx23_label),
 };
 
 /// Macronix MX23C8005 mask ROM (SOP-32, 4.5-5.5V, 8 Mibit / 1 MiB)
@@ -324,7 +453,10 @@ pub static MACRONIX_MX23C8003: NomParser<GameMaskRom> = NomParser {
 /// ```
 pub static MACRONIX_MX23C8005: NomParser<GameMaskRom> = NomParser {
     name: "Macronix MX23C8005",
-    f: |input| gb_mx23c("MX23C8005-12", "49", GameRomType::F1).parse(input),
+    examples: &["C010649-M MX23C8005-12 CGB-BHFE-0 F1 1C5450LB"],
+    prefixes: &[],
+    f: &|input| gb_mx23c("MX23C8005-12", "49", GameRomType::F1).parse(input),
+    format: Some(&mx23_label),
 };
 
 /// Macronix MX23C8006 mask ROM (TSOP-I-32, 4.5-5.5V, 8 Mibit / 1 MiB)
@@ -335,7 +467,10 @@ pub static MACRONIX_MX23C8005: NomParser<GameMaskRom> = NomParser {
 /// ```
 pub static MACRONIX_MX23C8006: NomParser<GameMaskRom> = NomParser {
     name: "Macronix MX23C8006",
-    f: |input| gb_mx23c("MX23C8006-12", "49", GameRomType::F).parse(input),
+    examples: &["T991349-M MX23C8006-12 DMG-VPHJ-0 F 1A4891A2"],
+    prefixes: &[],
+    f: &|input| gb_mx23c("MX23C8006-12", "49", GameRomType::F).parse(input),
+    format: Some(&mx23_label),
 };
 
 /// Macronix MX23C1603 mask ROM (TSOP-II-44, 4.5-5.5V, 16 Mibit / 2 MiB)
@@ -347,7 +482,12 @@ pub static MACRONIX_MX23C8006: NomParser<GameMaskRom> = NomParser {
 /// ```
 pub static MACRONIX_MX23C1603: NomParser<GameMaskRom> = NomParser {
     name: "Macronix MX23C1603",
-    f: |input| {
+    examples: &[
+        "E052804-MG MX23C1603-12A CGB-AAUK-0 G2 1D4499A2A1",
+        "M994395-M MX23C1603-12 1 CGB-VYHE-0 G2 1Q6065A1",
+    ],
+    prefixes: &[],
+    f: &|input| {
         alt((
             gb_mx23c("MX23C1603-12 1", "95", GameRomType::G2),
             gb_mx23c("MX23C1603-12A", "04", GameRomType::G2),
@@ -355,6 +495,7 @@ pub static MACRONIX_MX23C1603: NomParser<GameMaskRom> = NomParser {
         ))
         .parse(input)
     },
+    format: Some(&mx23_label),
 };
 
 /// Macronix MX23C1605 mask ROM (SOP-44, 4.5-5.5V, 16 Mibit / 2 MiB)
@@ -365,7 +506,10 @@ pub static MACRONIX_MX23C1603: NomParser<GameMaskRom> = NomParser {
 /// ```
 pub static MACRONIX_MX23C1605: NomParser<GameMaskRom> = NomParser {
     name: "Macronix MX23C1605",
-    f: |input| gb_mx23c("MX23C1605-12A", "19", GameRomType::G1).parse(input),
+    examples: &["C004219-M MX23C1605-12A CGB-BTKP-0 G1 2D246301"],
+    prefixes: &[],
+    f: &|input| gb_mx23c("MX23C1605-12A", "19", GameRomType::G1).parse(input),
+    format: Some(&mx23_label),
 };
 
 /// Macronix MX23C3203 mask ROM (TSOP-II-44, 4.5-5.5V, 32 Mibit / 4 MiB)
@@ -378,7 +522,13 @@ pub static MACRONIX_MX23C1605: NomParser<GameMaskRom> = NomParser {
 /// ```
 pub static MACRONIX_MX23C3203: NomParser<GameMaskRom> = NomParser {
     name: "Macronix MX23C3203",
-    f: |input| {
+    examples: &[
+        "E034623-M MX23C3203-12A2 CGB-BY3D-0 H2 2G513304",
+        "M004523-M MX23C3203-11A2 CGB-B82J-0 02 H2 2D224301",
+        "M002595-M MX23C3203-12 1 CGB-BY3J-0 H2 1R0833A1",
+    ],
+    prefixes: &[],
+    f: &|input| {
         alt((
             gb_mx23c("MX23C3203-12 1", "95", GameRomType::H2),
             gb_mx23c("MX23C3203-12A2", "95", GameRomType::H2),
@@ -387,8 +537,34 @@ pub static MACRONIX_MX23C3203: NomParser<GameMaskRom> = NomParser {
         ))
         .parse(input)
     },
+    format: Some(&mx23_label),
 };
 
+fn mx23_label(rom: &GameMaskRom) -> Option<String> {
+    let (trace_code, lot_code) = trace_code_label(rom.date_code, rom.lot_code.as_deref())?;
+    let kind = rom.chip_type.as_ref()?;
+    let rom_id = &rom.rom_id;
+    let rom_type = rom.rom_type.as_str();
+    Some(match lot_code.split_once(' ') {
+        Some((extra, lot_code)) => {
+            format!("{trace_code} {kind} {rom_id} {extra} {rom_type} {lot_code}")
+        }
+        None => format!("{trace_code} {kind} {rom_id} {rom_type} {lot_code}"),
+    })
+}
+
+/// Splits the lot code into the trace code word, with the date code put back after the assembly
+/// vendor code, and the rest of the lot code
+fn trace_code_label(
+    date_code: Option<PartDateCode>,
+    lot_code: Option<&str>,
+) -> Option<(String, &str)> {
+    let (trace_code, lot_code) = lot_code?.split_once(' ')?;
+    let (vendor, rest) = trace_code.split_at(trace_code.chars().next()?.len_utf8());
+    let date_code = for_format::year2_week2(date_code?)?;
+    Some((format!("{vendor}{date_code}{rest}"), lot_code))
+}
+
 fn assembly_vendor_code<'a, E: ParseError<&'a str>>(input: &'a str) -> IResult<&'a str, char, E> {
     satisfy(|c| match c {
         'a' => true, // ChipMOS
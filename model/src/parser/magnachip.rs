@@ -3,13 +3,13 @@
 // SPDX-License-Identifier: MIT
 
 use nom::{
-    Parser, branch::alt, bytes::streaming::tag, character::streaming::char, error::ParseError,
+    Parser, branch::alt, character::streaming::char, combinator::recognize, error::ParseError,
     sequence::separated_pair,
 };
 
 use super::{
-    GameMaskRom, GameRomType,
-    for_nom::{agb_rom_code, digits, lines4},
+    GameMaskRom, GameRomType, Voltage,
+    for_nom::{agb_rom_code, digits, lines4, tag},
 };
 use crate::parser::{Manufacturer, NomParser};
 
@@ -21,22 +21,40 @@ fn ac23v<'a, E: ParseError<&'a str>>(
         tag("MAGNACHIP"),
         tag(chip_type),
         separated_pair(agb_rom_code(), char(' '), tag(rom_type.as_str())),
-        separated_pair(
+        recognize(separated_pair(
             alt((tag("GB"), tag("SP"))).and(digits(4)),
             char(' '),
             tag("PS"),
-        ),
+        )),
     )
-    .map(move |(_, kind, (rom_id, _), _)| GameMaskRom {
+    .map(move |(_, kind, (rom_id, _), lot_code)| GameMaskRom {
         rom_id: String::from(rom_id),
         rom_type,
         manufacturer: Some(Manufacturer::Magnachip),
         chip_type: Some(String::from(kind)),
         mask_code: None,
         date_code: None,
+        lot_code: Some(String::from(lot_code)),
+        revision: None,
+        origin_country: None,
+        package: Some(rom_type.package()),
+        voltage: Some(Voltage::V3_3),
+        density: rom_type.density(),
+        organization: None,
+        access_time: None,
     })
 }
 
+fn ac23v_label(rom: &GameMaskRom) -> Option<String> {
+    Some(format!(
+        "MAGNACHIP {kind} {rom_id} {rom_type} {lot_code}",
+        kind = rom.chip_type.as_ref()?,
+        rom_id = rom.rom_id,
+        rom_type = rom.rom_type.as_str(),
+        lot_code = rom.lot_code.as_ref()?,
+    ))
+}
+
 /// Magnachip AC23V32101 AGB mask ROM (TSOP-II-44, 3.3V, 32 Mibit / 4 MiB)
 ///
 /// ```
@@ -45,7 +63,10 @@ fn ac23v<'a, E: ParseError<&'a str>>(
 /// ```
 pub static MAGNACHIP_AC23V32101: NomParser<GameMaskRom> = NomParser {
     name: "Magnachip AC23V32101",
-    f: |input| ac23v("AC23V32101", GameRomType::H2).parse(input),
+    examples: &["MAGNACHIP AC23V32101 AGB-BCRP-0 H2 GB1191 PS"],
+    prefixes: &["MAGNACHIP"],
+    f: &|input| ac23v("AC23V32101", GameRomType::H2).parse(input),
+    format: Some(&ac23v_label),
 };
 
 /// Magnachip AC23V64101 AGB mask ROM (TSOP-II-44, 3.3V, 64 Mibit / 8 MiB)
@@ -56,7 +77,10 @@ pub static MAGNACHIP_AC23V32101: NomParser<GameMaskRom> = NomParser {
 /// ```
 pub static MAGNACHIP_AC23V64101: NomParser<GameMaskRom> = NomParser {
     name: "Magnachip AC23V64101",
-    f: |input| ac23v("AC23V64101", GameRomType::I2).parse(input),
+    examples: &["MAGNACHIP AC23V64101 AGB-BQQX-0 I2 GB0249 PS"],
+    prefixes: &["MAGNACHIP"],
+    f: &|input| ac23v("AC23V64101", GameRomType::I2).parse(input),
+    format: Some(&ac23v_label),
 };
 
 /// Magnachip AC23V128111 AGB mask ROM (TSOP-II-44, 3.3V, 128 Mibit / 16 MiB)
@@ -67,5 +91,8 @@ pub static MAGNACHIP_AC23V64101: NomParser<GameMaskRom> = NomParser {
 /// ```
 pub static MAGNACHIP_AC23V128111: NomParser<GameMaskRom> = NomParser {
     name: "Magnachip AC23V128111",
-    f: |input| ac23v("AC23V128111", GameRomType::J2).parse(input),
+    examples: &["MAGNACHIP AC23V128111 AGB-BPRE-1 J2 SP0730 PS"],
+    prefixes: &["MAGNACHIP"],
+    f: &|input| ac23v("AC23V128111", GameRomType::J2).parse(input),
+    format: Some(&ac23v_label),
 };
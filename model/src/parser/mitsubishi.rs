@@ -2,11 +2,11 @@
 //
 // SPDX-License-Identifier: MIT
 
-use nom::{Parser as _, bytes::streaming::tag};
+use nom::{Parser as _, combinator::recognize};
 
 use super::{
-    GenericPart, Manufacturer, NomParser,
-    for_nom::{alnum_uppers, digits, year1},
+    GenericPart, Manufacturer, NomParser, for_format,
+    for_nom::{alnum_uppers, digits, tag, year1},
 };
 use crate::parser::PartDateCode;
 
@@ -18,13 +18,31 @@ use crate::parser::PartDateCode;
 /// ```
 pub static MITSUBISHI_M62021P: NomParser<GenericPart> = NomParser {
     name: "Mitsubishi M62021P",
-    f: |input| {
-        (tag("2021 "), (year1, alnum_uppers(1), digits(1)))
-            .map(|(_, (year, _, _))| GenericPart {
+    examples: &["2021 7Z2"],
+    prefixes: &["2021 "],
+    f: &|input| {
+        (
+            tag("2021 "),
+            year1,
+            recognize(alnum_uppers(1).and(digits(1))),
+        )
+            .map(|(_, year, lot_code)| GenericPart {
                 kind: "M62021P".to_owned(),
                 manufacturer: Some(Manufacturer::Mitsubishi),
                 date_code: Some(PartDateCode::Year { year }),
+                lot_code: Some(String::from(lot_code)),
+                ..GenericPart::default()
             })
             .parse(input)
     },
+    format: Some(&|part| {
+        let PartDateCode::Year { year } = part.date_code? else {
+            return None;
+        };
+        Some(format!(
+            "2021 {year}{lot_code}",
+            year = for_format::year1(year)?,
+            lot_code = part.lot_code.as_ref()?,
+        ))
+    }),
 };
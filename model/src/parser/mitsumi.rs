@@ -3,12 +3,12 @@
 // SPDX-License-Identifier: MIT
 
 use nom::{
-    Parser as _, branch::alt, bytes::streaming::tag, character::streaming::char, combinator::opt,
+    Parser as _, branch::alt, character::streaming::char, combinator::opt, sequence::delimited,
 };
 
 use super::{
-    GenericPart, Manufacturer, NomParser,
-    for_nom::{alnum_uppers, uppers, year1, year1_week2},
+    Country, GenericPart, Manufacturer, NomParser, for_format,
+    for_nom::{alnum_uppers, country, tag, uppers, year1, year1_week2},
 };
 use crate::parser::PartDateCode;
 
@@ -21,15 +21,20 @@ use crate::parser::PartDateCode;
 /// ```
 pub static MITSUMI_MM1026A: NomParser<GenericPart> = NomParser {
     name: "Mitsumi MM1026A",
-    f: |input| {
+    examples: &["843 26A", "1L51 26A"],
+    prefixes: &[],
+    f: &|input| {
         (year1, alt((alnum_uppers(3), alnum_uppers(2))), tag(" 26A"))
-            .map(|(year, _, _)| GenericPart {
+            .map(|(year, lot_code, _)| GenericPart {
                 kind: "MM1026A".to_owned(),
                 manufacturer: Some(Manufacturer::Mitsumi),
                 date_code: Some(PartDateCode::Year { year }),
+                lot_code: Some(String::from(lot_code)),
+                ..GenericPart::default()
             })
             .parse(input)
     },
+    format: Some(&|part| year_and_lot_label(part, "26A")),
 };
 
 /// Mitsumi MM1134A supervisor
@@ -40,15 +45,24 @@ pub static MITSUMI_MM1026A: NomParser<GenericPart> = NomParser {
 /// ```
 pub static MITSUMI_MM1134A: NomParser<GenericPart> = NomParser {
     name: "Mitsumi MM1134A",
-    f: |input| {
+    examples: &["939 134A"],
+    prefixes: &[],
+    f: &|input| {
         (year1_week2, tag(" 134A"))
             .map(|(date_code, _)| GenericPart {
                 kind: "MM1134A".to_owned(),
                 manufacturer: Some(Manufacturer::Mitsumi),
                 date_code: Some(date_code),
+                ..GenericPart::default()
             })
             .parse(input)
     },
+    format: Some(&|part| {
+        Some(format!(
+            "{} 134A",
+            for_format::year1_week2(part.date_code?)?
+        ))
+    }),
 };
 
 /// Mitsumi MM1514X
@@ -60,15 +74,20 @@ pub static MITSUMI_MM1134A: NomParser<GenericPart> = NomParser {
 /// ```
 pub static MITSUMI_MM1514X: NomParser<GenericPart> = NomParser {
     name: "Mitsumi MM1514X",
-    f: |input| {
+    examples: &["105 514X", "081 514X"],
+    prefixes: &[],
+    f: &|input| {
         (year1, alnum_uppers(2), tag(" 514X"))
-            .map(|(year, _, _)| GenericPart {
+            .map(|(year, lot_code, _)| GenericPart {
                 kind: "MM1514X".to_owned(),
                 manufacturer: Some(Manufacturer::Mitsumi),
                 date_code: Some(PartDateCode::Year { year }),
+                lot_code: Some(String::from(lot_code)),
+                ..GenericPart::default()
             })
             .parse(input)
     },
+    format: Some(&|part| year_and_lot_label(part, "514X")),
 };
 
 /// Mitsumi MM1581A
@@ -79,15 +98,24 @@ pub static MITSUMI_MM1514X: NomParser<GenericPart> = NomParser {
 /// ```
 pub static MITSUMI_MM1581A: NomParser<GenericPart> = NomParser {
     name: "Mitsumi MM1581A",
-    f: |input| {
+    examples: &["422 1581A"],
+    prefixes: &[],
+    f: &|input| {
         (year1_week2, tag(" 1581A"))
             .map(|(date_code, _)| GenericPart {
                 kind: "MM1581A".to_owned(),
                 manufacturer: Some(Manufacturer::Mitsumi),
                 date_code: Some(date_code),
+                ..GenericPart::default()
             })
             .parse(input)
     },
+    format: Some(&|part| {
+        Some(format!(
+            "{} 1581A",
+            for_format::year1_week2(part.date_code?)?
+        ))
+    }),
 };
 
 /// Mitsumi MM1592F
@@ -98,15 +126,24 @@ pub static MITSUMI_MM1581A: NomParser<GenericPart> = NomParser {
 /// ```
 pub static MITSUMI_MM1592F: NomParser<GenericPart> = NomParser {
     name: "Mitsumi MM1592F",
-    f: |input| {
+    examples: &["548 592F"],
+    prefixes: &[],
+    f: &|input| {
         (year1_week2, tag(" 592F"))
             .map(|(date_code, _)| GenericPart {
                 kind: "MM1592F".to_owned(),
                 manufacturer: Some(Manufacturer::Mitsumi),
                 date_code: Some(date_code),
+                ..GenericPart::default()
             })
             .parse(input)
     },
+    format: Some(&|part| {
+        Some(format!(
+            "{} 592F",
+            for_format::year1_week2(part.date_code?)?
+        ))
+    }),
 };
 
 /// Mitsumi PM
@@ -117,22 +154,38 @@ pub static MITSUMI_MM1592F: NomParser<GenericPart> = NomParser {
 /// ```
 pub static MITSUMI_PM: NomParser<GenericPart> = NomParser {
     name: "Mitsumi PM",
-    f: |input| {
+    examples: &["MITSUMI JAPAN 528A PM C"],
+    prefixes: &["MITSUMI "],
+    f: &|input| {
         (
-            tag("MITSUMI JAPAN "),
+            delimited(tag("MITSUMI "), country(Country::Japan), char(' ')),
             year1_week2,
             opt(char(' ')),
             uppers(1),
             char(' '),
             alt((tag("PM B3"), tag("PM B4"), tag("PM C"))),
         )
-            .map(|(_, date_code, _, _, _, kind)| GenericPart {
-                kind: String::from(kind),
-                manufacturer: Some(Manufacturer::Mitsumi),
-                date_code: Some(date_code),
-            })
+            .map(
+                |(origin_country, date_code, _, lot_code, _, kind)| GenericPart {
+                    kind: String::from(kind),
+                    manufacturer: Some(Manufacturer::Mitsumi),
+                    date_code: Some(date_code),
+                    lot_code: Some(String::from(lot_code)),
+                    origin_country: Some(origin_country),
+                    ..GenericPart::default()
+                },
+            )
             .parse(input)
     },
+    format: Some(&|part| {
+        Some(format!(
+            "MITSUMI {country} {date_code}{lot_code} {kind}",
+            country = part.origin_country?.label(),
+            date_code = for_format::year1_week2(part.date_code?)?,
+            lot_code = part.lot_code.as_ref()?,
+            kind = part.kind,
+        ))
+    }),
 };
 
 /// ```
@@ -142,13 +195,28 @@ pub static MITSUMI_PM: NomParser<GenericPart> = NomParser {
 /// ```
 pub static MITSUMI_MGL_TRANSFORMER: NomParser<GenericPart> = NomParser {
     name: "Mitsumi MGL transformer",
-    f: |input| {
+    examples: &["82Y7", "84Z7"],
+    prefixes: &["82Y7", "84Z7"],
+    f: &|input| {
         alt((tag("82Y7"), tag("84Z7")))
             .map(|kind| GenericPart {
                 kind: String::from(kind),
                 manufacturer: Some(Manufacturer::Mitsumi),
                 date_code: None,
+                ..GenericPart::default()
             })
             .parse(input)
     },
+    format: Some(&|part| Some(part.kind.clone())),
 };
+
+fn year_and_lot_label(part: &GenericPart, kind: &str) -> Option<String> {
+    let PartDateCode::Year { year } = part.date_code? else {
+        return None;
+    };
+    Some(format!(
+        "{year}{lot_code} {kind}",
+        year = for_format::year1(year)?,
+        lot_code = part.lot_code.as_ref()?,
+    ))
+}
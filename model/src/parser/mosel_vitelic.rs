@@ -4,15 +4,15 @@
 
 use nom::{
     Parser as _,
-    bytes::streaming::tag,
     character::streaming::one_of,
     combinator::{opt, recognize},
-    sequence::delimited,
+    sequence::{preceded, separated_pair},
 };
 
 use super::{
-    GenericPart, Manufacturer, NomParser, PartDateCode,
-    for_nom::{alnum_uppers, digits, lines4, uppers, week2, year1, year1_week2},
+    Country, GenericPart, KIBIT, Manufacturer, NomParser, Organization, Package, PartDateCode,
+    Voltage, dash_speed_grade, for_format,
+    for_nom::{alnum_uppers, country, digits, lines4, tag, uppers, week2, year1, year1_week2},
 };
 
 /// Mosel-Vitelic LH52B256 (SOP-28, 5V, 256 Kibit / 32 KiB)
@@ -27,24 +27,44 @@ use super::{
 /// ```
 pub static MOSEL_VITELIC_LH52B256N: NomParser<GenericPart> = NomParser {
     name: "Mosel-Vitelic LH52B256N",
-    f: |input| {
+    examples: &[
+        "LH52B256NA-10PLL MOSEL-VITELIC JAPAN N643 0T BB",
+        "LH52B256NB-10PLL MOSEL-VITELIC JAPAN N539 0W BA",
+        "LH52B256NZ-10PLL MOSEL-VITELIC JAPAN N636 06 CB",
+    ],
+    prefixes: &["LH52B256N"],
+    f: &|input| {
         lines4(
             recognize((tag("LH52B256N"), one_of("ABZ"), tag("-10PLL"))),
             tag("MOSEL-VITELIC"),
-            tag("JAPAN"),
-            delimited(
+            country(Country::Japan),
+            preceded(
                 tag("N"),
-                year1_week2,
-                (tag(" "), digits(1), alnum_uppers(1), tag(" "), uppers(2)),
+                separated_pair(
+                    year1_week2,
+                    tag(" "),
+                    recognize((digits(1), alnum_uppers(1), tag(" "), uppers(2))),
+                ),
             ),
         )
-        .map(|(kind, _, _, date_code)| GenericPart {
-            kind: String::from(kind),
-            manufacturer: Some(Manufacturer::MoselVitelic),
-            date_code: Some(date_code),
-        })
+        .map(
+            |(kind, _, origin_country, (date_code, lot_code))| GenericPart {
+                kind: String::from(kind),
+                manufacturer: Some(Manufacturer::MoselVitelic),
+                date_code: Some(date_code),
+                lot_code: Some(String::from(lot_code)),
+                revision: None,
+                origin_country: Some(origin_country),
+                package: Some(Package::Sop28),
+                voltage: Some(Voltage::V5),
+                density: Some(256 * KIBIT),
+                organization: Some(Organization::new(32 * 1024, 8)),
+                access_time: dash_speed_grade(kind),
+            },
+        )
         .parse(input)
     },
+    format: Some(&mosel_vitelic_label),
 };
 
 /// Mosel-Vitelic LH5168N (SOP-28, 5V, 64 Kibit / 8 KiB)
@@ -58,25 +78,44 @@ pub static MOSEL_VITELIC_LH52B256N: NomParser<GenericPart> = NomParser {
 /// ```
 pub static MOSEL_VITELIC_LH5168N: NomParser<GenericPart> = NomParser {
     name: "Mosel-Vitelic LH5168N",
-    f: |input| {
+    examples: &[
+        "LH5168N-10PL MOSEL-VITELIC JAPAN N745 1G BH",
+        "LH5168N-10PL MOSEL-VITELIC JAPAN N7 34 22 BH",
+    ],
+    prefixes: &["LH5168N-10PL"],
+    f: &|input| {
         lines4(
             tag("LH5168N-10PL"),
             tag("MOSEL-VITELIC"),
-            tag("JAPAN"),
-            delimited(
+            country(Country::Japan),
+            preceded(
                 tag("N"),
-                (year1, opt(tag(" ")), week2)
-                    .map(|(year, _, week)| PartDateCode::YearWeek { year, week }),
-                (tag(" "), digits(1), alnum_uppers(1), tag(" "), uppers(2)),
+                separated_pair(
+                    (year1, opt(tag(" ")), week2)
+                        .map(|(year, _, week)| PartDateCode::YearWeek { year, week }),
+                    tag(" "),
+                    recognize((digits(1), alnum_uppers(1), tag(" "), uppers(2))),
+                ),
             ),
         )
-        .map(|(kind, _, _, date_code)| GenericPart {
-            kind: String::from(kind),
-            manufacturer: Some(Manufacturer::MoselVitelic),
-            date_code: Some(date_code),
-        })
+        .map(
+            |(kind, _, origin_country, (date_code, lot_code))| GenericPart {
+                kind: String::from(kind),
+                manufacturer: Some(Manufacturer::MoselVitelic),
+                date_code: Some(date_code),
+                lot_code: Some(String::from(lot_code)),
+                revision: None,
+                origin_country: Some(origin_country),
+                package: Some(Package::Sop28),
+                voltage: Some(Voltage::V5),
+                density: Some(64 * KIBIT),
+                organization: Some(Organization::new(8 * 1024, 8)),
+                access_time: dash_speed_grade(kind),
+            },
+        )
         .parse(input)
     },
+    format: None,
 };
 
 /// Mosel-Vitelic LH5268AN (SOP-28, 5V, 64 Kibit / 8 KiB)
@@ -90,24 +129,43 @@ pub static MOSEL_VITELIC_LH5168N: NomParser<GenericPart> = NomParser {
 /// ```
 pub static MOSEL_VITELIC_LH5268AN: NomParser<GenericPart> = NomParser {
     name: "Mosel-Vitelic LH5268AN",
-    f: |input| {
+    examples: &[
+        "LH5268ANF-10PLL MOSEL-VITELIC JAPAN N526 0H BC",
+        "LH5268ANA-10PLL MOSEL-VITELIC JAPAN N527 02 BC",
+    ],
+    prefixes: &["LH5268AN"],
+    f: &|input| {
         lines4(
             recognize((tag("LH5268AN"), one_of("AF"), tag("-10PLL"))),
             tag("MOSEL-VITELIC"),
-            tag("JAPAN"),
-            delimited(
+            country(Country::Japan),
+            preceded(
                 tag("N"),
-                year1_week2,
-                (tag(" "), digits(1), alnum_uppers(1), tag(" "), uppers(2)),
+                separated_pair(
+                    year1_week2,
+                    tag(" "),
+                    recognize((digits(1), alnum_uppers(1), tag(" "), uppers(2))),
+                ),
             ),
         )
-        .map(|(kind, _, _, date_code)| GenericPart {
-            kind: String::from(kind),
-            manufacturer: Some(Manufacturer::MoselVitelic),
-            date_code: Some(date_code),
-        })
+        .map(
+            |(kind, _, origin_country, (date_code, lot_code))| GenericPart {
+                kind: String::from(kind),
+                manufacturer: Some(Manufacturer::MoselVitelic),
+                date_code: Some(date_code),
+                lot_code: Some(String::from(lot_code)),
+                revision: None,
+                origin_country: Some(origin_country),
+                package: Some(Package::Sop28),
+                voltage: Some(Voltage::V5),
+                density: Some(64 * KIBIT),
+                organization: Some(Organization::new(8 * 1024, 8)),
+                access_time: dash_speed_grade(kind),
+            },
+        )
         .parse(input)
     },
+    format: Some(&mosel_vitelic_label),
 };
 
 /// Mosel-Vitelic LH52A64N (SOP-28, 5V, 64 Kibit / 8 KiB)
@@ -120,22 +178,48 @@ pub static MOSEL_VITELIC_LH5268AN: NomParser<GenericPart> = NomParser {
 /// ```
 pub static MOSEL_VITELIC_LH52A64N: NomParser<GenericPart> = NomParser {
     name: "Mosel-Vitelic LH52A64N",
-    f: |input| {
+    examples: &["LH52A64N-PL MOSEL-VITELIC JAPAN N651 0F C"],
+    prefixes: &["LH52A64N-PL"],
+    f: &|input| {
         lines4(
             tag("LH52A64N-PL"),
             tag("MOSEL-VITELIC"),
-            tag("JAPAN"),
-            delimited(
+            country(Country::Japan),
+            preceded(
                 tag("N"),
-                year1_week2,
-                (tag(" "), digits(1), alnum_uppers(1), tag(" "), uppers(1)),
+                separated_pair(
+                    year1_week2,
+                    tag(" "),
+                    recognize((digits(1), alnum_uppers(1), tag(" "), uppers(1))),
+                ),
             ),
         )
-        .map(|(kind, _, _, date_code)| GenericPart {
-            kind: String::from(kind),
-            manufacturer: Some(Manufacturer::MoselVitelic),
-            date_code: Some(date_code),
-        })
+        .map(
+            |(kind, _, origin_country, (date_code, lot_code))| GenericPart {
+                kind: String::from(kind),
+                manufacturer: Some(Manufacturer::MoselVitelic),
+                date_code: Some(date_code),
+                lot_code: Some(String::from(lot_code)),
+                revision: None,
+                origin_country: Some(origin_country),
+                package: Some(Package::Sop28),
+                voltage: Some(Voltage::V5),
+                density: Some(64 * KIBIT),
+                organization: Some(Organization::new(8 * 1024, 8)),
+                access_time: dash_speed_grade(kind),
+            },
+        )
         .parse(input)
     },
+    format: Some(&mosel_vitelic_label),
 };
+
+fn mosel_vitelic_label(part: &GenericPart) -> Option<String> {
+    Some(format!(
+        "{kind} MOSEL-VITELIC {country} N{date_code} {lot_code}",
+        kind = part.kind,
+        country = part.origin_country?.label(),
+        date_code = for_format::year1_week2(part.date_code?)?,
+        lot_code = part.lot_code.as_ref()?,
+    ))
+}
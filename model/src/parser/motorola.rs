@@ -2,11 +2,11 @@
 //
 // SPDX-License-Identifier: MIT
 
-use nom::{Parser as _, bytes::streaming::tag, sequence::delimited};
+use nom::{Parser as _, sequence::preceded};
 
 use super::{
-    Manufacturer, Mapper, MapperChip, NomParser,
-    for_nom::{lines4, year2_week2},
+    Manufacturer, Mapper, MapperChip, NomParser, for_format,
+    for_nom::{lines4, tag, year2_week2},
 };
 
 /// Motorola MBC1B (SOP-24)
@@ -17,18 +17,30 @@ use super::{
 /// ```
 pub static MOTOROLA_MBC1B: NomParser<Mapper> = NomParser {
     name: "Motorola MBC1B",
-    f: |input| {
+    examples: &["DMG MBC1B Nintendo J9130BR"],
+    prefixes: &["DMG"],
+    f: &|input| {
         lines4(
             tag("DMG"),
             tag("MBC1B"),
             tag("Nintendo"),
-            delimited(tag("J"), year2_week2, tag("BR")),
+            preceded(tag("J"), year2_week2.and(tag("BR"))),
         )
-        .map(|(_, _, _, date_code)| Mapper {
+        .map(|(_, _, _, (date_code, lot_code))| Mapper {
             kind: MapperChip::Mbc1B,
             manufacturer: Some(Manufacturer::Motorola),
             date_code: Some(date_code),
+            lot_code: Some(String::from(lot_code)),
+            revision: None,
+            origin_country: None,
         })
         .parse(input)
     },
+    format: Some(&|mapper| {
+        Some(format!(
+            "DMG MBC1B Nintendo J{date_code}{lot_code}",
+            date_code = for_format::year2_week2(mapper.date_code?)?,
+            lot_code = mapper.lot_code.as_ref()?,
+        ))
+    }),
 };
@@ -5,7 +5,6 @@
 use nom::{
     IResult, Parser,
     branch::alt,
-    bytes::streaming::tag,
     character::streaming::char,
     combinator::{cond, consumed, recognize, value},
     error::ParseError,
@@ -13,8 +12,9 @@ use nom::{
 };
 
 use super::{
-    GameMaskRom, GameRomType, GenericPart, Mapper, MapperChip, MaskCode, MaskRom, PartDateCode,
-    for_nom::{alnum_uppers, cgb_rom_code, digits, dmg_rom_code, lines3, uppers},
+    Country, GameMaskRom, GameRomType, GenericPart, MIBIT, Mapper, MapperChip, MaskCode, MaskRom,
+    Organization, PartDateCode, Voltage, for_format,
+    for_nom::{alnum_uppers, cgb_rom_code, country, digits, dmg_rom_code, lines3, tag, uppers},
 };
 use crate::parser::{Manufacturer, NomParser, for_nom::year2_week2};
 
@@ -30,9 +30,14 @@ use crate::parser::{Manufacturer, NomParser, for_nom::year2_week2};
 /// ```
 pub static NEC_UPD442012A_X: NomParser<GenericPart> = NomParser {
     name: "NEC μPD442012A-X",
-    f: |input| {
+    examples: &[
+        "NEC JAPAN D442012AGY-BB85X-MJH 0037K7027",
+        "NEC JAPAN D442012AGY-BC85X-MJH 0330K7043",
+    ],
+    prefixes: &["NEC "],
+    f: &|input| {
         (
-            tag("NEC JAPAN "),
+            delimited(tag("NEC "), country(Country::Japan), char(' ')),
             recognize((
                 tag("D442012A"),
                 tag("GY"), // package
@@ -46,13 +51,24 @@ pub static NEC_UPD442012A_X: NomParser<GenericPart> = NomParser {
             char(' '),
             date_and_lot_code,
         )
-            .map(|(_, kind, _, date_code)| GenericPart {
-                kind: String::from(kind),
-                manufacturer: Some(Manufacturer::Nec),
-                date_code: Some(date_code),
-            })
+            .map(
+                |(origin_country, kind, _, (date_code, lot_code))| GenericPart {
+                    kind: String::from(kind),
+                    manufacturer: Some(Manufacturer::Nec),
+                    date_code: Some(date_code),
+                    lot_code: Some(String::from(lot_code)),
+                    revision: None,
+                    origin_country: Some(origin_country),
+                    package: Some(super::Package::TsopI48),
+                    voltage: Some(Voltage::range(2_700, 3_600)),
+                    density: Some(2 * MIBIT),
+                    organization: Some(Organization::new(128 * 1024, 16)),
+                    access_time: Some(85),
+                },
+            )
             .parse(input)
     },
+    format: Some(&upd442012_label),
 };
 
 /// NEC μPD442012L-X SRAM (TSOP-I-48, 2.7-3.6V, 2 Mibit / 256 KiB / 128x16)
@@ -66,9 +82,11 @@ pub static NEC_UPD442012A_X: NomParser<GenericPart> = NomParser {
 /// ```
 pub static NEC_UPD442012L_X: NomParser<GenericPart> = NomParser {
     name: "NEC μPD442012L-X",
-    f: |input| {
+    examples: &["NEC JAPAN D442012LGY-B85X-MJH 0138K7037"],
+    prefixes: &["NEC "],
+    f: &|input| {
         (
-            tag("NEC JAPAN "),
+            delimited(tag("NEC "), country(Country::Japan), char(' ')),
             recognize((
                 tag("D442012L"),
                 tag("GY"), // package
@@ -82,15 +100,35 @@ pub static NEC_UPD442012L_X: NomParser<GenericPart> = NomParser {
             char(' '),
             date_and_lot_code,
         )
-            .map(|(_, kind, _, date_code)| GenericPart {
-                kind: String::from(kind),
-                manufacturer: Some(Manufacturer::Nec),
-                date_code: Some(date_code),
-            })
+            .map(
+                |(origin_country, kind, _, (date_code, lot_code))| GenericPart {
+                    kind: String::from(kind),
+                    manufacturer: Some(Manufacturer::Nec),
+                    date_code: Some(date_code),
+                    lot_code: Some(String::from(lot_code)),
+                    revision: None,
+                    origin_country: Some(origin_country),
+                    package: Some(super::Package::TsopI48),
+                    voltage: Some(Voltage::range(2_700, 3_600)),
+                    density: Some(2 * MIBIT),
+                    organization: Some(Organization::new(128 * 1024, 16)),
+                    access_time: Some(85),
+                },
+            )
             .parse(input)
     },
+    format: Some(&upd442012_label),
 };
 
+fn upd442012_label(part: &GenericPart) -> Option<String> {
+    Some(format!(
+        "NEC {country} {kind} {}",
+        date_and_lot_label(part.date_code, part.lot_code.as_deref())?,
+        country = part.origin_country?.label(),
+        kind = part.kind,
+    ))
+}
+
 fn upd23c<'a, E: ParseError<&'a str>>(
     chip_type: &'static str,
     package: Package,
@@ -113,7 +151,15 @@ fn upd23c<'a, E: ParseError<&'a str>>(
         date_and_lot_code,
     )
         .map(
-            move |(rom_id, _, _, _, (mask_code, (series, kind, package)), _, date_code)| {
+            move |(
+                rom_id,
+                _,
+                _,
+                _,
+                (mask_code, (series, kind, package)),
+                _,
+                (date_code, lot_code),
+            )| {
                 GameMaskRom {
                     rom_id: String::from(rom_id),
                     rom_type,
@@ -121,6 +167,14 @@ fn upd23c<'a, E: ParseError<&'a str>>(
                     chip_type: Some(format!("{series}{kind}{package}")),
                     mask_code: Some(MaskCode::Nec(String::from(mask_code))),
                     date_code: Some(date_code),
+                    lot_code: Some(String::from(lot_code)),
+                    revision: None,
+                    origin_country: None,
+                    package: Some(rom_type.package()),
+                    voltage: Some(Voltage::V5),
+                    density: rom_type.density(),
+                    organization: None,
+                    access_time: None,
                 }
             },
         )
@@ -132,7 +186,7 @@ fn upd23c_old<'a, E: ParseError<&'a str>>(
     rom_type: GameRomType,
 ) -> impl Parser<&'a str, Output = GameMaskRom, Error = E> {
     (
-        tag("NEC JAPAN "),
+        delimited(tag("NEC "), country(Country::Japan), char(' ')),
         alt((dmg_rom_code(), cgb_rom_code())),
         char(' '),
         tag(rom_type.as_str()),
@@ -149,7 +203,16 @@ fn upd23c_old<'a, E: ParseError<&'a str>>(
         date_and_lot_code,
     )
         .map(
-            move |(_, rom_id, _, _, _, (mask_code, (series, kind, package)), _, date_code)| {
+            move |(
+                origin_country,
+                rom_id,
+                _,
+                _,
+                _,
+                (mask_code, (series, kind, package)),
+                _,
+                (date_code, lot_code),
+            )| {
                 GameMaskRom {
                     rom_id: String::from(rom_id),
                     rom_type,
@@ -157,6 +220,14 @@ fn upd23c_old<'a, E: ParseError<&'a str>>(
                     chip_type: Some(format!("{series}{kind}{package}")),
                     mask_code: Some(MaskCode::Nec(String::from(mask_code))),
                     date_code: Some(date_code),
+                    lot_code: Some(String::from(lot_code)),
+                    revision: None,
+                    origin_country: Some(origin_country),
+                    package: Some(rom_type.package()),
+                    voltage: Some(Voltage::V5),
+                    density: rom_type.density(),
+                    organization: None,
+                    access_time: None,
                 }
             },
         )
@@ -187,7 +258,16 @@ fn upd23c_licensed<'a, E: ParseError<&'a str>>(
         date_and_lot_code,
     )
         .map(
-            move |(_, _, rom_id, _, _, (mask_code, (series, kind, package)), _, date_code)| {
+            move |(
+                _,
+                _,
+                rom_id,
+                _,
+                _,
+                (mask_code, (series, kind, package)),
+                _,
+                (date_code, lot_code),
+            )| {
                 GameMaskRom {
                     rom_id: String::from(rom_id),
                     rom_type,
@@ -195,6 +275,14 @@ fn upd23c_licensed<'a, E: ParseError<&'a str>>(
                     chip_type: Some(format!("{series}{kind}{package}")),
                     mask_code: Some(MaskCode::Nec(String::from(mask_code))),
                     date_code: Some(date_code),
+                    lot_code: Some(String::from(lot_code)),
+                    revision: None,
+                    origin_country: None,
+                    package: Some(rom_type.package()),
+                    voltage: Some(Voltage::V5),
+                    density: rom_type.density(),
+                    organization: None,
+                    access_time: None,
                 }
             },
         )
@@ -207,9 +295,28 @@ fn upd23c_licensed<'a, E: ParseError<&'a str>>(
 /// assert!(parser::nec::NEC_UPD23C1001E.parse("NEC JAPAN DMG-SAJ-0 C1 UPD23C1001EGW-J01 9010E9702").is_ok());
 /// assert!(parser::nec::NEC_UPD23C1001E.parse("DMG-HQE-0 C1 N-1001EGW-J23 9110E9001").is_ok());
 /// ```
+fn upd23c_label(prefix: &str, has_rom_type: bool, rom: &GameMaskRom) -> Option<String> {
+    let rom_type = if has_rom_type {
+        format!(" {}", rom.rom_type.as_str())
+    } else {
+        String::new()
+    };
+    Some(format!(
+        "{prefix}{rom_id}{rom_type} {mask_code} {}",
+        date_and_lot_label(rom.date_code, rom.lot_code.as_deref())?,
+        rom_id = rom.rom_id,
+        mask_code = rom.mask_code.as_ref()?.as_str(),
+    ))
+}
+
 pub static NEC_UPD23C1001E: NomParser<GameMaskRom> = NomParser {
     name: "NEC μPD23C1001E",
-    f: |input| {
+    examples: &[
+        "NEC JAPAN DMG-SAJ-0 C1 UPD23C1001EGW-J01 9010E9702",
+        "DMG-HQE-0 C1 N-1001EGW-J23 9110E9001",
+    ],
+    prefixes: &["NEC ", "DMG-", "CGB-"],
+    f: &|input| {
         let package = Package::Sop32;
         alt((
             upd23c_old("1001E", package, GameRomType::C1),
@@ -219,6 +326,10 @@ pub static NEC_UPD23C1001E: NomParser<GameMaskRom> = NomParser {
         ))
         .parse(input)
     },
+    format: Some(&|rom| match rom.origin_country {
+        Some(country) => upd23c_label(&format!("NEC {} ", country.label()), true, rom),
+        None => upd23c_label("", true, rom),
+    }),
 };
 
 /// NEC μPD23C2001E (SOP-32, 5V)
@@ -229,7 +340,9 @@ pub static NEC_UPD23C1001E: NomParser<GameMaskRom> = NomParser {
 /// ```
 pub static NEC_UPD23C2001E: NomParser<GameMaskRom> = NomParser {
     name: "NEC μPD23C2001E",
-    f: |input| {
+    examples: &["DMG-AVLP-0 D1 N-2001EUGW-J38 9840E7004"],
+    prefixes: &["DMG-", "CGB-"],
+    f: &|input| {
         let package = Package::Sop32;
         alt((
             upd23c("2001E", package, GameRomType::D1),
@@ -237,6 +350,7 @@ pub static NEC_UPD23C2001E: NomParser<GameMaskRom> = NomParser {
         ))
         .parse(input)
     },
+    format: Some(&|rom| upd23c_label("", true, rom)),
 };
 
 /// NEC μPD23C4001E (SOP-32, 5V)
@@ -248,7 +362,12 @@ pub static NEC_UPD23C2001E: NomParser<GameMaskRom> = NomParser {
 /// ```
 pub static NEC_UPD23C4001E: NomParser<GameMaskRom> = NomParser {
     name: "NEC μPD23C4001E",
-    f: |input| {
+    examples: &[
+        "DMG-AYWJ-1 E1 N-4001EJGW-J82 9804E7012",
+        "DMG-ZLE-0 E1 N-4001EAGW-J14 9325X9700",
+    ],
+    prefixes: &["DMG-", "CGB-"],
+    f: &|input| {
         let package = Package::Sop32;
         alt((
             upd23c("4001EA", package, GameRomType::E1),
@@ -257,6 +376,7 @@ pub static NEC_UPD23C4001E: NomParser<GameMaskRom> = NomParser {
         ))
         .parse(input)
     },
+    format: Some(&|rom| upd23c_label("", true, rom)),
 };
 
 /// NEC μPD23C8001E (SOP-32, 5V)
@@ -267,7 +387,10 @@ pub static NEC_UPD23C4001E: NomParser<GameMaskRom> = NomParser {
 /// ```
 pub static NEC_UPD23C8001E: NomParser<GameMaskRom> = NomParser {
     name: "NEC μPD23C8001E",
-    f: |input| upd23c("8001EJ", Package::Sop32, GameRomType::F1).parse(input),
+    examples: &["DMG-AGQE-0 F1 N-8001EJGW-K14 0033K7036"],
+    prefixes: &["DMG-", "CGB-"],
+    f: &|input| upd23c("8001EJ", Package::Sop32, GameRomType::F1).parse(input),
+    format: Some(&|rom| upd23c_label("", true, rom)),
 };
 
 /// NEC μPD23C16019W (TSOP-II-44, 5V)
@@ -278,7 +401,10 @@ pub static NEC_UPD23C8001E: NomParser<GameMaskRom> = NomParser {
 /// ```
 pub static NEC_UPD23C16019W: NomParser<GameMaskRom> = NomParser {
     name: "NEC μPD23C16019W",
-    f: |input| upd23c("16019W", Package::TsopIi44, GameRomType::G2).parse(input),
+    examples: &["DMG-VPHP-0 G2 N-16019WG5-M51 0029K7039"],
+    prefixes: &["DMG-", "CGB-"],
+    f: &|input| upd23c("16019W", Package::TsopIi44, GameRomType::G2).parse(input),
+    format: Some(&|rom| upd23c_label("", true, rom)),
 };
 
 /// AT&T μPD23C1001E (SOP-32, 5V)
@@ -291,7 +417,9 @@ pub static NEC_UPD23C16019W: NomParser<GameMaskRom> = NomParser {
 /// ```
 pub static AT_T_UPD23C1001E: NomParser<GameMaskRom> = NomParser {
     name: "AT&T μPD23C1001E",
-    f: |input| {
+    examples: &["Ⓜ AT&T JAPAN DMG-Q6E-0 C1 23C1001EAGW-K37 9351E9005"],
+    prefixes: &["Ⓜ AT&T JAPAN"],
+    f: &|input| {
         upd23c_licensed(
             "1001EA",
             Package::Sop32,
@@ -301,6 +429,7 @@ pub static AT_T_UPD23C1001E: NomParser<GameMaskRom> = NomParser {
         )
         .parse(input)
     },
+    format: Some(&|rom| upd23c_label("Ⓜ AT&T JAPAN ", true, rom)),
 };
 
 /// Standard Microsystems μPD23C1001E (SOP-32, 5V)
@@ -313,7 +442,9 @@ pub static AT_T_UPD23C1001E: NomParser<GameMaskRom> = NomParser {
 /// ```
 pub static SMSC_UPD23C1001E: NomParser<GameMaskRom> = NomParser {
     name: "SMSC μPD23C1001E",
-    f: |input| {
+    examples: &["STANDARD MICRO DMG-BIA-0 C1 23C1001EGW-J61 9140E9017"],
+    prefixes: &["STANDARD MICRO"],
+    f: &|input| {
         let package = Package::Sop32;
         let manufacturer = ("STANDARD MICRO", Manufacturer::Smsc);
         alt((
@@ -322,6 +453,7 @@ pub static SMSC_UPD23C1001E: NomParser<GameMaskRom> = NomParser {
         ))
         .parse(input)
     },
+    format: Some(&|rom| upd23c_label("STANDARD MICRO ", true, rom)),
 };
 
 /// MANI μPD23C4001E (SOP-32, 5V)
@@ -334,7 +466,9 @@ pub static SMSC_UPD23C1001E: NomParser<GameMaskRom> = NomParser {
 /// ```
 pub static MANI_UPD23C4001E: NomParser<GameMaskRom> = NomParser {
     name: "MANI μPD23C4001E",
-    f: |input| {
+    examples: &["MANI DMG-MQE-2 23C4001EAGW-J22 9447X9200"],
+    prefixes: &["MANI"],
+    f: &|input| {
         upd23c_licensed(
             "4001EA",
             Package::Sop32,
@@ -344,6 +478,7 @@ pub static MANI_UPD23C4001E: NomParser<GameMaskRom> = NomParser {
         )
         .parse(input)
     },
+    format: Some(&|rom| upd23c_label("MANI ", false, rom)),
 };
 
 /// NEC GBS-DOL
@@ -354,19 +489,30 @@ pub static MANI_UPD23C4001E: NomParser<GameMaskRom> = NomParser {
 /// ```
 pub static NEC_GBS_DOL: NomParser<GenericPart> = NomParser {
     name: "NEC GBS-DOL",
-    f: |input| {
+    examples: &["Nintendo GBS-DOL 011 0623L3001"],
+    prefixes: &["Nintendo "],
+    f: &|input| {
         (
             delimited(tag("Nintendo "), tag("GBS-DOL"), tag(" 011")),
             char(' '),
             date_and_lot_code,
         )
-            .map(|(kind, _, date_code)| GenericPart {
+            .map(|(kind, _, (date_code, lot_code))| GenericPart {
                 kind: String::from(kind),
                 manufacturer: Some(Manufacturer::Nec),
                 date_code: Some(date_code),
+                lot_code: Some(String::from(lot_code)),
+                ..GenericPart::default()
             })
             .parse(input)
     },
+    format: Some(&|part| {
+        Some(format!(
+            "Nintendo {kind} 011 {}",
+            date_and_lot_label(part.date_code, part.lot_code.as_deref())?,
+            kind = part.kind,
+        ))
+    }),
 };
 
 /// NEC ICD2-N
@@ -377,20 +523,25 @@ pub static NEC_GBS_DOL: NomParser<GenericPart> = NomParser {
 /// ```
 pub static NEC_ICD2_N: NomParser<GenericPart> = NomParser {
     name: "NEC ICD2-N",
-    f: |input| {
+    examples: &["Nintendo ICD2-N 9415KX226 D93115"],
+    prefixes: &["Nintendo "],
+    f: &|input| {
         (
             preceded(tag("Nintendo "), tag("ICD2-N")),
             char(' '),
             date_and_lot_code,
             tag(" D93115"),
         )
-            .map(|(kind, _, date_code, _)| GenericPart {
+            .map(|(kind, _, (date_code, lot_code), _)| GenericPart {
                 kind: String::from(kind),
                 manufacturer: Some(Manufacturer::Nec),
                 date_code: Some(date_code),
+                lot_code: Some(String::from(lot_code)),
+                ..GenericPart::default()
             })
             .parse(input)
     },
+    format: Some(&|part| icd2_label(part, "D93115")),
 };
 
 /// NEC ICD2-R
@@ -401,20 +552,25 @@ pub static NEC_ICD2_N: NomParser<GenericPart> = NomParser {
 /// ```
 pub static NEC_ICD2_R: NomParser<GenericPart> = NomParser {
     name: "NEC ICD2-R",
-    f: |input| {
+    examples: &["Nintendo ICD2-R 9802EX006 D93128"],
+    prefixes: &["Nintendo "],
+    f: &|input| {
         (
             preceded(tag("Nintendo "), tag("ICD2-R")),
             char(' '),
             date_and_lot_code,
             tag(" D93128"),
         )
-            .map(|(kind, _, date_code, _)| GenericPart {
+            .map(|(kind, _, (date_code, lot_code), _)| GenericPart {
                 kind: String::from(kind),
                 manufacturer: Some(Manufacturer::Nec),
                 date_code: Some(date_code),
+                lot_code: Some(String::from(lot_code)),
+                ..GenericPart::default()
             })
             .parse(input)
     },
+    format: Some(&|part| icd2_label(part, "D93128")),
 };
 
 /// NEC SGB mask ROM
@@ -425,7 +581,9 @@ pub static NEC_ICD2_R: NomParser<GenericPart> = NomParser {
 /// ```
 pub static NEC_SGB_ROM: NomParser<MaskRom> = NomParser {
     name: "NEC SGB ROM",
-    f: |input| {
+    examples: &["© 1994 Nintendo SYS-SGB-NT N-2001EGW-J56 9414X9013"],
+    prefixes: &["© 1994 Nintendo"],
+    f: &|input| {
         let mask_code = "J56";
         (
             preceded(tag("© 1994 Nintendo "), tag("SYS-SGB-NT")),
@@ -440,22 +598,53 @@ pub static NEC_SGB_ROM: NomParser<MaskRom> = NomParser {
             date_and_lot_code,
         )
             .map(
-                |(rom_id, _, ((series, kind, package), _), _, date_code)| MaskRom {
+                |(rom_id, _, ((series, kind, package), _), _, (date_code, lot_code))| MaskRom {
                     rom_id: String::from(rom_id),
                     manufacturer: Some(Manufacturer::Nec),
                     chip_type: Some(format!("{series}{kind}{package}")),
                     mask_code: Some(MaskCode::Nec(String::from(mask_code))),
                     date_code: Some(date_code),
+                    lot_code: Some(String::from(lot_code)),
                 },
             )
             .parse(input)
     },
+    format: Some(&|rom| {
+        Some(format!(
+            "© 1994 Nintendo {rom_id} N-2001E{package}-{mask_code} {date_code}{lot_code}",
+            rom_id = rom.rom_id,
+            package = Package::Sop32.code(),
+            mask_code = rom.mask_code.as_ref()?.as_str(),
+            date_code = for_format::year2_week2(rom.date_code?)?,
+            lot_code = rom.lot_code.as_ref()?,
+        ))
+    }),
 };
 
+fn icd2_label(part: &GenericPart, suffix: &str) -> Option<String> {
+    Some(format!(
+        "Nintendo {kind} {} {suffix}",
+        date_and_lot_label(part.date_code, part.lot_code.as_deref())?,
+        kind = part.kind,
+    ))
+}
+
+fn date_and_lot_label(date_code: Option<PartDateCode>, lot_code: Option<&str>) -> Option<String> {
+    Some(format!(
+        "{}{}",
+        for_format::year2_week2(date_code?)?,
+        lot_code?
+    ))
+}
+
 fn date_and_lot_code<'a, E: ParseError<&'a str>>(
     input: &'a str,
-) -> IResult<&'a str, PartDateCode, E> {
-    terminated(year2_week2, uppers(1).and(alnum_uppers(1)).and(digits(3))).parse(input)
+) -> IResult<&'a str, (PartDateCode, &'a str), E> {
+    (
+        year2_week2,
+        recognize(uppers(1).and(alnum_uppers(1)).and(digits(3))),
+    )
+        .parse(input)
 }
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
@@ -473,6 +662,15 @@ impl Package {
     }
 }
 
+impl From<Package> for super::Package {
+    fn from(package: Package) -> Self {
+        match package {
+            Package::Sop32 => super::Package::Sop32,
+            Package::TsopIi44 => super::Package::TsopIi44,
+        }
+    }
+}
+
 /// NEC MBC1B (SOP-24)
 ///
 /// ```
@@ -484,23 +682,34 @@ impl Package {
 /// ```
 pub static NEC_MBC1B: NomParser<Mapper> = NomParser {
     name: "NEC MBC1B",
-    f: |input| {
+    examples: &[
+        "Nintendo DMG MBC1B N 9019BA012",
+        "Nintendo DMG MBC1B N9542B3004",
+        "Nintendo DMG MBC1B N9004AD",
+        "Nintendo DMG MBC1B 8940AJ",
+    ],
+    prefixes: &["Nintendo"],
+    f: &|input| {
         lines3(
             tag("Nintendo"),
             preceded(tag("DMG "), tag("MBC1B")),
             alt((
-                terminated(year2_week2, uppers(2)),
-                delimited(tag("N"), year2_week2, uppers(2)),
+                year2_week2.and(uppers(2)),
+                preceded(tag("N"), year2_week2.and(uppers(2))),
                 preceded(tag("N ").or(tag("N")), date_and_lot_code),
             )),
         )
-        .map(|(_, _, date_code)| Mapper {
+        .map(|(_, _, (date_code, lot_code))| Mapper {
             kind: MapperChip::Mbc1B,
             manufacturer: Some(Manufacturer::Nec),
             date_code: Some(date_code),
+            lot_code: Some(String::from(lot_code)),
+            revision: None,
+            origin_country: None,
         })
         .parse(input)
     },
+    format: None,
 };
 
 /// NEC MBC2A (SOP-28)
@@ -511,19 +720,30 @@ pub static NEC_MBC1B: NomParser<Mapper> = NomParser {
 /// ```
 pub static NEC_MBC2A: NomParser<Mapper> = NomParser {
     name: "NEC MBC2A",
-    f: |input| {
+    examples: &["Nintendo DMG MBC2A N 9011CA005"],
+    prefixes: &["Nintendo"],
+    f: &|input| {
         lines3(
             tag("Nintendo"),
             preceded(tag("DMG "), tag("MBC2A")),
             preceded(tag("N "), date_and_lot_code),
         )
-        .map(|(_, _, date_code)| Mapper {
+        .map(|(_, _, (date_code, lot_code))| Mapper {
             kind: MapperChip::Mbc2A,
             manufacturer: Some(Manufacturer::Nec),
             date_code: Some(date_code),
+            lot_code: Some(String::from(lot_code)),
+            revision: None,
+            origin_country: None,
         })
         .parse(input)
     },
+    format: Some(&|mapper| {
+        Some(format!(
+            "Nintendo DMG MBC2A N {}",
+            date_and_lot_label(mapper.date_code, mapper.lot_code.as_deref())?
+        ))
+    }),
 };
 
 /// NEC MBC6 (QFP-64)
@@ -534,13 +754,24 @@ pub static NEC_MBC2A: NomParser<Mapper> = NomParser {
 /// ```
 pub static NEC_MBC6: NomParser<Mapper> = NomParser {
     name: "NEC MBC6",
-    f: |input| {
+    examples: &["Nintendo MBC6 0103XP014"],
+    prefixes: &["Nintendo"],
+    f: &|input| {
         lines3(tag("Nintendo"), tag("MBC6"), date_and_lot_code)
-            .map(|(_, _, date_code)| Mapper {
+            .map(|(_, _, (date_code, lot_code))| Mapper {
                 kind: MapperChip::Mbc6,
                 manufacturer: Some(Manufacturer::Nec),
                 date_code: Some(date_code),
+                lot_code: Some(String::from(lot_code)),
+                revision: None,
+                origin_country: None,
             })
             .parse(input)
     },
+    format: Some(&|mapper| {
+        Some(format!(
+            "Nintendo MBC6 {}",
+            date_and_lot_label(mapper.date_code, mapper.lot_code.as_deref())?
+        ))
+    }),
 };
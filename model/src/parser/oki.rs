@@ -5,18 +5,17 @@
 use nom::{
     Parser,
     branch::alt,
-    bytes::streaming::tag,
     character::streaming::char,
-    combinator::{consumed, value},
+    combinator::{consumed, recognize, value},
     error::ParseError,
-    sequence::terminated,
+    sequence::{delimited, terminated},
 };
 
 use super::{
-    GameMaskRom, GameRomType, MaskCode, MaskRom,
+    Country, GameMaskRom, GameRomType, MaskCode, MaskRom, Voltage, for_format,
     for_nom::{
-        agb_rom_code, alnum_uppers, cgb_rom_code, digits, dmg_rom_code, satisfy_m_n_complete,
-        year1_week2,
+        agb_rom_code, alnum_uppers, cgb_rom_code, country, digits, dmg_rom_code,
+        satisfy_m_n_complete, tag, year1_week2,
     },
 };
 use crate::parser::{Manufacturer, NomParser};
@@ -29,29 +28,44 @@ use crate::parser::{Manufacturer, NomParser};
 /// ```
 pub static OKI_MASK_ROM_QFP_44_512_KIBIT: NomParser<GameMaskRom> = NomParser {
     name: "OKI mask ROM",
-    f: |input| {
+    examples: &["DMG-QXA-0 OKI JAPAN B0 03 X0 02"],
+    prefixes: &["DMG-"],
+    f: &|input| {
         let rom_type = GameRomType::B0;
         (
             dmg_rom_code(),
-            tag(" OKI JAPAN "),
+            delimited(tag(" OKI "), country(Country::Japan), char(' ')),
             tag(rom_type.as_str()),
             char(' '),
-            digits(2),
-            char(' '),
-            alnum_uppers(2),
-            char(' '),
-            digits(2),
+            recognize((digits(2), char(' '), alnum_uppers(2), char(' '), digits(2))),
         )
-            .map(|(rom_id, _, _, _, _, _, _, _, _)| GameMaskRom {
+            .map(|(rom_id, origin_country, _, _, lot_code)| GameMaskRom {
                 rom_id: String::from(rom_id),
                 rom_type,
                 manufacturer: Some(Manufacturer::Oki),
                 chip_type: None,
                 mask_code: None,
                 date_code: None,
+                lot_code: Some(String::from(lot_code)),
+                revision: None,
+                origin_country: Some(origin_country),
+                package: Some(rom_type.package()),
+                voltage: Some(Voltage::V5),
+                density: rom_type.density(),
+                organization: None,
+                access_time: None,
             })
             .parse(input)
     },
+    format: Some(&|rom| {
+        Some(format!(
+            "{rom_id} OKI {country} {rom_type} {lot_code}",
+            rom_id = rom.rom_id,
+            country = rom.origin_country?.label(),
+            rom_type = rom.rom_type.as_str(),
+            lot_code = rom.lot_code.as_ref()?,
+        ))
+    }),
 };
 
 fn gb<'a, E: ParseError<&'a str>>(
@@ -66,16 +80,27 @@ fn gb<'a, E: ParseError<&'a str>>(
         char(' '),
         consumed(terminated(tag(chip_type), char('-').and(alnum_uppers(2)))),
         char(' '),
-        (year1_week2, alnum_uppers(1), digits(2), alnum_uppers(1)),
+        (
+            year1_week2,
+            recognize((alnum_uppers(1), digits(2), alnum_uppers(1))),
+        ),
     )
         .map(
-            move |(rom_id, _, _, _, (mask_code, kind), _, (date_code, _, _, _))| GameMaskRom {
+            move |(rom_id, _, _, _, (mask_code, kind), _, (date_code, lot_code))| GameMaskRom {
                 rom_id: String::from(rom_id),
                 rom_type,
                 manufacturer: Some(Manufacturer::Oki),
                 chip_type: Some(format!("{prefix}{kind}")),
                 mask_code: Some(MaskCode::Oki(String::from(mask_code))),
                 date_code: Some(date_code),
+                lot_code: Some(String::from(lot_code)),
+                revision: None,
+                origin_country: None,
+                package: Some(rom_type.package()),
+                voltage: Some(Voltage::V5),
+                density: rom_type.density(),
+                organization: None,
+                access_time: None,
             },
         )
 }
@@ -86,9 +111,23 @@ fn gb<'a, E: ParseError<&'a str>>(
 /// use gbhwdb_model::parser::{self, LabelParser};
 /// assert!(parser::oki::OKI_MSM534011.parse("CGB-ADME-0 E1 M534011E-09 841232A").is_ok());
 /// ```
+fn oki_label(rom: &GameMaskRom) -> Option<String> {
+    Some(format!(
+        "{rom_id} {rom_type} {mask_code} {date_code}{lot_code}",
+        rom_id = rom.rom_id,
+        rom_type = rom.rom_type.as_str(),
+        mask_code = rom.mask_code.as_ref()?.as_str(),
+        date_code = for_format::year1_week2(rom.date_code?)?,
+        lot_code = rom.lot_code.as_ref()?,
+    ))
+}
+
 pub static OKI_MSM534011: NomParser<GameMaskRom> = NomParser {
     name: "OKI MSM534011",
-    f: |input| gb("MS", "M534011E", GameRomType::E1).parse(input),
+    examples: &["CGB-ADME-0 E1 M534011E-09 841232A"],
+    prefixes: &["DMG-", "CGB-"],
+    f: &|input| gb("MS", "M534011E", GameRomType::E1).parse(input),
+    format: Some(&oki_label),
 };
 
 /// OKI MSM538011 (SOP-32, 5V, 8 Mibit / 1 MiB)
@@ -100,7 +139,13 @@ pub static OKI_MSM534011: NomParser<GameMaskRom> = NomParser {
 /// ```
 pub static OKI_MSM538011_SOP_32: NomParser<GameMaskRom> = NomParser {
     name: "OKI MSM538011",
-    f: |input| gb("MS", "M538011E", GameRomType::F1).parse(input),
+    examples: &[
+        "DMG-AM6J-0 F1 M538011E-36 9085401",
+        "CGB-BJWP-0 F1 M538011E-4D 0475408",
+    ],
+    prefixes: &["DMG-", "CGB-"],
+    f: &|input| gb("MS", "M538011E", GameRomType::F1).parse(input),
+    format: Some(&oki_label),
 };
 
 /// OKI MSM538011 (TSOP-I-32, 5V, 8 Mibit / 1 MiB)
@@ -111,7 +156,10 @@ pub static OKI_MSM538011_SOP_32: NomParser<GameMaskRom> = NomParser {
 /// ```
 pub static OKI_MSM538011_TSOP_I_32: NomParser<GameMaskRom> = NomParser {
     name: "OKI MSM538011",
-    f: |input| gb("MS", "M538011E", GameRomType::F).parse(input),
+    examples: &["CGB-VGRJ-0 F M538011E-42 9125401"],
+    prefixes: &["DMG-", "CGB-"],
+    f: &|input| gb("MS", "M538011E", GameRomType::F).parse(input),
+    format: Some(&oki_label),
 };
 
 /// OKI MR531614 (TSOP-II-44, 5V, 16 Mibit / 2 MiB)
@@ -122,7 +170,10 @@ pub static OKI_MSM538011_TSOP_I_32: NomParser<GameMaskRom> = NomParser {
 /// ```
 pub static OKI_MR531614: NomParser<GameMaskRom> = NomParser {
     name: "OKI MR531614",
-    f: |input| gb("M", "R531614G", GameRomType::G2).parse(input),
+    examples: &["CGB-BPTE-0 G2 R531614G-44 044232E"],
+    prefixes: &["DMG-", "CGB-"],
+    f: &|input| gb("M", "R531614G", GameRomType::G2).parse(input),
+    format: Some(&oki_label),
 };
 
 fn gba<'a, E: ParseError<&'a str>>(
@@ -143,13 +194,21 @@ fn gba<'a, E: ParseError<&'a str>>(
         ),
     )
         .map(
-            move |(rom_id, _, _, _, (mask_code, kind), _, (date_code, _))| GameMaskRom {
+            move |(rom_id, _, _, _, (mask_code, kind), _, (date_code, lot_code))| GameMaskRom {
                 rom_id: String::from(rom_id),
                 rom_type,
                 manufacturer: Some(Manufacturer::Oki),
                 chip_type: Some(format!("{prefix}{kind}")),
                 mask_code: Some(MaskCode::Oki(String::from(mask_code))),
                 date_code: Some(date_code),
+                lot_code: Some(String::from(lot_code)),
+                revision: None,
+                origin_country: None,
+                package: Some(rom_type.package()),
+                voltage: Some(Voltage::V3_3),
+                density: rom_type.density(),
+                organization: None,
+                access_time: None,
             },
         )
 }
@@ -162,7 +221,10 @@ fn gba<'a, E: ParseError<&'a str>>(
 /// ```
 pub static OKI_MR26V3210: NomParser<GameMaskRom> = NomParser {
     name: "OKI MR26V3210",
-    f: |input| gba("M", "R26V3210F", GameRomType::H2).parse(input),
+    examples: &["AGB-TCHK-1 H2 R26V3210F-087 244A239"],
+    prefixes: &["AGB-"],
+    f: &|input| gba("M", "R26V3210F", GameRomType::H2).parse(input),
+    format: Some(&oki_label),
 };
 
 /// OKI MR26V3211 (TSOP-II-44, 3.3V, 32 Mibit / 4 MiB)
@@ -173,7 +235,10 @@ pub static OKI_MR26V3210: NomParser<GameMaskRom> = NomParser {
 /// ```
 pub static OKI_MR26V3211: NomParser<GameMaskRom> = NomParser {
     name: "OKI MR26V3211",
-    f: |input| gba("M", "R26V3211F", GameRomType::H2).parse(input),
+    examples: &["AGB-BR3P-0 H2 R26V3211F-0T6 442ABAJJ"],
+    prefixes: &["AGB-"],
+    f: &|input| gba("M", "R26V3211F", GameRomType::H2).parse(input),
+    format: Some(&oki_label),
 };
 
 /// OKI MR26V6413 (TSOP-II-44, 3.3V, 64 Mibit / 8 MiB)
@@ -184,7 +249,10 @@ pub static OKI_MR26V3211: NomParser<GameMaskRom> = NomParser {
 /// ```
 pub static OKI_MR26V6413: NomParser<GameMaskRom> = NomParser {
     name: "OKI MR26V6413",
-    f: |input| gba("M", "R26V6413G", GameRomType::I2).parse(input),
+    examples: &["AGB-A7HJ-0 I2 R26V6413G-0A9 242A273"],
+    prefixes: &["AGB-"],
+    f: &|input| gba("M", "R26V6413G", GameRomType::I2).parse(input),
+    format: Some(&oki_label),
 };
 
 /// OKI MR26V6414 (TSOP-II-44, 3.3V, 64 Mibit / 8 MiB)
@@ -195,7 +263,10 @@ pub static OKI_MR26V6413: NomParser<GameMaskRom> = NomParser {
 /// ```
 pub static OKI_MR26V6414: NomParser<GameMaskRom> = NomParser {
     name: "OKI MR26V6414",
-    f: |input| gba("M", "R26V6414G", GameRomType::I2).parse(input),
+    examples: &["AGB-AXVJ-0 I2 R26V6414G-0A7 243A262"],
+    prefixes: &["AGB-"],
+    f: &|input| gba("M", "R26V6414G", GameRomType::I2).parse(input),
+    format: Some(&oki_label),
 };
 
 /// OKI MR26V6415 (TSOP-II-44, 3.3V, 64 Mibit / 8 MiB)
@@ -206,7 +277,10 @@ pub static OKI_MR26V6414: NomParser<GameMaskRom> = NomParser {
 /// ```
 pub static OKI_MR26V6415: NomParser<GameMaskRom> = NomParser {
     name: "OKI MR26V6415",
-    f: |input| gba("M", "R26V6415G", GameRomType::I2).parse(input),
+    examples: &["AGB-BR4J-0 I2 R26V6415G-02L 427ABA3"],
+    prefixes: &["AGB-"],
+    f: &|input| gba("M", "R26V6415G", GameRomType::I2).parse(input),
+    format: Some(&oki_label),
 };
 
 /// OKI MR27V810 (TSOP-II-44, 3.3V, 8 Mibit / 1 MiB)
@@ -217,7 +291,10 @@ pub static OKI_MR26V6415: NomParser<GameMaskRom> = NomParser {
 /// ```
 pub static OKI_MR27V810: NomParser<GameMaskRom> = NomParser {
     name: "OKI MR27V810",
-    f: |input| gba("M", "R27V810F", GameRomType::F2).parse(input),
+    examples: &["AGB-FADP-0 F2 R27V810F-059 4475BB4J"],
+    prefixes: &["AGB-"],
+    f: &|input| gba("M", "R27V810F", GameRomType::F2).parse(input),
+    format: Some(&oki_label),
 };
 
 /// OKI MR27V6416 (TSOP-II-44, 3.3V, 64 Mibit / 8 MiB)
@@ -228,7 +305,10 @@ pub static OKI_MR27V810: NomParser<GameMaskRom> = NomParser {
 /// ```
 pub static OKI_MR27V6416: NomParser<GameMaskRom> = NomParser {
     name: "OKI MR27V6416",
-    f: |input| gba("M", "R27V6416M", GameRomType::I2).parse(input),
+    examples: &["AGB-B2LP-0 I2 R27V6416M-0TB 6445BJ9J"],
+    prefixes: &["AGB-"],
+    f: &|input| gba("M", "R27V6416M", GameRomType::I2).parse(input),
+    format: Some(&oki_label),
 };
 
 /// OKI MR27V12813 (TSOP-II-44, 3.3V, 128 Mibit / 16 MiB)
@@ -239,7 +319,10 @@ pub static OKI_MR27V6416: NomParser<GameMaskRom> = NomParser {
 /// ```
 pub static OKI_MR27V12813: NomParser<GameMaskRom> = NomParser {
     name: "OKI MR27V12813",
-    f: |input| gba("M", "R27V12813M", GameRomType::J2).parse(input),
+    examples: &["AGB-AXPS-1 J2 R27V12813M-0C7 6145BARJ"],
+    prefixes: &["AGB-"],
+    f: &|input| gba("M", "R27V12813M", GameRomType::J2).parse(input),
+    format: Some(&oki_label),
 };
 
 /// OKI SGB2 mask ROM, MSM534011 (SOP-32, 5V, 4 Mibit / 512 KiB)
@@ -250,23 +333,35 @@ pub static OKI_MR27V12813: NomParser<GameMaskRom> = NomParser {
 /// ```
 pub static OKI_SGB2_ROM: NomParser<MaskRom> = NomParser {
     name: "OKI SGB2 ROM",
-    f: |input| {
+    examples: &["SYS-SGB2-10 © 1998 Nintendo M534011E-05 8012354"],
+    prefixes: &["SYS-SGB2-10"],
+    f: &|input| {
         (
             tag("SYS-SGB2-10"),
             tag(" © 1998 Nintendo "),
             consumed(value("MSM534011E", tag("M534011E-05"))),
             char(' '),
-            (year1_week2, alnum_uppers(1), digits(2), alnum_uppers(1)),
+            year1_week2.and(recognize((alnum_uppers(1), digits(2), alnum_uppers(1)))),
         )
             .map(
-                move |(rom_id, _, (mask_code, kind), _, (date_code, _, _, _))| MaskRom {
+                move |(rom_id, _, (mask_code, kind), _, (date_code, lot_code))| MaskRom {
                     rom_id: String::from(rom_id),
                     manufacturer: Some(Manufacturer::Oki),
                     chip_type: Some(String::from(kind)),
                     mask_code: Some(MaskCode::Oki(String::from(mask_code))),
                     date_code: Some(date_code),
+                    lot_code: Some(String::from(lot_code)),
                 },
             )
             .parse(input)
     },
+    format: Some(&|rom| {
+        Some(format!(
+            "{rom_id} © 1998 Nintendo {mask_code} {date_code}{lot_code}",
+            rom_id = rom.rom_id,
+            mask_code = rom.mask_code.as_ref()?.as_str(),
+            date_code = for_format::year1_week2(rom.date_code?)?,
+            lot_code = rom.lot_code.as_ref()?,
+        ))
+    }),
 };
@@ -2,21 +2,16 @@
 //
 // SPDX-License-Identifier: MIT
 
-use nom::{
-    IResult, Parser as _,
-    bytes::streaming::tag,
-    combinator::recognize,
-    error::ParseError,
-    sequence::{preceded, terminated},
-};
+use nom::{IResult, Parser as _, combinator::recognize, error::ParseError, sequence::preceded};
 
 use crate::parser::{
     GenericPart,
-    for_nom::{alnum_uppers, year2_week2},
+    for_nom::{alnum_uppers, country, tag, year2_week2},
 };
 
 use super::{
-    Manufacturer, Mapper, MapperChip, NomParser, PartDateCode,
+    Country, KIBIT, Manufacturer, Mapper, MapperChip, NomParser, Organization, Package,
+    PartDateCode, Voltage, dash_speed_grade, for_format,
     for_nom::{digits, lines3, lines4, month1_123ond, uppers, year1, year1_week2},
 };
 
@@ -28,20 +23,26 @@ use super::{
 /// ```
 pub static PANASONIC_MBC1B: NomParser<Mapper> = NomParser {
     name: "Panasonic MBC1B",
-    f: |input| {
+    examples: &["DMG MBC1-B Nintendo P 0'D7"],
+    prefixes: &["DMG"],
+    f: &|input| {
         lines4(
             tag("DMG"),
             tag("MBC1-B"),
             tag("Nintendo"),
             preceded(tag("P "), date_code_sop),
         )
-        .map(|(_, _, _, date_code)| Mapper {
+        .map(|(_, _, _, (date_code, lot_code))| Mapper {
             kind: MapperChip::Mbc1B,
             manufacturer: Some(Manufacturer::Panasonic),
             date_code: Some(date_code),
+            lot_code: Some(String::from(lot_code)),
+            revision: None,
+            origin_country: None,
         })
         .parse(input)
     },
+    format: Some(&|mapper| sop_label("MBC1-B", mapper)),
 };
 
 /// Panasonic MBC2A (SOP-28)
@@ -52,20 +53,26 @@ pub static PANASONIC_MBC1B: NomParser<Mapper> = NomParser {
 /// ```
 pub static PANASONIC_MBC2A: NomParser<Mapper> = NomParser {
     name: "Panasonic MBC2A",
-    f: |input| {
+    examples: &["DMG MBC2-A Nintendo P 8'73"],
+    prefixes: &["DMG"],
+    f: &|input| {
         lines4(
             tag("DMG"),
             tag("MBC2-A"),
             tag("Nintendo"),
             preceded(tag("P "), date_code_sop),
         )
-        .map(|(_, _, _, date_code)| Mapper {
+        .map(|(_, _, _, (date_code, lot_code))| Mapper {
             kind: MapperChip::Mbc2A,
             manufacturer: Some(Manufacturer::Panasonic),
             date_code: Some(date_code),
+            lot_code: Some(String::from(lot_code)),
+            revision: None,
+            origin_country: None,
         })
         .parse(input)
     },
+    format: Some(&|mapper| sop_label("MBC2-A", mapper)),
 };
 
 /// Panasonic MBC3A (QFP-32)
@@ -76,15 +83,21 @@ pub static PANASONIC_MBC2A: NomParser<Mapper> = NomParser {
 /// ```
 pub static PANASONIC_MBC3A: NomParser<Mapper> = NomParser {
     name: "Panasonic MBC3A",
-    f: |input| {
+    examples: &["MBC3 A P-2 834U4E"],
+    prefixes: &["MBC3 A"],
+    f: &|input| {
         lines3(tag("MBC3 A"), tag("P-2"), date_code_qfp)
-            .map(|(_, _, date_code)| Mapper {
+            .map(|(_, mark, (date_code, lot_code))| Mapper {
                 kind: MapperChip::Mbc3A,
                 manufacturer: Some(Manufacturer::Panasonic),
                 date_code: Some(date_code),
+                lot_code: Some(format!("{mark} {lot_code}")),
+                revision: None,
+                origin_country: None,
             })
             .parse(input)
     },
+    format: Some(&|mapper| qfp_label("MBC3 A", mapper)),
 };
 
 /// Panasonic MBC3B (QFP-32)
@@ -95,15 +108,21 @@ pub static PANASONIC_MBC3A: NomParser<Mapper> = NomParser {
 /// ```
 pub static PANASONIC_MBC3B: NomParser<Mapper> = NomParser {
     name: "Panasonic MBC3B",
-    f: |input| {
+    examples: &["MBC3 B P-2 134U2D"],
+    prefixes: &["MBC3 B"],
+    f: &|input| {
         lines3(tag("MBC3 B"), tag("P-2"), date_code_qfp)
-            .map(|(_, _, date_code)| Mapper {
+            .map(|(_, mark, (date_code, lot_code))| Mapper {
                 kind: MapperChip::Mbc3B,
                 manufacturer: Some(Manufacturer::Panasonic),
                 date_code: Some(date_code),
+                lot_code: Some(format!("{mark} {lot_code}")),
+                revision: None,
+                origin_country: None,
             })
             .parse(input)
     },
+    format: Some(&|mapper| qfp_label("MBC3 B", mapper)),
 };
 
 /// Panasonic MBC30 (QFP-32)
@@ -114,15 +133,21 @@ pub static PANASONIC_MBC3B: NomParser<Mapper> = NomParser {
 /// ```
 pub static PANASONIC_MBC30: NomParser<Mapper> = NomParser {
     name: "Panasonic MBC30",
-    f: |input| {
+    examples: &["MBC30 P 047U2M"],
+    prefixes: &["MBC30"],
+    f: &|input| {
         lines3(tag("MBC30"), tag("P"), date_code_qfp)
-            .map(|(_, _, date_code)| Mapper {
+            .map(|(_, mark, (date_code, lot_code))| Mapper {
                 kind: MapperChip::Mbc30,
                 manufacturer: Some(Manufacturer::Panasonic),
                 date_code: Some(date_code),
+                lot_code: Some(format!("{mark} {lot_code}")),
+                revision: None,
+                origin_country: None,
             })
             .parse(input)
     },
+    format: Some(&|mapper| qfp_label("MBC30", mapper)),
 };
 
 /// Panasonic MBC5 (QFP-32)
@@ -135,19 +160,25 @@ pub static PANASONIC_MBC30: NomParser<Mapper> = NomParser {
 /// ```
 pub static PANASONIC_MBC5: NomParser<Mapper> = NomParser {
     name: "Panasonic MBC5",
-    f: |input| {
+    examples: &["MBC5 P 041U7M", "MBC5 P-1 850U3L", "MBC5 P-2 104U4M"],
+    prefixes: &["MBC5"],
+    f: &|input| {
         lines3(
             tag("MBC5"),
             tag("P-2").or(tag("P-1")).or(tag("P")),
             date_code_qfp,
         )
-        .map(|(_, _, date_code)| Mapper {
+        .map(|(_, mark, (date_code, lot_code))| Mapper {
             kind: MapperChip::Mbc5,
             manufacturer: Some(Manufacturer::Panasonic),
             date_code: Some(date_code),
+            lot_code: Some(format!("{mark} {lot_code}")),
+            revision: None,
+            origin_country: None,
         })
         .parse(input)
     },
+    format: Some(&|mapper| qfp_label("MBC5", mapper)),
 };
 
 /// Panasonic MN4464 SRAM (SOP-28, 4.5-5.5V, 64 Kibit / 8 KiB)
@@ -158,30 +189,75 @@ pub static PANASONIC_MBC5: NomParser<Mapper> = NomParser {
 /// ```
 pub static PANASONIC_MN4464: NomParser<GenericPart> = NomParser {
     name: "Panasonic MN4464",
-    f: |input| {
+    examples: &["Panasonic JAPAN MN4464S-08LL 93205B035"],
+    prefixes: &["Panasonic "],
+    f: &|input| {
         lines3(
-            tag("Panasonic JAPAN"),
+            preceded(tag("Panasonic "), country(Country::Japan)),
             recognize(tag("MN4464").and(tag("S-08LL"))),
-            (year2_week2, digits(1), alnum_uppers(1), digits(3)),
+            (
+                year2_week2,
+                recognize((digits(1), alnum_uppers(1), digits(3))),
+            ),
+        )
+        .map(
+            |(origin_country, kind, (date_code, lot_code))| GenericPart {
+                kind: String::from(kind),
+                manufacturer: Some(Manufacturer::Panasonic),
+                date_code: Some(date_code),
+                lot_code: Some(String::from(lot_code)),
+                revision: None,
+                origin_country: Some(origin_country),
+                package: Some(Package::Sop28),
+                voltage: Some(Voltage::range(4_500, 5_500)),
+                density: Some(64 * KIBIT),
+                organization: Some(Organization::new(8 * 1024, 8)),
+                access_time: dash_speed_grade(kind),
+            },
         )
-        .map(|(_, kind, (date_code, _, _, _))| GenericPart {
-            kind: String::from(kind),
-            manufacturer: Some(Manufacturer::Panasonic),
-            date_code: Some(date_code),
-        })
         .parse(input)
     },
+    format: Some(&|part| {
+        Some(format!(
+            "Panasonic {country} {kind} {date_code}{lot_code}",
+            country = part.origin_country?.label(),
+            kind = part.kind,
+            date_code = for_format::year2_week2(part.date_code?)?,
+            lot_code = part.lot_code.as_ref()?,
+        ))
+    }),
 };
 
-fn date_code_sop<'a, E: ParseError<&'a str>>(input: &'a str) -> IResult<&'a str, PartDateCode, E> {
-    terminated(
+fn sop_label(kind: &str, mapper: &Mapper) -> Option<String> {
+    let date_code = for_format::year1_month1(mapper.date_code?, for_format::month1_123ond)?;
+    let (year, month) = date_code.split_at(1);
+    Some(format!(
+        "DMG {kind} Nintendo P {year}'{month}{lot_code}",
+        lot_code = mapper.lot_code.as_ref()?,
+    ))
+}
+
+fn qfp_label(kind: &str, mapper: &Mapper) -> Option<String> {
+    let (mark, lot_code) = mapper.lot_code.as_ref()?.split_once(' ')?;
+    Some(format!(
+        "{kind} {mark} {date_code}{lot_code}",
+        date_code = for_format::year1_week2(mapper.date_code?)?,
+    ))
+}
+
+fn date_code_sop<'a, E: ParseError<&'a str>>(
+    input: &'a str,
+) -> IResult<&'a str, (PartDateCode, &'a str), E> {
+    (
         (year1, tag("'"), month1_123ond)
             .map(|(year, _, month)| PartDateCode::YearMonth { year, month }),
         digits(1),
     )
-    .parse(input)
+        .parse(input)
 }
 
-fn date_code_qfp<'a, E: ParseError<&'a str>>(input: &'a str) -> IResult<&'a str, PartDateCode, E> {
-    terminated(year1_week2, (tag("U"), digits(1), uppers(1))).parse(input)
+fn date_code_qfp<'a, E: ParseError<&'a str>>(
+    input: &'a str,
+) -> IResult<&'a str, (PartDateCode, &'a str), E> {
+    (year1_week2, recognize((tag("U"), digits(1), uppers(1)))).parse(input)
 }
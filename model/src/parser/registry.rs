@@ -0,0 +1,448 @@
+// SPDX-FileCopyrightText: Joonas Javanainen <joonas.javanainen@gmail.com>
+//
+// SPDX-License-Identifier: MIT
+
+//! Introspection of all known parser families and their member parsers.
+
+use std::{fmt::Debug, sync::OnceLock};
+
+use super::{
+    LabelFormatter, LabelParser, Manufacturer, Package, ParsedChip, Voltage, normalize_label,
+};
+use crate::{Console, config::cartridge::PartRole, parser};
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ParserInfo {
+    pub name: &'static str,
+    pub manufacturer: Option<Manufacturer>,
+    pub examples: &'static [&'static str],
+    /// True if the parser can synthesize labels from parsed parts
+    pub formattable: bool,
+}
+
+type AcceptsFn = dyn Fn(&str) -> bool + Send + Sync;
+type CandidatesFn = dyn Fn(&str) -> Vec<usize> + Send + Sync;
+type ParseAllFn = dyn Fn(&str) -> Vec<(&'static str, String)> + Send + Sync;
+type ReformatFn = dyn Fn(usize, &str) -> Option<String> + Send + Sync;
+
+/// Role of a chip that only appears on console mainboards
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+pub enum ConsoleRole {
+    Cpu,
+    Amplifier,
+    Regulator,
+    Pmic,
+    ChargeController,
+    Transformer,
+    Coil,
+    Cic,
+    Interface,
+    LcdDriver,
+    LcdScreen,
+}
+
+impl ConsoleRole {
+    pub fn display(&self) -> &'static str {
+        match self {
+            ConsoleRole::Cpu => "CPU",
+            ConsoleRole::Amplifier => "Amplifier",
+            ConsoleRole::Regulator => "Regulator",
+            ConsoleRole::Pmic => "Power management",
+            ConsoleRole::ChargeController => "Charge controller",
+            ConsoleRole::Transformer => "Transformer",
+            ConsoleRole::Coil => "Coil",
+            ConsoleRole::Cic => "CIC",
+            ConsoleRole::Interface => "Interface",
+            ConsoleRole::LcdDriver => "LCD driver",
+            ConsoleRole::LcdScreen => "LCD screen",
+        }
+    }
+}
+
+/// Role of the chips parsed by a family
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+pub enum ChipRole {
+    /// A role that is also used on cartridge boards
+    Part(PartRole),
+    Console(ConsoleRole),
+}
+
+impl ChipRole {
+    pub fn display(&self) -> &'static str {
+        match self {
+            ChipRole::Part(role) => role.display(),
+            ChipRole::Console(role) => role.display(),
+        }
+    }
+}
+
+impl From<PartRole> for ChipRole {
+    fn from(role: PartRole) -> Self {
+        ChipRole::Part(role)
+    }
+}
+
+impl From<ConsoleRole> for ChipRole {
+    fn from(role: ConsoleRole) -> Self {
+        ChipRole::Console(role)
+    }
+}
+
+pub struct ParserFamily {
+    /// Name of the family function in `gbhwdb_model::parser`
+    pub id: &'static str,
+    pub role: ChipRole,
+    /// Console the family is specific to, or None for cartridge parts
+    pub console: Option<Console>,
+    pub package: Option<Package>,
+    pub voltage: Option<Voltage>,
+    pub parsers: Vec<ParserInfo>,
+    accepts: Box<AcceptsFn>,
+    accepts_sequentially: Box<AcceptsFn>,
+    candidates: Box<CandidatesFn>,
+    parse_all: Box<ParseAllFn>,
+    reformat: Box<ReformatFn>,
+}
+
+impl ParserFamily {
+    fn new<T: ParsedChip + Debug + PartialEq + 'static>(
+        id: &'static str,
+        role: ChipRole,
+        console: Option<Console>,
+        package: Option<Package>,
+        voltage: Option<Voltage>,
+        parser: &'static impl LabelParser<T>,
+    ) -> Self {
+        let parsers = parser
+            .members()
+            .into_iter()
+            .map(|member| ParserInfo {
+                name: member.name,
+                manufacturer: member
+                    .examples
+                    .iter()
+                    .find_map(|label| member.parse(label).ok())
+                    .and_then(|chip| chip.manufacturer()),
+                examples: member.examples,
+                formattable: member.can_format(),
+            })
+            .collect();
+        let members = parser.members();
+        ParserFamily {
+            id,
+            role,
+            console,
+            package,
+            voltage,
+            parsers,
+            accepts: Box::new(|label| parser.parse(label).is_ok()),
+            accepts_sequentially: Box::new(move |label| {
+                members.iter().any(|member| member.parse(label).is_ok())
+            }),
+            candidates: Box::new(|label| parser.candidates(&normalize_label(label).canonical)),
+            parse_all: Box::new(|label| {
+                parser
+                    .parse_all(label)
+                    .into_iter()
+                    .map(|(name, chip)| (name, format!("{chip:?}")))
+                    .collect()
+            }),
+            reformat: Box::new(|index, label| {
+                let member = parser.members().into_iter().nth(index)?;
+                member.format(&member.parse(label).ok()?)
+            }),
+        }
+    }
+    /// Returns true if the family parses the label, using the same dispatch as the site build
+    pub fn accepts(&self, label: &str) -> bool {
+        (self.accepts)(label)
+    }
+    /// Returns true if any member parser accepts the label, trying every member in order
+    ///
+    /// This skips the dispatch table, and is only useful as a baseline for benchmarks.
+    pub fn accepts_sequentially(&self, label: &str) -> bool {
+        (self.accepts_sequentially)(label)
+    }
+    /// Returns the indices of the member parsers that the label is dispatched to
+    pub fn candidates(&self, label: &str) -> Vec<usize> {
+        (self.candidates)(label)
+    }
+    /// Returns the name and debug-formatted output of every member parser that accepts the label
+    pub fn parse_all(&self, label: &str) -> Vec<(&'static str, String)> {
+        (self.parse_all)(label)
+    }
+    /// Parses the label with the member parser at the given index, and formats the result back
+    /// into a label
+    pub fn reformat(&self, index: usize, label: &str) -> Option<String> {
+        (self.reformat)(index, label)
+    }
+}
+
+/// Returns all known parser families
+///
+/// ```
+/// use gbhwdb_model::parser::registry;
+/// let family = registry::families()
+///     .iter()
+///     .find(|family| family.id == "mbc5_qfp32")
+///     .unwrap();
+/// assert!(family.parsers.iter().any(|parser| parser.name == "Sharp MBC5"));
+/// ```
+pub fn families() -> &'static [ParserFamily] {
+    macro_rules! family {
+        ($id:ident, $role:expr, $console:expr, $package:expr, $voltage:expr) => {
+            ParserFamily::new(
+                stringify!($id),
+                $role.into(),
+                $console,
+                $package,
+                $voltage,
+                parser::$id(),
+            )
+        };
+    }
+    static FAMILIES: OnceLock<Vec<ParserFamily>> = OnceLock::new();
+    FAMILIES.get_or_init(|| {
+        use Console::*;
+        use ConsoleRole::*;
+        use PartRole::*;
+        vec![
+            family!(dmg_soc_qfp_80, Cpu, Some(Dmg), Some(Package::Qfp80), None),
+            family!(
+                dmg_soc_glop_top,
+                Cpu,
+                Some(Dmg),
+                Some(Package::GlopTop),
+                None
+            ),
+            family!(sgb_soc_qfp_80, Cpu, Some(Sgb), Some(Package::Qfp80), None),
+            family!(mgb_soc_qfp_80, Cpu, Some(Mgb), Some(Package::Qfp80), None),
+            family!(sgb2_soc_qfp_80, Cpu, Some(Sgb2), Some(Package::Qfp80), None),
+            family!(
+                cgb_soc_qfp_128_old,
+                Cpu,
+                Some(Cgb),
+                Some(Package::Qfp128),
+                None
+            ),
+            family!(
+                cgb_soc_qfp_128_new,
+                Cpu,
+                Some(Cgb),
+                Some(Package::Qfp128),
+                None
+            ),
+            family!(agb_soc_qfp_128, Cpu, Some(Agb), Some(Package::Qfp128), None),
+            family!(agb_soc_qfp_156, Cpu, Some(Agb), Some(Package::Qfp156), None),
+            family!(agb_soc_bga, Cpu, Some(Agb), Some(Package::Bga), None),
+            family!(dmg_amp, Amplifier, Some(Dmg), None, None),
+            family!(mgb_amp, Amplifier, Some(Mgb), None, None),
+            family!(agb_amp, Amplifier, Some(Agb), None, None),
+            family!(dmg_reg, Regulator, Some(Dmg), None, None),
+            family!(cgb_reg, Regulator, Some(Cgb), Some(Package::Ssop18), None),
+            family!(agb_reg, Regulator, Some(Agb), Some(Package::Ssop18), None),
+            family!(gbs_reg, Regulator, Some(Gbs), None, None),
+            family!(agb_pmic, Pmic, Some(Agb), None, None),
+            family!(ags_pmic_old, Pmic, Some(Ags), None, None),
+            family!(ags_pmic_new, Pmic, Some(Ags), None, None),
+            family!(oxy_pmic, Pmic, Some(Oxy), None, None),
+            family!(ags_charge_ctrl, ChargeController, Some(Ags), None, None),
+            family!(mgl_transformer, Transformer, Some(Mgl), None, None),
+            family!(sgb2_coil, Coil, Some(Sgb2), None, None),
+            family!(cic, Cic, None, None, None),
+            family!(icd2, Interface, Some(Sgb), None, None),
+            family!(gbs_dol, Interface, Some(Gbs), None, None),
+            family!(oxy_u4, Unknown, Some(Oxy), None, None),
+            family!(oxy_u5, Unknown, Some(Oxy), None, None),
+            family!(lcd_chip, LcdDriver, None, None, None),
+            family!(lcd_screen, LcdScreen, None, None, None),
+            family!(sgb_rom, Rom, Some(Sgb), None, None),
+            family!(sgb2_rom, Rom, Some(Sgb2), None, None),
+            family!(dmg_crystal, Crystal, Some(Dmg), None, None),
+            family!(mgb_crystal, Crystal, Some(Mgb), None, None),
+            family!(sgb2_crystal, Crystal, Some(Sgb2), None, None),
+            family!(cgb_crystal, Crystal, Some(Cgb), None, None),
+            family!(agb_crystal, Crystal, Some(Agb), None, None),
+            family!(ags_crystal, Crystal, Some(Ags), None, None),
+            family!(gbs_crystal, Crystal, Some(Gbs), None, None),
+            family!(rtc_crystal, Crystal, None, None, None),
+            family!(
+                gb_mask_rom_glop_top_28_5v,
+                Rom,
+                None,
+                Some(Package::GlopTop),
+                Some(Voltage::V5)
+            ),
+            family!(
+                gb_mask_rom_sop_32_5v,
+                Rom,
+                None,
+                Some(Package::Sop32),
+                Some(Voltage::V5)
+            ),
+            family!(
+                gb_mask_rom_sop_44_5v,
+                Rom,
+                None,
+                Some(Package::Sop44),
+                Some(Voltage::V5)
+            ),
+            family!(
+                gb_mask_rom_tsop_i_32_5v,
+                Rom,
+                None,
+                Some(Package::TsopI32),
+                Some(Voltage::V5)
+            ),
+            family!(
+                gb_mask_rom_tsop_ii_44_5v,
+                Rom,
+                None,
+                Some(Package::TsopIi44),
+                Some(Voltage::V5)
+            ),
+            family!(
+                gb_mask_rom_qfp_44_5v,
+                Rom,
+                None,
+                Some(Package::Qfp44),
+                Some(Voltage::V5)
+            ),
+            family!(
+                agb_mask_rom_tsop_ii_44_3v3,
+                Rom,
+                None,
+                Some(Package::TsopIi44),
+                Some(Voltage::V3_3)
+            ),
+            family!(mbc1_glop_top, Mapper, None, Some(Package::GlopTop), None),
+            family!(mbc1_sop24, Mapper, None, Some(Package::Sop24), None),
+            family!(mbc2_sop28, Mapper, None, Some(Package::Sop28), None),
+            family!(mbc3_qfp32, Mapper, None, Some(Package::Qfp32), None),
+            family!(mbc30_qfp32, Mapper, None, Some(Package::Qfp32), None),
+            family!(mbc5_qfp32, Mapper, None, Some(Package::Qfp32), None),
+            family!(mbc6_qfp64, Mapper, None, Some(Package::Qfp64), None),
+            family!(mbc7_qfp56, Mapper, None, Some(Package::Qfp56), None),
+            family!(mmm01_qfp32, Mapper, None, Some(Package::Qfp32), None),
+            family!(huc1_qfp32, Mapper, None, Some(Package::Qfp32), None),
+            family!(huc3_qfp48, Mapper, None, Some(Package::Qfp48), None),
+            family!(
+                sram_sop_28_5v,
+                Ram,
+                None,
+                Some(Package::Sop28),
+                Some(Voltage::V5)
+            ),
+            family!(
+                sram_sop_28_3v3,
+                Ram,
+                None,
+                Some(Package::Sop28),
+                Some(Voltage::V3_3)
+            ),
+            family!(
+                sram_sop_32_5v,
+                Ram,
+                None,
+                Some(Package::Sop32),
+                Some(Voltage::V5)
+            ),
+            family!(
+                sram_tsop_i_28_3v3,
+                Ram,
+                None,
+                Some(Package::TsopI28),
+                Some(Voltage::V3_3)
+            ),
+            family!(
+                sram_tsop_i_28_5v,
+                Ram,
+                None,
+                Some(Package::TsopI28),
+                Some(Voltage::V5)
+            ),
+            family!(sram_tsop_i_48, Ram, None, Some(Package::TsopI48), None),
+            family!(
+                fram_sop_28_3v3,
+                Ram,
+                None,
+                Some(Package::Sop28),
+                Some(Voltage::V3_3)
+            ),
+            family!(
+                flash_tsop_i_32_3v3,
+                Flash,
+                None,
+                Some(Package::TsopI32),
+                Some(Voltage::V3_3)
+            ),
+            family!(
+                flash_tsop_i_40_5v,
+                Flash,
+                None,
+                Some(Package::TsopI40),
+                Some(Voltage::V5)
+            ),
+            family!(
+                eeprom_sop_8_3v3,
+                Eeprom,
+                None,
+                Some(Package::Sop8),
+                Some(Voltage::V3_3)
+            ),
+            family!(
+                eeprom_tssop_8_5v,
+                Eeprom,
+                None,
+                Some(Package::Tssop8),
+                Some(Voltage::V5)
+            ),
+            family!(rtc_sop_8, Rtc, None, Some(Package::Sop8), None),
+            family!(rtc_sop_20, Rtc, None, Some(Package::Sop20), None),
+            family!(supervisor_reset, SupervisorReset, None, None, None),
+            family!(line_decoder, LineDecoder, None, None, None),
+            family!(hex_inverter, HexInverter, None, None, None),
+            family!(battery, Battery, None, None, None),
+        ]
+    })
+}
+
+#[test]
+fn test_examples() {
+    for family in families() {
+        for parser in &family.parsers {
+            for label in parser.examples {
+                assert!(
+                    family
+                        .parse_all(label)
+                        .iter()
+                        .any(|(name, _)| *name == parser.name),
+                    "{}: {} rejects its own example {label:?}",
+                    family.id,
+                    parser.name
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn test_round_trip() {
+    for family in families() {
+        for (index, parser) in family.parsers.iter().enumerate() {
+            if !parser.formattable {
+                continue;
+            }
+            for label in parser.examples {
+                let canonical = parser::normalize_label(label).canonical;
+                assert_eq!(
+                    family.reformat(index, label).as_deref(),
+                    Some(canonical.as_str()),
+                    "{}: {} does not round-trip its own example",
+                    family.id,
+                    parser.name
+                );
+            }
+        }
+    }
+}
@@ -5,15 +5,15 @@
 use nom::{
     Parser as _,
     branch::alt,
-    bytes::streaming::tag,
     character::streaming::{char, one_of},
     combinator::{opt, recognize},
-    sequence::{preceded, terminated},
+    sequence::{preceded, separated_pair},
 };
 
 use super::{
-    GenericPart, Manufacturer, Mapper, MapperChip, NomParser, PartDateCode,
-    for_nom::{alnum_uppers, digits, lines2, lines3, month1_123abc, year1, year1_week2},
+    GenericPart, KIBIT, Manufacturer, Mapper, MapperChip, NomParser, Organization, Package,
+    PartDateCode, Voltage, dash_speed_grade, for_format,
+    for_nom::{alnum_uppers, digits, lines2, lines3, month1_123abc, tag, year1, year1_week2},
 };
 
 /// ROHM ??9853 EEPROM (SOP-8)
@@ -25,15 +25,21 @@ use super::{
 /// ```
 pub static ROHM_9853: NomParser<GenericPart> = NomParser {
     name: "ROHM 9853",
-    f: |input| {
+    examples: &["9853 2A46", "9853 6912"],
+    prefixes: &["9853"],
+    f: &|input| {
         lines2(tag("9853"), (year1, month1_123abc, digits(2)))
-            .map(|(kind, (year, month, _))| GenericPart {
+            .map(|(kind, (year, month, lot_code))| GenericPart {
                 kind: String::from(kind),
                 manufacturer: Some(Manufacturer::Rohm),
                 date_code: Some(PartDateCode::YearMonth { year, month }),
+                lot_code: Some(String::from(lot_code)),
+                package: Some(Package::Sop8),
+                ..GenericPart::default()
             })
             .parse(input)
     },
+    format: Some(&|part| year_month_label(&part.kind, part)),
 };
 
 /// ROHM ??9854 EEPROM (SOP-8)
@@ -44,15 +50,24 @@ pub static ROHM_9853: NomParser<GenericPart> = NomParser {
 /// ```
 pub static ROHM_9854: NomParser<GenericPart> = NomParser {
     name: "ROHM 9854",
-    f: |input| {
-        lines2(tag("9854"), (year1, alnum_uppers(1), digits(2), char('W')))
-            .map(|(kind, (year, _, _, _))| GenericPart {
-                kind: String::from(kind),
-                manufacturer: Some(Manufacturer::Rohm),
-                date_code: Some(PartDateCode::Year { year }),
-            })
-            .parse(input)
+    examples: &["9854 5S95W"],
+    prefixes: &["9854"],
+    f: &|input| {
+        lines2(
+            tag("9854"),
+            year1.and(recognize((alnum_uppers(1), digits(2), char('W')))),
+        )
+        .map(|(kind, (year, lot_code))| GenericPart {
+            kind: String::from(kind),
+            manufacturer: Some(Manufacturer::Rohm),
+            date_code: Some(PartDateCode::Year { year }),
+            lot_code: Some(String::from(lot_code)),
+            package: Some(Package::Sop8),
+            ..GenericPart::default()
+        })
+        .parse(input)
     },
+    format: Some(&|part| year_label(&part.kind, part)),
 };
 
 /// ROHM BA6129 supervisor
@@ -64,21 +79,25 @@ pub static ROHM_9854: NomParser<GenericPart> = NomParser {
 /// ```
 pub static ROHM_BA6129: NomParser<GenericPart> = NomParser {
     name: "ROHM BA6129",
-    f: |input| {
+    examples: &["6129 4803", "6129A 6194"],
+    prefixes: &["6129"],
+    f: &|input| {
         (
             alt((tag("6129A"), tag("6129"))),
             char(' '),
             year1,
-            alnum_uppers(1),
-            digits(2),
+            recognize(alnum_uppers(1).and(digits(2))),
         )
-            .map(|(kind, _, year, _, _)| GenericPart {
+            .map(|(kind, _, year, lot_code)| GenericPart {
                 kind: format!("BA{kind}"),
                 manufacturer: Some(Manufacturer::Rohm),
                 date_code: Some(PartDateCode::Year { year }),
+                lot_code: Some(String::from(lot_code)),
+                ..GenericPart::default()
             })
             .parse(input)
     },
+    format: Some(&|part| year_label(part.kind.strip_prefix("BA")?, part)),
 };
 
 /// ROHM BA6735 supervisor
@@ -89,15 +108,20 @@ pub static ROHM_BA6129: NomParser<GenericPart> = NomParser {
 /// ```
 pub static ROHM_BA6735: NomParser<GenericPart> = NomParser {
     name: "ROHM BA6735",
-    f: |input| {
+    examples: &["6735 8C19"],
+    prefixes: &["6735"],
+    f: &|input| {
         (tag("6735"), char(' '), year1, month1_123abc, digits(2))
-            .map(|(_, _, year, month, _)| GenericPart {
+            .map(|(_, _, year, month, lot_code)| GenericPart {
                 kind: "BA6735".to_owned(),
                 manufacturer: Some(Manufacturer::Rohm),
                 date_code: Some(PartDateCode::YearMonth { year, month }),
+                lot_code: Some(String::from(lot_code)),
+                ..GenericPart::default()
             })
             .parse(input)
     },
+    format: Some(&|part| year_month_label(part.kind.strip_prefix("BA")?, part)),
 };
 
 /// ROHM ??9750
@@ -109,7 +133,9 @@ pub static ROHM_BA6735: NomParser<GenericPart> = NomParser {
 /// ```
 pub static ROHM_9750: NomParser<GenericPart> = NomParser {
     name: "ROHM 9750",
-    f: |input| {
+    examples: &["9750A 1581", "9750B 2A69"],
+    prefixes: &["9750"],
+    f: &|input| {
         (
             recognize(tag("9750").and(one_of("AB"))),
             char(' '),
@@ -117,13 +143,16 @@ pub static ROHM_9750: NomParser<GenericPart> = NomParser {
             month1_123abc,
             digits(2),
         )
-            .map(|(kind, _, year, month, _)| GenericPart {
+            .map(|(kind, _, year, month, lot_code)| GenericPart {
                 kind: String::from(kind),
                 manufacturer: Some(Manufacturer::Rohm),
                 date_code: Some(PartDateCode::YearMonth { year, month }),
+                lot_code: Some(String::from(lot_code)),
+                ..GenericPart::default()
             })
             .parse(input)
     },
+    format: Some(&|part| year_month_label(&part.kind, part)),
 };
 
 /// ROHM ??9753
@@ -134,15 +163,20 @@ pub static ROHM_9750: NomParser<GenericPart> = NomParser {
 /// ```
 pub static ROHM_9753: NomParser<GenericPart> = NomParser {
     name: "ROHM 9753",
-    f: |input| {
+    examples: &["9753 4862"],
+    prefixes: &["9753"],
+    f: &|input| {
         (tag("9753"), char(' '), year1, month1_123abc, digits(2))
-            .map(|(kind, _, year, month, _)| GenericPart {
+            .map(|(kind, _, year, month, lot_code)| GenericPart {
                 kind: String::from(kind),
                 manufacturer: Some(Manufacturer::Rohm),
                 date_code: Some(PartDateCode::YearMonth { year, month }),
+                lot_code: Some(String::from(lot_code)),
+                ..GenericPart::default()
             })
             .parse(input)
     },
+    format: Some(&|part| year_month_label(&part.kind, part)),
 };
 
 /// ROHM BH7835AFS AGB amplifier
@@ -153,22 +187,26 @@ pub static ROHM_9753: NomParser<GenericPart> = NomParser {
 /// ```
 pub static ROHM_BH7835AFS: NomParser<GenericPart> = NomParser {
     name: "ROHM BH7835AFS",
-    f: |input| {
+    examples: &["BH7835AFS 337 T22"],
+    prefixes: &["BH7835AFS"],
+    f: &|input| {
         (
             tag("BH7835AFS"),
             char(' '),
             year1_week2,
             char(' '),
-            alnum_uppers(1),
-            digits(2),
+            recognize(alnum_uppers(1).and(digits(2))),
         )
-            .map(|(kind, _, date_code, _, _, _)| GenericPart {
+            .map(|(kind, _, date_code, _, lot_code)| GenericPart {
                 kind: String::from(kind),
                 manufacturer: Some(Manufacturer::Rohm),
                 date_code: Some(date_code),
+                lot_code: Some(String::from(lot_code)),
+                ..GenericPart::default()
             })
             .parse(input)
     },
+    format: Some(&|part| year_week_label(&part.kind, part.date_code, part.lot_code.as_deref())),
 };
 
 /// ROHM ICD2-R
@@ -179,22 +217,32 @@ pub static ROHM_BH7835AFS: NomParser<GenericPart> = NomParser {
 /// ```
 pub static ROHM_ICD2_R: NomParser<GenericPart> = NomParser {
     name: "ROHM ICD2_R",
-    f: |input| {
+    examples: &["Nintendo ICD2-R 435 179"],
+    prefixes: &["Nintendo "],
+    f: &|input| {
         (
             preceded(tag("Nintendo "), tag("ICD2-R")),
             char(' '),
             year1_week2,
             char(' '),
-            alnum_uppers(1),
-            digits(2),
+            recognize(alnum_uppers(1).and(digits(2))),
         )
-            .map(|(kind, _, date_code, _, _, _)| GenericPart {
+            .map(|(kind, _, date_code, _, lot_code)| GenericPart {
                 kind: String::from(kind),
                 manufacturer: Some(Manufacturer::Rohm),
                 date_code: Some(date_code),
+                lot_code: Some(String::from(lot_code)),
+                ..GenericPart::default()
             })
             .parse(input)
     },
+    format: Some(&|part| {
+        year_week_label(
+            &format!("Nintendo {}", part.kind),
+            part.date_code,
+            part.lot_code.as_deref(),
+        )
+    }),
 };
 
 /// ROHM MBC3 (QFP-32)
@@ -205,19 +253,31 @@ pub static ROHM_ICD2_R: NomParser<GenericPart> = NomParser {
 /// ```
 pub static ROHM_MBC3: NomParser<Mapper> = NomParser {
     name: "ROHM MBC3",
-    f: |input| {
+    examples: &["MBC3 BU3631K 802 127"],
+    prefixes: &["MBC3"],
+    f: &|input| {
         lines3(
             tag("MBC3"),
             tag("BU3631K"),
-            terminated(year1_week2, (tag(" "), alnum_uppers(1), digits(2))),
+            separated_pair(
+                year1_week2,
+                tag(" "),
+                recognize(alnum_uppers(1).and(digits(2))),
+            ),
         )
-        .map(|(_, _, date_code)| Mapper {
+        .map(|(_, _, (date_code, lot_code))| Mapper {
             kind: MapperChip::Mbc3,
             manufacturer: Some(Manufacturer::Rohm),
             date_code: Some(date_code),
+            lot_code: Some(String::from(lot_code)),
+            revision: None,
+            origin_country: None,
         })
         .parse(input)
     },
+    format: Some(&|mapper| {
+        year_week_label("MBC3 BU3631K", mapper.date_code, mapper.lot_code.as_deref())
+    }),
 };
 
 /// ROHM MBC3A (QFP-32)
@@ -228,19 +288,35 @@ pub static ROHM_MBC3: NomParser<Mapper> = NomParser {
 /// ```
 pub static ROHM_MBC3A: NomParser<Mapper> = NomParser {
     name: "ROHM MBC3A",
-    f: |input| {
+    examples: &["MBC-3 A BU3632K 004 H64"],
+    prefixes: &["MBC-3 A"],
+    f: &|input| {
         lines3(
             tag("MBC-3 A"),
             tag("BU3632K"),
-            terminated(year1_week2, (tag(" "), alnum_uppers(1), digits(2))),
+            separated_pair(
+                year1_week2,
+                tag(" "),
+                recognize(alnum_uppers(1).and(digits(2))),
+            ),
         )
-        .map(|(_, _, date_code)| Mapper {
+        .map(|(_, _, (date_code, lot_code))| Mapper {
             kind: MapperChip::Mbc3A,
             manufacturer: Some(Manufacturer::Rohm),
             date_code: Some(date_code),
+            lot_code: Some(String::from(lot_code)),
+            revision: None,
+            origin_country: None,
         })
         .parse(input)
     },
+    format: Some(&|mapper| {
+        year_week_label(
+            "MBC-3 A BU3632K",
+            mapper.date_code,
+            mapper.lot_code.as_deref(),
+        )
+    }),
 };
 
 /// ROHM MBC3B (QFP-32)
@@ -251,19 +327,35 @@ pub static ROHM_MBC3A: NomParser<Mapper> = NomParser {
 /// ```
 pub static ROHM_MBC3B: NomParser<Mapper> = NomParser {
     name: "ROHM MBC3B",
-    f: |input| {
+    examples: &["MBC-3 B BU3634K 135 H48"],
+    prefixes: &["MBC-3 B"],
+    f: &|input| {
         lines3(
             tag("MBC-3 B"),
             tag("BU3634K"),
-            terminated(year1_week2, (tag(" "), alnum_uppers(1), digits(2))),
+            separated_pair(
+                year1_week2,
+                tag(" "),
+                recognize(alnum_uppers(1).and(digits(2))),
+            ),
         )
-        .map(|(_, _, date_code)| Mapper {
+        .map(|(_, _, (date_code, lot_code))| Mapper {
             kind: MapperChip::Mbc3B,
             manufacturer: Some(Manufacturer::Rohm),
             date_code: Some(date_code),
+            lot_code: Some(String::from(lot_code)),
+            revision: None,
+            origin_country: None,
         })
         .parse(input)
     },
+    format: Some(&|mapper| {
+        year_week_label(
+            "MBC-3 B BU3634K",
+            mapper.date_code,
+            mapper.lot_code.as_deref(),
+        )
+    }),
 };
 
 /// ROHM MBC30 (QFP-32)
@@ -274,19 +366,35 @@ pub static ROHM_MBC3B: NomParser<Mapper> = NomParser {
 /// ```
 pub static ROHM_MBC30: NomParser<Mapper> = NomParser {
     name: "ROHM MBC30",
-    f: |input| {
+    examples: &["MBC-30 BU3633AK 046 175"],
+    prefixes: &["MBC-30"],
+    f: &|input| {
         lines3(
             tag("MBC-30"),
             tag("BU3633AK"),
-            terminated(year1_week2, (tag(" "), alnum_uppers(1), digits(2))),
+            separated_pair(
+                year1_week2,
+                tag(" "),
+                recognize(alnum_uppers(1).and(digits(2))),
+            ),
         )
-        .map(|(_, _, date_code)| Mapper {
+        .map(|(_, _, (date_code, lot_code))| Mapper {
             kind: MapperChip::Mbc30,
             manufacturer: Some(Manufacturer::Rohm),
             date_code: Some(date_code),
+            lot_code: Some(String::from(lot_code)),
+            revision: None,
+            origin_country: None,
         })
         .parse(input)
     },
+    format: Some(&|mapper| {
+        year_week_label(
+            "MBC-30 BU3633AK",
+            mapper.date_code,
+            mapper.lot_code.as_deref(),
+        )
+    }),
 };
 
 /// ROHM MBC5 (QFP-32)
@@ -298,19 +406,29 @@ pub static ROHM_MBC30: NomParser<Mapper> = NomParser {
 /// ```
 pub static ROHM_MBC5: NomParser<Mapper> = NomParser {
     name: "ROHM MBC5",
-    f: |input| {
+    examples: &["MBC5 BU3650K 229 H51", "MBC-5 BU3650K 049 186"],
+    prefixes: &["MBC5", "MBC-5"],
+    f: &|input| {
         lines3(
             tag("MBC5").or(tag("MBC-5")),
             tag("BU3650K"),
-            terminated(year1_week2, (tag(" "), alnum_uppers(1), digits(2))),
+            separated_pair(
+                year1_week2,
+                tag(" "),
+                recognize(alnum_uppers(1).and(digits(2))),
+            ),
         )
-        .map(|(_, _, date_code)| Mapper {
+        .map(|(_, _, (date_code, lot_code))| Mapper {
             kind: MapperChip::Mbc5,
             manufacturer: Some(Manufacturer::Rohm),
             date_code: Some(date_code),
+            lot_code: Some(String::from(lot_code)),
+            revision: None,
+            origin_country: None,
         })
         .parse(input)
     },
+    format: None,
 };
 
 /// ROHM MBC7 (QFP-56)
@@ -321,19 +439,35 @@ pub static ROHM_MBC5: NomParser<Mapper> = NomParser {
 /// ```
 pub static ROHM_MBC7: NomParser<Mapper> = NomParser {
     name: "ROHM MBC7",
-    f: |input| {
+    examples: &["MBC-7 BU3667KS 041 170"],
+    prefixes: &["MBC-7"],
+    f: &|input| {
         lines3(
             tag("MBC-7"),
             tag("BU3667KS"),
-            terminated(year1_week2, (tag(" "), alnum_uppers(1), digits(2))),
+            separated_pair(
+                year1_week2,
+                tag(" "),
+                recognize(alnum_uppers(1).and(digits(2))),
+            ),
         )
-        .map(|(_, _, date_code)| Mapper {
+        .map(|(_, _, (date_code, lot_code))| Mapper {
             kind: MapperChip::Mbc7,
             manufacturer: Some(Manufacturer::Rohm),
             date_code: Some(date_code),
+            lot_code: Some(String::from(lot_code)),
+            revision: None,
+            origin_country: None,
         })
         .parse(input)
     },
+    format: Some(&|mapper| {
+        year_week_label(
+            "MBC-7 BU3667KS",
+            mapper.date_code,
+            mapper.lot_code.as_deref(),
+        )
+    }),
 };
 
 /// ROHM BR62256F (SOP-28, 4.5-5.5V)
@@ -346,28 +480,43 @@ pub static ROHM_MBC7: NomParser<Mapper> = NomParser {
 /// ```
 pub static ROHM_BR62256F: NomParser<GenericPart> = NomParser {
     name: "ROHM BR62256F",
-    f: |input| {
+    examples: &[
+        "BR62256F-70LL 817 126",
+        "BR62256F-70LL 845 131A",
+        "BR62256F-70LL 031 150NA",
+    ],
+    prefixes: &["BR62256F-70LL"],
+    f: &|input| {
         lines2(
             tag("BR62256F-70LL"),
-            terminated(
+            separated_pair(
                 year1_week2,
-                (
-                    tag(" "),
+                tag(" "),
+                recognize((
                     digits(3),
                     opt(alt((
                         nom::bytes::complete::tag("NA"),
                         nom::bytes::complete::tag("A"),
                     ))),
-                ),
+                )),
             ),
         )
-        .map(|(kind, date_code)| GenericPart {
+        .map(|(kind, (date_code, lot_code))| GenericPart {
             kind: String::from(kind),
             manufacturer: Some(Manufacturer::Rohm),
             date_code: Some(date_code),
+            lot_code: Some(String::from(lot_code)),
+            revision: None,
+            origin_country: None,
+            package: Some(Package::Sop28),
+            voltage: Some(Voltage::range(4_500, 5_500)),
+            density: Some(256 * KIBIT),
+            organization: Some(Organization::new(32 * 1024, 8)),
+            access_time: dash_speed_grade(kind),
         })
         .parse(input)
     },
+    format: Some(&|part| year_week_label(&part.kind, part.date_code, part.lot_code.as_deref())),
 };
 
 /// ROHM BR6265BF (SOP-28, 4.5-5.5V, 64 Kibit / 8 KiB)
@@ -378,18 +527,29 @@ pub static ROHM_BR62256F: NomParser<GenericPart> = NomParser {
 /// ```
 pub static ROHM_BR6265BF: NomParser<GenericPart> = NomParser {
     name: "ROHM BR6265BF",
-    f: |input| {
+    examples: &["BR6265BF-10SL 737 189N"],
+    prefixes: &["BR6265BF-10SL"],
+    f: &|input| {
         lines2(
             tag("BR6265BF-10SL"),
-            terminated(year1_week2, (tag(" "), digits(3), tag("N"))),
+            separated_pair(year1_week2, tag(" "), recognize(digits(3).and(tag("N")))),
         )
-        .map(|(kind, date_code)| GenericPart {
+        .map(|(kind, (date_code, lot_code))| GenericPart {
             kind: String::from(kind),
             manufacturer: Some(Manufacturer::Rohm),
             date_code: Some(date_code),
+            lot_code: Some(String::from(lot_code)),
+            revision: None,
+            origin_country: None,
+            package: Some(Package::Sop28),
+            voltage: Some(Voltage::range(4_500, 5_500)),
+            density: Some(64 * KIBIT),
+            organization: Some(Organization::new(8 * 1024, 8)),
+            access_time: dash_speed_grade(kind),
         })
         .parse(input)
     },
+    format: Some(&|part| year_week_label(&part.kind, part.date_code, part.lot_code.as_deref())),
 };
 
 /// ROHM XLJ6265AF (SOP-28, 5V, 64 Kibit / 8 KiB)
@@ -400,18 +560,29 @@ pub static ROHM_BR6265BF: NomParser<GenericPart> = NomParser {
 /// ```
 pub static ROHM_XLJ6265AF: NomParser<GenericPart> = NomParser {
     name: "ROHM XLJ6265AF",
-    f: |input| {
+    examples: &["XLJ6265AF-10SL 437 159"],
+    prefixes: &["XLJ6265AF-10SL"],
+    f: &|input| {
         lines2(
             tag("XLJ6265AF-10SL"),
-            terminated(year1_week2, (tag(" "), digits(3))),
+            separated_pair(year1_week2, tag(" "), digits(3)),
         )
-        .map(|(kind, date_code)| GenericPart {
+        .map(|(kind, (date_code, lot_code))| GenericPart {
             kind: String::from(kind),
             manufacturer: Some(Manufacturer::Rohm),
             date_code: Some(date_code),
+            lot_code: Some(String::from(lot_code)),
+            revision: None,
+            origin_country: None,
+            package: Some(Package::Sop28),
+            voltage: Some(Voltage::V5),
+            density: Some(64 * KIBIT),
+            organization: Some(Organization::new(8 * 1024, 8)),
+            access_time: dash_speed_grade(kind),
         })
         .parse(input)
     },
+    format: Some(&|part| year_week_label(&part.kind, part.date_code, part.lot_code.as_deref())),
 };
 
 /// ROHM XLJ6265BF (SOP-28, 5V, 64 Kibit / 8 KiB)
@@ -422,16 +593,58 @@ pub static ROHM_XLJ6265AF: NomParser<GenericPart> = NomParser {
 /// ```
 pub static ROHM_XLJ6265BF: NomParser<GenericPart> = NomParser {
     name: "ROHM XLJ6265BF",
-    f: |input| {
+    examples: &["XLJ6265BF-10SL 640 171N"],
+    prefixes: &["XLJ6265BF-10SL"],
+    f: &|input| {
         lines2(
             tag("XLJ6265BF-10SL"),
-            terminated(year1_week2, (tag(" "), digits(3), tag("N"))),
+            separated_pair(year1_week2, tag(" "), recognize(digits(3).and(tag("N")))),
         )
-        .map(|(kind, date_code)| GenericPart {
+        .map(|(kind, (date_code, lot_code))| GenericPart {
             kind: String::from(kind),
             manufacturer: Some(Manufacturer::Rohm),
             date_code: Some(date_code),
+            lot_code: Some(String::from(lot_code)),
+            revision: None,
+            origin_country: None,
+            package: Some(Package::Sop28),
+            voltage: Some(Voltage::V5),
+            density: Some(64 * KIBIT),
+            organization: Some(Organization::new(8 * 1024, 8)),
+            access_time: dash_speed_grade(kind),
         })
         .parse(input)
     },
+    format: Some(&|part| year_week_label(&part.kind, part.date_code, part.lot_code.as_deref())),
 };
+
+fn year_label(kind: &str, part: &GenericPart) -> Option<String> {
+    let PartDateCode::Year { year } = part.date_code? else {
+        return None;
+    };
+    Some(format!(
+        "{kind} {year}{lot_code}",
+        year = for_format::year1(year)?,
+        lot_code = part.lot_code.as_ref()?,
+    ))
+}
+
+fn year_month_label(kind: &str, part: &GenericPart) -> Option<String> {
+    Some(format!(
+        "{kind} {date_code}{lot_code}",
+        date_code = for_format::year1_month1(part.date_code?, for_format::month1_123abc)?,
+        lot_code = part.lot_code.as_ref()?,
+    ))
+}
+
+fn year_week_label(
+    kind: &str,
+    date_code: Option<PartDateCode>,
+    lot_code: Option<&str>,
+) -> Option<String> {
+    Some(format!(
+        "{kind} {} {}",
+        for_format::year1_week2(date_code?)?,
+        lot_code?
+    ))
+}
@@ -5,7 +5,6 @@
 use nom::{
     Parser,
     branch::alt,
-    bytes::streaming::tag,
     character::{complete::one_of, streaming::char},
     combinator::{opt, recognize},
     error::ParseError,
@@ -13,8 +12,8 @@ use nom::{
 };
 
 use super::{
-    GameMaskRom, GameRomType, Manufacturer, NomParser,
-    for_nom::{alnum_uppers, cgb_rom_code, digits, dmg_rom_code, lines3, uppers},
+    GameMaskRom, GameRomType, Manufacturer, NomParser, Voltage,
+    for_nom::{alnum_uppers, cgb_rom_code, digits, dmg_rom_code, lines3, tag, uppers},
 };
 
 fn gb_km23c_old<'a, E: ParseError<&'a str>>(
@@ -45,13 +44,21 @@ fn gb_km23c_old<'a, E: ParseError<&'a str>>(
                 .and(uppers(1)),
         ),
     )
-    .map(move |(kind, (rom_id, _), _)| GameMaskRom {
+    .map(move |(kind, (rom_id, _), lot_code)| GameMaskRom {
         rom_id: String::from(rom_id),
         rom_type,
         manufacturer: Some(Manufacturer::Samsung),
         chip_type: Some(String::from(kind)),
         mask_code: None,
         date_code: None,
+        lot_code: Some(String::from(lot_code)),
+        revision: None,
+        origin_country: None,
+        package: Some(rom_type.package()),
+        voltage: Some(Voltage::V5),
+        density: rom_type.density(),
+        organization: None,
+        access_time: None,
     })
 }
 
@@ -78,13 +85,21 @@ fn gb_km23c_new<'a, E: ParseError<&'a str>>(
         ),
         recognize(tag(unknown2).and(digits(3)).and(uppers(2))),
     )
-    .map(move |(kind, (rom_id, _), _)| GameMaskRom {
+    .map(move |(kind, (rom_id, _), lot_code)| GameMaskRom {
         rom_id: String::from(rom_id),
         rom_type,
         manufacturer: Some(Manufacturer::Samsung),
         chip_type: Some(String::from(kind)),
         mask_code: None,
         date_code: None,
+        lot_code: Some(String::from(lot_code)),
+        revision: None,
+        origin_country: None,
+        package: Some(rom_type.package()),
+        voltage: Some(Voltage::V5),
+        density: rom_type.density(),
+        organization: None,
+        access_time: None,
     })
 }
 
@@ -94,9 +109,22 @@ fn gb_km23c_new<'a, E: ParseError<&'a str>>(
 /// use gbhwdb_model::parser::{self, LabelParser};
 /// assert!(parser::samsung::SAMSUNG_KM23C4000.parse("SEC KM23C4000DG DMG-ATEA-0 E1 KF5304U").is_ok());
 /// ```
+fn km23c_label(rom: &GameMaskRom) -> Option<String> {
+    Some(format!(
+        "SEC {kind} {rom_id} {rom_type} {lot_code}",
+        kind = rom.chip_type.as_ref()?,
+        rom_id = rom.rom_id,
+        rom_type = rom.rom_type.as_str(),
+        lot_code = rom.lot_code.as_ref()?,
+    ))
+}
+
 pub static SAMSUNG_KM23C4000: NomParser<GameMaskRom> = NomParser {
     name: "Samsung KM23C4000",
-    f: |input| gb_km23c_old("4000", Package::Sop, GameRomType::E1, "KF5").parse(input),
+    examples: &["SEC KM23C4000DG DMG-ATEA-0 E1 KF5304U"],
+    prefixes: &["SEC "],
+    f: &|input| gb_km23c_old("4000", Package::Sop, GameRomType::E1, "KF5").parse(input),
+    format: Some(&km23c_label),
 };
 
 /// Samsung KM23C8000 (SOP-32, 5V, 8 Mibit / 1 MiB)
@@ -108,7 +136,13 @@ pub static SAMSUNG_KM23C4000: NomParser<GameMaskRom> = NomParser {
 /// ```
 pub static SAMSUNG_KM23C8000: NomParser<GameMaskRom> = NomParser {
     name: "Samsung KM23C8000",
-    f: |input| gb_km23c_old("8000", Package::Sop, GameRomType::F1, "KFX").parse(input),
+    examples: &[
+        "SEC KM23C8000DG DMG-APSJ-0 F1 KFX3ALY",
+        "SEC KM23C8000DG DMG-AAUJ-1 F1 KFX331U",
+    ],
+    prefixes: &["SEC "],
+    f: &|input| gb_km23c_old("8000", Package::Sop, GameRomType::F1, "KFX").parse(input),
+    format: Some(&km23c_label),
 };
 
 /// Samsung KM23C16120 (TSOP-II-44, 5V, 16 Mibit / 2 MiB)
@@ -121,13 +155,20 @@ pub static SAMSUNG_KM23C8000: NomParser<GameMaskRom> = NomParser {
 /// ```
 pub static SAMSUNG_KM23C16120: NomParser<GameMaskRom> = NomParser {
     name: "Samsung KM23C16120",
-    f: |input| {
+    examples: &[
+        "SEC KM23C16120T DMG-ADQJ-0 G2 KF6402G",
+        "SEC KM23C16120DT DMG-AWLP-0 G2 KF6409G",
+        "SEC KM23C16120DT CGB-BHMJ-0 G2 K3N5C317GD",
+    ],
+    prefixes: &["SEC "],
+    f: &|input| {
         alt((
             gb_km23c_old("16120", Package::Tsop, GameRomType::G2, "KF6"),
             gb_km23c_new("16120", Package::Tsop, GameRomType::G2, "K3N5C"),
         ))
         .parse(input)
     },
+    format: Some(&km23c_label),
 };
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
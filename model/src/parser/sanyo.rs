@@ -4,7 +4,6 @@
 
 use nom::{
     IResult, Parser as _,
-    bytes::streaming::tag,
     character::streaming::{char, one_of},
     combinator::{opt, recognize},
     error::ParseError,
@@ -12,8 +11,10 @@ use nom::{
 };
 
 use super::{
-    GenericPart,
-    for_nom::{lines2, lines3},
+    Country, GenericPart, KIBIT, MIBIT, Organization, Package, Voltage, dash_speed_grade,
+    for_format,
+    for_nom::{country, lines2, lines3, tag},
+    speed_grade,
 };
 use crate::parser::{
     Manufacturer, NomParser, PartDateCode,
@@ -29,22 +30,39 @@ use crate::parser::{
 /// ```
 pub static SANYO_LE26FV10: NomParser<GenericPart> = NomParser {
     name: "Sanyo LE26FV10",
-    f: |input| {
+    examples: &["LE26FV10N1TS -10 3MU50", "LE26FV10N1TS -10 4DU2A"],
+    prefixes: &["LE26FV10N1"],
+    f: &|input| {
         lines2(
             recognize(tag("LE26FV10N1").and(tag("TS"))),
             separated_pair(
                 tag("-10"), // speed
                 char(' '),
-                (date_code, uppers(1), digits(1), alnum_uppers(1)),
+                date_code.and(recognize((uppers(1), digits(1), alnum_uppers(1)))),
             ),
         )
-        .map(|(kind, (speed, (date_code, _, _, _)))| GenericPart {
+        .map(|(kind, (speed, (date_code, lot_code)))| GenericPart {
             kind: format!("{kind}{speed}"),
             manufacturer: Some(Manufacturer::Sanyo),
             date_code: Some(date_code),
+            lot_code: Some(String::from(lot_code)),
+            revision: None,
+            origin_country: None,
+            package: Some(Package::TsopI32),
+            voltage: Some(Voltage::V3_3),
+            density: Some(MIBIT),
+            organization: Some(Organization::new(128 * 1024, 8)),
+            access_time: dash_speed_grade(speed),
         })
         .parse(input)
     },
+    format: Some(&|part| {
+        let (kind, speed) = part.kind.split_at(part.kind.find('-')?);
+        Some(format!(
+            "{kind} {speed} {}",
+            date_and_lot_label(part.date_code, part.lot_code.as_deref())?
+        ))
+    }),
 };
 
 /// Sanyo LC35256 SRAM (SOP-28, 2.7-5.5V, 256 Kibit / 32 KiB)
@@ -56,7 +74,12 @@ pub static SANYO_LE26FV10: NomParser<GenericPart> = NomParser {
 /// ```
 pub static SANYO_LC35256: NomParser<GenericPart> = NomParser {
     name: "Sanyo LC35256",
-    f: |input| {
+    examples: &[
+        "SANYO LC35256DM-70W JAPAN 0EUPG",
+        "SANYO LC35256FM-70U JAPAN 0LK5G",
+    ],
+    prefixes: &["SANYO"],
+    f: &|input| {
         lines3(
             tag("SANYO"),
             (
@@ -64,19 +87,34 @@ pub static SANYO_LC35256: NomParser<GenericPart> = NomParser {
                 char('M'),
                 char('-'),
                 tag("70"),
-                alnum_uppers(1),
+                alnum_uppers(1), // voltage
+            ),
+            separated_pair(
+                country(Country::Japan),
+                char(' '),
+                date_code.and(alnum_uppers(3)),
             ),
-            separated_pair(tag("JAPAN"), char(' '), date_code.and(alnum_uppers(3))),
         )
         .map(
-            |(_, (kind, package, _, speed, _), (_, (date_code, _)))| GenericPart {
-                kind: format!("{kind}{package}-{speed}"),
-                manufacturer: Some(Manufacturer::Sanyo),
-                date_code: Some(date_code),
+            |(_, (kind, package, _, speed, voltage), (origin_country, (date_code, lot_code)))| {
+                GenericPart {
+                    kind: format!("{kind}{package}-{speed}{voltage}"),
+                    manufacturer: Some(Manufacturer::Sanyo),
+                    date_code: Some(date_code),
+                    lot_code: Some(String::from(lot_code)),
+                    revision: None,
+                    origin_country: Some(origin_country),
+                    package: Some(Package::Sop28),
+                    voltage: Some(Voltage::range(2_700, 5_500)),
+                    density: Some(256 * KIBIT),
+                    organization: Some(Organization::new(32 * 1024, 8)),
+                    access_time: speed_grade(speed),
+                }
             },
         )
         .parse(input)
     },
+    format: Some(&sanyo_label),
 };
 
 /// Sanyo LC3564 SRAM (SOP-28, 2.7-5.5V, 64 Kibit / 8 KiB)
@@ -87,7 +125,9 @@ pub static SANYO_LC35256: NomParser<GenericPart> = NomParser {
 /// ```
 pub static SANYO_LC3564: NomParser<GenericPart> = NomParser {
     name: "Sanyo LC3564",
-    f: |input| {
+    examples: &["SANYO LC3564BM-70 JAPAN 9MUBG"],
+    prefixes: &["SANYO"],
+    f: &|input| {
         lines3(
             tag("SANYO"),
             (
@@ -96,17 +136,30 @@ pub static SANYO_LC3564: NomParser<GenericPart> = NomParser {
                 char('-'),
                 tag("70"),
             ),
-            separated_pair(tag("JAPAN"), char(' '), date_code.and(alnum_uppers(3))),
+            separated_pair(
+                country(Country::Japan),
+                char(' '),
+                date_code.and(alnum_uppers(3)),
+            ),
         )
         .map(
-            |(_, (kind, package, _, speed), (_, (date_code, _)))| GenericPart {
+            |(_, (kind, package, _, speed), (origin_country, (date_code, lot_code)))| GenericPart {
                 kind: format!("{kind}{package}-{speed}"),
                 manufacturer: Some(Manufacturer::Sanyo),
                 date_code: Some(date_code),
+                lot_code: Some(String::from(lot_code)),
+                revision: None,
+                origin_country: Some(origin_country),
+                package: Some(Package::Sop28),
+                voltage: Some(Voltage::range(2_700, 5_500)),
+                density: Some(64 * KIBIT),
+                organization: Some(Organization::new(8 * 1024, 8)),
+                access_time: speed_grade(speed),
             },
         )
         .parse(input)
     },
+    format: Some(&sanyo_label),
 };
 
 fn date_code<'a, E: ParseError<&'a str>>(input: &'a str) -> IResult<&'a str, PartDateCode, E> {
@@ -115,3 +168,20 @@ fn date_code<'a, E: ParseError<&'a str>>(input: &'a str) -> IResult<&'a str, Par
         .map(|(year, month)| PartDateCode::YearMonth { year, month })
         .parse(input)
 }
+
+fn sanyo_label(part: &GenericPart) -> Option<String> {
+    Some(format!(
+        "SANYO {kind} {country} {}",
+        date_and_lot_label(part.date_code, part.lot_code.as_deref())?,
+        kind = part.kind,
+        country = part.origin_country?.label(),
+    ))
+}
+
+fn date_and_lot_label(date_code: Option<PartDateCode>, lot_code: Option<&str>) -> Option<String> {
+    Some(format!(
+        "{}{}",
+        for_format::year1_month1(date_code?, for_format::month1_abc)?,
+        lot_code?
+    ))
+}
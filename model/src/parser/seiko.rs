@@ -4,7 +4,6 @@
 
 use nom::{
     IResult, Parser,
-    bytes::streaming::tag,
     character::{
         complete::one_of,
         streaming::{anychar, char},
@@ -15,8 +14,8 @@ use nom::{
 };
 
 use super::{
-    GenericPart, Manufacturer, NomParser, PartDateCode, Year,
-    for_nom::{alnum_uppers, digits, lines3, month1_123xyz},
+    GenericPart, Manufacturer, NomParser, Package, PartDateCode, Year,
+    for_nom::{alnum_uppers, digits, lines3, month1_123xyz, tag},
 };
 
 /// Seiko S-3511A RTC (SOP-8)
@@ -28,15 +27,20 @@ use super::{
 /// ```
 pub static SEIKO_S3511A: NomParser<GenericPart> = NomParser {
     name: "Seiko S-3511A",
-    f: |input| {
+    examples: &["S3511 AV31 9812", "S3511 AVEX 2753"],
+    prefixes: &["S3511"],
+    f: &|input| {
         lines3(tag("S3511"), preceded(tag("AV"), date_code), lot_code)
             .map(|(_, date_code, _)| GenericPart {
                 kind: String::from("S-3511A"),
                 manufacturer: Some(Manufacturer::Seiko),
                 date_code: Some(date_code),
+                package: Some(Package::Sop8),
+                ..GenericPart::default()
             })
             .parse(input)
     },
+    format: None,
 };
 
 /// Seiko S-3516AE RTC (SOP-8)
@@ -47,15 +51,20 @@ pub static SEIKO_S3511A: NomParser<GenericPart> = NomParser {
 /// ```
 pub static SEIKO_S3516AE: NomParser<GenericPart> = NomParser {
     name: "Seiko S-3516AE",
-    f: |input| {
+    examples: &["S3516 AEV42 7505"],
+    prefixes: &["S3516"],
+    f: &|input| {
         lines3(tag("S3516"), preceded(tag("AEV"), date_code), lot_code)
             .map(|(_, date_code, _)| GenericPart {
                 kind: String::from("S-3516AE"),
                 manufacturer: Some(Manufacturer::Seiko),
                 date_code: Some(date_code),
+                package: Some(Package::Sop8),
+                ..GenericPart::default()
             })
             .parse(input)
     },
+    format: None,
 };
 
 /// Seiko S-6403
@@ -66,7 +75,9 @@ pub static SEIKO_S3516AE: NomParser<GenericPart> = NomParser {
 /// ```
 pub static SEIKO_S6403: NomParser<GenericPart> = NomParser {
     name: "Seiko S-6403",
-    f: |input| {
+    examples: &["S6403 CU4E0 9723"],
+    prefixes: &["S6403"],
+    f: &|input| {
         lines3(
             tag("S6403"),
             (one_of("AC"), char('U'), year1, alnum_uppers(1), digits(1)),
@@ -76,9 +87,11 @@ pub static SEIKO_S6403: NomParser<GenericPart> = NomParser {
             kind: format!("S-6403{revision}"),
             manufacturer: Some(Manufacturer::Seiko),
             date_code: Some(PartDateCode::Year { year }),
+            ..GenericPart::default()
         })
         .parse(input)
     },
+    format: None,
 };
 
 /// Seiko S-6960E
@@ -90,15 +103,19 @@ pub static SEIKO_S6403: NomParser<GenericPart> = NomParser {
 /// ```
 pub static SEIKO_S6960E: NomParser<GenericPart> = NomParser {
     name: "Seiko S-6960E",
-    f: |input| {
+    examples: &["S6960 E-U2Z C700", "S6960 E-U2X C410"],
+    prefixes: &["S6960"],
+    f: &|input| {
         lines3(tag("S6960"), preceded(tag("E-U"), date_code), lot_code)
             .map(|(_, date_code, _)| GenericPart {
                 kind: String::from("S-6960E"),
                 manufacturer: Some(Manufacturer::Seiko),
                 date_code: Some(date_code),
+                ..GenericPart::default()
             })
             .parse(input)
     },
+    format: None,
 };
 
 fn date_code<'a, E: ParseError<&'a str>>(input: &'a str) -> IResult<&'a str, PartDateCode, E> {
@@ -5,19 +5,21 @@
 use nom::{
     IResult, Parser,
     branch::alt,
-    bytes::streaming::{tag, take},
+    bytes::streaming::take,
     character::{complete::one_of, streaming::char},
     combinator::{consumed, map_opt, opt, recognize, value},
     error::ParseError,
-    sequence::{delimited, separated_pair, terminated},
+    sequence::{preceded, separated_pair, terminated},
 };
 
 use super::{
-    GameMaskRom, GameRomType, GenericPart, Manufacturer, Mapper, MapperChip, MaskCode, MaskRom,
-    NomParser, PartDateCode, Year,
+    Country, GameMaskRom, GameRomType, GenericPart, KIBIT, Manufacturer, Mapper, MapperChip,
+    MaskCode, MaskRom, NomParser, Organization, Package, PartDateCode, Voltage, Year,
+    dash_speed_grade, for_format,
     for_nom::{
-        alnum_uppers, alphas, cgb_rom_code, digits, dmg_rom_code, lines3, lines4, lines5,
-        satisfy_m_n_complete, uppers, week2, year1, year1_month2, year1_week2, year2_month2,
+        alnum_uppers, alphas, cgb_rom_code, country, digits, dmg_rom_code, lines3, lines4, lines5,
+        month2, satisfy_m_n_complete, tag, uppers, week2, year1, year1_month2, year1_week2, year2,
+        year2_month2_day2,
     },
 };
 
@@ -29,7 +31,14 @@ use super::{
 /// ```
 pub static SHARP_IR3E02: NomParser<GenericPart> = NomParser {
     name: "Sharp IR3E02",
-    f: |input| ir3_old("DMG-REG", "IR3E02").parse(input),
+    examples: &[
+        "DMG-REG IR3E02 9527 CB",
+        "DMG-REG IR3E02 9820 n",
+        "DMG-REG IR3E02 9024 J",
+    ],
+    prefixes: &["DMG-REG"],
+    f: &|input| ir3_old("DMG-REG", "IR3E02").parse(input),
+    format: Some(&|part| ir3_old_label("DMG-REG", part)),
 };
 
 /// ```
@@ -39,7 +48,10 @@ pub static SHARP_IR3E02: NomParser<GenericPart> = NomParser {
 /// ```
 pub static SHARP_IR3E06: NomParser<GenericPart> = NomParser {
     name: "Sharp IR3E06",
-    f: |input| ir3("CGB-REG", "IR3E06", Package::Ssop18).parse(input),
+    examples: &["CGB-REG IR3E06N 9839 C", "CGB-REG IR3E06N 0046 A"],
+    prefixes: &["CGB-REG"],
+    f: &|input| ir3("CGB-REG", "IR3E06", Package::Ssop18).parse(input),
+    format: Some(&|part| ir3_label("CGB-REG", part)),
 };
 
 /// ```
@@ -52,7 +64,17 @@ pub static SHARP_IR3E06: NomParser<GenericPart> = NomParser {
 /// ```
 pub static SHARP_IR3E09: NomParser<GenericPart> = NomParser {
     name: "Sharp IR3E09",
-    f: |input| ir3("AGB-REG", "IR3E09", Package::Ssop18).parse(input),
+    examples: &[
+        "AGB-REG IR3E09N 0104 C",
+        "AGB-REG IR3E09N 0141 K",
+        "AGB-REG IR3E09N 0204 d",
+        "AGB-REG IR3E09N AA24 A",
+        "AGB-REG IR3E09N 0223 B",
+    ],
+    prefixes: &["AGB-REG"],
+    f: &|input| ir3("AGB-REG", "IR3E09", Package::Ssop18).parse(input),
+    // "AA" and "00" both mean 2000, so labels can't be synthesized
+    format: None,
 };
 
 /// ```
@@ -62,7 +84,10 @@ pub static SHARP_IR3E09: NomParser<GenericPart> = NomParser {
 /// ```
 pub static SHARP_IR3R40: NomParser<GenericPart> = NomParser {
     name: "Sharp IR3R40",
-    f: |input| ir3_old("DMG-AMP", "IR3R40").parse(input),
+    examples: &["DMG-AMP IR3R40 9222 AA", "DMG-AMP IR3R40 8909 A"],
+    prefixes: &["DMG-AMP"],
+    f: &|input| ir3_old("DMG-AMP", "IR3R40").parse(input),
+    format: Some(&|part| ir3_old_label("DMG-AMP", part)),
 };
 
 /// ```
@@ -72,7 +97,10 @@ pub static SHARP_IR3R40: NomParser<GenericPart> = NomParser {
 /// ```
 pub static SHARP_IR3R53: NomParser<GenericPart> = NomParser {
     name: "Sharp IR3R53",
-    f: |input| ir3("AMP MGB", "IR3R53", Package::Ssop18).parse(input),
+    examples: &["AMP MGB IR3R53N 9806 a", "AMP MGB IR3R53N 9724 C"],
+    prefixes: &["AMP MGB"],
+    f: &|input| ir3("AMP MGB", "IR3R53", Package::Ssop18).parse(input),
+    format: Some(&|part| ir3_label("AMP MGB", part)),
 };
 
 /// ```
@@ -82,7 +110,10 @@ pub static SHARP_IR3R53: NomParser<GenericPart> = NomParser {
 /// ```
 pub static SHARP_IR3R56: NomParser<GenericPart> = NomParser {
     name: "Sharp IR3R56",
-    f: |input| ir3("AMP MGB", "IR3R56", Package::Ssop18).parse(input),
+    examples: &["AMP MGB IR3R56N 0046 A", "AMP MGB IR3R56N 0040 C"],
+    prefixes: &["AMP MGB"],
+    f: &|input| ir3("AMP MGB", "IR3R56", Package::Ssop18).parse(input),
+    format: Some(&|part| ir3_label("AMP MGB", part)),
 };
 
 /// ```
@@ -92,7 +123,10 @@ pub static SHARP_IR3R56: NomParser<GenericPart> = NomParser {
 /// ```
 pub static SHARP_IR3R60: NomParser<GenericPart> = NomParser {
     name: "Sharp IR3R60",
-    f: |input| ir3("AMP AGB", "IR3R60", Package::Ssop18).parse(input),
+    examples: &["AMP AGB IR3R60N 0103 a", "AMP AGB IR3R60N 0240 N"],
+    prefixes: &["AMP AGB"],
+    f: &|input| ir3("AMP AGB", "IR3R60", Package::Ssop18).parse(input),
+    format: Some(&|part| ir3_label("AMP AGB", part)),
 };
 
 fn ir3<'a, E: ParseError<&'a str>>(
@@ -105,10 +139,13 @@ fn ir3<'a, E: ParseError<&'a str>>(
         recognize(tag(kind).and(package(pkg))),
         separated_pair(sharp_year2_week2, char(' '), alphas(1)),
     )
-    .map(|(_, kind, (date_code, _))| GenericPart {
+    .map(move |(_, kind, (date_code, revision))| GenericPart {
         kind: String::from(kind),
         manufacturer: Some(Manufacturer::Sharp),
         date_code: Some(date_code),
+        revision: Some(String::from(revision)),
+        package: Some(pkg),
+        ..GenericPart::default()
     })
 }
 
@@ -122,35 +159,76 @@ fn ir3_old<'a, E: ParseError<&'a str>>(
         separated_pair(
             sharp_year2_week2,
             char(' '),
-            alphas(1).and(opt(nom::character::complete::satisfy(|c| {
+            recognize(alphas(1).and(opt(nom::character::complete::satisfy(|c| {
                 c.is_ascii_uppercase()
-            }))),
+            })))),
         ),
     )
-    .map(|(_, kind, (date_code, _))| GenericPart {
+    .map(|(_, kind, (date_code, lot_code))| GenericPart {
         kind: String::from(kind),
         manufacturer: Some(Manufacturer::Sharp),
         date_code: Some(date_code),
+        lot_code: Some(String::from(lot_code)),
+        ..GenericPart::default()
     })
 }
 
+fn ir3_label(prefix: &str, part: &GenericPart) -> Option<String> {
+    Some(format!(
+        "{prefix} {kind} {date_code} {revision}",
+        kind = part.kind,
+        date_code = for_format::year2_week2(part.date_code?)?,
+        revision = part.revision.as_ref()?,
+    ))
+}
+
+fn ir3_old_label(prefix: &str, part: &GenericPart) -> Option<String> {
+    Some(format!(
+        "{prefix} {kind} {date_and_lot}",
+        kind = part.kind,
+        date_and_lot = date_and_lot_label(part.date_code, part.lot_code.as_deref())?,
+    ))
+}
+
+/// Formats the date code and lot code that end most Sharp labels
+fn date_and_lot_label(date_code: Option<PartDateCode>, lot_code: Option<&str>) -> Option<String> {
+    Some(format!(
+        "{} {}",
+        for_format::year2_week2(date_code?)?,
+        lot_code?
+    ))
+}
+
+/// Returns the revision letters that follow the base name of a CPU (e.g. "B" in "DMG-CPU B")
+fn cpu_revision(kind: &str, base: &str) -> Option<String> {
+    kind.strip_prefix(base)
+        .map(str::trim)
+        .filter(|revision| !revision.is_empty())
+        .map(String::from)
+}
+
 fn package<'a, E: ParseError<&'a str>>(
     package: Package,
 ) -> impl Parser<&'a str, Output = Package, Error = E> {
-    value(package, tag(package.code()))
+    value(package, tag(package_code(package)))
 }
 
-#[derive(Copy, Clone, Debug, PartialEq, Eq)]
-enum Package {
-    Ssop18,
+/// Returns the package code Sharp appends to part numbers
+const fn package_code(package: Package) -> &'static str {
+    match package {
+        Package::Ssop18 | Package::Sop28 => "N",
+        Package::TsopI28 => "T",
+        _ => panic!("no Sharp package code for the package"),
+    }
 }
 
-impl Package {
-    pub const fn code(&self) -> &'static str {
-        match self {
-            Package::Ssop18 => "N",
-        }
-    }
+fn glop_top_label(model: &str, rom: &GameMaskRom) -> Option<String> {
+    Some(format!(
+        "{model} {rom_id} {date}{lot}",
+        rom_id = rom.rom_id,
+        date = for_format::year2_week2(rom.date_code?)?,
+        lot = rom.lot_code.as_deref()?,
+    ))
 }
 
 /// Sharp unknown mask ROM (glop top, 256 Kibit / 32 KiB)
@@ -161,7 +239,9 @@ impl Package {
 /// ```
 pub static SHARP_MASK_ROM_GLOP_TOP_28_256_KIBIT: NomParser<GameMaskRom> = NomParser {
     name: "Sharp mask ROM",
-    f: |input| {
+    examples: &["LR0G150 DMG-TRA-1 97141"],
+    prefixes: &["LR0G150"],
+    f: &|input| {
         (
             tag("LR0G150"),
             char(' '),
@@ -170,16 +250,25 @@ pub static SHARP_MASK_ROM_GLOP_TOP_28_256_KIBIT: NomParser<GameMaskRom> = NomPar
             sharp_year2_week2,
             digits(1),
         )
-            .map(|(_, _, rom_id, _, date_code, _)| GameMaskRom {
+            .map(|(_, _, rom_id, _, date_code, lot_code)| GameMaskRom {
                 rom_id: String::from(rom_id),
                 rom_type: GameRomType::GlopTop,
                 manufacturer: Some(Manufacturer::Sharp),
                 chip_type: None,
                 mask_code: None,
                 date_code: Some(date_code),
+                lot_code: Some(String::from(lot_code)),
+                revision: None,
+                origin_country: None,
+                package: Some(Package::GlopTop),
+                voltage: Some(Voltage::V5),
+                density: Some(256 * KIBIT),
+                organization: None,
+                access_time: None,
             })
             .parse(input)
     },
+    format: Some(&|rom| glop_top_label("LR0G150", rom)),
 };
 
 /// Sharp unknown mask ROM (glop top, 512 Kibit / 64 KiB)
@@ -190,7 +279,9 @@ pub static SHARP_MASK_ROM_GLOP_TOP_28_256_KIBIT: NomParser<GameMaskRom> = NomPar
 /// ```
 pub static SHARP_MASK_ROM_GLOP_TOP_28_512_KIBIT: NomParser<GameMaskRom> = NomParser {
     name: "Sharp mask ROM",
-    f: |input| {
+    examples: &["LR0G1701 DMG-YOJ-0 92051"],
+    prefixes: &["LR0G1701"],
+    f: &|input| {
         (
             tag("LR0G1701"),
             char(' '),
@@ -199,16 +290,25 @@ pub static SHARP_MASK_ROM_GLOP_TOP_28_512_KIBIT: NomParser<GameMaskRom> = NomPar
             sharp_year2_week2,
             digits(1),
         )
-            .map(|(_, _, rom_id, _, date_code, _)| GameMaskRom {
+            .map(|(_, _, rom_id, _, date_code, lot_code)| GameMaskRom {
                 rom_id: String::from(rom_id),
                 rom_type: GameRomType::GlopTop,
                 manufacturer: Some(Manufacturer::Sharp),
                 chip_type: None,
                 mask_code: None,
                 date_code: Some(date_code),
+                lot_code: Some(String::from(lot_code)),
+                revision: None,
+                origin_country: None,
+                package: Some(Package::GlopTop),
+                voltage: Some(Voltage::V5),
+                density: Some(512 * KIBIT),
+                organization: None,
+                access_time: None,
             })
             .parse(input)
     },
+    format: Some(&|rom| glop_top_label("LR0G1701", rom)),
 };
 
 fn lh53_ancient<'a, E: ParseError<&'a str>>(
@@ -219,7 +319,7 @@ fn lh53_ancient<'a, E: ParseError<&'a str>>(
     lines4(
         dmg_rom_code(),
         tag("SHARP"),
-        tag("JAPAN"),
+        country(Country::Japan),
         (
             sharp_year2_week2,
             char(' '),
@@ -228,14 +328,24 @@ fn lh53_ancient<'a, E: ParseError<&'a str>>(
             char(unknown),
         ),
     )
-    .map(move |(rom_id, _, _, (date_code, _, _, _, _))| GameMaskRom {
-        rom_id: String::from(rom_id),
-        rom_type,
-        manufacturer: Some(Manufacturer::Sharp),
-        chip_type: kind.map(String::from),
-        mask_code: None,
-        date_code: Some(date_code),
-    })
+    .map(
+        move |(rom_id, _, origin_country, (date_code, _, lot_code, _, _))| GameMaskRom {
+            rom_id: String::from(rom_id),
+            rom_type,
+            manufacturer: Some(Manufacturer::Sharp),
+            chip_type: kind.map(String::from),
+            mask_code: None,
+            date_code: Some(date_code),
+            lot_code: Some(String::from(lot_code)),
+            revision: None,
+            origin_country: Some(origin_country),
+            package: Some(rom_type.package()),
+            voltage: Some(Voltage::V5),
+            density: rom_type.density(),
+            organization: None,
+            access_time: None,
+        },
+    )
 }
 
 fn lh53_old<'a, E: ParseError<&'a str>>(
@@ -245,17 +355,37 @@ fn lh53_old<'a, E: ParseError<&'a str>>(
     lines4(
         dmg_rom_code(),
         tag("SHARP"),
-        separated_pair(tag("JAPAN"), char(' '), tag(rom_type.as_str())),
+        separated_pair(country(Country::Japan), char(' '), tag(rom_type.as_str())),
         separated_pair(sharp_year2_week2, char(' '), alphas(1)),
     )
-    .map(move |(rom_id, _, _, (date_code, _))| GameMaskRom {
-        rom_id: String::from(rom_id),
-        rom_type,
-        manufacturer: Some(Manufacturer::Sharp),
-        chip_type: kind.map(String::from),
-        mask_code: None,
-        date_code: Some(date_code),
-    })
+    .map(
+        move |(rom_id, _, (origin_country, _), (date_code, lot_code))| GameMaskRom {
+            rom_id: String::from(rom_id),
+            rom_type,
+            manufacturer: Some(Manufacturer::Sharp),
+            chip_type: kind.map(String::from),
+            mask_code: None,
+            date_code: Some(date_code),
+            lot_code: Some(String::from(lot_code)),
+            revision: None,
+            origin_country: Some(origin_country),
+            package: Some(rom_type.package()),
+            voltage: Some(Voltage::V5),
+            density: rom_type.density(),
+            organization: None,
+            access_time: None,
+        },
+    )
+}
+
+fn lh53_old_label(rom: &GameMaskRom) -> Option<String> {
+    Some(format!(
+        "{rom_id} SHARP {country} {rom_type} {date_and_lot}",
+        rom_id = rom.rom_id,
+        country = rom.origin_country?.label(),
+        rom_type = rom.rom_type.as_str(),
+        date_and_lot = date_and_lot_label(rom.date_code, rom.lot_code.as_deref())?,
+    ))
 }
 
 fn lh53_new<'a, E: ParseError<&'a str>>(
@@ -270,24 +400,47 @@ fn lh53_new<'a, E: ParseError<&'a str>>(
             consumed(terminated(model, alnum_uppers(2))),
         ),
         separated_pair(
-            tag("JAPAN"),
+            country(Country::Japan),
             char(' '),
             tag(rom_type.as_str()).and(opt(tag(" SP"))),
         ),
         separated_pair(sharp_year2_week2, char(' '), alphas(1)),
     )
     .map(
-        move |(rom_id, (_, (mask_code, kind)), _, (date_code, _))| GameMaskRom {
-            rom_id: String::from(rom_id),
-            rom_type,
-            manufacturer: Some(Manufacturer::Sharp),
-            chip_type: kind.map(String::from),
-            mask_code: Some(MaskCode::Sharp(String::from(mask_code))),
-            date_code: Some(date_code),
+        move |(rom_id, (_, (mask_code, kind)), (origin_country, _), (date_code, lot_code))| {
+            GameMaskRom {
+                rom_id: String::from(rom_id),
+                rom_type,
+                manufacturer: Some(Manufacturer::Sharp),
+                chip_type: kind.map(String::from),
+                mask_code: Some(MaskCode::Sharp(String::from(mask_code))),
+                date_code: Some(date_code),
+                lot_code: Some(String::from(lot_code)),
+                revision: None,
+                origin_country: Some(origin_country),
+                package: Some(rom_type.package()),
+                voltage: Some(Voltage::V5),
+                density: rom_type.density(),
+                organization: None,
+                access_time: None,
+            }
         },
     )
 }
 
+fn lh53_new_label(rom: &GameMaskRom) -> Option<String> {
+    let Some(MaskCode::Sharp(mask_code)) = &rom.mask_code else {
+        return None;
+    };
+    Some(format!(
+        "{rom_id} S {mask_code} {country} {rom_type} {date_and_lot}",
+        rom_id = rom.rom_id,
+        country = rom.origin_country?.label(),
+        rom_type = rom.rom_type.as_str(),
+        date_and_lot = date_and_lot_label(rom.date_code, rom.lot_code.as_deref())?,
+    ))
+}
+
 /// Sharp LH53259M mask ROM (QFP-44, 256 Kibit / 32 KiB)
 ///
 /// ```
@@ -298,7 +451,13 @@ fn lh53_new<'a, E: ParseError<&'a str>>(
 /// ```
 pub static SHARP_LH53259M: NomParser<GameMaskRom> = NomParser {
     name: "Sharp LH53259",
-    f: |input| {
+    examples: &[
+        "DMG-AWA-0 SHARP JAPAN 8909 D A",
+        "DMG-AWA-0 SHARP JAPAN A0 8938 D",
+        "DMG-OPX-0 S LH5359UZ JAPAN A0 9722 D",
+    ],
+    prefixes: &["DMG-", "CGB-"],
+    f: &|input| {
         alt((
             lh53_ancient(Some("LH53259"), GameRomType::A0, 'A'),
             lh53_old(Some("LH53259"), GameRomType::A0),
@@ -310,6 +469,8 @@ pub static SHARP_LH53259M: NomParser<GameMaskRom> = NomParser {
         ))
         .parse(input)
     },
+    // The ancient and old layouts decode to identical parts
+    format: None,
 };
 
 /// Sharp LH53515M mask ROM (QFP-44, 512 Kibit / 64 KiB)
@@ -320,7 +481,10 @@ pub static SHARP_LH53259M: NomParser<GameMaskRom> = NomParser {
 /// ```
 pub static SHARP_LH53515M: NomParser<GameMaskRom> = NomParser {
     name: "Sharp LH53515",
-    f: |input| lh53_old(Some("LH53515"), GameRomType::B0).parse(input),
+    examples: &["DMG-CVJ-0 SHARP JAPAN B0 8941 D"],
+    prefixes: &["DMG-"],
+    f: &|input| lh53_old(Some("LH53515"), GameRomType::B0).parse(input),
+    format: Some(&lh53_old_label),
 };
 
 /// Sharp LH53514Z mask ROM (SOP-32, 512 Kibit / 64 KiB)
@@ -331,7 +495,9 @@ pub static SHARP_LH53515M: NomParser<GameMaskRom> = NomParser {
 /// ```
 pub static SHARP_LH53514Z: NomParser<GameMaskRom> = NomParser {
     name: "Sharp LH53514",
-    f: |input| {
+    examples: &["DMG-AYJ-0 S LH5314H1 JAPAN B1 9014 E"],
+    prefixes: &["DMG-", "CGB-"],
+    f: &|input| {
         lh53_new(
             // reasonable guess
             value(Some("LH53514"), tag("LH5314")),
@@ -339,6 +505,7 @@ pub static SHARP_LH53514Z: NomParser<GameMaskRom> = NomParser {
         )
         .parse(input)
     },
+    format: Some(&lh53_new_label),
 };
 
 /// Sharp LH53517Z mask ROM (SOP-32, 512 Kibit / 64 KiB)
@@ -349,7 +516,9 @@ pub static SHARP_LH53514Z: NomParser<GameMaskRom> = NomParser {
 /// ```
 pub static SHARP_LH53517Z: NomParser<GameMaskRom> = NomParser {
     name: "Sharp LH53517",
-    f: |input| {
+    examples: &["DMG-AYNP-0 S LH5317VR JAPAN B1 9850 E"],
+    prefixes: &["DMG-", "CGB-"],
+    f: &|input| {
         lh53_new(
             // reasonable guess
             value(Some("LH53517"), tag("LH5317")),
@@ -357,6 +526,7 @@ pub static SHARP_LH53517Z: NomParser<GameMaskRom> = NomParser {
         )
         .parse(input)
     },
+    format: Some(&lh53_new_label),
 };
 
 /// Sharp LH530800N (SOP-32, 512 Kibit / 64 KiB)
@@ -367,7 +537,9 @@ pub static SHARP_LH53517Z: NomParser<GameMaskRom> = NomParser {
 /// ```
 pub static SHARP_LH530800N: NomParser<GameMaskRom> = NomParser {
     name: "Sharp LH530800",
-    f: |input| {
+    examples: &["DMG-A6W-0 S LH531HF8 JAPAN C1 9709 E"],
+    prefixes: &["DMG-", "CGB-"],
+    f: &|input| {
         lh53_new(
             alt((
                 // reasonable guess
@@ -379,6 +551,7 @@ pub static SHARP_LH530800N: NomParser<GameMaskRom> = NomParser {
         )
         .parse(input)
     },
+    format: Some(&lh53_new_label),
 };
 
 /// Sharp unknown mask ROM (SOP-32, 1 Mibit / 128 KiB)
@@ -388,7 +561,10 @@ pub static SHARP_LH530800N: NomParser<GameMaskRom> = NomParser {
 /// ```
 pub static SHARP_MASK_ROM_SOP_32_1_MIBIT: NomParser<GameMaskRom> = NomParser {
     name: "Sharp mask ROM",
-    f: |input| lh53_old(None, GameRomType::C1).parse(input),
+    examples: &["DMG-NME-0 SHARP JAPAN C1 9009 E"],
+    prefixes: &["DMG-"],
+    f: &|input| lh53_old(None, GameRomType::C1).parse(input),
+    format: Some(&lh53_old_label),
 };
 
 /// Sharp LH532100N mask ROM (SOP-32, 2 Mibit / 256 KiB)
@@ -398,7 +574,10 @@ pub static SHARP_MASK_ROM_SOP_32_1_MIBIT: NomParser<GameMaskRom> = NomParser {
 /// ```
 pub static SHARP_LH532100N: NomParser<GameMaskRom> = NomParser {
     name: "Sharp LH532100N",
-    f: |input| lh53_new(value(Some("LH532100"), tag("LH5321")), GameRomType::D1).parse(input),
+    examples: &["DMG-DFJ-0 S LH5321FL JAPAN D1 9249 D"],
+    prefixes: &["DMG-", "CGB-"],
+    f: &|input| lh53_new(value(Some("LH532100"), tag("LH5321")), GameRomType::D1).parse(input),
+    format: Some(&lh53_new_label),
 };
 
 /// Sharp LH532xxxN mask ROM (SOP-32, 2 Mibit / 256 KiB)
@@ -409,7 +588,9 @@ pub static SHARP_LH532100N: NomParser<GameMaskRom> = NomParser {
 pub static SHARP_LH532XXXN: NomParser<GameMaskRom> = NomParser {
     // maybe: LH532100 series / LH532300 / LH532700 series
     name: "Sharp LH532???",
-    f: |input| {
+    examples: &["DMG-DIJ-0 S LH532D17 JAPAN D1 9223 D"],
+    prefixes: &["DMG-", "CGB-"],
+    f: &|input| {
         lh53_new(
             value(
                 None,
@@ -425,6 +606,7 @@ pub static SHARP_LH532XXXN: NomParser<GameMaskRom> = NomParser {
         )
         .parse(input)
     },
+    format: Some(&lh53_new_label),
 };
 
 /// Sharp LH534xxxN mask ROM (SOP-32, 4 Mibit / 512 KiB)
@@ -435,13 +617,16 @@ pub static SHARP_LH532XXXN: NomParser<GameMaskRom> = NomParser {
 pub static SHARP_LH534XXXN: NomParser<GameMaskRom> = NomParser {
     // maybe: LH534100 series / LH534300 series / LH534R00
     name: "Sharp LH534???",
-    f: |input| {
+    examples: &["DMG-A3ME-0 S LH534MW1 JAPAN E1 9547 E"],
+    prefixes: &["DMG-", "CGB-"],
+    f: &|input| {
         lh53_new(
             value(None, alt((tag("LH534M"), tag("LH5S4M"), tag("LHMN4M")))),
             GameRomType::E1,
         )
         .parse(input)
     },
+    format: Some(&lh53_new_label),
 };
 
 /// Sharp LH538xxxN mask ROM (SOP-32, 8 Mibit / 1 MiB)
@@ -452,7 +637,9 @@ pub static SHARP_LH534XXXN: NomParser<GameMaskRom> = NomParser {
 pub static SHARP_LH538XXXN: NomParser<GameMaskRom> = NomParser {
     // maybe: LH538300 series / LH538400 series / LH538700 / LH538R00 series
     name: "Sharp LH538???",
-    f: |input| {
+    examples: &["CGB-AHYE-0 S LH538WV9 JAPAN F1 9916 D"],
+    prefixes: &["DMG-", "CGB-"],
+    f: &|input| {
         lh53_new(
             value(
                 None,
@@ -468,6 +655,7 @@ pub static SHARP_LH538XXXN: NomParser<GameMaskRom> = NomParser {
         )
         .parse(input)
     },
+    format: Some(&lh53_new_label),
 };
 
 /// Sharp LH534xxxS mask ROM (TSOP-I-32, 4 Mibit / 512 KiB)
@@ -477,7 +665,10 @@ pub static SHARP_LH538XXXN: NomParser<GameMaskRom> = NomParser {
 /// ```
 pub static SHARP_LH534XXXS: NomParser<GameMaskRom> = NomParser {
     name: "Sharp LH534???",
-    f: |input| lh53_new(value(None, tag("LHMN4M")), GameRomType::E).parse(input),
+    examples: &["DMG-HFAJ-0 S LHMN4MTI JAPAN E 9838 E"],
+    prefixes: &["DMG-", "CGB-"],
+    f: &|input| lh53_new(value(None, tag("LHMN4M")), GameRomType::E).parse(input),
+    format: Some(&lh53_new_label),
 };
 
 /// Sharp LH538xxxS mask ROM (TSOP-I-32, 8 Mibit / 1 MiB)
@@ -487,7 +678,10 @@ pub static SHARP_LH534XXXS: NomParser<GameMaskRom> = NomParser {
 /// ```
 pub static SHARP_LH538XXXS: NomParser<GameMaskRom> = NomParser {
     name: "Sharp LH538???",
-    f: |input| lh53_new(value(None, tag("LH5S8M")), GameRomType::F).parse(input),
+    examples: &["DMG-HRCJ-0 S LH5S8MTI JAPAN F 9846 E"],
+    prefixes: &["DMG-", "CGB-"],
+    f: &|input| lh53_new(value(None, tag("LH5S8M")), GameRomType::F).parse(input),
+    format: Some(&lh53_new_label),
 };
 
 /// Sharp LH5316xxx mask ROM (TSOP-II-44, 16 Mibit / 2 MiB)
@@ -499,7 +693,10 @@ pub static SHARP_LH538XXXS: NomParser<GameMaskRom> = NomParser {
 pub static SHARP_LH5316XXX: NomParser<GameMaskRom> = NomParser {
     // maybe: LH5316400 / LH5316500 series / LH5316P00 series
     name: "Sharp LH5316???",
-    f: |input| lh53_new(value(None, tag("LH537M")), GameRomType::G2).parse(input),
+    examples: &["CGB-AFIP-0 S LH537MTJ JAPAN G2 9929 D"],
+    prefixes: &["DMG-", "CGB-"],
+    f: &|input| lh53_new(value(None, tag("LH537M")), GameRomType::G2).parse(input),
+    format: Some(&lh53_new_label),
 };
 
 /// Sharp LH5332xxx mask ROM (TSOP-II-44, 32 Mibit / 4 MiB)
@@ -511,13 +708,20 @@ pub static SHARP_LH5316XXX: NomParser<GameMaskRom> = NomParser {
 /// ```
 pub static SHARP_LH5332XXX: NomParser<GameMaskRom> = NomParser {
     name: "Sharp LH5332???",
-    f: |input| {
+    examples: &[
+        "CGB-AYQE-0 S LHMN5MTF JAPAN H2 0010 D",
+        "DMG-AYKJ-0 S LH5S5WT1 JAPAN H2 SP 9926 D",
+    ],
+    prefixes: &["DMG-", "CGB-"],
+    f: &|input| {
         lh53_new(
             value(None, alt((tag("LHMN5M"), tag("LH5S5W")))),
             GameRomType::H2,
         )
         .parse(input)
     },
+    // The optional "SP" marking is not kept in the parsed part
+    format: None,
 };
 
 fn sgb_rom<'a, E: ParseError<&'a str>>(
@@ -530,13 +734,24 @@ fn sgb_rom<'a, E: ParseError<&'a str>>(
         model,
         separated_pair(sharp_year2_week2, char(' '), uppers(1)),
     )
-    .map(|(rom_id, _, (mask_code, kind), (date_code, _))| MaskRom {
-        rom_id: String::from(rom_id),
-        chip_type: kind.map(String::from),
-        manufacturer: Some(Manufacturer::Sharp),
-        mask_code: Some(MaskCode::Sharp(String::from(mask_code))),
-        date_code: Some(date_code),
-    })
+    .map(
+        |(rom_id, _, (mask_code, kind), (date_code, lot_code))| MaskRom {
+            rom_id: String::from(rom_id),
+            chip_type: kind.map(String::from),
+            manufacturer: Some(Manufacturer::Sharp),
+            mask_code: Some(MaskCode::Sharp(String::from(mask_code))),
+            date_code: Some(date_code),
+            lot_code: Some(String::from(lot_code)),
+        },
+    )
+}
+
+fn sgb_rom_label(copyright: &str, rom: &MaskRom) -> Option<String> {
+    Some(format!(
+        "{copyright} {mask_code} {}",
+        date_and_lot_label(rom.date_code, rom.lot_code.as_deref())?,
+        mask_code = rom.mask_code.as_ref()?.as_str(),
+    ))
 }
 
 /// Sharp SGB mask ROM
@@ -549,7 +764,13 @@ fn sgb_rom<'a, E: ParseError<&'a str>>(
 /// ```
 pub static SHARP_SGB_ROM: NomParser<MaskRom> = NomParser {
     name: "Sharp SGB ROM",
-    f: |input| {
+    examples: &[
+        "SYS-SGB-2 © 1994 Nintendo LH532M0M 9432 E",
+        "SYS-SGB-2 © 1994 Nintendo LH532KND 9432 E",
+        "SYS-SGB-NT © 1994 Nintendo LH532KN8 9416 D",
+    ],
+    prefixes: &["SYS-SGB-NT", "SYS-SGB-2"],
+    f: &|input| {
         alt((
             sgb_rom(
                 consumed(value(Some("LH532100B"), tag("LH532K").and(tag("N8")))),
@@ -566,6 +787,7 @@ pub static SHARP_SGB_ROM: NomParser<MaskRom> = NomParser {
         ))
         .parse(input)
     },
+    format: Some(&|rom| sgb_rom_label(&format!("{} © 1994 Nintendo", rom.rom_id), rom)),
 };
 
 /// Sharp SGB2 mask ROM
@@ -576,22 +798,28 @@ pub static SHARP_SGB_ROM: NomParser<MaskRom> = NomParser {
 /// ```
 pub static SHARP_SGB2_ROM: NomParser<MaskRom> = NomParser {
     name: "Sharp SGB2 ROM",
-    f: |input| {
+    examples: &["© 1998 Nintendo SYS-SGB2-10 LH5S4RY4 0003 D"],
+    prefixes: &["© 1998 Nintendo"],
+    f: &|input| {
         lines4(
             tag("© 1998 Nintendo"),
             tag("SYS-SGB2-10"),
             consumed(value(Some("LH534R00B"), tag("LH5S4R").and(tag("Y4")))),
             separated_pair(sharp_year2_week2, char(' '), uppers(1)),
         )
-        .map(|(_, rom_id, (mask_code, kind), (date_code, _))| MaskRom {
-            rom_id: String::from(rom_id),
-            manufacturer: Some(Manufacturer::Sharp),
-            chip_type: kind.map(String::from),
-            mask_code: Some(MaskCode::Sharp(String::from(mask_code))),
-            date_code: Some(date_code),
-        })
+        .map(
+            |(_, rom_id, (mask_code, kind), (date_code, lot_code))| MaskRom {
+                rom_id: String::from(rom_id),
+                manufacturer: Some(Manufacturer::Sharp),
+                chip_type: kind.map(String::from),
+                mask_code: Some(MaskCode::Sharp(String::from(mask_code))),
+                date_code: Some(date_code),
+                lot_code: Some(String::from(lot_code)),
+            },
+        )
         .parse(input)
     },
+    format: Some(&|rom| sgb_rom_label(&format!("© 1998 Nintendo {}", rom.rom_id), rom)),
 };
 
 fn cic<'a, E: ParseError<&'a str>>(
@@ -604,13 +832,23 @@ fn cic<'a, E: ParseError<&'a str>>(
         tag("Nintendo"),
         separated_pair(sharp_year2_week2, char(' '), alphas(1)),
     )
-    .map(|(kind, _, _, (date_code, _))| GenericPart {
+    .map(|(kind, _, _, (date_code, lot_code))| GenericPart {
         kind: String::from(kind),
         manufacturer: Some(Manufacturer::Sharp),
         date_code: Some(date_code),
+        lot_code: Some(String::from(lot_code)),
+        ..GenericPart::default()
     })
 }
 
+fn cic_label(copyright: &str, part: &GenericPart) -> Option<String> {
+    Some(format!(
+        "{kind} {copyright} Nintendo {}",
+        date_and_lot_label(part.date_code, part.lot_code.as_deref())?,
+        kind = part.kind,
+    ))
+}
+
 /// Sharp F411
 ///
 /// ```
@@ -619,7 +857,10 @@ fn cic<'a, E: ParseError<&'a str>>(
 /// ```
 pub static SHARP_F411: NomParser<GenericPart> = NomParser {
     name: "Sharp F411",
-    f: |input| cic("F411", "© 1990").parse(input),
+    examples: &["F411A © 1990 Nintendo 9428 a"],
+    prefixes: &["F411"],
+    f: &|input| cic("F411", "© 1990").parse(input),
+    format: Some(&|part| cic_label("© 1990", part)),
 };
 
 /// Sharp F413
@@ -630,7 +871,10 @@ pub static SHARP_F411: NomParser<GenericPart> = NomParser {
 /// ```
 pub static SHARP_F413: NomParser<GenericPart> = NomParser {
     name: "Sharp F413",
-    f: |input| cic("F413", "© 1992").parse(input),
+    examples: &["F413A © 1992 Nintendo 9425 a"],
+    prefixes: &["F413"],
+    f: &|input| cic("F413", "© 1992").parse(input),
+    format: Some(&|part| cic_label("© 1992", part)),
 };
 
 /// Sharp LR35902 (QFP-80)
@@ -641,19 +885,31 @@ pub static SHARP_F413: NomParser<GenericPart> = NomParser {
 /// ```
 pub static SHARP_LR35902: NomParser<GenericPart> = NomParser {
     name: "Sharp LR35902",
-    f: |input| {
+    examples: &["DMG-CPU LR35902 8907 D"],
+    prefixes: &["DMG-CPU"],
+    f: &|input| {
         lines3(
             tag("DMG-CPU"),
             tag("LR35902"),
             separated_pair(sharp_year2_week2, char(' '), uppers(1)),
         )
-        .map(|(kind, _, (date_code, _))| GenericPart {
+        .map(|(kind, _, (date_code, lot_code))| GenericPart {
             kind: String::from(kind),
             manufacturer: Some(Manufacturer::Sharp),
             date_code: Some(date_code),
+            lot_code: Some(String::from(lot_code)),
+            package: Some(Package::Qfp80),
+            ..GenericPart::default()
         })
         .parse(input)
     },
+    format: Some(&|part| {
+        Some(format!(
+            "{kind} LR35902 {}",
+            date_and_lot_label(part.date_code, part.lot_code.as_deref())?,
+            kind = part.kind,
+        ))
+    }),
 };
 
 /// Sharp DMG-CPU (QFP-80)
@@ -667,7 +923,14 @@ pub static SHARP_LR35902: NomParser<GenericPart> = NomParser {
 /// ```
 pub static SHARP_DMG_CPU: NomParser<GenericPart> = NomParser {
     name: "Sharp DMG-CPU",
-    f: |input| {
+    examples: &[
+        "DMG-CPU © 1989 Nintendo JAPAN 8913 D",
+        "DMG-CPU A © 1989 Nintendo JAPAN 8937 D",
+        "DMG-CPU B © 1989 Nintendo JAPAN 9207 D",
+        "DMG-CPU C © 1989 Nintendo JAPAN 9835 D",
+    ],
+    prefixes: &["DMG-CPU"],
+    f: &|input| {
         lines4(
             alt((
                 tag("DMG-CPU A"),
@@ -676,20 +939,35 @@ pub static SHARP_DMG_CPU: NomParser<GenericPart> = NomParser {
                 tag("DMG-CPU"),
             )),
             tag("© 1989 Nintendo"),
-            tag("JAPAN"),
+            country(Country::Japan),
             separated_pair(
                 sharp_year2_week2,
                 char(' '),
                 satisfy_m_n_complete(1, 2, |c| c.is_ascii_uppercase()),
             ),
         )
-        .map(|(kind, _, _, (date_code, _))| GenericPart {
-            kind: String::from(kind),
-            manufacturer: Some(Manufacturer::Sharp),
-            date_code: Some(date_code),
-        })
+        .map(
+            |(kind, _, origin_country, (date_code, lot_code))| GenericPart {
+                kind: String::from(kind),
+                manufacturer: Some(Manufacturer::Sharp),
+                date_code: Some(date_code),
+                lot_code: Some(String::from(lot_code)),
+                revision: cpu_revision(kind, "DMG-CPU"),
+                origin_country: Some(origin_country),
+                package: Some(Package::Qfp80),
+                ..GenericPart::default()
+            },
+        )
         .parse(input)
     },
+    format: Some(&|part| {
+        Some(format!(
+            "{kind} © 1989 Nintendo {country} {date_and_lot}",
+            kind = part.kind,
+            country = part.origin_country?.label(),
+            date_and_lot = date_and_lot_label(part.date_code, part.lot_code.as_deref())?,
+        ))
+    }),
 };
 
 /// Sharp DMG-CPU (glop top)
@@ -701,7 +979,9 @@ pub static SHARP_DMG_CPU: NomParser<GenericPart> = NomParser {
 /// ```
 pub static SHARP_DMG_CPU_GLOP_TOP: NomParser<GenericPart> = NomParser {
     name: "Sharp DMG-CPU glop top",
-    f: |input| {
+    examples: &["B", "C"],
+    prefixes: &["B", "C"],
+    f: &|input| {
         alt((
             value("DMG-CPU B (blob)", tag("B")),
             value("DMG-CPU C (blob)", tag("C")),
@@ -710,9 +990,17 @@ pub static SHARP_DMG_CPU_GLOP_TOP: NomParser<GenericPart> = NomParser {
             kind: String::from(kind),
             manufacturer: Some(Manufacturer::Sharp),
             date_code: None,
+            package: Some(Package::GlopTop),
+            ..GenericPart::default()
         })
         .parse(input)
     },
+    format: Some(&|part| {
+        part.kind
+            .strip_prefix("DMG-CPU ")
+            .and_then(|kind| kind.strip_suffix(" (blob)"))
+            .map(String::from)
+    }),
 };
 
 /// Sharp SGB-CPU (QFP-80)
@@ -723,27 +1011,45 @@ pub static SHARP_DMG_CPU_GLOP_TOP: NomParser<GenericPart> = NomParser {
 /// ```
 pub static SHARP_SGB_CPU: NomParser<GenericPart> = NomParser {
     name: "Sharp SGB-CPU",
-    f: |input| {
+    examples: &["SGB-CPU 01 © 1994 Nintendo Ⓜ 1989 Nintendo JAPAN 9434 7 D"],
+    prefixes: &["SGB-CPU 01"],
+    f: &|input| {
         lines5(
             tag("SGB-CPU 01"),
             tag("© 1994 Nintendo"),
             tag("Ⓜ 1989 Nintendo"),
-            tag("JAPAN"),
+            country(Country::Japan),
             separated_pair(
                 sharp_year2_week2,
                 char(' '),
-                digits(1)
-                    .and(opt(nom::character::complete::char(' ')))
-                    .and(uppers(1)),
+                recognize(
+                    digits(1)
+                        .and(opt(nom::character::complete::char(' ')))
+                        .and(uppers(1)),
+                ),
             ),
         )
-        .map(|(kind, _, _, _, (date_code, _))| GenericPart {
-            kind: String::from(kind),
-            manufacturer: Some(Manufacturer::Sharp),
-            date_code: Some(date_code),
-        })
+        .map(
+            |(kind, _, _, origin_country, (date_code, lot_code))| GenericPart {
+                kind: String::from(kind),
+                manufacturer: Some(Manufacturer::Sharp),
+                date_code: Some(date_code),
+                lot_code: Some(String::from(lot_code)),
+                origin_country: Some(origin_country),
+                package: Some(Package::Qfp80),
+                ..GenericPart::default()
+            },
+        )
         .parse(input)
     },
+    format: Some(&|part| {
+        Some(format!(
+            "{kind} © 1994 Nintendo Ⓜ 1989 Nintendo {country} {date_and_lot}",
+            kind = part.kind,
+            country = part.origin_country?.label(),
+            date_and_lot = date_and_lot_label(part.date_code, part.lot_code.as_deref())?,
+        ))
+    }),
 };
 
 /// Sharp CPU MGB (QFP-80)
@@ -754,24 +1060,40 @@ pub static SHARP_SGB_CPU: NomParser<GenericPart> = NomParser {
 /// ```
 pub static SHARP_CPU_MGB: NomParser<GenericPart> = NomParser {
     name: "Sharp CPU MGB",
-    f: |input| {
+    examples: &["CPU MGB Ⓜ © 1996 Nintendo JAPAN 9629 D"],
+    prefixes: &["CPU MGB"],
+    f: &|input| {
         lines4(
             tag("CPU MGB"),
             tag("Ⓜ © 1996 Nintendo"),
-            tag("JAPAN"),
+            country(Country::Japan),
             separated_pair(
                 sharp_year2_week2,
                 char(' '),
                 satisfy_m_n_complete(1, 2, |c| c.is_ascii_uppercase()),
             ),
         )
-        .map(|(kind, _, _, (date_code, _))| GenericPart {
-            kind: String::from(kind),
-            manufacturer: Some(Manufacturer::Sharp),
-            date_code: Some(date_code),
-        })
+        .map(
+            |(kind, _, origin_country, (date_code, lot_code))| GenericPart {
+                kind: String::from(kind),
+                manufacturer: Some(Manufacturer::Sharp),
+                date_code: Some(date_code),
+                lot_code: Some(String::from(lot_code)),
+                origin_country: Some(origin_country),
+                package: Some(Package::Qfp80),
+                ..GenericPart::default()
+            },
+        )
         .parse(input)
     },
+    format: Some(&|part| {
+        Some(format!(
+            "{kind} Ⓜ © 1996 Nintendo {country} {date_and_lot}",
+            kind = part.kind,
+            country = part.origin_country?.label(),
+            date_and_lot = date_and_lot_label(part.date_code, part.lot_code.as_deref())?,
+        ))
+    }),
 };
 
 /// Sharp CPU SGB2 (QFP-80)
@@ -782,27 +1104,45 @@ pub static SHARP_CPU_MGB: NomParser<GenericPart> = NomParser {
 /// ```
 pub static SHARP_CPU_SGB2: NomParser<GenericPart> = NomParser {
     name: "Sharp CPU SGB2",
-    f: |input| {
+    examples: &["CPU SGB2 Ⓜ 1996 Nintendo © 1997 Nintendo JAPAN 9810 7E"],
+    prefixes: &["CPU SGB2"],
+    f: &|input| {
         lines5(
             tag("CPU SGB2"),
             tag("Ⓜ 1996 Nintendo"),
             tag("© 1997 Nintendo"),
-            tag("JAPAN"),
+            country(Country::Japan),
             separated_pair(
                 sharp_year2_week2,
                 char(' '),
-                digits(1)
-                    .and(opt(nom::character::complete::char(' ')))
-                    .and(uppers(1)),
+                recognize(
+                    digits(1)
+                        .and(opt(nom::character::complete::char(' ')))
+                        .and(uppers(1)),
+                ),
             ),
         )
-        .map(|(kind, _, _, _, (date_code, _))| GenericPart {
-            kind: String::from(kind),
-            manufacturer: Some(Manufacturer::Sharp),
-            date_code: Some(date_code),
-        })
+        .map(
+            |(kind, _, _, origin_country, (date_code, lot_code))| GenericPart {
+                kind: String::from(kind),
+                manufacturer: Some(Manufacturer::Sharp),
+                date_code: Some(date_code),
+                lot_code: Some(String::from(lot_code)),
+                origin_country: Some(origin_country),
+                package: Some(Package::Qfp80),
+                ..GenericPart::default()
+            },
+        )
         .parse(input)
     },
+    format: Some(&|part| {
+        Some(format!(
+            "{kind} Ⓜ 1996 Nintendo © 1997 Nintendo {country} {date_and_lot}",
+            kind = part.kind,
+            country = part.origin_country?.label(),
+            date_and_lot = date_and_lot_label(part.date_code, part.lot_code.as_deref())?,
+        ))
+    }),
 };
 
 /// Sharp CPU CGB (QFP-128)
@@ -817,7 +1157,15 @@ pub static SHARP_CPU_SGB2: NomParser<GenericPart> = NomParser {
 /// ```
 pub static SHARP_CPU_CGB: NomParser<GenericPart> = NomParser {
     name: "Sharp CPU CGB",
-    f: |input| {
+    examples: &[
+        "CPU CGB Ⓜ © 1998 Nintendo JAPAN 9832 I",
+        "CPU CGB A Ⓜ © 1998 Nintendo JAPAN 9837 I",
+        "CPU CGB B Ⓜ © 1998 Nintendo JAPAN 9840 I",
+        "CPU CGB C Ⓜ © 1998 Nintendo JAPAN 9927 IA",
+        "CPU CGB D Ⓜ © 1998 Nintendo JAPAN 0026 I",
+    ],
+    prefixes: &["CPU CGB"],
+    f: &|input| {
         lines4(
             alt((
                 tag("CPU CGB A"),
@@ -827,20 +1175,35 @@ pub static SHARP_CPU_CGB: NomParser<GenericPart> = NomParser {
                 tag("CPU CGB"),
             )),
             tag("Ⓜ © 1998 Nintendo"),
-            tag("JAPAN"),
+            country(Country::Japan),
             separated_pair(
                 sharp_year2_week2,
                 char(' '),
                 satisfy_m_n_complete(1, 2, |c| c.is_ascii_uppercase()),
             ),
         )
-        .map(|(kind, _, _, (date_code, _))| GenericPart {
-            kind: String::from(kind),
-            manufacturer: Some(Manufacturer::Sharp),
-            date_code: Some(date_code),
-        })
+        .map(
+            |(kind, _, origin_country, (date_code, lot_code))| GenericPart {
+                kind: String::from(kind),
+                manufacturer: Some(Manufacturer::Sharp),
+                date_code: Some(date_code),
+                lot_code: Some(String::from(lot_code)),
+                revision: cpu_revision(kind, "CPU CGB"),
+                origin_country: Some(origin_country),
+                package: Some(Package::Qfp128),
+                ..GenericPart::default()
+            },
+        )
         .parse(input)
     },
+    format: Some(&|part| {
+        Some(format!(
+            "{kind} Ⓜ © 1998 Nintendo {country} {date_and_lot}",
+            kind = part.kind,
+            country = part.origin_country?.label(),
+            date_and_lot = date_and_lot_label(part.date_code, part.lot_code.as_deref())?,
+        ))
+    }),
 };
 
 /// Sharp CPU CGB E (QFP-128)
@@ -851,24 +1214,41 @@ pub static SHARP_CPU_CGB: NomParser<GenericPart> = NomParser {
 /// ```
 pub static SHARP_CPU_CGB_E: NomParser<GenericPart> = NomParser {
     name: "Sharp CPU CGB E",
-    f: |input| {
+    examples: &["CPU CGB E Ⓜ © 2000 Nintendo JAPAN 0052 I"],
+    prefixes: &["CPU CGB E"],
+    f: &|input| {
         lines4(
             tag("CPU CGB E"),
             tag("Ⓜ © 2000 Nintendo"),
-            tag("JAPAN"),
+            country(Country::Japan),
             separated_pair(
                 sharp_year2_week2,
                 char(' '),
                 satisfy_m_n_complete(1, 2, |c| c.is_ascii_uppercase()),
             ),
         )
-        .map(|(kind, _, _, (date_code, _))| GenericPart {
-            kind: String::from(kind),
-            manufacturer: Some(Manufacturer::Sharp),
-            date_code: Some(date_code),
-        })
+        .map(
+            |(kind, _, origin_country, (date_code, lot_code))| GenericPart {
+                kind: String::from(kind),
+                manufacturer: Some(Manufacturer::Sharp),
+                date_code: Some(date_code),
+                lot_code: Some(String::from(lot_code)),
+                revision: cpu_revision(kind, "CPU CGB"),
+                origin_country: Some(origin_country),
+                package: Some(Package::Qfp128),
+                ..GenericPart::default()
+            },
+        )
         .parse(input)
     },
+    format: Some(&|part| {
+        Some(format!(
+            "{kind} Ⓜ © 2000 Nintendo {country} {date_and_lot}",
+            kind = part.kind,
+            country = part.origin_country?.label(),
+            date_and_lot = date_and_lot_label(part.date_code, part.lot_code.as_deref())?,
+        ))
+    }),
 };
 
 /// Sharp CPU AGB (QFP-128)
@@ -881,24 +1261,45 @@ pub static SHARP_CPU_CGB_E: NomParser<GenericPart> = NomParser {
 /// ```
 pub static SHARP_CPU_AGB: NomParser<GenericPart> = NomParser {
     name: "Sharp CPU AGB",
-    f: |input| {
+    examples: &[
+        "CPU AGB Ⓜ © 2000 Nintendo JAPAN ARM 0104 I",
+        "CPU AGB A Ⓜ © 2000 Nintendo JAPAN ARM 0228 mE",
+        "CPU AGB A E Ⓜ © 2000 Nintendo JAPAN ARM 0503 O",
+    ],
+    prefixes: &["CPU AGB"],
+    f: &|input| {
         lines4(
             alt((tag("CPU AGB A E"), tag("CPU AGB A"), tag("CPU AGB"))),
             tag("Ⓜ © 2000 Nintendo"),
-            tag("JAPAN ARM"),
+            terminated(country(Country::Japan), tag(" ARM")),
             separated_pair(
                 sharp_year2_week2,
                 char(' '),
                 satisfy_m_n_complete(1, 2, |c| c.is_ascii_alphabetic()),
             ),
         )
-        .map(|(kind, _, _, (date_code, _))| GenericPart {
-            kind: String::from(kind),
-            manufacturer: Some(Manufacturer::Sharp),
-            date_code: Some(date_code),
-        })
+        .map(
+            |(kind, _, origin_country, (date_code, lot_code))| GenericPart {
+                kind: String::from(kind),
+                manufacturer: Some(Manufacturer::Sharp),
+                date_code: Some(date_code),
+                lot_code: Some(String::from(lot_code)),
+                revision: cpu_revision(kind, "CPU AGB"),
+                origin_country: Some(origin_country),
+                package: Some(Package::Qfp128),
+                ..GenericPart::default()
+            },
+        )
         .parse(input)
     },
+    format: Some(&|part| {
+        Some(format!(
+            "{kind} Ⓜ © 2000 Nintendo {country} ARM {date_and_lot}",
+            kind = part.kind,
+            country = part.origin_country?.label(),
+            date_and_lot = date_and_lot_label(part.date_code, part.lot_code.as_deref())?,
+        ))
+    }),
 };
 
 /// Sharp CPU AGB B (QFP-156)
@@ -910,24 +1311,44 @@ pub static SHARP_CPU_AGB: NomParser<GenericPart> = NomParser {
 /// ```
 pub static SHARP_CPU_AGB_B: NomParser<GenericPart> = NomParser {
     name: "Sharp CPU AGB B",
-    f: |input| {
+    examples: &[
+        "CPU AGB B Ⓜ © 2002 Nintendo JAPAN ARM 0311 mB",
+        "CPU AGB B E Ⓜ © 2002 Nintendo JAPAN ARM 0602 UB",
+    ],
+    prefixes: &["CPU AGB B"],
+    f: &|input| {
         lines4(
             alt((tag("CPU AGB B E"), tag("CPU AGB B"))),
             tag("Ⓜ © 2002 Nintendo"),
-            tag("JAPAN ARM"),
+            terminated(country(Country::Japan), tag(" ARM")),
             separated_pair(
                 sharp_year2_week2,
                 char(' '),
                 satisfy_m_n_complete(1, 2, |c| c.is_ascii_alphabetic()),
             ),
         )
-        .map(|(kind, _, _, (date_code, _))| GenericPart {
-            kind: String::from(kind),
-            manufacturer: Some(Manufacturer::Sharp),
-            date_code: Some(date_code),
-        })
+        .map(
+            |(kind, _, origin_country, (date_code, lot_code))| GenericPart {
+                kind: String::from(kind),
+                manufacturer: Some(Manufacturer::Sharp),
+                date_code: Some(date_code),
+                lot_code: Some(String::from(lot_code)),
+                revision: cpu_revision(kind, "CPU AGB"),
+                origin_country: Some(origin_country),
+                package: Some(Package::Qfp156),
+                ..GenericPart::default()
+            },
+        )
         .parse(input)
     },
+    format: Some(&|part| {
+        Some(format!(
+            "{kind} Ⓜ © 2002 Nintendo {country} ARM {date_and_lot}",
+            kind = part.kind,
+            country = part.origin_country?.label(),
+            date_and_lot = date_and_lot_label(part.date_code, part.lot_code.as_deref())?,
+        ))
+    }),
 };
 
 /// Sharp CPU AGB E (BGA)
@@ -938,21 +1359,35 @@ pub static SHARP_CPU_AGB_B: NomParser<GenericPart> = NomParser {
 /// ```
 pub static SHARP_CPU_AGB_E: NomParser<GenericPart> = NomParser {
     name: "Sharp CPU AGB E",
-    f: |input| {
+    examples: &["0529 2m CPU AGB E Ⓜ © 2004 Nintendo JAPAN ARM"],
+    prefixes: &[],
+    f: &|input| {
         lines5(
             terminated(sharp_year2_week2, tag(" 2m")),
             tag("CPU AGB E"),
             tag("Ⓜ © 2004"),
             tag("Nintendo"),
-            tag("JAPAN ARM"),
+            terminated(country(Country::Japan), tag(" ARM")),
         )
-        .map(|(date_code, kind, _, _, _)| GenericPart {
+        .map(|(date_code, kind, _, _, origin_country)| GenericPart {
             kind: String::from(kind),
             manufacturer: Some(Manufacturer::Sharp),
             date_code: Some(date_code),
+            revision: cpu_revision(kind, "CPU AGB"),
+            origin_country: Some(origin_country),
+            package: Some(Package::Bga),
+            ..GenericPart::default()
         })
         .parse(input)
     },
+    format: Some(&|part| {
+        Some(format!(
+            "{date_code} 2m {kind} Ⓜ © 2004 Nintendo {country} ARM",
+            date_code = for_format::year2_week2(part.date_code?)?,
+            kind = part.kind,
+            country = part.origin_country?.label(),
+        ))
+    }),
 };
 
 /// Sharp MBC1 (SOP-24)
@@ -963,20 +1398,34 @@ pub static SHARP_CPU_AGB_E: NomParser<GenericPart> = NomParser {
 /// ```
 pub static SHARP_MBC1: NomParser<Mapper> = NomParser {
     name: "Sharp MBC1",
-    f: |input| {
+    examples: &["DMG MBC1 Nintendo S 8914 T"],
+    prefixes: &["DMG"],
+    f: &|input| {
         lines4(
             tag("DMG"),
             value(MapperChip::Mbc1, tag("MBC1")),
             tag("Nintendo"),
-            delimited(tag("S "), sharp_year2_week2, char(' ').and(uppers(1))),
+            preceded(
+                tag("S "),
+                separated_pair(sharp_year2_week2, char(' '), uppers(1)),
+            ),
         )
-        .map(|(_, kind, _, date_code)| Mapper {
+        .map(|(_, kind, _, (date_code, lot_code))| Mapper {
             kind,
             manufacturer: Some(Manufacturer::Sharp),
             date_code: Some(date_code),
+            lot_code: Some(String::from(lot_code)),
+            revision: None,
+            origin_country: None,
         })
         .parse(input)
     },
+    format: Some(&|mapper| {
+        Some(format!(
+            "DMG MBC1 Nintendo S {}",
+            date_and_lot_label(mapper.date_code, mapper.lot_code.as_deref())?
+        ))
+    }),
 };
 
 /// Sharp MBC1A (SOP-24)
@@ -987,24 +1436,38 @@ pub static SHARP_MBC1: NomParser<Mapper> = NomParser {
 /// ```
 pub static SHARP_MBC1A: NomParser<Mapper> = NomParser {
     name: "Sharp MBC1A",
-    f: |input| {
+    examples: &["DMG MBC1A Nintendo S 9025 1 A"],
+    prefixes: &["DMG"],
+    f: &|input| {
         lines4(
             tag("DMG"),
             value(MapperChip::Mbc1A, tag("MBC1A")),
             tag("Nintendo"),
-            delimited(
+            preceded(
                 tag("S "),
-                sharp_year2_week2,
-                (char(' '), digits(1), char(' '), uppers(1)),
+                separated_pair(
+                    sharp_year2_week2,
+                    char(' '),
+                    recognize((digits(1), char(' '), uppers(1))),
+                ),
             ),
         )
-        .map(|(_, kind, _, date_code)| Mapper {
+        .map(|(_, kind, _, (date_code, lot_code))| Mapper {
             kind,
             manufacturer: Some(Manufacturer::Sharp),
             date_code: Some(date_code),
+            lot_code: Some(String::from(lot_code)),
+            revision: None,
+            origin_country: None,
         })
         .parse(input)
     },
+    format: Some(&|mapper| {
+        Some(format!(
+            "DMG MBC1A Nintendo S {}",
+            date_and_lot_label(mapper.date_code, mapper.lot_code.as_deref())?
+        ))
+    }),
 };
 
 /// Sharp MBC1B (SOP-24)
@@ -1015,29 +1478,42 @@ pub static SHARP_MBC1A: NomParser<Mapper> = NomParser {
 /// ```
 pub static SHARP_MBC1B: NomParser<Mapper> = NomParser {
     name: "Sharp MBC1B",
-    f: |input| {
+    examples: &["DMG MBC1B Nintendo S 9107 5 A"],
+    prefixes: &["DMG"],
+    f: &|input| {
         lines4(
             tag("DMG"),
             value(MapperChip::Mbc1B, tag("MBC1B")),
             tag("Nintendo"),
-            delimited(
+            preceded(
                 tag("S "),
-                sharp_year2_week2,
-                (
-                    char(' '),
-                    digits(1),
+                separated_pair(
+                    sharp_year2_week2,
                     char(' '),
-                    satisfy_m_n_complete(1, 2, |c| c.is_ascii_uppercase()),
+                    recognize((
+                        digits(1),
+                        char(' '),
+                        satisfy_m_n_complete(1, 2, |c| c.is_ascii_uppercase()),
+                    )),
                 ),
             ),
         )
-        .map(|(_, kind, _, date_code)| Mapper {
+        .map(|(_, kind, _, (date_code, lot_code))| Mapper {
             kind,
             manufacturer: Some(Manufacturer::Sharp),
             date_code: Some(date_code),
+            lot_code: Some(String::from(lot_code)),
+            revision: None,
+            origin_country: None,
         })
         .parse(input)
     },
+    format: Some(&|mapper| {
+        Some(format!(
+            "DMG MBC1B Nintendo S {}",
+            date_and_lot_label(mapper.date_code, mapper.lot_code.as_deref())?
+        ))
+    }),
 };
 
 /// Sharp MBC1B1 (SOP-24)
@@ -1048,24 +1524,38 @@ pub static SHARP_MBC1B: NomParser<Mapper> = NomParser {
 /// ```
 pub static SHARP_MBC1B1: NomParser<Mapper> = NomParser {
     name: "Sharp MBC1B1",
-    f: |input| {
+    examples: &["DMG MBC1B1 Nintendo S 9838 5 A"],
+    prefixes: &["DMG"],
+    f: &|input| {
         lines4(
             tag("DMG"),
             value(MapperChip::Mbc1B1, tag("MBC1B1")),
             tag("Nintendo"),
-            delimited(
+            preceded(
                 tag("S "),
-                sharp_year2_week2,
-                (char(' '), digits(1), char(' '), uppers(1)),
+                separated_pair(
+                    sharp_year2_week2,
+                    char(' '),
+                    recognize((digits(1), char(' '), uppers(1))),
+                ),
             ),
         )
-        .map(|(_, kind, _, date_code)| Mapper {
+        .map(|(_, kind, _, (date_code, lot_code))| Mapper {
             kind,
             manufacturer: Some(Manufacturer::Sharp),
             date_code: Some(date_code),
+            lot_code: Some(String::from(lot_code)),
+            revision: None,
+            origin_country: None,
         })
         .parse(input)
     },
+    format: Some(&|mapper| {
+        Some(format!(
+            "DMG MBC1B1 Nintendo S {}",
+            date_and_lot_label(mapper.date_code, mapper.lot_code.as_deref())?
+        ))
+    }),
 };
 
 /// Sharp MBC2A (SOP-28)
@@ -1076,29 +1566,42 @@ pub static SHARP_MBC1B1: NomParser<Mapper> = NomParser {
 /// ```
 pub static SHARP_MBC2A: NomParser<Mapper> = NomParser {
     name: "Sharp MBC2A",
-    f: |input| {
+    examples: &["DMG MBC2A Nintendo S 9730 5 AB"],
+    prefixes: &["DMG"],
+    f: &|input| {
         lines4(
             tag("DMG"),
             value(MapperChip::Mbc2A, tag("MBC2A")),
             tag("Nintendo"),
-            delimited(
+            preceded(
                 tag("S "),
-                sharp_year2_week2,
-                (
+                separated_pair(
+                    sharp_year2_week2,
                     char(' '),
-                    digits(1),
-                    char(' '),
-                    satisfy_m_n_complete(1, 2, |c| c.is_ascii_uppercase()),
+                    recognize((
+                        digits(1),
+                        char(' '),
+                        satisfy_m_n_complete(1, 2, |c| c.is_ascii_uppercase()),
+                    )),
                 ),
             ),
         )
-        .map(|(_, kind, _, date_code)| Mapper {
+        .map(|(_, kind, _, (date_code, lot_code))| Mapper {
             kind,
             manufacturer: Some(Manufacturer::Sharp),
             date_code: Some(date_code),
+            lot_code: Some(String::from(lot_code)),
+            revision: None,
+            origin_country: None,
         })
         .parse(input)
     },
+    format: Some(&|mapper| {
+        Some(format!(
+            "DMG MBC2A Nintendo S {}",
+            date_and_lot_label(mapper.date_code, mapper.lot_code.as_deref())?
+        ))
+    }),
 };
 
 /// Sharp MBC3 (QFP-32)
@@ -1109,19 +1612,30 @@ pub static SHARP_MBC2A: NomParser<Mapper> = NomParser {
 /// ```
 pub static SHARP_MBC3: NomParser<Mapper> = NomParser {
     name: "Sharp MBC3",
-    f: |input| {
+    examples: &["MBC3 LR385364 9743 A"],
+    prefixes: &["MBC3"],
+    f: &|input| {
         lines3(
             value(MapperChip::Mbc3, tag("MBC3")),
             tag("LR385364"),
-            terminated(sharp_year2_week2, (char(' '), uppers(1))),
+            separated_pair(sharp_year2_week2, char(' '), uppers(1)),
         )
-        .map(|(kind, _, date_code)| Mapper {
+        .map(|(kind, _, (date_code, lot_code))| Mapper {
             kind,
             manufacturer: Some(Manufacturer::Sharp),
             date_code: Some(date_code),
+            lot_code: Some(String::from(lot_code)),
+            revision: None,
+            origin_country: None,
         })
         .parse(input)
     },
+    format: Some(&|mapper| {
+        Some(format!(
+            "MBC3 LR385364 {}",
+            date_and_lot_label(mapper.date_code, mapper.lot_code.as_deref())?
+        ))
+    }),
 };
 
 /// Sharp MBC3A (QFP-32)
@@ -1132,19 +1646,30 @@ pub static SHARP_MBC3: NomParser<Mapper> = NomParser {
 /// ```
 pub static SHARP_MBC3A: NomParser<Mapper> = NomParser {
     name: "Sharp MBC3A",
-    f: |input| {
+    examples: &["MBC3 A LR38536B 9935 A"],
+    prefixes: &["MBC3 A"],
+    f: &|input| {
         lines3(
             value(MapperChip::Mbc3A, tag("MBC3 A")),
             tag("LR38536B"),
-            terminated(sharp_year2_week2, (char(' '), uppers(1))),
+            separated_pair(sharp_year2_week2, char(' '), uppers(1)),
         )
-        .map(|(kind, _, date_code)| Mapper {
+        .map(|(kind, _, (date_code, lot_code))| Mapper {
             kind,
             manufacturer: Some(Manufacturer::Sharp),
             date_code: Some(date_code),
+            lot_code: Some(String::from(lot_code)),
+            revision: None,
+            origin_country: None,
         })
         .parse(input)
     },
+    format: Some(&|mapper| {
+        Some(format!(
+            "MBC3 A LR38536B {}",
+            date_and_lot_label(mapper.date_code, mapper.lot_code.as_deref())?
+        ))
+    }),
 };
 
 /// Sharp MBC5 (QFP-32)
@@ -1156,19 +1681,26 @@ pub static SHARP_MBC3A: NomParser<Mapper> = NomParser {
 /// ```
 pub static SHARP_MBC5: NomParser<Mapper> = NomParser {
     name: "Sharp MBC5",
-    f: |input| {
+    examples: &["MBC5 LZ9GB31 AL23 A", "MBC5 LZ9GB31 AK50 A"],
+    prefixes: &["MBC5"],
+    f: &|input| {
         lines3(
             value(MapperChip::Mbc5, tag("MBC5")),
             tag("LZ9GB31"),
-            terminated(sharp_year2_week2, (char(' '), uppers(1))),
+            separated_pair(sharp_year2_week2, char(' '), uppers(1)),
         )
-        .map(|(kind, _, date_code)| Mapper {
+        .map(|(kind, _, (date_code, lot_code))| Mapper {
             kind,
             manufacturer: Some(Manufacturer::Sharp),
             date_code: Some(date_code),
+            lot_code: Some(String::from(lot_code)),
+            revision: None,
+            origin_country: None,
         })
         .parse(input)
     },
+    // "AK" and "00" both mean 2000, so labels can't be synthesized
+    format: None,
 };
 
 /// Sharp LCD Chip (old)
@@ -1179,7 +1711,10 @@ pub static SHARP_MBC5: NomParser<Mapper> = NomParser {
 /// ```
 pub static SHARP_LCD_CHIP_OLD: NomParser<PartDateCode> = NomParser {
     name: "Sharp LCD Chip (old)",
-    f: |input| year1_month2.parse(input),
+    examples: &["110"],
+    prefixes: &[],
+    f: &|input| year1_month2.parse(input),
+    format: Some(&|&date_code| for_format::year1_month2(date_code)),
 };
 
 /// Sharp LCD Chip (new)
@@ -1190,13 +1725,17 @@ pub static SHARP_LCD_CHIP_OLD: NomParser<PartDateCode> = NomParser {
 /// ```
 pub static SHARP_LCD_CHIP_NEW: NomParser<PartDateCode> = NomParser {
     name: "Sharp LCD Chip (new)",
-    f: |input| terminated(year1_week2, digits(1)).parse(input),
+    examples: &["5341"],
+    prefixes: &[],
+    f: &|input| terminated(year1_week2, digits(1)).parse(input),
+    // The trailing digit is not kept in the parsed date code
+    format: None,
 };
 
 /// Sharp LCD Screen
 ///
 /// ```
-/// use gbhwdb_model::parser::{self, LabelParser};
+/// use gbhwdb_model::{parser::{self, LabelParser, PartDateCode, Year}, time::Month};
 /// assert!(parser::sharp::SHARP_LCD_SCREEN.parse("S890220").is_ok());
 /// assert!(parser::sharp::SHARP_LCD_SCREEN.parse("AH900327").is_ok());
 /// assert!(parser::sharp::SHARP_LCD_SCREEN.parse("N AE900724").is_ok());
@@ -1204,11 +1743,28 @@ pub static SHARP_LCD_CHIP_NEW: NomParser<PartDateCode> = NomParser {
 /// assert!(parser::sharp::SHARP_LCD_SCREEN.parse("A890407").is_ok());
 /// assert!(parser::sharp::SHARP_LCD_SCREEN.parse("N1 AH910720").is_ok());
 /// assert!(parser::sharp::SHARP_LCD_SCREEN.parse("890808").is_ok());
+/// assert!(parser::sharp::SHARP_LCD_SCREEN.parse("AE900400").is_ok());
+/// // Day 00 means the day is not known
+/// assert_eq!(
+///     parser::sharp::SHARP_LCD_SCREEN.parse("AE900400").unwrap(),
+///     PartDateCode::YearMonth { year: Year::Full(1990), month: Month::April }
+/// );
 /// ```
 pub static SHARP_LCD_SCREEN: NomParser<PartDateCode> = NomParser {
     name: "Sharp LCD Screen",
-    f: |input| {
-        delimited(
+    examples: &[
+        "S890220",
+        "AH900327",
+        "N AE900724",
+        "AE900404",
+        "A890407",
+        "N1 AH910720",
+        "890808",
+        "AE900400",
+    ],
+    prefixes: &[],
+    f: &|input| {
+        preceded(
             alt([
                 tag("ST"),
                 tag("AH"),
@@ -1226,11 +1782,17 @@ pub static SHARP_LCD_SCREEN: NomParser<PartDateCode> = NomParser {
                 tag(""),
             ])
             .and(opt(tag(" "))),
-            year2_month2,
-            digits(2),
+            alt((
+                year2_month2_day2,
+                // Day 00 is printed when the day is not known
+                terminated(year2.and(month2), tag("00"))
+                    .map(|(year, month)| PartDateCode::YearMonth { year, month }),
+            )),
         )
         .parse(input)
     },
+    // The leading marking is not kept in the parsed date code
+    format: None,
 };
 
 /// Sharp LH51D256T (TSOP-I-28, 3.3V, 256 Kibit / 32 KiB)
@@ -1242,57 +1804,110 @@ pub static SHARP_LCD_SCREEN: NomParser<PartDateCode> = NomParser {
 /// ```
 pub static SHARP_LH51D256T: NomParser<GenericPart> = NomParser {
     name: "Sharp LH51D256T",
-    f: |input| {
+    examples: &[
+        "LH51D256T-Z5 SHARP Y007 5 J",
+        "LH51D256T-Z7 SHARP Y0 50 3 J",
+    ],
+    prefixes: &["LH51D256T-Z"],
+    f: &|input| {
         lines3(
             recognize((tag("LH51D256T-Z"), one_of("57"))),
             tag("SHARP"),
-            delimited(
-                alt((tag("AY"), tag("Y"))),
-                (year1, opt(tag(" ")), week2)
-                    .map(|(year, _, week)| PartDateCode::YearWeek { year, week }),
-                (tag(" "), digits(1), tag(" J")),
+            separated_pair(
+                preceded(
+                    alt((tag("AY"), tag("Y"))),
+                    (year1, opt(tag(" ")), week2)
+                        .map(|(year, _, week)| PartDateCode::YearWeek { year, week }),
+                ),
+                tag(" "),
+                recognize((digits(1), tag(" J"))),
             ),
         )
-        .map(|(kind, _, date_code)| GenericPart {
+        .map(|(kind, _, (date_code, lot_code))| GenericPart {
             kind: String::from(kind),
             manufacturer: Some(Manufacturer::Sharp),
             date_code: Some(date_code),
+            lot_code: Some(String::from(lot_code)),
+            revision: None,
+            origin_country: None,
+            package: Some(Package::TsopI28),
+            voltage: Some(Voltage::V3_3),
+            density: Some(256 * KIBIT),
+            organization: Some(Organization::new(32 * 1024, 8)),
+            access_time: dash_speed_grade(kind),
         })
         .parse(input)
     },
+    // "AY"/"Y" and the optional space in the date code are not kept in the parsed part
+    format: None,
 };
 
+fn lh51_52_label(part: &GenericPart) -> Option<String> {
+    let date_and_lot = date_and_lot_label(part.date_code, part.lot_code.as_deref())?;
+    Some(match part.origin_country {
+        Some(country) => format!("{} SHARP {} {date_and_lot}", part.kind, country.label()),
+        None => format!("{} SHARP A{date_and_lot}", part.kind),
+    })
+}
+
 fn lh51_52_alt<'a, E: ParseError<&'a str>>(
     kind: &'static str,
+    package: Package,
+    voltage: Voltage,
+    density: u32,
     suffix: impl Parser<&'a str, Output = &'a str, Error = E>,
 ) -> impl Parser<&'a str, Output = GenericPart, Error = E> {
     lines3(
         tag(kind),
         tag("SHARP"),
-        delimited(tag("A"), sharp_year2_week2, (tag(" "), suffix)),
+        preceded(
+            tag("A"),
+            separated_pair(sharp_year2_week2, tag(" "), suffix),
+        ),
     )
-    .map(|(kind, _, date_code)| GenericPart {
+    .map(move |(kind, _, (date_code, lot_code))| GenericPart {
         kind: String::from(kind),
         manufacturer: Some(Manufacturer::Sharp),
         date_code: Some(date_code),
+        lot_code: Some(String::from(lot_code)),
+        revision: None,
+        origin_country: None,
+        package: Some(package),
+        voltage: Some(voltage),
+        density: Some(density),
+        organization: Some(Organization::new(density / 8, 8)),
+        access_time: dash_speed_grade(kind),
     })
 }
 
 fn lh51_52<'a, E: ParseError<&'a str>>(
     kind: &'static str,
+    package: Package,
+    voltage: Voltage,
+    density: u32,
     suffix: impl Parser<&'a str, Output = &'a str, Error = E>,
 ) -> impl Parser<&'a str, Output = GenericPart, Error = E> {
     lines4(
         tag(kind),
         tag("SHARP"),
-        tag("JAPAN"),
-        terminated(sharp_year2_week2, (tag(" "), suffix)),
+        country(Country::Japan),
+        separated_pair(sharp_year2_week2, tag(" "), suffix),
+    )
+    .map(
+        move |(kind, _, origin_country, (date_code, lot_code))| GenericPart {
+            kind: String::from(kind),
+            manufacturer: Some(Manufacturer::Sharp),
+            date_code: Some(date_code),
+            lot_code: Some(String::from(lot_code)),
+            revision: None,
+            origin_country: Some(origin_country),
+            package: Some(package),
+            voltage: Some(voltage),
+            density: Some(density),
+            organization: Some(Organization::new(density / 8, 8)),
+            access_time: dash_speed_grade(kind),
+        },
     )
-    .map(|(kind, _, _, date_code)| GenericPart {
-        kind: String::from(kind),
-        manufacturer: Some(Manufacturer::Sharp),
-        date_code: Some(date_code),
-    })
 }
 
 /// Sharp LH52CV256JT (TSOP-I-28, 3.3V, 256 Kibit / 32 KiB)
@@ -1303,13 +1918,19 @@ fn lh51_52<'a, E: ParseError<&'a str>>(
 /// ```
 pub static SHARP_LH52CV256JT: NomParser<GenericPart> = NomParser {
     name: "Sharp LH52CV256JT",
-    f: |input| {
+    examples: &["LH52CV256JT-10LL SHARP JAPAN 9814 7 SA"],
+    prefixes: &["LH52CV256JT-10LL"],
+    f: &|input| {
         lh51_52(
             "LH52CV256JT-10LL",
+            Package::TsopI28,
+            Voltage::V3_3,
+            256 * KIBIT,
             recognize((digits(1), tag(" "), uppers(2))),
         )
         .parse(input)
     },
+    format: Some(&lh51_52_label),
 };
 
 /// Sharp LH52256CVT (TSOP-I-28, 2.7-5.5V, 256 Kibit / 32 KiB)
@@ -1320,7 +1941,19 @@ pub static SHARP_LH52CV256JT: NomParser<GenericPart> = NomParser {
 /// ```
 pub static SHARP_LH52256CVT: NomParser<GenericPart> = NomParser {
     name: "Sharp LH52256CVT",
-    f: |input| lh51_52("LH52256CVT", recognize((digits(1), tag(" "), uppers(2)))).parse(input),
+    examples: &["LH52256CVT SHARP JAPAN 9933 3 SO"],
+    prefixes: &["LH52256CVT"],
+    f: &|input| {
+        lh51_52(
+            "LH52256CVT",
+            Package::TsopI28,
+            Voltage::range(2_700, 5_500),
+            256 * KIBIT,
+            recognize((digits(1), tag(" "), uppers(2))),
+        )
+        .parse(input)
+    },
+    format: Some(&lh51_52_label),
 };
 
 /// Sharp LH52256CVN (SOP-28, 2.7-5.5V, 256 Kibit / 32 KiB)
@@ -1331,7 +1964,19 @@ pub static SHARP_LH52256CVT: NomParser<GenericPart> = NomParser {
 /// ```
 pub static SHARP_LH52256CVN: NomParser<GenericPart> = NomParser {
     name: "Sharp LH52256CVN",
-    f: |input| lh51_52("LH52256CVN", recognize((digits(1), tag(" "), uppers(2)))).parse(input),
+    examples: &["LH52256CVN SHARP JAPAN 9944 5 SO"],
+    prefixes: &["LH52256CVN"],
+    f: &|input| {
+        lh51_52(
+            "LH52256CVN",
+            Package::Sop28,
+            Voltage::range(2_700, 5_500),
+            256 * KIBIT,
+            recognize((digits(1), tag(" "), uppers(2))),
+        )
+        .parse(input)
+    },
+    format: Some(&lh51_52_label),
 };
 
 /// Sharp LH52256CT (TSOP-I-28, 5V, 256 Kibit / 32 KiB)
@@ -1342,13 +1987,19 @@ pub static SHARP_LH52256CVN: NomParser<GenericPart> = NomParser {
 /// ```
 pub static SHARP_LH52256CT: NomParser<GenericPart> = NomParser {
     name: "Sharp LH52256CT",
-    f: |input| {
+    examples: &["LH52256CT-10LL SHARP JAPAN 9842 7 SS"],
+    prefixes: &["LH52256CT-10LL"],
+    f: &|input| {
         lh51_52(
             "LH52256CT-10LL",
+            Package::TsopI28,
+            Voltage::V5,
+            256 * KIBIT,
             recognize((digits(1), tag(" "), uppers(2))),
         )
         .parse(input)
     },
+    format: Some(&lh51_52_label),
 };
 
 /// Sharp LH52256CN (SOP-28, 5V, 256 Kibit / 32 KiB)
@@ -1360,19 +2011,31 @@ pub static SHARP_LH52256CT: NomParser<GenericPart> = NomParser {
 /// ```
 pub static SHARP_LH52256CN: NomParser<GenericPart> = NomParser {
     name: "Sharp LH52256CN",
-    f: |input| {
+    examples: &[
+        "LH52256CN-10LL SHARP JAPAN 0036 5 SO",
+        "LH52256CN-10LL SHARP A9802 3 EC",
+    ],
+    prefixes: &["LH52256CN-10LL"],
+    f: &|input| {
         alt((
             lh51_52(
                 "LH52256CN-10LL",
+                Package::Sop28,
+                Voltage::V5,
+                256 * KIBIT,
                 recognize((digits(1), tag(" "), uppers(2))),
             ),
             lh51_52_alt(
                 "LH52256CN-10LL",
+                Package::Sop28,
+                Voltage::V5,
+                256 * KIBIT,
                 recognize((digits(1), tag(" "), uppers(2))),
             ),
         ))
         .parse(input)
     },
+    format: Some(&lh51_52_label),
 };
 
 /// Sharp LH52A64N (SOP-28, 5V, 64 Kibit / 8 KiB)
@@ -1383,7 +2046,19 @@ pub static SHARP_LH52256CN: NomParser<GenericPart> = NomParser {
 /// ```
 pub static SHARP_LH52A64N: NomParser<GenericPart> = NomParser {
     name: "Sharp LH52A64N",
-    f: |input| lh51_52("LH52A64N-L", recognize((digits(1), tag(" "), uppers(1)))).parse(input),
+    examples: &["LH52A64N-L SHARP JAPAN 9817 1 Y"],
+    prefixes: &["LH52A64N-L"],
+    f: &|input| {
+        lh51_52(
+            "LH52A64N-L",
+            Package::Sop28,
+            Voltage::V5,
+            64 * KIBIT,
+            recognize((digits(1), tag(" "), uppers(1))),
+        )
+        .parse(input)
+    },
+    format: Some(&lh51_52_label),
 };
 
 /// Sharp LH5264TN (SOP-28, 5V, 64 Kibit / 8 KiB)
@@ -1394,7 +2069,19 @@ pub static SHARP_LH52A64N: NomParser<GenericPart> = NomParser {
 /// ```
 pub static SHARP_LH5264TN: NomParser<GenericPart> = NomParser {
     name: "Sharp LH5264TN",
-    f: |input| lh51_52("LH5264TN-L", recognize((digits(1), tag(" "), uppers(1)))).parse(input),
+    examples: &["LH5264TN-L SHARP JAPAN 8937 3 Y"],
+    prefixes: &["LH5264TN-L"],
+    f: &|input| {
+        lh51_52(
+            "LH5264TN-L",
+            Package::Sop28,
+            Voltage::V5,
+            64 * KIBIT,
+            recognize((digits(1), tag(" "), uppers(1))),
+        )
+        .parse(input)
+    },
+    format: Some(&lh51_52_label),
 };
 
 /// Sharp LH5264N4 (SOP-28, 5V, 64 Kibit / 8 KiB)
@@ -1405,7 +2092,19 @@ pub static SHARP_LH5264TN: NomParser<GenericPart> = NomParser {
 /// ```
 pub static SHARP_LH5264N4: NomParser<GenericPart> = NomParser {
     name: "Sharp LH5264N4",
-    f: |input| lh51_52("LH5264N4", recognize((digits(1), tag(" "), uppers(1)))).parse(input),
+    examples: &["LH5264N4 SHARP JAPAN 8922 1 Y"],
+    prefixes: &["LH5264N4"],
+    f: &|input| {
+        lh51_52(
+            "LH5264N4",
+            Package::Sop28,
+            Voltage::V5,
+            64 * KIBIT,
+            recognize((digits(1), tag(" "), uppers(1))),
+        )
+        .parse(input)
+    },
+    format: Some(&lh51_52_label),
 };
 
 /// Sharp LH5164N (SOP-28, 5V, 64 Kibit / 8 KiB)
@@ -1417,13 +2116,31 @@ pub static SHARP_LH5264N4: NomParser<GenericPart> = NomParser {
 /// ```
 pub static SHARP_LH5164N: NomParser<GenericPart> = NomParser {
     name: "Sharp LH5164N",
-    f: |input| {
+    examples: &[
+        "LH5164N-10L SHARP JAPAN 9043 1 DA",
+        "LH5164LN-10 SHARP JAPAN 8848 3 D",
+    ],
+    prefixes: &["LH5164N-10L", "LH5164LN-10"],
+    f: &|input| {
         alt((
-            lh51_52("LH5164N-10L", recognize((digits(1), tag(" "), uppers(2)))),
-            lh51_52("LH5164LN-10", recognize((digits(1), tag(" "), uppers(1)))),
+            lh51_52(
+                "LH5164N-10L",
+                Package::Sop28,
+                Voltage::V5,
+                64 * KIBIT,
+                recognize((digits(1), tag(" "), uppers(2))),
+            ),
+            lh51_52(
+                "LH5164LN-10",
+                Package::Sop28,
+                Voltage::V5,
+                64 * KIBIT,
+                recognize((digits(1), tag(" "), uppers(1))),
+            ),
         ))
         .parse(input)
     },
+    format: Some(&lh51_52_label),
 };
 
 /// Sharp LH5168N (SOP-28, 5V, 64 Kibit / 8 KiB)
@@ -1434,7 +2151,19 @@ pub static SHARP_LH5164N: NomParser<GenericPart> = NomParser {
 /// ```
 pub static SHARP_LH5168N: NomParser<GenericPart> = NomParser {
     name: "Sharp LH5168N",
-    f: |input| lh51_52("LH5168N-10L", recognize((digits(1), tag(" "), uppers(2)))).parse(input),
+    examples: &["LH5168N-10L SHARP JAPAN 9818 1 CG"],
+    prefixes: &["LH5168N-10L"],
+    f: &|input| {
+        lh51_52(
+            "LH5168N-10L",
+            Package::Sop28,
+            Voltage::V5,
+            64 * KIBIT,
+            recognize((digits(1), tag(" "), uppers(2))),
+        )
+        .parse(input)
+    },
+    format: Some(&lh51_52_label),
 };
 
 /// Sharp LH5168NF (SOP-28, 5V, 64 Kibit / 8 KiB)
@@ -1447,14 +2176,39 @@ pub static SHARP_LH5168N: NomParser<GenericPart> = NomParser {
 /// ```
 pub static SHARP_LH5168NF: NomParser<GenericPart> = NomParser {
     name: "Sharp LH5168NF",
-    f: |input| {
+    examples: &[
+        "LH5168NF-10L SHARP JAPAN 9039 5 S",
+        "LH5168NFA-10L SHARP JAPAN 9103 3 SA",
+        "LH5168NFB-10L SHARP JAPAN 9147 DC",
+    ],
+    prefixes: &["LH5168NF"],
+    f: &|input| {
         alt((
-            lh51_52("LH5168NF-10L", recognize((digits(1), tag(" "), uppers(1)))),
-            lh51_52("LH5168NFA-10L", recognize((digits(1), tag(" "), uppers(2)))),
-            lh51_52("LH5168NFB-10L", recognize(uppers(2))),
+            lh51_52(
+                "LH5168NF-10L",
+                Package::Sop28,
+                Voltage::V5,
+                64 * KIBIT,
+                recognize((digits(1), tag(" "), uppers(1))),
+            ),
+            lh51_52(
+                "LH5168NFA-10L",
+                Package::Sop28,
+                Voltage::V5,
+                64 * KIBIT,
+                recognize((digits(1), tag(" "), uppers(2))),
+            ),
+            lh51_52(
+                "LH5168NFB-10L",
+                Package::Sop28,
+                Voltage::V5,
+                64 * KIBIT,
+                recognize(uppers(2)),
+            ),
         ))
         .parse(input)
     },
+    format: Some(&lh51_52_label),
 };
 
 /// Sharp LH5160N (SOP-28, 4.5-5.5V, 64 Kibit / 8 KiB)
@@ -1465,7 +2219,19 @@ pub static SHARP_LH5168NF: NomParser<GenericPart> = NomParser {
 /// ```
 pub static SHARP_LH5160N: NomParser<GenericPart> = NomParser {
     name: "Sharp LH5160N",
-    f: |input| lh51_52("LH5160N-10L", recognize((digits(1), tag(" "), uppers(2)))).parse(input),
+    examples: &["LH5160N-10L SHARP JAPAN 9007 5 DA"],
+    prefixes: &["LH5160N-10L"],
+    f: &|input| {
+        lh51_52(
+            "LH5160N-10L",
+            Package::Sop28,
+            Voltage::range(4_500, 5_500),
+            64 * KIBIT,
+            recognize((digits(1), tag(" "), uppers(2))),
+        )
+        .parse(input)
+    },
+    format: Some(&lh51_52_label),
 };
 
 /// Sharp LH5164AN (SOP-28, 5V, 64 Kibit / 8 KiB)
@@ -1477,13 +2243,31 @@ pub static SHARP_LH5160N: NomParser<GenericPart> = NomParser {
 /// ```
 pub static SHARP_LH5164AN: NomParser<GenericPart> = NomParser {
     name: "Sharp LH5164AN",
-    f: |input| {
+    examples: &[
+        "LH5164AN-10L SHARP JAPAN 9933 3 EB",
+        "LH5164AN-10L SHARP A9846 7 CB",
+    ],
+    prefixes: &["LH5164AN-10L"],
+    f: &|input| {
         alt((
-            lh51_52("LH5164AN-10L", recognize((digits(1), tag(" "), uppers(2)))),
-            lh51_52_alt("LH5164AN-10L", recognize((digits(1), tag(" "), uppers(2)))),
+            lh51_52(
+                "LH5164AN-10L",
+                Package::Sop28,
+                Voltage::V5,
+                64 * KIBIT,
+                recognize((digits(1), tag(" "), uppers(2))),
+            ),
+            lh51_52_alt(
+                "LH5164AN-10L",
+                Package::Sop28,
+                Voltage::V5,
+                64 * KIBIT,
+                recognize((digits(1), tag(" "), uppers(2))),
+            ),
         ))
         .parse(input)
     },
+    format: Some(&lh51_52_label),
 };
 
 fn sharp_year2<'a, E: ParseError<&'a str>>(input: &'a str) -> IResult<&'a str, Year, E> {
@@ -1491,7 +2275,7 @@ fn sharp_year2<'a, E: ParseError<&'a str>>(input: &'a str) -> IResult<&'a str, Y
         "AA" => Some(Year::Full(2000)),
         "AK" => Some(Year::Full(2000)),
         "AL" => Some(Year::Full(2001)),
-        _ => match u16::from_str_radix(text, 10) {
+        _ => match text.parse::<u16>() {
             Ok(value @ 0..=87) => Some(Year::Full(value + 2000)),
             Ok(value @ 88..=99) => Some(Year::Full(value + 1900)),
             _ => None,
@@ -2,14 +2,14 @@
 //
 // SPDX-License-Identifier: MIT
 
-use nom::{Parser as _, bytes::streaming::tag};
+use nom::Parser as _;
 
 use crate::parser::{
     Manufacturer, NomParser,
-    for_nom::{digits, year2_week2},
+    for_nom::{digits, tag, year2_week2},
 };
 
-use super::{GenericPart, for_nom::lines3};
+use super::{GenericPart, KIBIT, Organization, Package, Voltage, for_format, for_nom::lines3};
 
 /// SST SST39VF512 flash (TSOP-I-32, 2.7-3.6V)
 ///
@@ -20,17 +20,34 @@ use super::{GenericPart, for_nom::lines3};
 /// ```
 pub static SST_SST39VF512: NomParser<GenericPart> = NomParser {
     name: "SST SST39VF512",
-    f: |input| {
+    examples: &["39VF512 70-4C-WH 0216049-D", "39VF512 70-4C-WH 0350077-D"],
+    prefixes: &["39VF512"],
+    f: &|input| {
         lines3(
             tag("39VF512"),
             tag("70-4C-WH"), // speed, durability, grade, package
             (year2_week2, digits(3), tag("-D")),
         )
-        .map(|(kind, attrs, (date_code, _, _))| GenericPart {
+        .map(|(kind, attrs, (date_code, lot_code, _))| GenericPart {
             kind: format!("SST{kind}-{attrs}"),
             manufacturer: Some(Manufacturer::Sst),
             date_code: Some(date_code),
+            lot_code: Some(String::from(lot_code)),
+            revision: None,
+            origin_country: None,
+            package: Some(Package::TsopI32),
+            voltage: Some(Voltage::range(2_700, 3_600)),
+            density: Some(512 * KIBIT),
+            organization: Some(Organization::new(64 * 1024, 8)),
+            access_time: Some(70),
         })
         .parse(input)
     },
+    format: Some(&|part| {
+        Some(format!(
+            "39VF512 70-4C-WH {date}{lot}-D",
+            date = for_format::year2_week2(part.date_code?)?,
+            lot = part.lot_code.as_deref()?,
+        ))
+    }),
 };
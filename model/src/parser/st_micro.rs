@@ -4,15 +4,14 @@
 
 use nom::{
     Parser as _,
-    bytes::streaming::tag,
     character::streaming::char,
     combinator::{opt, recognize},
     sequence::{preceded, separated_pair},
 };
 
 use super::{
-    GenericPart,
-    for_nom::{alnum_uppers, lines4, uppers, year1_week2},
+    GenericPart, MIBIT, Organization, Package, Voltage, for_format,
+    for_nom::{alnum_uppers, lines4, tag, uppers, year1_week2},
 };
 use crate::parser::{Manufacturer, NomParser};
 
@@ -24,7 +23,9 @@ use crate::parser::{Manufacturer, NomParser};
 /// ```
 pub static ST_MICRO_M68AS128: NomParser<GenericPart> = NomParser {
     name: "STMicro M68AS128",
-    f: |input| {
+    examples: &["M68AS128 DL70N6 AANFG F6 TWN 8B 414"],
+    prefixes: &["E M68AS128", "M68AS128"],
+    f: &|input| {
         preceded(
             opt(tag("E ")),
             lines4(
@@ -45,11 +46,28 @@ pub static ST_MICRO_M68AS128: NomParser<GenericPart> = NomParser {
                 ),
             ),
         )
-        .map(|(kind, attrs, _, (_, _, _, _, date_code))| GenericPart {
-            kind: format!("{kind}{attrs}"),
-            manufacturer: Some(Manufacturer::StMicro),
-            date_code: Some(date_code),
-        })
+        .map(
+            |(kind, attrs, (lot, _), (_, _, trace, _, date_code))| GenericPart {
+                kind: format!("{kind}{attrs}"),
+                manufacturer: Some(Manufacturer::StMicro),
+                date_code: Some(date_code),
+                lot_code: Some(format!("{lot} {trace}")),
+                revision: None,
+                origin_country: None,
+                package: Some(Package::TsopI48),
+                voltage: Some(Voltage::V3_3),
+                density: Some(2 * MIBIT),
+                organization: Some(Organization::new(128 * 1024, 16)),
+                access_time: Some(70),
+            },
+        )
         .parse(input)
     },
+    format: Some(&|part| {
+        let (lot, trace) = part.lot_code.as_deref()?.split_once(' ')?;
+        Some(format!(
+            "M68AS128 DL70N6 {lot} F6 TWN {trace} {date}",
+            date = for_format::year1_week2(part.date_code?)?,
+        ))
+    }),
 };
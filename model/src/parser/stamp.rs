@@ -26,7 +26,9 @@ pub struct DmgStamp {
 /// ```
 pub static DMG_STAMP: NomParser<DmgStamp> = NomParser {
     name: "DMG stamp",
-    f: |input| {
+    examples: &["010 23", "903-22", "709.3901", "202-0007", "008.270-"],
+    prefixes: &[],
+    f: &|input| {
         terminated(
             year1.and(month2),
             opt(one_of("- ."))
@@ -41,6 +43,8 @@ pub static DMG_STAMP: NomParser<DmgStamp> = NomParser {
         })
         .parse(input)
     },
+    // The separator and serial digits are not kept in the parsed stamp
+    format: None,
 };
 
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -55,7 +59,9 @@ pub struct CgbStamp {
 /// ```
 pub static CGB_STAMP: NomParser<CgbStamp> = NomParser {
     name: "CGB stamp",
-    f: |input| {
+    examples: &["218-2221"],
+    prefixes: &[],
+    f: &|input| {
         terminated(
             week2.and(year1),
             opt(one_of("- .X"))
@@ -68,4 +74,6 @@ pub static CGB_STAMP: NomParser<CgbStamp> = NomParser {
         })
         .parse(input)
     },
+    // The separator and serial digits are not kept in the parsed stamp
+    format: None,
 };
@@ -2,13 +2,17 @@
 //
 // SPDX-License-Identifier: MIT
 
-use nom::{Parser, bytes::streaming::tag};
+use nom::Parser;
 
 use super::{
     GenericPart, Manufacturer, NomParser,
-    for_nom::{alphas, lines3, uppers},
+    for_nom::{alphas, lines3, tag, uppers},
 };
 
+fn tdk_label(part: &GenericPart) -> Option<String> {
+    Some(format!("TDK {} {}", part.kind, part.lot_code.as_deref()?))
+}
+
 /// TDK ZJY-M4A
 ///
 /// ```
@@ -17,15 +21,20 @@ use super::{
 /// ```
 pub static TDK_ZJY_M4A: NomParser<GenericPart> = NomParser {
     name: "TDK ZJY-M4A",
-    f: |input| {
+    examples: &["TDK ZJY-M4A N"],
+    prefixes: &["TDK"],
+    f: &|input| {
         lines3(tag("TDK"), tag("ZJY-M4A"), uppers(1))
-            .map(|(_, kind, _)| GenericPart {
+            .map(|(_, kind, lot_code)| GenericPart {
                 kind: String::from(kind),
                 manufacturer: Some(Manufacturer::Tdk),
                 date_code: None,
+                lot_code: Some(String::from(lot_code)),
+                ..GenericPart::default()
             })
             .parse(input)
     },
+    format: Some(&tdk_label),
 };
 
 /// TDK ZJY-M4PA
@@ -36,13 +45,18 @@ pub static TDK_ZJY_M4A: NomParser<GenericPart> = NomParser {
 /// ```
 pub static TDK_ZJY_M4PA: NomParser<GenericPart> = NomParser {
     name: "TDK ZJY-M4PA",
-    f: |input| {
+    examples: &["TDK ZJY-M4PA n"],
+    prefixes: &["TDK"],
+    f: &|input| {
         lines3(tag("TDK"), tag("ZJY-M4PA"), alphas(1))
-            .map(|(_, kind, _)| GenericPart {
+            .map(|(_, kind, lot_code)| GenericPart {
                 kind: String::from(kind),
                 manufacturer: Some(Manufacturer::Tdk),
                 date_code: None,
+                lot_code: Some(String::from(lot_code)),
+                ..GenericPart::default()
             })
             .parse(input)
     },
+    format: Some(&tdk_label),
 };
@@ -2,11 +2,11 @@
 //
 // SPDX-License-Identifier: MIT
 
-use nom::{Parser as _, bytes::streaming::tag, sequence::terminated};
+use nom::{Parser as _, combinator::recognize, sequence::terminated};
 
 use super::{
-    GenericPart, Manufacturer, Mapper, MapperChip, NomParser,
-    for_nom::{alnum_uppers, lines3, uppers, year1_month1_123abc},
+    GenericPart, Manufacturer, Mapper, MapperChip, NomParser, for_format,
+    for_nom::{alnum_uppers, lines3, tag, uppers, year1_month1_123abc},
 };
 
 /// TI SN74LV2416 supervisor
@@ -19,19 +19,30 @@ use super::{
 /// ```
 pub static TI_SN74LV2416: NomParser<GenericPart> = NomParser {
     name: "TI SN74LV2416",
-    f: |input| {
+    examples: &["LV2416 17M A23D", "LV2416 13M A8R3", "LV2416 0CM A73E"],
+    prefixes: &["LV2416"],
+    f: &|input| {
         lines3(
             tag("LV2416"),
             terminated(year1_month1_123abc, tag("M")),
-            tag("A").and(alnum_uppers(3)),
+            recognize(tag("A").and(alnum_uppers(3))),
         )
-        .map(|(_, date_code, _)| GenericPart {
+        .map(|(_, date_code, lot_code)| GenericPart {
             kind: "SN74LV2416".to_owned(),
             manufacturer: Some(Manufacturer::TexasInstruments),
             date_code: Some(date_code),
+            lot_code: Some(String::from(lot_code)),
+            ..GenericPart::default()
         })
         .parse(input)
     },
+    format: Some(&|part| {
+        Some(format!(
+            "LV2416 {date}M {lot}",
+            date = for_format::year1_month1(part.date_code?, for_format::month1_123abc)?,
+            lot = part.lot_code.as_deref()?,
+        ))
+    }),
 };
 
 /// Texas Instruments MBC5 (QFP-32)
@@ -42,17 +53,32 @@ pub static TI_SN74LV2416: NomParser<GenericPart> = NomParser {
 /// ```
 pub static TI_MBC5: NomParser<Mapper> = NomParser {
     name: "TI MBC5",
-    f: |input| {
+    examples: &["11CH8VT MBC5 2417"],
+    prefixes: &[],
+    f: &|input| {
         lines3(
-            terminated(year1_month1_123abc, (uppers(1), alnum_uppers(3), tag("T"))),
+            (
+                year1_month1_123abc,
+                recognize((uppers(1), alnum_uppers(3), tag("T"))),
+            ),
             tag("MBC5"),
             tag("2417"),
         )
-        .map(|(date_code, _, _)| Mapper {
+        .map(|((date_code, lot_code), _, _)| Mapper {
             kind: MapperChip::Mbc5,
             manufacturer: Some(Manufacturer::TexasInstruments),
             date_code: Some(date_code),
+            lot_code: Some(String::from(lot_code)),
+            revision: None,
+            origin_country: None,
         })
         .parse(input)
     },
+    format: Some(&|mapper| {
+        Some(format!(
+            "{date}{lot} MBC5 2417",
+            date = for_format::year1_month1(mapper.date_code?, for_format::month1_123abc)?,
+            lot = mapper.lot_code.as_deref()?,
+        ))
+    }),
 };
@@ -5,20 +5,20 @@
 use nom::{
     Parser,
     branch::alt,
-    bytes::streaming::tag,
     character::streaming::char,
     combinator::{recognize, value},
     error::ParseError,
-    sequence::{delimited, separated_pair, terminated},
+    sequence::{delimited, preceded, separated_pair, terminated},
 };
 
 use super::{
-    GameMaskRom, GameRomType, GenericPart, Manufacturer, Mapper, MapperChip, MaskRom, NomParser,
-    PartDateCode,
+    Country, GameMaskRom, GameRomType, GenericPart, MIBIT, Manufacturer, Mapper, MapperChip,
+    MaskRom, NomParser, Organization, PartDateCode, Voltage, for_format,
     for_nom::{
-        cgb_rom_code, digits, dmg_rom_code, lines2, lines3, lines4, uppers, week2, year1,
-        year2_week2,
+        cgb_rom_code, country, digits, dmg_rom_code, lines2, lines3, lines4, tag, uppers, week2,
+        year1, year2_week2,
     },
+    speed_grade,
 };
 
 /// Toshiba TC8521AM RTC (SOP-20)
@@ -32,7 +32,16 @@ use super::{
 /// ```
 pub static TOSHIBA_TC8521AM: NomParser<GenericPart> = NomParser {
     name: "Toshiba TC8521AM",
-    f: |input| toshiba_tc8521a(Package::SOP20).parse(input),
+    examples: &["T 9722HB 8521AM"],
+    prefixes: &["T "],
+    f: &|input| toshiba_tc8521a(Package::SOP20).parse(input),
+    format: Some(&|part| {
+        Some(format!(
+            "T {date_code}HB {kind}",
+            date_code = for_format::year2_week2(part.date_code?)?,
+            kind = part.kind.strip_prefix("TC")?,
+        ))
+    }),
 };
 
 fn toshiba_tc8521a<'a, E: ParseError<&'a str>>(
@@ -46,6 +55,8 @@ fn toshiba_tc8521a<'a, E: ParseError<&'a str>>(
         kind: format!("TC8521A{package_code}"),
         manufacturer: Some(Manufacturer::Toshiba),
         date_code: Some(date_code),
+        package: Some(package.into()),
+        ..GenericPart::default()
     })
 }
 
@@ -57,7 +68,9 @@ fn toshiba_tc8521a<'a, E: ParseError<&'a str>>(
 /// ```
 pub static TOSHIBA_TC7W139F: NomParser<GenericPart> = NomParser {
     name: "Toshiba TC7W139F",
-    f: |input| {
+    examples: &["7W139 0J"],
+    prefixes: &["7W139"],
+    f: &|input| {
         lines2(
             alt((
                 value("TC7W139FU", tag("7W139")),
@@ -65,13 +78,29 @@ pub static TOSHIBA_TC7W139F: NomParser<GenericPart> = NomParser {
             )),
             year1.and(uppers(1)),
         )
-        .map(|(kind, (year, _))| GenericPart {
+        .map(|(kind, (year, lot_code))| GenericPart {
             kind: String::from(kind),
             manufacturer: Some(Manufacturer::Toshiba),
             date_code: Some(PartDateCode::Year { year }),
+            lot_code: Some(String::from(lot_code)),
+            ..GenericPart::default()
         })
         .parse(input)
     },
+    format: Some(&|part| {
+        let PartDateCode::Year { year } = part.date_code? else {
+            return None;
+        };
+        Some(format!(
+            "{kind} {year}{lot_code}",
+            kind = match part.kind.as_str() {
+                "TC7W139FU" => "7W139",
+                kind => kind.strip_prefix("TC")?,
+            },
+            year = for_format::year1(year)?,
+            lot_code = part.lot_code.as_ref()?,
+        ))
+    }),
 };
 
 /// Toshiba TC74LVX04FT hex inverter
@@ -82,7 +111,9 @@ pub static TOSHIBA_TC7W139F: NomParser<GenericPart> = NomParser {
 /// ```
 pub static TOSHIBA_TC74LVX04FT: NomParser<GenericPart> = NomParser {
     name: "Toshiba TC74LVX04FT",
-    f: |input| {
+    examples: &["LVX 04 8 45"],
+    prefixes: &["LVX"],
+    f: &|input| {
         lines3(
             tag("LVX"),
             tag("04"),
@@ -92,9 +123,15 @@ pub static TOSHIBA_TC74LVX04FT: NomParser<GenericPart> = NomParser {
             kind: "TC74LVX04FT".to_owned(),
             manufacturer: Some(Manufacturer::Toshiba),
             date_code: Some(PartDateCode::YearWeek { year, week }),
+            ..GenericPart::default()
         })
         .parse(input)
     },
+    format: Some(&|part| {
+        let date_code = for_format::year1_week2(part.date_code?)?;
+        let (year, week) = date_code.split_at(1);
+        Some(format!("LVX 04 {year} {week}"))
+    }),
 };
 
 fn tc53<'a, E: ParseError<&'a str>>(
@@ -114,16 +151,42 @@ fn tc53<'a, E: ParseError<&'a str>>(
             char(' '),
             tag(rom_type.as_str()),
         ),
-        separated_pair(uppers(1).and(digits(3)), char(' '), tag("JAPAN")),
+        separated_pair(
+            recognize(uppers(1).and(digits(3))),
+            char(' '),
+            country(Country::Japan),
+        ),
     )
-    .map(move |((_, date_code), kind, (rom_id, _), _)| GameMaskRom {
-        rom_id: String::from(rom_id),
-        rom_type,
-        manufacturer: Some(Manufacturer::Toshiba),
-        chip_type: Some(String::from(kind)),
-        mask_code: None,
-        date_code: Some(date_code),
-    })
+    .map(
+        move |((_, date_code), kind, (rom_id, _), (lot_code, origin_country))| GameMaskRom {
+            rom_id: String::from(rom_id),
+            rom_type,
+            manufacturer: Some(Manufacturer::Toshiba),
+            chip_type: Some(String::from(kind)),
+            mask_code: None,
+            date_code: Some(date_code),
+            lot_code: Some(String::from(lot_code)),
+            revision: None,
+            origin_country: Some(origin_country),
+            package: Some(rom_type.package()),
+            voltage: Some(Voltage::V5),
+            density: rom_type.density(),
+            organization: None,
+            access_time: None,
+        },
+    )
+}
+
+fn tc53_label(rom: &GameMaskRom) -> Option<String> {
+    Some(format!(
+        "TOSHIBA {date_code}EAI {kind} {rom_id} {rom_type} {lot_code} {country}",
+        date_code = for_format::year2_week2(rom.date_code?)?,
+        kind = rom.chip_type.as_ref()?,
+        rom_id = rom.rom_id,
+        rom_type = rom.rom_type.as_str(),
+        lot_code = rom.lot_code.as_ref()?,
+        country = rom.origin_country?.label(),
+    ))
 }
 
 /// Toshiba TC531001 (SOP-32, 4.5-5.5V)
@@ -134,7 +197,10 @@ fn tc53<'a, E: ParseError<&'a str>>(
 /// ```
 pub static TOSHIBA_TC531001: NomParser<GameMaskRom> = NomParser {
     name: "Toshiba TC531001",
-    f: |input| tc53("TC531001C", GameRomType::C1, Package::SOP32).parse(input),
+    examples: &["TOSHIBA 9144EAI TC531001CF DMG-FAE-0 C1 J619 JAPAN"],
+    prefixes: &["TOSHIBA"],
+    f: &|input| tc53("TC531001C", GameRomType::C1, Package::SOP32).parse(input),
+    format: Some(&tc53_label),
 };
 
 /// Toshiba TC532000 (SOP-32, 4.5-5.5V)
@@ -145,7 +211,10 @@ pub static TOSHIBA_TC531001: NomParser<GameMaskRom> = NomParser {
 /// ```
 pub static TOSHIBA_TC532000: NomParser<GameMaskRom> = NomParser {
     name: "Toshiba TC532000",
-    f: |input| tc53("TC532000B", GameRomType::D1, Package::SOP32).parse(input),
+    examples: &["TOSHIBA 9114EAI TC532000BF DMG-GWJ-0 D1 J542 JAPAN"],
+    prefixes: &["TOSHIBA"],
+    f: &|input| tc53("TC532000B", GameRomType::D1, Package::SOP32).parse(input),
+    format: Some(&tc53_label),
 };
 
 /// Toshiba TC534000 (SOP-32, 4.5-5.5V)
@@ -157,13 +226,19 @@ pub static TOSHIBA_TC532000: NomParser<GameMaskRom> = NomParser {
 /// ```
 pub static TOSHIBA_TC534000: NomParser<GameMaskRom> = NomParser {
     name: "Toshiba TC534000",
-    f: |input| {
+    examples: &[
+        "TOSHIBA 9301EAI TC534000BF DMG-MQE-2 E1 N516 JAPAN",
+        "TOSHIBA 9614EAI TC534000DF DMG-WJA-0 E1 N750 JAPAN",
+    ],
+    prefixes: &["TOSHIBA"],
+    f: &|input| {
         alt((
             tc53("TC534000B", GameRomType::E1, Package::SOP32),
             tc53("TC534000D", GameRomType::E1, Package::SOP32),
         ))
         .parse(input)
     },
+    format: Some(&tc53_label),
 };
 
 /// Toshiba TC55V200 SRAM (TSOP-I-48, 2.7-3.6V, 2 Mibit / 256 KiB / 128x16)
@@ -177,22 +252,47 @@ pub static TOSHIBA_TC534000: NomParser<GameMaskRom> = NomParser {
 /// ```
 pub static TOSHIBA_TC55V200: NomParser<GenericPart> = NomParser {
     name: "Toshiba TC55V200",
-    f: |input| {
+    examples: &["K13529 JAPAN 0106 MAD TC55V200 FT-70"],
+    prefixes: &[],
+    f: &|input| {
         lines4(
-            uppers(1).and(digits(5)),
-            (tag("JAPAN"), char(' '), year2_week2, char(' '), tag("MAD")),
+            recognize(uppers(1).and(digits(5))),
+            (
+                country(Country::Japan),
+                char(' '),
+                year2_week2,
+                char(' '),
+                tag("MAD"),
+            ),
             tag("TC55V200"),
             tag("FT-").and(alt((tag("70"), tag("85"), tag("10")))),
         )
         .map(
-            |(_, (_, _, date_code, _, _), kind, (_, speed))| GenericPart {
+            |(lot_code, (origin_country, _, date_code, _, _), kind, (_, speed))| GenericPart {
                 kind: format!("{kind}FT-{speed}"),
                 manufacturer: Some(Manufacturer::Toshiba),
                 date_code: Some(date_code),
+                lot_code: Some(String::from(lot_code)),
+                revision: None,
+                origin_country: Some(origin_country),
+                package: Some(super::Package::TsopI48),
+                voltage: Some(Voltage::range(2_700, 3_600)),
+                density: Some(2 * MIBIT),
+                organization: Some(Organization::new(128 * 1024, 16)),
+                access_time: speed_grade(speed),
             },
         )
         .parse(input)
     },
+    format: Some(&|part| {
+        Some(format!(
+            "{lot_code} {country} {date_code} MAD TC55V200 {suffix}",
+            lot_code = part.lot_code.as_ref()?,
+            country = part.origin_country?.label(),
+            date_code = for_format::year2_week2(part.date_code?)?,
+            suffix = part.kind.strip_prefix("TC55V200")?,
+        ))
+    }),
 };
 
 /// Toshiba SGB mask ROM
@@ -203,23 +303,38 @@ pub static TOSHIBA_TC55V200: NomParser<GenericPart> = NomParser {
 /// ```
 pub static TOSHIBA_SGB_ROM: NomParser<MaskRom> = NomParser {
     name: "Toshiba SGB ROM",
-    f: |input| {
+    examples: &["SYS-SGB-2 © 1994 Nintendo TC532000BF-N807 JAPAN 9431EAI"],
+    prefixes: &["SYS-SGB-2"],
+    f: &|input| {
         lines4(
             tag("SYS-SGB-2"),
             tag("© 1994 Nintendo"),
-            recognize(tag("TC532000B").and(char(Package::SOP32.code_char())))
-                .and(char('-').and(uppers(1)).and(digits(3))),
+            separated_pair(
+                recognize(tag("TC532000B").and(char(Package::SOP32.code_char()))),
+                char('-'),
+                recognize(uppers(1).and(digits(3))),
+            ),
             separated_pair(tag("JAPAN"), char(' '), terminated(year2_week2, tag("EAI"))),
         )
-        .map(|(rom_id, _, (kind, _), (_, date_code))| MaskRom {
+        .map(|(rom_id, _, (kind, lot_code), (_, date_code))| MaskRom {
             rom_id: String::from(rom_id),
             manufacturer: Some(Manufacturer::Toshiba),
             chip_type: Some(String::from(kind)),
             mask_code: None,
             date_code: Some(date_code),
+            lot_code: Some(String::from(lot_code)),
         })
         .parse(input)
     },
+    format: Some(&|rom| {
+        Some(format!(
+            "{rom_id} © 1994 Nintendo {kind}-{lot_code} JAPAN {date_code}EAI",
+            rom_id = rom.rom_id,
+            kind = rom.chip_type.as_ref()?,
+            lot_code = rom.lot_code.as_ref()?,
+            date_code = for_format::year2_week2(rom.date_code?)?,
+        ))
+    }),
 };
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
@@ -237,6 +352,15 @@ impl Package {
     }
 }
 
+impl From<Package> for super::Package {
+    fn from(package: Package) -> Self {
+        match package {
+            Package::SOP20 => super::Package::Sop20,
+            Package::SOP32 => super::Package::Sop32,
+        }
+    }
+}
+
 /// Toshiba TAMA5
 ///
 /// ```
@@ -245,18 +369,34 @@ impl Package {
 /// ```
 pub static TOSHIBA_TAMA5: NomParser<Mapper> = NomParser {
     name: "Toshiba TAMA5",
-    f: |input| {
+    examples: &["TAMA5 9726 EAD1"],
+    prefixes: &["TAMA5"],
+    f: &|input| {
         lines2(
             tag("TAMA5"),
-            terminated(year2_week2, tag(" EA").and(uppers(1)).and(tag("1"))),
+            separated_pair(
+                year2_week2,
+                char(' '),
+                recognize(tag("EA").and(uppers(1)).and(tag("1"))),
+            ),
         )
-        .map(|(_, date_code)| Mapper {
+        .map(|(_, (date_code, lot_code))| Mapper {
             kind: MapperChip::Tama5,
             manufacturer: Some(Manufacturer::Toshiba),
             date_code: Some(date_code),
+            lot_code: Some(String::from(lot_code)),
+            revision: None,
+            origin_country: None,
         })
         .parse(input)
     },
+    format: Some(&|mapper| {
+        Some(format!(
+            "TAMA5 {date_code} {lot_code}",
+            date_code = for_format::year2_week2(mapper.date_code?)?,
+            lot_code = mapper.lot_code.as_ref()?,
+        ))
+    }),
 };
 
 /// Toshiba TAMA6
@@ -267,16 +407,29 @@ pub static TOSHIBA_TAMA5: NomParser<Mapper> = NomParser {
 /// ```
 pub static TOSHIBA_TAMA6: NomParser<GenericPart> = NomParser {
     name: "Toshiba TAMA6",
-    f: |input| {
+    examples: &["TAMA6 JAPAN 47C243M FV61 9751H"],
+    prefixes: &["TAMA6 "],
+    f: &|input| {
         lines2(
-            tag("TAMA6 JAPAN"),
-            delimited(tag("47C243M FV61 "), year2_week2, tag("H")),
+            preceded(tag("TAMA6 "), country(Country::Japan)),
+            preceded(tag("47C243M FV61 "), year2_week2.and(tag("H"))),
         )
-        .map(|(_, date_code)| GenericPart {
+        .map(|(origin_country, (date_code, lot_code))| GenericPart {
             kind: "TAMA6".to_owned(),
             manufacturer: Some(Manufacturer::Toshiba),
             date_code: Some(date_code),
+            lot_code: Some(String::from(lot_code)),
+            origin_country: Some(origin_country),
+            ..GenericPart::default()
         })
         .parse(input)
     },
+    format: Some(&|part| {
+        Some(format!(
+            "TAMA6 {country} 47C243M FV61 {date_code}{lot_code}",
+            country = part.origin_country?.label(),
+            date_code = for_format::year2_week2(part.date_code?)?,
+            lot_code = part.lot_code.as_ref()?,
+        ))
+    }),
 };
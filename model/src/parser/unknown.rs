@@ -5,18 +5,17 @@
 use nom::{
     Parser,
     branch::alt,
-    bytes::streaming::tag,
     character::streaming::char,
-    combinator::opt,
-    sequence::{delimited, preceded, separated_pair, terminated},
+    combinator::{opt, recognize},
+    sequence::{delimited, preceded, separated_pair},
 };
 
 use super::{
-    Crystal, GameMaskRom, GameRomType, GenericPart, Mapper, MapperChip, MaskRom, NomParser,
-    PartDateCode,
+    Country, Crystal, GameMaskRom, GameRomType, GenericPart, MIBIT, Mapper, MapperChip, MaskRom,
+    NomParser, Package, PartDateCode, Voltage, for_format,
     for_nom::{
-        alnum_uppers, digits, lines2, lines3, lines4, uppers, year1, year1_month2, year1_week2,
-        year2_week2,
+        alnum_uppers, country, digits, lines2, lines3, lines4, tag, uppers, year1, year1_month2,
+        year1_week2, year2_week2,
     },
 };
 
@@ -27,41 +26,62 @@ use super::{
 /// ```
 pub static UNKNOWN_SGB_ROM: NomParser<MaskRom> = NomParser {
     name: "Unknown SGB ROM",
-    f: |input| {
+    examples: &[
+        "SYS-SGB-2 JAPAN © 1994 Nintendo 427A2 A04 NND",
+        "SYS-SGB-2 © 1994 Nintendo 9423 E",
+    ],
+    prefixes: &["SYS-SGB-2"],
+    f: &|input| {
         alt((
             lines3(
                 separated_pair(tag("SYS-SGB-2"), char(' '), tag("JAPAN")),
                 tag("© 1994 Nintendo"),
-                (
+                recognize((
                     alnum_uppers(5),
                     char(' '),
                     alnum_uppers(3),
                     char(' '),
                     uppers(3),
-                ),
+                )),
             )
-            .map(|((rom_id, _), _, _)| MaskRom {
+            .map(|((rom_id, _), _, lot_code)| MaskRom {
                 rom_id: String::from(rom_id),
                 manufacturer: None,
                 chip_type: None,
                 mask_code: None,
                 date_code: None,
+                lot_code: Some(String::from(lot_code)),
             }),
             lines3(
                 tag("SYS-SGB-2"),
                 tag("© 1994 Nintendo"),
                 separated_pair(year2_week2, char(' '), uppers(1)),
             )
-            .map(|(rom_id, _, (date_code, _))| MaskRom {
+            .map(|(rom_id, _, (date_code, lot_code))| MaskRom {
                 rom_id: String::from(rom_id),
                 manufacturer: None,
                 chip_type: None,
                 mask_code: None,
                 date_code: Some(date_code),
+                lot_code: Some(String::from(lot_code)),
             }),
         ))
         .parse(input)
     },
+    format: Some(&|rom| {
+        let lot_code = rom.lot_code.as_ref()?;
+        match rom.date_code {
+            Some(date_code) => Some(format!(
+                "{rom_id} © 1994 Nintendo {date_code} {lot_code}",
+                rom_id = rom.rom_id,
+                date_code = for_format::year2_week2(date_code)?,
+            )),
+            None => Some(format!(
+                "{rom_id} JAPAN © 1994 Nintendo {lot_code}",
+                rom_id = rom.rom_id
+            )),
+        }
+    }),
 };
 
 /// ```
@@ -71,19 +91,30 @@ pub static UNKNOWN_SGB_ROM: NomParser<MaskRom> = NomParser {
 /// ```
 pub static UNKNOWN_LCS5_EEPROM: NomParser<GenericPart> = NomParser {
     name: "Unknown LCS5 EEPROM",
-    f: |input| {
+    examples: &["LCS5 040", "LCS5 435 09"],
+    prefixes: &["LCS5 "],
+    f: &|input| {
         (
             tag("LCS5 "),
             year1_week2,
-            opt((nom::character::complete::char(' '), digits(2))),
+            opt(preceded(nom::character::complete::char(' '), digits(2))),
         )
-            .map(|(_, date_code, _)| GenericPart {
+            .map(|(_, date_code, lot_code)| GenericPart {
                 kind: "LC56".to_owned(),
                 manufacturer: None,
                 date_code: Some(date_code),
+                lot_code: lot_code.map(String::from),
+                ..GenericPart::default()
             })
             .parse(input)
     },
+    format: Some(&|part| {
+        let date_code = for_format::year1_week2(part.date_code?)?;
+        Some(match &part.lot_code {
+            Some(lot_code) => format!("LCS5 {date_code} {lot_code}"),
+            None => format!("LCS5 {date_code}"),
+        })
+    }),
 };
 
 /// ```
@@ -92,15 +123,20 @@ pub static UNKNOWN_LCS5_EEPROM: NomParser<GenericPart> = NomParser {
 /// ```
 pub static UNKNOWN_LC56_EEPROM: NomParser<GenericPart> = NomParser {
     name: "Unknown LC56 EEPROM",
-    f: |input| {
-        lines3(tag("LC56"), uppers(1).and(digits(3)), digits(2))
-            .map(|(_, _, _)| GenericPart {
+    examples: &["LC56 W617 08"],
+    prefixes: &["LC56"],
+    f: &|input| {
+        lines3(tag("LC56"), recognize(uppers(1).and(digits(3))), digits(2))
+            .map(|(_, trace, lot)| GenericPart {
                 kind: "LC56".to_owned(),
                 manufacturer: None,
                 date_code: None,
+                lot_code: Some(format!("{trace} {lot}")),
+                ..GenericPart::default()
             })
             .parse(input)
     },
+    format: Some(&|part| Some(format!("LC56 {}", part.lot_code.as_deref()?))),
 };
 
 /// ```
@@ -109,17 +145,36 @@ pub static UNKNOWN_LC56_EEPROM: NomParser<GenericPart> = NomParser {
 /// ```
 pub static UNKNOWN_AGS_CHARGE_CONTROLLER: NomParser<GenericPart> = NomParser {
     name: "Unknown AGS charge controller",
-    f: |input| {
-        lines2(tag("2253B"), (digits(1), alnum_uppers(1), digits(2)))
-            .map(|(kind, _)| GenericPart {
-                kind: String::from(kind),
-                manufacturer: None,
-                date_code: None,
-            })
-            .parse(input)
+    examples: &["2253B 3129"],
+    prefixes: &["2253B"],
+    f: &|input| {
+        lines2(
+            tag("2253B"),
+            recognize((digits(1), alnum_uppers(1), digits(2))),
+        )
+        .map(|(kind, lot_code)| GenericPart {
+            kind: String::from(kind),
+            manufacturer: None,
+            date_code: None,
+            lot_code: Some(String::from(lot_code)),
+            ..GenericPart::default()
+        })
+        .parse(input)
     },
+    format: Some(&|part| Some(format!("{} {}", part.kind, part.lot_code.as_deref()?))),
 };
 
+fn year_and_lot_label(prefix: &str, crystal: &Crystal) -> Option<String> {
+    let PartDateCode::Year { year } = crystal.date_code? else {
+        return None;
+    };
+    Some(format!(
+        "{prefix}{year}{lot}",
+        year = for_format::year1(year)?,
+        lot = crystal.lot_code.as_deref()?,
+    ))
+}
+
 /// ```
 /// use gbhwdb_model::parser::{self, LabelParser};
 /// assert!(parser::unknown::UNKNOWN_CRYSTAL_32_KIHZ.parse("32K09").is_ok());
@@ -127,16 +182,22 @@ pub static UNKNOWN_AGS_CHARGE_CONTROLLER: NomParser<GenericPart> = NomParser {
 /// ```
 pub static UNKNOWN_CRYSTAL_32_KIHZ: NomParser<Crystal> = NomParser {
     name: "Unknown crystal, 32 KiHz",
-    f: |input| {
+    examples: &["32K09", "32K0Z"],
+    prefixes: &["32K"],
+    f: &|input| {
         tag("32K")
             .and(year1.and(alnum_uppers(1)))
-            .map(|(_, (year, _))| Crystal {
+            .map(|(_, (year, lot_code))| Crystal {
                 manufacturer: None,
                 frequency: Crystal::FREQ_32_KIHZ,
                 date_code: Some(PartDateCode::Year { year }),
+                lot_code: Some(String::from(lot_code)),
+                revision: None,
+                origin_country: None,
             })
             .parse(input)
     },
+    format: Some(&|crystal| year_and_lot_label("32K", crystal)),
 };
 
 /// ```
@@ -145,16 +206,22 @@ pub static UNKNOWN_CRYSTAL_32_KIHZ: NomParser<Crystal> = NomParser {
 /// ```
 pub static UNKNOWN_DMG_CRYSTAL_4_MIHZ: NomParser<Crystal> = NomParser {
     name: "Unknown DMG crystal, 4 MiHz",
-    f: |input| {
+    examples: &["4.19C59"],
+    prefixes: &["4.19C"],
+    f: &|input| {
         tag("4.19C")
             .and(year1.and(alnum_uppers(1)))
-            .map(|(_, (year, _))| Crystal {
+            .map(|(_, (year, lot_code))| Crystal {
                 manufacturer: None,
                 frequency: Crystal::FREQ_4_MIHZ,
                 date_code: Some(PartDateCode::Year { year }),
+                lot_code: Some(String::from(lot_code)),
+                revision: None,
+                origin_country: None,
             })
             .parse(input)
     },
+    format: Some(&|crystal| year_and_lot_label("4.19C", crystal)),
 };
 
 /// ```
@@ -164,7 +231,9 @@ pub static UNKNOWN_DMG_CRYSTAL_4_MIHZ: NomParser<Crystal> = NomParser {
 /// ```
 pub static UNKNOWN_MGB_CRYSTAL_4_MIHZ: NomParser<Crystal> = NomParser {
     name: "Unknown MGB crystal, 4 MiHz",
-    f: |input| {
+    examples: &["4.1943 RVR 841", "4.1943 9752"],
+    prefixes: &["4.1943"],
+    f: &|input| {
         lines2(
             tag("4.1943"),
             alt((preceded(tag("RVR "), year1_week2), year2_week2)),
@@ -173,9 +242,19 @@ pub static UNKNOWN_MGB_CRYSTAL_4_MIHZ: NomParser<Crystal> = NomParser {
             manufacturer: None,
             frequency: Crystal::FREQ_4_MIHZ,
             date_code: Some(date_code),
+            lot_code: None,
+            revision: None,
+            origin_country: None,
         })
         .parse(input)
     },
+    format: Some(&|crystal| {
+        let date_code = crystal.date_code?;
+        match for_format::year1_week2(date_code) {
+            Some(date_code) => Some(format!("4.1943 RVR {date_code}")),
+            None => Some(format!("4.1943 {}", for_format::year2_week2(date_code)?)),
+        }
+    }),
 };
 
 /// ```
@@ -184,15 +263,25 @@ pub static UNKNOWN_MGB_CRYSTAL_4_MIHZ: NomParser<Crystal> = NomParser {
 /// ```
 pub static UNKNOWN_OXY_U4: NomParser<GenericPart> = NomParser {
     name: "Unknown OXY U4",
-    f: |input| {
+    examples: &["AKV 522"],
+    prefixes: &["AKV"],
+    f: &|input| {
         lines2(tag("AKV"), year1_week2)
             .map(|(kind, date_code)| GenericPart {
                 kind: String::from(kind),
                 manufacturer: None,
                 date_code: Some(date_code),
+                ..GenericPart::default()
             })
             .parse(input)
     },
+    format: Some(&|part| {
+        Some(format!(
+            "{} {}",
+            part.kind,
+            for_format::year1_week2(part.date_code?)?
+        ))
+    }),
 };
 
 /// ```
@@ -201,20 +290,32 @@ pub static UNKNOWN_OXY_U4: NomParser<GenericPart> = NomParser {
 /// ```
 pub static UNKNOWN_OXY_U5: NomParser<GenericPart> = NomParser {
     name: "Unknown OXY U5",
-    f: |input| {
+    examples: &["CP6465 B 02 KOR0531 635963"],
+    prefixes: &["CP6465"],
+    f: &|input| {
         lines4(
             tag("CP6465"),
-            separated_pair(tag("B"), char(' '), char('0').and(digits(1))),
+            separated_pair(tag("B"), char(' '), recognize(char('0').and(digits(1)))),
             preceded(tag("KOR"), year2_week2),
             digits(6),
         )
-        .map(|(kind, _, date_code, _)| GenericPart {
+        .map(|(kind, (_, revision), date_code, lot_code)| GenericPart {
             kind: String::from(kind),
             manufacturer: None,
             date_code: Some(date_code),
+            lot_code: Some(format!("{revision} {lot_code}")),
+            ..GenericPart::default()
         })
         .parse(input)
     },
+    format: Some(&|part| {
+        let (revision, lot_code) = part.lot_code.as_deref()?.split_once(' ')?;
+        Some(format!(
+            "{kind} B {revision} KOR{date} {lot_code}",
+            kind = part.kind,
+            date = for_format::year2_week2(part.date_code?)?,
+        ))
+    }),
 };
 
 /// Unknown MMM01 (QFP-32)
@@ -225,18 +326,30 @@ pub static UNKNOWN_OXY_U5: NomParser<GenericPart> = NomParser {
 /// ```
 pub static UNKNOWN_MMM01: NomParser<Mapper> = NomParser {
     name: "Unknown MMM01",
-    f: |input| {
+    examples: &["MMM01 645 113"],
+    prefixes: &["MMM01"],
+    f: &|input| {
         lines2(
             tag("MMM01"),
-            terminated(year1_week2, tag(" ").and(digits(3))),
+            separated_pair(year1_week2, tag(" "), digits(3)),
         )
-        .map(|(_, date_code)| Mapper {
+        .map(|(_, (date_code, lot_code))| Mapper {
             kind: MapperChip::Mmm01,
             manufacturer: None,
             date_code: Some(date_code),
+            lot_code: Some(String::from(lot_code)),
+            revision: None,
+            origin_country: None,
         })
         .parse(input)
     },
+    format: Some(&|mapper| {
+        Some(format!(
+            "MMM01 {date} {lot}",
+            date = for_format::year1_week2(mapper.date_code?)?,
+            lot = mapper.lot_code.as_deref()?,
+        ))
+    }),
 };
 
 /// Unknown TAMA7
@@ -247,23 +360,43 @@ pub static UNKNOWN_MMM01: NomParser<Mapper> = NomParser {
 /// ```
 pub static UNKNOWN_TAMA7: NomParser<GameMaskRom> = NomParser {
     name: "Unknown TAMA7",
-    f: |input| {
+    examples: &["TAMA7 B9748 43913A TAIWAN"],
+    prefixes: &["TAMA7"],
+    f: &|input| {
         lines4(
             tag("TAMA7"),
-            preceded(uppers(1), year2_week2),
-            digits(5).and(uppers(1)),
-            tag("TAIWAN"),
+            uppers(1).and(year2_week2),
+            recognize(digits(5).and(uppers(1))),
+            country(Country::Taiwan),
+        )
+        .map(
+            |(_, (mark, date_code), lot_code, origin_country)| GameMaskRom {
+                rom_id: String::from("DMG-AOMJ-0"),
+                rom_type: GameRomType::E1,
+                manufacturer: None,
+                chip_type: None,
+                mask_code: None,
+                date_code: Some(date_code),
+                lot_code: Some(format!("{mark} {lot_code}")),
+                revision: None,
+                origin_country: Some(origin_country),
+                package: Some(Package::Sop32),
+                voltage: Some(Voltage::V5),
+                density: Some(4 * MIBIT),
+                organization: None,
+                access_time: None,
+            },
         )
-        .map(|(_, date_code, _, _)| GameMaskRom {
-            rom_id: String::from("DMG-AOMJ-0"),
-            rom_type: GameRomType::E1,
-            manufacturer: None,
-            chip_type: None,
-            mask_code: None,
-            date_code: Some(date_code),
-        })
         .parse(input)
     },
+    format: Some(&|rom| {
+        let (mark, lot_code) = rom.lot_code.as_deref()?.split_once(' ')?;
+        Some(format!(
+            "TAMA7 {mark}{date} {lot_code} {country}",
+            date = for_format::year2_week2(rom.date_code?)?,
+            country = rom.origin_country?.label(),
+        ))
+    }),
 };
 
 /// Unknown LCD Screen
@@ -274,5 +407,9 @@ pub static UNKNOWN_TAMA7: NomParser<GameMaskRom> = NomParser {
 /// ```
 pub static UNKNOWN_LCD_SCREEN: NomParser<PartDateCode> = NomParser {
     name: "Unknown LCD Screen",
-    f: |input| delimited(tag("T61102S T"), year1_month2, digits(2)).parse(input),
+    examples: &["T61102S T61104"],
+    prefixes: &["T61102S T"],
+    f: &|input| delimited(tag("T61102S T"), year1_month2, digits(2)).parse(input),
+    // The trailing digits are not kept in the parsed date code
+    format: None,
 };
@@ -2,11 +2,12 @@
 //
 // SPDX-License-Identifier: MIT
 
-use nom::{Parser, bytes::streaming::tag, combinator::recognize};
+use nom::{Parser, combinator::recognize};
 
 use super::{
-    GenericPart, Manufacturer, NomParser,
-    for_nom::{alnum_uppers, digits, lines3, year2_week2},
+    GenericPart, KIBIT, Manufacturer, NomParser, Organization, Package, Voltage, dash_speed_grade,
+    for_format,
+    for_nom::{alnum_uppers, digits, lines3, tag, year2_week2},
 };
 
 /// Victronix VN4464 SRAM (SOP-28, 5V, 64 Kibit / 8 KiB)
@@ -17,17 +18,38 @@ use super::{
 /// ```
 pub static VICTRONIX_VN4464: NomParser<GenericPart> = NomParser {
     name: "Victronix VN4464",
-    f: |input| {
+    examples: &["Victronix VN4464S-08LL 95103B029"],
+    prefixes: &["Victronix"],
+    f: &|input| {
         lines3(
             tag("Victronix"),
             recognize(tag("VN4464").and(tag("S-08LL"))),
-            (year2_week2, digits(1), alnum_uppers(1), digits(3)),
+            (
+                year2_week2,
+                recognize((digits(1), alnum_uppers(1), digits(3))),
+            ),
         )
-        .map(|(_, kind, (date_code, _, _, _))| GenericPart {
+        .map(|(_, kind, (date_code, lot_code))| GenericPart {
             kind: String::from(kind),
             manufacturer: Some(Manufacturer::Victronix),
             date_code: Some(date_code),
+            lot_code: Some(String::from(lot_code)),
+            revision: None,
+            origin_country: None,
+            package: Some(Package::Sop28),
+            voltage: Some(Voltage::V5),
+            density: Some(64 * KIBIT),
+            organization: Some(Organization::new(8 * 1024, 8)),
+            access_time: dash_speed_grade(kind),
         })
         .parse(input)
     },
+    format: Some(&|part| {
+        Some(format!(
+            "Victronix {kind} {date}{lot}",
+            kind = part.kind,
+            date = for_format::year2_week2(part.date_code?)?,
+            lot = part.lot_code.as_deref()?,
+        ))
+    }),
 };
@@ -0,0 +1,390 @@
+// SPDX-FileCopyrightText: Joonas Javanainen <joonas.javanainen@gmail.com>
+//
+// SPDX-License-Identifier: MIT
+
+//! Game Boy / Game Boy Color cartridge header, stored at 0x100-0x14F in the ROM.
+
+use std::fmt;
+
+use crate::{
+    ParseError,
+    config::cartridge::{BoardLabel, BoardPart},
+    parser::{Mapper, MapperType},
+};
+
+pub mod agb;
+
+/// Address of the first header byte in the ROM
+pub const HEADER_START: usize = 0x100;
+/// Address of the first byte after the header
+pub const HEADER_END: usize = 0x150;
+/// Size of the header in bytes
+pub const HEADER_SIZE: usize = HEADER_END - HEADER_START;
+
+const TITLE: usize = 0x134;
+const CGB_FLAG: usize = 0x143;
+const NEW_LICENSEE: usize = 0x144;
+const SGB_FLAG: usize = 0x146;
+const CARTRIDGE_TYPE: usize = 0x147;
+const ROM_SIZE: usize = 0x148;
+const RAM_SIZE: usize = 0x149;
+const DESTINATION: usize = 0x14A;
+const OLD_LICENSEE: usize = 0x14B;
+const VERSION: usize = 0x14C;
+const HEADER_CHECKSUM: usize = 0x14D;
+const GLOBAL_CHECKSUM: usize = 0x14E;
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum CgbFlag {
+    /// The game doesn't use any CGB features
+    None,
+    /// The game uses CGB features, but also works on DMG
+    Compatible,
+    /// The game only works on CGB
+    Only,
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Licensee {
+    /// Old single-byte licensee code
+    Old(u8),
+    /// New two-character licensee code, used when the old code is 0x33
+    New([u8; 2]),
+}
+
+impl fmt::Display for Licensee {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Licensee::Old(code) => write!(f, "{code:02X}"),
+            Licensee::New(code) => write!(f, "{}", String::from_utf8_lossy(code)),
+        }
+    }
+}
+
+/// Cartridge type byte (0x147)
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct CartridgeType(pub u8);
+
+/// Hardware declared by a known cartridge type byte
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct CartridgeFeatures {
+    pub name: &'static str,
+    /// Mapper type, or None if the cartridge has no mapper or the mapper isn't a known type
+    pub mapper: Option<MapperType>,
+    pub ram: bool,
+    pub battery: bool,
+    pub timer: bool,
+    pub rumble: bool,
+}
+
+impl CartridgeType {
+    /// Returns the hardware declared by the cartridge type byte, or None if the byte is unknown.
+    ///
+    /// MBC30 can't be told apart from MBC3 by this byte alone, so both are reported as MBC3.
+    pub const fn features(&self) -> Option<CartridgeFeatures> {
+        const fn features(
+            name: &'static str,
+            mapper: Option<MapperType>,
+            ram: bool,
+            battery: bool,
+            timer: bool,
+            rumble: bool,
+        ) -> Option<CartridgeFeatures> {
+            Some(CartridgeFeatures {
+                name,
+                mapper,
+                ram,
+                battery,
+                timer,
+                rumble,
+            })
+        }
+        use MapperType::*;
+        match self.0 {
+            0x00 => features("ROM ONLY", None, false, false, false, false),
+            0x01 => features("MBC1", Some(Mbc1), false, false, false, false),
+            0x02 => features("MBC1+RAM", Some(Mbc1), true, false, false, false),
+            0x03 => features("MBC1+RAM+BATTERY", Some(Mbc1), true, true, false, false),
+            0x05 => features("MBC2", Some(Mbc2), false, false, false, false),
+            0x06 => features("MBC2+BATTERY", Some(Mbc2), false, true, false, false),
+            0x08 => features("ROM+RAM", None, true, false, false, false),
+            0x09 => features("ROM+RAM+BATTERY", None, true, true, false, false),
+            0x0b => features("MMM01", Some(Mmm01), false, false, false, false),
+            0x0c => features("MMM01+RAM", Some(Mmm01), true, false, false, false),
+            0x0d => features("MMM01+RAM+BATTERY", Some(Mmm01), true, true, false, false),
+            0x0f => features("MBC3+TIMER+BATTERY", Some(Mbc3), false, true, true, false),
+            0x10 => features(
+                "MBC3+TIMER+RAM+BATTERY",
+                Some(Mbc3),
+                true,
+                true,
+                true,
+                false,
+            ),
+            0x11 => features("MBC3", Some(Mbc3), false, false, false, false),
+            0x12 => features("MBC3+RAM", Some(Mbc3), true, false, false, false),
+            0x13 => features("MBC3+RAM+BATTERY", Some(Mbc3), true, true, false, false),
+            0x19 => features("MBC5", Some(Mbc5), false, false, false, false),
+            0x1a => features("MBC5+RAM", Some(Mbc5), true, false, false, false),
+            0x1b => features("MBC5+RAM+BATTERY", Some(Mbc5), true, true, false, false),
+            0x1c => features("MBC5+RUMBLE", Some(Mbc5), false, false, false, true),
+            0x1d => features("MBC5+RUMBLE+RAM", Some(Mbc5), true, false, false, true),
+            0x1e => features(
+                "MBC5+RUMBLE+RAM+BATTERY",
+                Some(Mbc5),
+                true,
+                true,
+                false,
+                true,
+            ),
+            0x20 => features("MBC6", Some(Mbc6), true, true, false, false),
+            0x22 => features(
+                "MBC7+SENSOR+RUMBLE+RAM+BATTERY",
+                Some(Mbc7),
+                true,
+                true,
+                false,
+                true,
+            ),
+            0xfc => features("POCKET CAMERA", None, true, true, false, false),
+            0xfd => features("BANDAI TAMA5", Some(Tama5), true, true, true, false),
+            0xfe => features("HuC3", Some(Huc3), true, true, true, false),
+            0xff => features("HuC1+RAM+BATTERY", Some(Huc1), true, true, false, false),
+            _ => None,
+        }
+    }
+}
+
+/// Decoded cartridge header.
+///
+/// ```
+/// use gbhwdb_model::rom_header::{self, CgbFlag, RomHeader};
+/// use gbhwdb_model::parser::MapperType;
+/// let mut rom = vec![0; 0x8000];
+/// rom[0x134..0x13b].copy_from_slice(b"TETRIS\0");
+/// rom[0x147] = 0x03;
+/// rom[0x148] = 0x01;
+/// rom[0x149] = 0x02;
+/// rom[0x14b] = 0x01;
+/// rom[0x14d] = rom_header::header_checksum(&rom[0x100..0x150]);
+/// let header = RomHeader::from_rom(&rom).unwrap();
+/// assert_eq!(header.title, "TETRIS");
+/// assert_eq!(header.cgb_flag, CgbFlag::None);
+/// assert_eq!(header.mapper_type(), Some(MapperType::Mbc1));
+/// assert_eq!(header.rom_size(), Some(64 * 1024));
+/// assert_eq!(header.ram_size(), Some(8 * 1024));
+/// assert!(header.has_valid_header_checksum());
+/// assert!(!header.has_valid_global_checksum(&rom));
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RomHeader {
+    pub title: String,
+    pub cgb_flag: CgbFlag,
+    pub sgb_flag: bool,
+    pub cartridge_type: CartridgeType,
+    pub rom_size_code: u8,
+    pub ram_size_code: u8,
+    /// True if the game was meant to be sold only in Japan
+    pub japan_only: bool,
+    pub licensee: Licensee,
+    pub version: u8,
+    pub header_checksum: u8,
+    pub global_checksum: u16,
+    computed_header_checksum: u8,
+}
+
+impl RomHeader {
+    /// Decodes the header from a full ROM dump, or any prefix of one that covers the header
+    pub fn from_rom(rom: &[u8]) -> Result<RomHeader, ParseError> {
+        let header = rom
+            .get(HEADER_START..HEADER_END)
+            .ok_or(ParseError("ROM is too small to contain a header"))?;
+        RomHeader::from_header(header)
+    }
+    /// Decodes the header from the 0x50 bytes at 0x100-0x14F
+    pub fn from_header(header: &[u8]) -> Result<RomHeader, ParseError> {
+        if header.len() != HEADER_SIZE {
+            return Err(ParseError("invalid header size"));
+        }
+        let byte = |addr: usize| header[addr - HEADER_START];
+        let cgb_flag = match byte(CGB_FLAG) {
+            0xc0 => CgbFlag::Only,
+            flag if flag & 0x80 != 0 => CgbFlag::Compatible,
+            _ => CgbFlag::None,
+        };
+        // CGB games use the last title byte for the CGB flag
+        let title_end = match cgb_flag {
+            CgbFlag::None => NEW_LICENSEE,
+            CgbFlag::Compatible | CgbFlag::Only => CGB_FLAG,
+        };
+        let title = &header[TITLE - HEADER_START..title_end - HEADER_START];
+        let title = title.split(|&b| b == 0).next().unwrap_or_default();
+        let licensee = match byte(OLD_LICENSEE) {
+            0x33 => Licensee::New([byte(NEW_LICENSEE), byte(NEW_LICENSEE + 1)]),
+            code => Licensee::Old(code),
+        };
+        Ok(RomHeader {
+            title: String::from_utf8_lossy(title).trim_end().to_owned(),
+            cgb_flag,
+            sgb_flag: byte(SGB_FLAG) == 0x03,
+            cartridge_type: CartridgeType(byte(CARTRIDGE_TYPE)),
+            rom_size_code: byte(ROM_SIZE),
+            ram_size_code: byte(RAM_SIZE),
+            japan_only: byte(DESTINATION) == 0x00,
+            licensee,
+            version: byte(VERSION),
+            header_checksum: byte(HEADER_CHECKSUM),
+            global_checksum: u16::from_be_bytes([byte(GLOBAL_CHECKSUM), byte(GLOBAL_CHECKSUM + 1)]),
+            computed_header_checksum: header_checksum(header),
+        })
+    }
+    /// Returns the mapper type declared by the header.
+    ///
+    /// MBC3 is reported as MBC30 if the ROM or RAM is too large for a plain MBC3.
+    pub fn mapper_type(&self) -> Option<MapperType> {
+        match self.cartridge_type.features()?.mapper? {
+            MapperType::Mbc3 if self.rom_size_code >= 0x07 || self.ram_size_code == 0x05 => {
+                Some(MapperType::Mbc30)
+            }
+            mapper => Some(mapper),
+        }
+    }
+    /// Returns the ROM size in bytes, or None if the size byte is unknown
+    pub const fn rom_size(&self) -> Option<u32> {
+        match self.rom_size_code {
+            code @ 0x00..=0x08 => Some((32 * 1024) << code),
+            0x52 => Some(1152 * 1024),
+            0x53 => Some(1280 * 1024),
+            0x54 => Some(1536 * 1024),
+            _ => None,
+        }
+    }
+    /// Returns the external RAM size in bytes, or None if the size byte is unknown.
+    ///
+    /// RAM built into the mapper, such as the MBC2 RAM, is not included.
+    pub const fn ram_size(&self) -> Option<u32> {
+        match self.ram_size_code {
+            0x00 => Some(0),
+            0x01 => Some(2 * 1024),
+            0x02 => Some(8 * 1024),
+            0x03 => Some(32 * 1024),
+            0x04 => Some(128 * 1024),
+            0x05 => Some(64 * 1024),
+            _ => None,
+        }
+    }
+    pub const fn has_valid_header_checksum(&self) -> bool {
+        self.header_checksum == self.computed_header_checksum
+    }
+    /// Checks the global checksum against the full ROM dump the header was read from
+    pub fn has_valid_global_checksum(&self, rom: &[u8]) -> bool {
+        self.global_checksum == global_checksum(rom)
+    }
+    /// Checks the header against the board revision and the mapper parsed from the mapper chip
+    /// label.
+    ///
+    /// ```
+    /// use gbhwdb_model::config::cartridge::BoardLabel;
+    /// use gbhwdb_model::parser::{LabelParser, mbc1_sop24};
+    /// use gbhwdb_model::rom_header::RomHeader;
+    /// let mut header = [0; 0x50];
+    /// header[0x47] = 0x03; // MBC1+RAM+BATTERY
+    /// let header = RomHeader::from_header(&header).unwrap();
+    /// let mapper = mbc1_sop24().parse("DMG MBC1A Nintendo S 8939 3 A").unwrap();
+    /// assert!(header.check_board(&BoardLabel::parse("DMG-DECN-01").unwrap(), Some(&mapper)).is_empty());
+    /// let mismatches = header.check_board(&BoardLabel::parse("DMG-BEAN-10").unwrap(), Some(&mapper));
+    /// assert_eq!(mismatches[0].to_string(), "header declares a battery, but the board has no battery");
+    /// ```
+    pub fn check_board(&self, board: &BoardLabel, mapper: Option<&Mapper>) -> Vec<HeaderMismatch> {
+        let mut result = Vec::new();
+        let Some(features) = self.cartridge_type.features() else {
+            result.push(HeaderMismatch::UnknownCartridgeType(self.cartridge_type));
+            return result;
+        };
+        let board_has_mapper = board
+            .parts()
+            .any(|(_, part)| matches!(part, BoardPart::Mapper(..)));
+        let header_mapper = self.mapper_type();
+        let board_mapper = mapper.map(|mapper| mapper.kind.mapper_type());
+        let mapper_matches = match (header_mapper, board_mapper) {
+            (Some(header), Some(board)) => header == board,
+            // The board mapper is known to exist, but its label wasn't parsed
+            (Some(_), None) => board_has_mapper,
+            (None, Some(_)) => false,
+            (None, None) => !board_has_mapper,
+        };
+        if !mapper_matches {
+            result.push(HeaderMismatch::Mapper {
+                header: header_mapper,
+                board: board_mapper,
+            });
+        }
+        // MBC7 cartridges keep saves in an EEPROM, even though the cartridge type says BATTERY
+        let header_battery = features.battery && header_mapper != Some(MapperType::Mbc7);
+        let board_battery = board.config.battery_type().is_some();
+        if header_battery != board_battery {
+            result.push(HeaderMismatch::Battery {
+                header: header_battery,
+                board: board_battery,
+            });
+        }
+        result
+    }
+}
+
+/// Mismatch between a cartridge header and the cartridge hardware
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum HeaderMismatch {
+    UnknownCartridgeType(CartridgeType),
+    Mapper {
+        header: Option<MapperType>,
+        board: Option<MapperType>,
+    },
+    Battery {
+        header: bool,
+        board: bool,
+    },
+}
+
+impl fmt::Display for HeaderMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fn mapper_name(mapper: Option<MapperType>) -> &'static str {
+            mapper.map_or("no mapper", |mapper| mapper.name())
+        }
+        fn battery(battery: bool) -> &'static str {
+            if battery { "a battery" } else { "no battery" }
+        }
+        match self {
+            HeaderMismatch::UnknownCartridgeType(CartridgeType(code)) => {
+                write!(f, "unknown cartridge type {code:02X}")
+            }
+            HeaderMismatch::Mapper { header, board } => write!(
+                f,
+                "header declares {header}, but the board has {board}",
+                header = mapper_name(*header),
+                board = mapper_name(*board),
+            ),
+            HeaderMismatch::Battery { header, board } => write!(
+                f,
+                "header declares {header}, but the board has {board}",
+                header = battery(*header),
+                board = battery(*board),
+            ),
+        }
+    }
+}
+
+/// Computes the header checksum over the 0x50 header bytes
+pub fn header_checksum(header: &[u8]) -> u8 {
+    header[TITLE - HEADER_START..=VERSION - HEADER_START]
+        .iter()
+        .fold(0u8, |acc, &b| acc.wrapping_sub(b).wrapping_sub(1))
+}
+
+/// Computes the global checksum over a full ROM dump
+pub fn global_checksum(rom: &[u8]) -> u16 {
+    rom.iter()
+        .enumerate()
+        .filter(|&(addr, _)| addr != GLOBAL_CHECKSUM && addr != GLOBAL_CHECKSUM + 1)
+        .fold(0u16, |acc, (_, &b)| acc.wrapping_add(u16::from(b)))
+}
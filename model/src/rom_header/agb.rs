@@ -0,0 +1,122 @@
+// SPDX-FileCopyrightText: Joonas Javanainen <joonas.javanainen@gmail.com>
+//
+// SPDX-License-Identifier: MIT
+
+//! Game Boy Advance cartridge header, stored at 0xA0-0xBF in the ROM, and save type detection.
+
+use crate::{
+    ParseError,
+    config::cartridge::{GameConfig, SaveType},
+};
+
+const TITLE: usize = 0xa0;
+const GAME_CODE: usize = 0xac;
+const MAKER_CODE: usize = 0xb0;
+const FIXED_VALUE: usize = 0xb2;
+const VERSION: usize = 0xbc;
+const COMPLEMENT_CHECK: usize = 0xbd;
+const HEADER_END: usize = 0xc0;
+
+/// Decoded GBA cartridge header.
+///
+/// ```
+/// use gbhwdb_model::rom_header::agb::AgbHeader;
+/// let mut rom = vec![0; 0x200];
+/// rom[0xa0..0xac].copy_from_slice(b"POKEMON RUBY");
+/// rom[0xac..0xb0].copy_from_slice(b"AXVE");
+/// rom[0xb0..0xb2].copy_from_slice(b"01");
+/// rom[0xb2] = 0x96;
+/// rom[0xbd] = AgbHeader::complement_check(&rom).unwrap();
+/// let header = AgbHeader::from_rom(&rom).unwrap();
+/// assert_eq!(header.title, "POKEMON RUBY");
+/// assert_eq!(header.game_code, "AXVE");
+/// assert_eq!(header.maker_code, "01");
+/// assert_eq!(header.rom_id(), "AGB-AXVE-0");
+/// assert!(header.has_valid_complement_check());
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AgbHeader {
+    pub title: String,
+    /// 4-character game code, e.g. "AXVE"
+    pub game_code: String,
+    /// 2-character maker code, e.g. "01" for Nintendo
+    pub maker_code: String,
+    pub version: u8,
+    pub complement_check: u8,
+    computed_complement_check: u8,
+}
+
+impl AgbHeader {
+    /// Decodes the header from a full ROM dump, or any prefix of one that covers the header
+    pub fn from_rom(rom: &[u8]) -> Result<AgbHeader, ParseError> {
+        let header = rom
+            .get(..HEADER_END)
+            .ok_or(ParseError("ROM is too small to contain a header"))?;
+        if header[FIXED_VALUE] != 0x96 {
+            return Err(ParseError("invalid GBA header"));
+        }
+        let text = |range: std::ops::Range<usize>| {
+            let bytes = &header[range];
+            let bytes = bytes.split(|&b| b == 0).next().unwrap_or_default();
+            String::from_utf8_lossy(bytes).trim_end().to_owned()
+        };
+        Ok(AgbHeader {
+            title: text(TITLE..GAME_CODE),
+            game_code: text(GAME_CODE..MAKER_CODE),
+            maker_code: text(MAKER_CODE..FIXED_VALUE),
+            version: header[VERSION],
+            complement_check: header[COMPLEMENT_CHECK],
+            computed_complement_check: AgbHeader::complement_check(header)?,
+        })
+    }
+    /// Computes the header complement check from a ROM dump
+    pub fn complement_check(rom: &[u8]) -> Result<u8, ParseError> {
+        let bytes = rom
+            .get(TITLE..COMPLEMENT_CHECK)
+            .ok_or(ParseError("ROM is too small to contain a header"))?;
+        let sum = bytes.iter().fold(0u8, |acc, &b| acc.wrapping_add(b));
+        Ok(0u8.wrapping_sub(sum).wrapping_sub(0x19))
+    }
+    pub const fn has_valid_complement_check(&self) -> bool {
+        self.complement_check == self.computed_complement_check
+    }
+    /// Returns the ROM ID printed on the mask ROM, e.g. "AGB-AXVE-1" for revision 1
+    pub fn rom_id(&self) -> String {
+        format!("AGB-{}-{}", self.game_code, self.version)
+    }
+    /// Checks the game code and version against a game configuration
+    pub fn matches_game(&self, game: &GameConfig) -> bool {
+        game.rom_id == self.rom_id()
+    }
+}
+
+/// Save library identification string embedded in the ROM
+const fn library_id(save_type: SaveType) -> &'static str {
+    match save_type {
+        SaveType::Eeprom => "EEPROM_V",
+        SaveType::Sram => "SRAM_V",
+        SaveType::Flash => "FLASH_V",
+        SaveType::Flash512 => "FLASH512_V",
+        SaveType::Flash1M => "FLASH1M_V",
+    }
+}
+
+/// Detects the save type by scanning the ROM for save library identification strings.
+///
+/// Returns None if no save library was found.
+///
+/// ```
+/// use gbhwdb_model::{config::cartridge::SaveType, rom_header::agb::detect_save_type};
+/// let mut rom = vec![0xff; 0x1000];
+/// rom[0x800..0x80d].copy_from_slice(b"FLASH1M_V103\0");
+/// assert_eq!(detect_save_type(&rom), Some(SaveType::Flash1M));
+/// assert_eq!(detect_save_type(&[0; 0x100]), None);
+/// ```
+pub fn detect_save_type(rom: &[u8]) -> Option<SaveType> {
+    // Library strings are word-aligned
+    (0..rom.len()).step_by(4).find_map(|offset| {
+        SaveType::ALL
+            .into_iter()
+            .find(|&save_type| rom[offset..].starts_with(library_id(save_type).as_bytes()))
+    })
+}
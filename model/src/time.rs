@@ -5,6 +5,8 @@
 use serde::{Deserialize, Serialize};
 use std::{fmt, ops::RangeInclusive};
 
+use crate::parser::Year;
+
 #[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
 #[repr(transparent)]
 #[serde(into = "u8", try_from = "u8")]
@@ -162,6 +164,202 @@ impl Jun {
     }
 }
 
+impl From<Month> for ::time::Month {
+    fn from(month: Month) -> Self {
+        match month {
+            Month::January => ::time::Month::January,
+            Month::February => ::time::Month::February,
+            Month::March => ::time::Month::March,
+            Month::April => ::time::Month::April,
+            Month::May => ::time::Month::May,
+            Month::June => ::time::Month::June,
+            Month::July => ::time::Month::July,
+            Month::August => ::time::Month::August,
+            Month::September => ::time::Month::September,
+            Month::October => ::time::Month::October,
+            Month::November => ::time::Month::November,
+            Month::December => ::time::Month::December,
+        }
+    }
+}
+
 fn is_leap_year(year: u16) -> bool {
-    (year % 4 == 0 && year % 100 != 0) || (year % 400 == 0)
+    (year.is_multiple_of(4) && !year.is_multiple_of(100)) || year.is_multiple_of(400)
+}
+
+/// Range of years during which something was manufactured
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ProductionWindow {
+    pub first: u16,
+    pub last: u16,
+}
+
+impl ProductionWindow {
+    pub const fn new(first: u16, last: u16) -> Self {
+        ProductionWindow { first, last }
+    }
+    pub const fn contains(&self, year: u16) -> bool {
+        self.first <= year && year <= self.last
+    }
+    /// Returns the number of years between the window and the given year, or 0 if the year is
+    /// inside the window
+    pub const fn distance(&self, year: u16) -> u16 {
+        if year < self.first {
+            self.first - year
+        } else {
+            year.saturating_sub(self.last)
+        }
+    }
+}
+
+impl fmt::Display for ProductionWindow {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}-{}", self.first, self.last)
+    }
+}
+
+/// Years considered as candidates when a label only has the last digit of the year
+pub const DEFAULT_ERA: ProductionWindow = ProductionWindow::new(1980, 2009);
+
+/// How confidently a year was resolved
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum YearConfidence {
+    /// The year is outside the production window, or was picked from several candidates without
+    /// a hint
+    Low,
+    /// Several candidates fit the production window, and the one closest to the hint was picked
+    Medium,
+    /// Only one candidate fits the production window
+    High,
+    /// The full year was printed on the label
+    Exact,
+}
+
+/// A full year resolved from a possibly partial year
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ResolvedYear {
+    pub year: u16,
+    /// Other years in the era the partial year could refer to
+    pub candidates: Vec<u16>,
+    pub confidence: YearConfidence,
+}
+
+/// Turns partial years into full years using a production window and an optional hint
+///
+/// The hint is usually a year known from elsewhere in the same submission (e.g. the board date),
+/// and takes priority over the production window when picking between candidates. Years outside
+/// the window are always low confidence, and shouldn't be published as-is.
+///
+/// ```
+/// use gbhwdb_model::{
+///     Console,
+///     parser::Year,
+///     time::{YearConfidence, YearResolver},
+/// };
+/// let resolver = YearResolver::new(Console::Cgb.production_window());
+/// let resolved = resolver.resolve(Year::Partial(9)).unwrap();
+/// assert_eq!(resolved.year, 1999);
+/// assert_eq!(resolved.candidates, [1989, 2009]);
+/// assert_eq!(resolved.confidence, YearConfidence::High);
+///
+/// let resolver = YearResolver::new(Console::Dmg.production_window()).with_hint(Some(1989));
+/// let resolved = resolver.resolve(Year::Partial(8)).unwrap();
+/// assert_eq!(resolved.year, 1988);
+/// assert_eq!(resolved.confidence, YearConfidence::Low);
+///
+/// let resolved = resolver.resolve(Year::Full(1990)).unwrap();
+/// assert_eq!(resolved.year, 1990);
+/// assert_eq!(resolved.confidence, YearConfidence::Exact);
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct YearResolver {
+    pub era: ProductionWindow,
+    pub window: ProductionWindow,
+    pub hint: Option<u16>,
+}
+
+impl YearResolver {
+    pub const fn new(window: ProductionWindow) -> Self {
+        YearResolver {
+            era: DEFAULT_ERA,
+            window,
+            hint: None,
+        }
+    }
+    pub const fn with_era(self, era: ProductionWindow) -> Self {
+        YearResolver { era, ..self }
+    }
+    /// Replaces the hint, unless the given hint is `None`
+    pub const fn with_hint(self, hint: Option<u16>) -> Self {
+        match hint {
+            Some(hint) => YearResolver {
+                hint: Some(hint),
+                ..self
+            },
+            None => self,
+        }
+    }
+    /// Resolves a year, returning `None` if a partial year has no candidates in the era
+    pub fn resolve(&self, year: Year) -> Option<ResolvedYear> {
+        let partial_year = match year {
+            Year::Full(year) => {
+                return Some(ResolvedYear {
+                    year,
+                    candidates: Vec::new(),
+                    confidence: YearConfidence::Exact,
+                });
+            }
+            Year::Partial(partial_year) => u16::from(partial_year),
+        };
+        let candidates = (self.era.first..=self.era.last)
+            .filter(|year| year % 10 == partial_year)
+            .collect::<Vec<_>>();
+        let year = match self.hint {
+            Some(hint) => candidates
+                .iter()
+                .copied()
+                .min_by_key(|&year| hint.abs_diff(year)),
+            None => candidates
+                .iter()
+                .copied()
+                .min_by_key(|&year| self.window.distance(year)),
+        }?;
+        let in_window = candidates
+            .iter()
+            .filter(|&&year| self.window.contains(year))
+            .count();
+        let confidence = if !self.window.contains(year) {
+            YearConfidence::Low
+        } else if in_window == 1 {
+            YearConfidence::High
+        } else if self.hint.is_some() {
+            YearConfidence::Medium
+        } else {
+            YearConfidence::Low
+        };
+        Some(ResolvedYear {
+            year,
+            candidates: candidates
+                .into_iter()
+                .filter(|&candidate| candidate != year)
+                .collect(),
+            confidence,
+        })
+    }
+}
+
+#[test]
+fn test_resolve_partial_year_with_hint() {
+    let resolver = YearResolver::new(ProductionWindow::new(1989, 1998));
+    let resolve = |hint: u16, year: u8| {
+        resolver
+            .with_hint(Some(hint))
+            .resolve(Year::Partial(year))
+            .map(|resolved| resolved.year)
+    };
+    assert_eq!(resolve(1992, 2), Some(1992));
+    assert_eq!(resolve(1989, 9), Some(1989));
+    assert_eq!(resolve(1990, 0), Some(1990));
+    assert_eq!(resolve(1998, 9), Some(1999));
+    assert_eq!(resolve(2005, 0), Some(2000));
 }
@@ -60,6 +60,11 @@ fn part() -> Builder<ProcessedPart> {
         .add("label", |c| (&c.label).csv())
         .add("manufacturer", |c| c.manufacturer.map(|m| m.name()).csv())
         .add_date_code(|c| c.date_code)
+        .add("lot_code", |c| (&c.lot_code).csv())
+        .add("revision", |c| (&c.revision).csv())
+        .add("origin_country", |c| {
+            c.origin_country.map(|c| c.name()).csv()
+        })
 }
 
 trait Field<'a> {
@@ -28,10 +28,14 @@ impl ToCsv for LegacyMetadata {
                         .add_date_code(|m| m.date_code)
                 },
             );
-        for &designator in PartDesignator::VARIANTS {
+        // The battery keeps its own column names
+        for &designator in PartDesignator::VARIANTS
+            .iter()
+            .filter(|&&designator| designator != PartDesignator::BT1)
+        {
             builder = builder.add(format!("{}_role", designator.as_lower_str()), move |m| {
                 m.board
-                    .cfg
+                    .label
                     .part(designator)
                     .map(|part| Cow::from(part.role().display()))
                     .unwrap_or_default()
@@ -43,15 +47,15 @@ impl ToCsv for LegacyMetadata {
             )
         }
         builder
-            .nest("battery", |m| m.board.battery.as_ref(), part)
+            .nest("battery", |m| m.board.parts.get(&PartDesignator::BT1), part)
             .nest("dump", |m| m.dump.as_ref(), dump)
     }
 }
 
 fn dump() -> Builder<CartridgeDump> {
     Builder::<CartridgeDump>::new()
-        .add("crc32", |c| (&c.crc32).csv())
-        .add("md5", |c| (&c.md5).csv())
-        .add("sha1", |c| (&c.sha1).csv())
-        .add("sha256", |c| (&c.sha256).csv())
+        .add("crc32", |c| c.crc32.csv())
+        .add("md5", |c| c.md5.csv())
+        .add("sha1", |c| c.sha1.csv())
+        .add("sha256", |c| c.sha256.csv())
 }
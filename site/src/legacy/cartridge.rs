@@ -2,12 +2,19 @@
 //
 // SPDX-License-Identifier: MIT
 
-use crate::process::part::loose_datecode;
+use crate::problems::Problems;
+use crate::process::part::{canonical_label, loose_datecode};
 use crate::{
     process::DateCode,
     process::part::{ParsedPart, ProcessedPart},
 };
-use gbhwdb_model::{config::cartridge::*, input::cartridge::*, parser, parser::LabelParser};
+use gbhwdb_model::{
+    config::cartridge::*,
+    input::cartridge::*,
+    parser,
+    parser::{LabelParser, PartDateCode},
+    time::YearResolver,
+};
 use std::collections::HashMap;
 
 #[derive(Clone, Debug)]
@@ -23,84 +30,136 @@ impl super::LegacyMetadata for LegacyMetadata {}
 
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct LegacyBoard {
-    pub cfg: BoardConfig,
+    pub label: BoardLabel,
     pub kind: String,
     pub circled_letters: Option<String>,
     pub panel_position: Option<String>,
     pub date_code: DateCode,
     pub parts: HashMap<PartDesignator, ProcessedPart>,
-    pub battery: Option<ProcessedPart>,
 }
 
 impl LegacyBoard {
-    pub fn new(board: CartridgeBoard, cfg: BoardConfig) -> Self {
-        let parts = cfg
+    pub fn new(
+        board: CartridgeBoard,
+        label: BoardLabel,
+        platform: GamePlatform,
+        problems: &mut Problems,
+    ) -> Self {
+        let years = YearResolver::new(platform.production_window()).with_hint(board.year);
+        let battery_type = label.config.battery_type();
+        let parts = label
             .parts()
             .filter_map(|(designator, part)| {
-                fn parse<T: ParsedPart>(
+                fn parse<T: ParsedPart + 'static>(
                     board: &CartridgeBoard,
+                    years: YearResolver,
                     designator: PartDesignator,
                     parser: &dyn LabelParser<T>,
+                    problems: &mut Problems,
                 ) -> Option<(PartDesignator, ProcessedPart)> {
-                    let submission_part = &board[designator];
+                    let submission_part = board.part(designator)?;
                     let label = Some(&submission_part.label).filter(|label| !label.is_empty())?;
 
-                    let parsed = parser
-                        .parse(label)
-                        .unwrap_or_else(|_| panic!("Failed to parse {designator:?}:{label}"));
+                    let (normalized, parsed) = match parser.parse_normalized(label) {
+                        Ok(parsed) => parsed,
+                        Err(err) => {
+                            let suggestions = parser::suggest(label, parser)
+                                .iter()
+                                .take(3)
+                                .map(|suggestion| format!("\n  did you mean {suggestion}?"))
+                                .collect::<String>();
+                            problems.push(format!("{designator:?}: {err}{suggestions}"));
+                            return None;
+                        }
+                    };
 
-                    Some((designator, parsed.process(board.year, String::from(label))))
+                    let mut part = parsed.process(years, String::from(label));
+                    part.canonical_label = canonical_label(normalized);
+                    Some((designator, part))
                 }
                 match part {
-                    BoardPart::Unknown(parser) => parse(&board, designator, parser),
-                    BoardPart::Rom(parser) => parse(&board, designator, parser),
-                    BoardPart::Mapper(parser) => parse(&board, designator, parser),
-                    BoardPart::Ram(parser) => parse(&board, designator, parser),
-                    BoardPart::SupervisorReset(parser) => parse(&board, designator, parser),
-                    BoardPart::Crystal(parser) => parse(&board, designator, parser),
-                    BoardPart::Flash(parser) => parse(&board, designator, parser),
-                    BoardPart::Eeprom(parser) => parse(&board, designator, parser),
-                    BoardPart::Accelerometer(parser) => parse(&board, designator, parser),
-                    BoardPart::LineDecoder(parser) => parse(&board, designator, parser),
-                    BoardPart::HexInverter(parser) => parse(&board, designator, parser),
-                    BoardPart::Mcu(parser) => parse(&board, designator, parser),
-                    BoardPart::Rtc(parser) => parse(&board, designator, parser),
+                    BoardPart::Unknown(parser) => {
+                        parse(&board, years, designator, parser, problems)
+                    }
+                    BoardPart::Rom(parser, _) => parse(&board, years, designator, parser, problems),
+                    BoardPart::Mapper(parser, _) => {
+                        parse(&board, years, designator, parser, problems)
+                    }
+                    BoardPart::Ram(parser) => parse(&board, years, designator, parser, problems),
+                    BoardPart::SupervisorReset(parser) => {
+                        parse(&board, years, designator, parser, problems)
+                    }
+                    BoardPart::Crystal(parser) => {
+                        parse(&board, years, designator, parser, problems)
+                    }
+                    BoardPart::Flash(parser) => parse(&board, years, designator, parser, problems),
+                    BoardPart::Eeprom(parser) => parse(&board, years, designator, parser, problems),
+                    BoardPart::Accelerometer(parser) => {
+                        parse(&board, years, designator, parser, problems)
+                    }
+                    BoardPart::LineDecoder(parser) => {
+                        parse(&board, years, designator, parser, problems)
+                    }
+                    BoardPart::HexInverter(parser) => {
+                        parse(&board, years, designator, parser, problems)
+                    }
+                    BoardPart::Mcu(parser) => parse(&board, years, designator, parser, problems),
+                    BoardPart::Rtc(parser) => parse(&board, years, designator, parser, problems),
+                    BoardPart::Battery(parser) => {
+                        let kind = battery_type?;
+                        Some((designator, battery(&board, years, kind, parser, problems)))
+                    }
+                    BoardPart::Motor(parser) => parse(&board, years, designator, parser, problems),
+                    BoardPart::IrLed(parser) => parse(&board, years, designator, parser, problems),
+                    BoardPart::Phototransistor(parser) => {
+                        parse(&board, years, designator, parser, problems)
+                    }
+                    BoardPart::LightSensor(parser) => {
+                        parse(&board, years, designator, parser, problems)
+                    }
                 }
             })
             .collect();
         LegacyBoard {
-            cfg,
+            label,
             kind: board.label,
             circled_letters: Some(board.circled_letters).filter(|letters| !letters.is_empty()),
             panel_position: Some(board.panel_position).filter(|position| !position.is_empty()),
             date_code: DateCode::year_month(board.year, board.month),
             parts,
-            battery: cfg.battery_type().map(|kind| {
-                let label = Some(&board.battery.label).filter(|label| !label.is_empty());
-                ProcessedPart {
-                    kind: Some(match kind {
-                        BatteryType::Cr1616 => String::from("CR1616"),
-                        BatteryType::Cr2025 => String::from("CR2025"),
-                    }),
-                    label: label.cloned(),
-                    manufacturer: board.battery.manufacturer,
-                    date_code: loose_datecode(
-                        board.year,
-                        label.as_ref().map(|label| {
-                            parser::battery()
-                                .parse(&label)
-                                .unwrap_or_else(|_| panic!("Failed to parse battery label:{label}"))
-                        }),
-                    ),
-                    rom_id: None,
-                }
-            }),
         }
     }
     pub fn mapper(&self) -> Option<&ProcessedPart> {
-        self.cfg
+        self.label
             .parts()
-            .find(|(_, part)| matches!(part, BoardPart::Mapper(_)))
+            .find(|(_, part)| matches!(part, BoardPart::Mapper(..)))
             .and_then(|(designator, _)| self.parts.get(&designator))
     }
 }
+
+fn battery(
+    board: &CartridgeBoard,
+    years: YearResolver,
+    kind: BatteryType,
+    parser: &dyn LabelParser<PartDateCode>,
+    problems: &mut Problems,
+) -> ProcessedPart {
+    let label = Some(&board.battery.label).filter(|label| !label.is_empty());
+    let date_code = label.and_then(|label| problems.parsed(parser.parse(label)));
+    ProcessedPart {
+        kind: Some(match kind {
+            BatteryType::Cr1616 => String::from("CR1616"),
+            BatteryType::Cr2025 => String::from("CR2025"),
+        }),
+        label: label.cloned(),
+        canonical_label: None,
+        manufacturer: board.battery.manufacturer,
+        date_code: loose_datecode(years, date_code),
+        rom_id: None,
+        lot_code: None,
+        revision: None,
+        origin_country: None,
+        mask_code: None,
+        decoded_mask_code: None,
+    }
+}
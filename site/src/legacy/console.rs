@@ -5,8 +5,8 @@
 use gbhwdb_model::{
     Console,
     input::{LcdChip, LcdScreen, dmg::DmgJackBoard},
-    parser::{LabelParser, Manufacturer},
-    time::{Jun, Month, Week},
+    parser::{LabelParser, Manufacturer, ParseFailure},
+    time::{Jun, Month, Week, YearResolver},
 };
 
 use crate::{
@@ -42,8 +42,8 @@ pub trait LegacyConsoleMetadata: LegacyMetadata {
     const CONSOLE: Console;
 
     fn parts() -> Vec<ChipInfo<Self>>;
-    fn shell(&self) -> LegacyConsoleShell;
-    fn mainboard(&self) -> LegacyMainboard;
+    fn shell(&self) -> LegacyConsoleShell<'_>;
+    fn mainboard(&self) -> LegacyMainboard<'_>;
     fn lcd_panel(&self) -> Option<&LegacyLcdPanel> {
         None
     }
@@ -252,7 +252,7 @@ impl LegacyConsoleMetadata for LegacyDmgMetadata {
         ]
     }
 
-    fn shell(&self) -> LegacyConsoleShell {
+    fn shell(&self) -> LegacyConsoleShell<'_> {
         LegacyConsoleShell {
             color: self.color.as_deref(),
             date_code: self.date_code(),
@@ -260,7 +260,7 @@ impl LegacyConsoleMetadata for LegacyDmgMetadata {
         }
     }
 
-    fn mainboard(&self) -> LegacyMainboard {
+    fn mainboard(&self) -> LegacyMainboard<'_> {
         LegacyMainboard {
             kind: &self.mainboard.kind,
             date_code: self.mainboard.date_code(),
@@ -380,14 +380,14 @@ impl LegacyConsoleMetadata for LegacySgbMetadata {
         ]
     }
 
-    fn shell(&self) -> LegacyConsoleShell {
+    fn shell(&self) -> LegacyConsoleShell<'_> {
         LegacyConsoleShell {
             stamp: self.stamp.as_deref(),
             ..LegacyConsoleShell::default()
         }
     }
 
-    fn mainboard(&self) -> LegacyMainboard {
+    fn mainboard(&self) -> LegacyMainboard<'_> {
         LegacyMainboard {
             kind: &self.mainboard.kind,
             date_code: self.mainboard.date_code(),
@@ -453,14 +453,14 @@ impl LegacyConsoleMetadata for LegacySgb2Metadata {
         ]
     }
 
-    fn shell(&self) -> LegacyConsoleShell {
+    fn shell(&self) -> LegacyConsoleShell<'_> {
         LegacyConsoleShell {
             stamp: self.stamp.as_deref(),
             ..LegacyConsoleShell::default()
         }
     }
 
-    fn mainboard(&self) -> LegacyMainboard {
+    fn mainboard(&self) -> LegacyMainboard<'_> {
         LegacyMainboard {
             kind: &self.mainboard.kind,
             date_code: self.mainboard.date_code(),
@@ -522,7 +522,7 @@ impl LegacyConsoleMetadata for LegacyMgbMetadata {
         ]
     }
 
-    fn shell(&self) -> LegacyConsoleShell {
+    fn shell(&self) -> LegacyConsoleShell<'_> {
         LegacyConsoleShell {
             color: self.color.as_deref(),
             release_code: self.release_code.as_deref(),
@@ -531,7 +531,7 @@ impl LegacyConsoleMetadata for LegacyMgbMetadata {
         }
     }
 
-    fn mainboard(&self) -> LegacyMainboard {
+    fn mainboard(&self) -> LegacyMainboard<'_> {
         LegacyMainboard {
             kind: &self.mainboard.kind,
             date_code: self.mainboard.date_code(),
@@ -626,7 +626,7 @@ impl LegacyConsoleMetadata for LegacyMglMetadata {
         ]
     }
 
-    fn shell(&self) -> LegacyConsoleShell {
+    fn shell(&self) -> LegacyConsoleShell<'_> {
         LegacyConsoleShell {
             color: self.color.as_deref(),
             release_code: self.release_code.as_deref(),
@@ -635,7 +635,7 @@ impl LegacyConsoleMetadata for LegacyMglMetadata {
         }
     }
 
-    fn mainboard(&self) -> LegacyMainboard {
+    fn mainboard(&self) -> LegacyMainboard<'_> {
         LegacyMainboard {
             kind: &self.mainboard.kind,
             date_code: self.mainboard.date_code(),
@@ -728,7 +728,7 @@ impl LegacyConsoleMetadata for LegacyCgbMetadata {
         ]
     }
 
-    fn shell(&self) -> LegacyConsoleShell {
+    fn shell(&self) -> LegacyConsoleShell<'_> {
         LegacyConsoleShell {
             color: self.color.as_deref(),
             release_code: self.release_code.as_deref(),
@@ -737,7 +737,7 @@ impl LegacyConsoleMetadata for LegacyCgbMetadata {
         }
     }
 
-    fn mainboard(&self) -> LegacyMainboard {
+    fn mainboard(&self) -> LegacyMainboard<'_> {
         LegacyMainboard {
             kind: &self.mainboard.kind,
             date_code: self.mainboard.date_code(),
@@ -825,7 +825,7 @@ impl LegacyConsoleMetadata for LegacyAgbMetadata {
         ]
     }
 
-    fn shell(&self) -> LegacyConsoleShell {
+    fn shell(&self) -> LegacyConsoleShell<'_> {
         LegacyConsoleShell {
             color: self.color.as_deref(),
             release_code: self.release_code.as_deref(),
@@ -834,7 +834,7 @@ impl LegacyConsoleMetadata for LegacyAgbMetadata {
         }
     }
 
-    fn mainboard(&self) -> LegacyMainboard {
+    fn mainboard(&self) -> LegacyMainboard<'_> {
         LegacyMainboard {
             kind: &self.mainboard.kind,
             date_code: self.mainboard.date_code(),
@@ -909,7 +909,7 @@ impl LegacyConsoleMetadata for LegacyAgsMetadata {
         ]
     }
 
-    fn shell(&self) -> LegacyConsoleShell {
+    fn shell(&self) -> LegacyConsoleShell<'_> {
         LegacyConsoleShell {
             color: self.color.as_deref(),
             release_code: self.release_code.as_deref(),
@@ -917,7 +917,7 @@ impl LegacyConsoleMetadata for LegacyAgsMetadata {
         }
     }
 
-    fn mainboard(&self) -> LegacyMainboard {
+    fn mainboard(&self) -> LegacyMainboard<'_> {
         LegacyMainboard {
             kind: &self.mainboard.kind,
             date_code: self.mainboard.date_code(),
@@ -997,7 +997,7 @@ impl LegacyConsoleMetadata for LegacyGbsMetadata {
         ]
     }
 
-    fn shell(&self) -> LegacyConsoleShell {
+    fn shell(&self) -> LegacyConsoleShell<'_> {
         LegacyConsoleShell {
             color: self.color.as_deref(),
             release_code: self.release_code.as_deref(),
@@ -1006,7 +1006,7 @@ impl LegacyConsoleMetadata for LegacyGbsMetadata {
         }
     }
 
-    fn mainboard(&self) -> LegacyMainboard {
+    fn mainboard(&self) -> LegacyMainboard<'_> {
         LegacyMainboard {
             kind: &self.mainboard.kind,
             date_code: self.mainboard.date_code(),
@@ -1070,7 +1070,7 @@ impl LegacyConsoleMetadata for LegacyOxyMetadata {
         ]
     }
 
-    fn shell(&self) -> LegacyConsoleShell {
+    fn shell(&self) -> LegacyConsoleShell<'_> {
         LegacyConsoleShell {
             color: self.color.as_deref(),
             release_code: self.release_code.as_deref(),
@@ -1078,7 +1078,7 @@ impl LegacyConsoleMetadata for LegacyOxyMetadata {
         }
     }
 
-    fn mainboard(&self) -> LegacyMainboard {
+    fn mainboard(&self) -> LegacyMainboard<'_> {
         LegacyMainboard {
             kind: &self.mainboard.kind,
             date_code: self.mainboard.date_code(),
@@ -1125,7 +1125,10 @@ impl HasDateCode for LegacyLcdPanel {
     }
 }
 
-pub fn to_legacy_lcd_chip(year_hint: Option<u16>, chip: &LcdChip) -> ProcessedPart {
+pub fn to_legacy_lcd_chip(
+    years: YearResolver,
+    chip: &LcdChip,
+) -> Result<ProcessedPart, ParseFailure> {
     let label = &chip.label;
     let ribbon_label = &chip.ribbon_label;
     if label.is_empty() {
@@ -1134,51 +1137,59 @@ pub fn to_legacy_lcd_chip(year_hint: Option<u16>, chip: &LcdChip) -> ProcessedPa
         } else {
             Some(ribbon_label.clone())
         };
-        ProcessedPart {
+        Ok(ProcessedPart {
             kind: label.clone(),
             label,
             manufacturer: Some(Manufacturer::Sharp),
             ..ProcessedPart::default()
-        }
+        })
     } else {
-        let date_code = gbhwdb_model::parser::lcd_chip()
-            .parse(&label)
-            .unwrap_or_else(|_| panic!("{}", label));
-        ProcessedPart {
+        let date_code = Some(gbhwdb_model::parser::lcd_chip().parse(label)?);
+        Ok(ProcessedPart {
             label: Some(if ribbon_label.is_empty() {
                 label.clone()
             } else {
                 format!("{} {}", ribbon_label, label)
             }),
+            canonical_label: None,
             kind: Some(ribbon_label.clone()),
             manufacturer: Some(Manufacturer::Sharp),
-            date_code: loose_datecode(year_hint, Some(date_code)),
+            date_code: loose_datecode(years, date_code),
             rom_id: None,
-        }
+            lot_code: None,
+            revision: None,
+            origin_country: None,
+            mask_code: None,
+            decoded_mask_code: None,
+        })
     }
 }
 
-pub fn to_legacy_lcd_panel(year_hint: Option<u16>, screen: &LcdScreen) -> Option<LegacyLcdPanel> {
+pub fn to_legacy_lcd_panel(
+    years: YearResolver,
+    screen: &LcdScreen,
+) -> Result<Option<LegacyLcdPanel>, ParseFailure> {
     let column_driver = Some(&screen.column_driver)
         .filter(|chip| !chip.is_unknown())
-        .map(|chip| to_legacy_lcd_chip(year_hint, chip));
+        .map(|chip| to_legacy_lcd_chip(years, chip))
+        .transpose()?;
     let row_driver = Some(&screen.row_driver)
         .filter(|chip| !chip.is_unknown())
-        .map(|chip| to_legacy_lcd_chip(year_hint, chip));
+        .map(|chip| to_legacy_lcd_chip(years, chip))
+        .transpose()?;
     let label = if screen.label.is_empty() {
         None
     } else {
         Some(screen.label.clone())
     };
-    let date_code = label.as_ref().map(|label| {
-        gbhwdb_model::parser::lcd_screen()
-            .parse(label)
-            .unwrap_or_else(|_| panic!("{}", label))
-    });
-    Some(LegacyLcdPanel {
+    let date_code = match &label {
+        Some(label) => Some(gbhwdb_model::parser::lcd_screen().parse(label)?),
+        None => None,
+    };
+    Ok(Some(LegacyLcdPanel {
         label,
-        date_code: loose_datecode(year_hint, date_code),
+        date_code: loose_datecode(years, date_code),
         column_driver,
         row_driver,
-    })
+    }))
 }
@@ -2,6 +2,8 @@
 //
 // SPDX-License-Identifier: MIT
 
+#![allow(clippy::type_complexity)]
+
 use anyhow::{Context as _, Error, anyhow};
 use csv_export::{ToCsv, write_submission_csv};
 use filetime::{FileTime, set_file_mtime};
@@ -10,10 +12,12 @@ use gbhwdb_model::{
     config::cartridge::*,
     input::cartridge::*,
     parser::{self, LabelParser},
+    time::YearResolver,
 };
 use glob::glob;
 use image::{codecs::jpeg::JpegEncoder, imageops::FilterType};
 use log::{LevelFilter, debug, info, warn};
+use problems::Problems;
 use process::part::map_part;
 use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
 use simplelog::{ColorChoice, TermLogger, TerminalMode};
@@ -31,6 +35,7 @@ use site::{SubmissionCounts, build_site};
 mod css;
 mod csv_export;
 mod legacy;
+mod problems;
 mod process;
 mod site;
 mod template;
@@ -202,6 +207,12 @@ fn main() -> Result<(), Error> {
         ColorChoice::Auto,
     );
 
+    if std::env::var_os("GBHWDB_AUDIT").is_some() {
+        info!("Parser ambiguity audit enabled");
+        parser::audit::set_enabled(true);
+    }
+
+    BoardConfig::validate()?;
     let cfgs = gbhwdb_model::config::cartridge::load_cfgs("config/games.json")?;
 
     info!("Processing submissions");
@@ -288,6 +299,7 @@ fn read_cartridge_submissions(
         cfgs: &BTreeMap<String, GameConfig>,
         root: &Path,
         path: &Path,
+        problems: &mut Problems,
     ) -> Result<LegacyCartridgeSubmission, Error> {
         let file = File::open(path)?;
         let cartridge: Cartridge = serde_json::from_reader(file)?;
@@ -295,12 +307,17 @@ fn read_cartridge_submissions(
             Some(cartridge.slug.as_str()),
             root.file_name().and_then(|name| name.to_str())
         );
+        problems.start(&format!(
+            "{code} {slug}",
+            code = cartridge.code,
+            slug = cartridge.slug
+        ));
         let cfg = cfgs
             .get(&cartridge.code)
             .ok_or_else(|| anyhow!("Unknown ROM code: {}", cartridge.code))?;
 
-        let board_cfg = BoardConfig::from_label(&cartridge.board.label)
-            .unwrap_or_else(|| panic!("Failed to find config for board {}", cartridge.board.label));
+        let board_label = BoardLabel::parse(&cartridge.board.label)
+            .ok_or_else(|| anyhow!("Failed to find config for board {}", cartridge.board.label))?;
 
         if let Some(year) = cartridge.board.year {
             assert!((1989..2010).contains(&year));
@@ -346,7 +363,19 @@ fn read_cartridge_submissions(
             }
         }
 
-        let board = LegacyBoard::new(cartridge.board, board_cfg);
+        let chips = parse_board_chips(&board_label, &cartridge.board);
+        for mismatch in check_roms(&board_label, &chips.roms, cfg) {
+            problems.push(mismatch);
+        }
+
+        for inconsistency in check_consistency(cfg, &board_label, chips.mapper.as_ref()) {
+            match inconsistency.severity() {
+                Severity::Warning => warn!("{code}: {inconsistency}", code = cartridge.code),
+                Severity::Error => problems.push(inconsistency),
+            }
+        }
+
+        let board = LegacyBoard::new(cartridge.board, board_label, cfg.platform, problems);
         let metadata = LegacyMetadata {
             cfg: cfg.clone(),
             code: Some(cartridge.shell.code).filter(|code| !code.is_empty()),
@@ -374,19 +403,21 @@ fn read_cartridge_submissions(
     use legacy::cartridge::*;
     let walker = WalkDir::new("data/cartridges").min_depth(3).max_depth(3);
     let mut submissions = Vec::new();
+    let mut problems = Problems::default();
     for entry in walker.into_iter().filter_entry(is_metadata_file) {
         let entry = entry?;
         if let Some(root) = entry.path().parent() {
-            submissions.push(
-                read_cartridge_submission(&cfgs, root, entry.path()).with_context(|| {
+            let submission = read_cartridge_submission(cfgs, root, entry.path(), &mut problems)
+                .with_context(|| {
                     format!(
                         "failed to read cartridge submission from {root}",
                         root = root.display()
                     )
-                })?,
-            );
+                })?;
+            submissions.push(submission);
         }
     }
+    problems.finish("cartridge")?;
     submissions.sort_by_key(|submission| (submission.code.clone(), submission.slug.clone()));
     Ok(submissions)
 }
@@ -398,12 +429,14 @@ fn read_dmg_submissions() -> Result<Vec<LegacyDmgSubmission>, Error> {
     use process::to_full_year;
     let walker = WalkDir::new("data/consoles/DMG").min_depth(2).max_depth(2);
     let mut submissions = Vec::new();
+    let mut problems = Problems::default();
     for entry in walker.into_iter().filter_entry(is_metadata_file) {
         let entry = entry?;
         if let Some(root) = entry.path().parent() {
             debug!("{}", entry.path().display());
             let file = File::open(entry.path())?;
             let console: DmgConsole = serde_json::from_reader(file)?;
+            problems.start(&console.slug);
             assert_eq!(
                 Some(console.slug.as_str()),
                 root.file_name().and_then(|name| name.to_str())
@@ -412,13 +445,20 @@ fn read_dmg_submissions() -> Result<Vec<LegacyDmgSubmission>, Error> {
                 assert_eq!(console.slug, console.shell.serial);
             }
 
+            let years = YearResolver::new(Console::Dmg.production_window());
             let cpu = match console.mainboard.label.as_str() {
-                "DMG-CPU-07" | "DMG-CPU-08" => {
-                    map_part(None, &console.mainboard.u1, parser::dmg_soc_glop_top())
-                }
-                _ => map_part(None, &console.mainboard.u1, parser::dmg_soc_qfp_80()),
+                "DMG-CPU-07" | "DMG-CPU-08" => problems.ok(map_part(
+                    years,
+                    &console.mainboard.u1,
+                    parser::dmg_soc_glop_top(),
+                )),
+                _ => problems.ok(map_part(
+                    years,
+                    &console.mainboard.u1,
+                    parser::dmg_soc_qfp_80(),
+                )),
             };
-            let year_hint = cpu.as_ref().map(|cpu| cpu.date_code.year.unwrap_or(1996));
+            let years = years.with_hint(cpu.as_ref().and_then(|cpu| cpu.date_code.year));
 
             let (work_ram, video_ram, amplifier) = match console.mainboard.label.as_str() {
                 "DMG-CPU-07" | "DMG-CPU-08" => {
@@ -429,13 +469,25 @@ fn read_dmg_submissions() -> Result<Vec<LegacyDmgSubmission>, Error> {
                     (blob.clone(), blob.clone(), blob)
                 }
                 _ => (
-                    map_part(year_hint, &console.mainboard.u2, parser::sram_sop_28_5v()),
-                    map_part(year_hint, &console.mainboard.u3, parser::sram_sop_28_5v()),
-                    map_part(year_hint, &console.mainboard.u4, parser::dmg_amp()),
+                    problems.ok(map_part(
+                        years,
+                        &console.mainboard.u2,
+                        parser::sram_sop_28_5v(),
+                    )),
+                    problems.ok(map_part(
+                        years,
+                        &console.mainboard.u3,
+                        parser::sram_sop_28_5v(),
+                    )),
+                    problems.ok(map_part(years, &console.mainboard.u4, parser::dmg_amp())),
                 ),
             };
 
-            let crystal = map_part(year_hint, &console.mainboard.x1, parser::dmg_crystal());
+            let crystal = problems.ok(map_part(
+                years,
+                &console.mainboard.x1,
+                parser::dmg_crystal(),
+            ));
 
             let mainboard = LegacyDmgMainboard {
                 kind: console.mainboard.label.clone(),
@@ -464,10 +516,10 @@ fn read_dmg_submissions() -> Result<Vec<LegacyDmgSubmission>, Error> {
             let lcd_board = Some(&console.lcd_board)
                 .filter(|board| !board.is_unknown())
                 .map(|board| {
-                    let regulator = map_part(year_hint, &board.chip, parser::dmg_reg());
+                    let regulator = problems.ok(map_part(years, &board.chip, parser::dmg_reg()));
                     let lcd_panel = Some(&board.screen)
                         .filter(|screen| !screen.is_unknown())
-                        .and_then(|screen| to_legacy_lcd_panel(year_hint, screen));
+                        .and_then(|screen| problems.ok(to_legacy_lcd_panel(years, screen)));
 
                     LegacyDmgLcdBoard {
                         kind: board.label.clone(),
@@ -504,25 +556,17 @@ fn read_dmg_submissions() -> Result<Vec<LegacyDmgSubmission>, Error> {
 
             let mainboard_stamp = Some(&console.mainboard.stamp)
                 .filter(|stamp| !stamp.is_empty() && !console.mainboard.outlier)
-                .map(|stamp| {
-                    gbhwdb_model::parser::dmg_stamp()
-                        .parse(stamp)
-                        .unwrap_or_else(|_| panic!("{}", stamp))
-                });
+                .and_then(|stamp| problems.parsed(gbhwdb_model::parser::dmg_stamp().parse(stamp)));
             let lcd_board_stamp = Some(&console.lcd_board.stamp)
                 .filter(|stamp| !stamp.is_empty() && !console.lcd_board.outlier)
-                .map(|stamp| {
-                    gbhwdb_model::parser::dmg_stamp()
-                        .parse(stamp)
-                        .unwrap_or_else(|_| panic!("{}", stamp))
-                });
+                .and_then(|stamp| problems.parsed(gbhwdb_model::parser::dmg_stamp().parse(stamp)));
             let stamp = mainboard_stamp.or(lcd_board_stamp);
 
             let metadata = LegacyDmgMetadata {
                 color: console.shell.color.map(|c| format!("{:?}", c)),
                 year: stamp
                     .as_ref()
-                    .and_then(|stamp| to_full_year(year_hint, stamp.year)),
+                    .and_then(|stamp| to_full_year(years, stamp.year)),
                 month: stamp.as_ref().and_then(|stamp| stamp.month),
                 mainboard,
                 lcd_board,
@@ -568,6 +612,7 @@ fn read_dmg_submissions() -> Result<Vec<LegacyDmgSubmission>, Error> {
         }
     }
     submissions.sort_by_key(|submission| (submission.sort_group.clone(), submission.slug.clone()));
+    problems.finish("DMG")?;
     Ok(submissions)
 }
 
@@ -576,24 +621,39 @@ fn read_sgb_submissions() -> Result<Vec<LegacySgbSubmission>, Error> {
     use legacy::console::*;
     let walker = WalkDir::new("data/consoles/SGB").min_depth(2).max_depth(2);
     let mut submissions = Vec::new();
+    let mut problems = Problems::default();
     for entry in walker.into_iter().filter_entry(is_metadata_file) {
         let entry = entry?;
         if let Some(root) = entry.path().parent() {
             debug!("{}", entry.path().display());
             let file = File::open(entry.path())?;
             let console: SgbConsole = serde_json::from_reader(file)?;
+            problems.start(&console.slug);
             assert_eq!(
                 Some(console.slug.as_str()),
                 root.file_name().and_then(|name| name.to_str())
             );
 
-            let year_hint = console.mainboard.year;
-            let cpu = map_part(year_hint, &console.mainboard.u1, parser::sgb_soc_qfp_80());
-            let icd2 = map_part(year_hint, &console.mainboard.u2, parser::icd2());
-            let work_ram = map_part(year_hint, &console.mainboard.u3, parser::sram_sop_28_5v());
-            let video_ram = map_part(year_hint, &console.mainboard.u4, parser::sram_sop_28_5v());
-            let rom = map_part(year_hint, &console.mainboard.u5, parser::sgb_rom());
-            let cic = map_part(year_hint, &console.mainboard.u6, parser::cic());
+            let years = YearResolver::new(Console::Sgb.production_window())
+                .with_hint(console.mainboard.year);
+            let cpu = problems.ok(map_part(
+                years,
+                &console.mainboard.u1,
+                parser::sgb_soc_qfp_80(),
+            ));
+            let icd2 = problems.ok(map_part(years, &console.mainboard.u2, parser::icd2()));
+            let work_ram = problems.ok(map_part(
+                years,
+                &console.mainboard.u3,
+                parser::sram_sop_28_5v(),
+            ));
+            let video_ram = problems.ok(map_part(
+                years,
+                &console.mainboard.u4,
+                parser::sram_sop_28_5v(),
+            ));
+            let rom = problems.ok(map_part(years, &console.mainboard.u5, parser::sgb_rom()));
+            let cic = problems.ok(map_part(years, &console.mainboard.u6, parser::cic()));
             let mainboard = LegacySgbMainboard {
                 kind: console.mainboard.label.clone(),
                 circled_letters: Some(&console.mainboard.circled_letters)
@@ -637,6 +697,7 @@ fn read_sgb_submissions() -> Result<Vec<LegacySgbSubmission>, Error> {
         }
     }
     submissions.sort_by_key(|submission| (submission.sort_group.clone(), submission.slug.clone()));
+    problems.finish("SGB")?;
     Ok(submissions)
 }
 
@@ -646,12 +707,14 @@ fn read_mgb_submissions() -> Result<Vec<LegacyMgbSubmission>, Error> {
     use process::to_full_year;
     let walker = WalkDir::new("data/consoles/MGB").min_depth(2).max_depth(2);
     let mut submissions = Vec::new();
+    let mut problems = Problems::default();
     for entry in walker.into_iter().filter_entry(is_metadata_file) {
         let entry = entry?;
         if let Some(root) = entry.path().parent() {
             debug!("{}", entry.path().display());
             let file = File::open(entry.path())?;
             let console: MgbConsole = serde_json::from_reader(file)?;
+            problems.start(&console.slug);
             assert_eq!(
                 Some(console.slug.as_str()),
                 root.file_name().and_then(|name| name.to_str())
@@ -660,12 +723,25 @@ fn read_mgb_submissions() -> Result<Vec<LegacyMgbSubmission>, Error> {
                 assert_eq!(console.slug, console.shell.serial);
             }
 
-            let year_hint = console.mainboard.year;
-            let cpu = map_part(year_hint, &console.mainboard.u1, parser::mgb_soc_qfp_80());
-            let work_ram = map_part(year_hint, &console.mainboard.u2, parser::sram_sop_28_5v());
-            let amplifier = map_part(year_hint, &console.mainboard.u3, parser::mgb_amp());
-            let regulator = map_part(year_hint, &console.mainboard.u4, parser::dmg_reg());
-            let crystal = map_part(year_hint, &console.mainboard.x1, parser::mgb_crystal());
+            let years = YearResolver::new(Console::Mgb.production_window())
+                .with_hint(console.mainboard.year);
+            let cpu = problems.ok(map_part(
+                years,
+                &console.mainboard.u1,
+                parser::mgb_soc_qfp_80(),
+            ));
+            let work_ram = problems.ok(map_part(
+                years,
+                &console.mainboard.u2,
+                parser::sram_sop_28_5v(),
+            ));
+            let amplifier = problems.ok(map_part(years, &console.mainboard.u3, parser::mgb_amp()));
+            let regulator = problems.ok(map_part(years, &console.mainboard.u4, parser::dmg_reg()));
+            let crystal = problems.ok(map_part(
+                years,
+                &console.mainboard.x1,
+                parser::mgb_crystal(),
+            ));
             let mainboard = LegacyMgbMainboard {
                 kind: console.mainboard.label.clone(),
                 circled_letters: Some(&console.mainboard.circled_letters)
@@ -686,15 +762,11 @@ fn read_mgb_submissions() -> Result<Vec<LegacyMgbSubmission>, Error> {
                 regulator,
                 crystal,
             };
-            let lcd_panel = to_legacy_lcd_panel(year_hint, &console.screen);
+            let lcd_panel = problems.ok(to_legacy_lcd_panel(years, &console.screen));
 
             let stamp = Some(&console.mainboard.stamp)
                 .filter(|stamp| !stamp.is_empty())
-                .map(|stamp| {
-                    gbhwdb_model::parser::dmg_stamp()
-                        .parse(stamp)
-                        .unwrap_or_else(|_| panic!("{}", stamp))
-                });
+                .and_then(|stamp| problems.parsed(gbhwdb_model::parser::dmg_stamp().parse(stamp)));
 
             let metadata = LegacyMgbMetadata {
                 color: console.shell.color.map(|c| format!("{:?}", c)),
@@ -703,7 +775,7 @@ fn read_mgb_submissions() -> Result<Vec<LegacyMgbSubmission>, Error> {
                     .cloned(),
                 year: stamp
                     .as_ref()
-                    .and_then(|stamp| to_full_year(year_hint, stamp.year)),
+                    .and_then(|stamp| to_full_year(years, stamp.year)),
                 month: stamp.as_ref().and_then(|stamp| stamp.month),
                 mainboard,
                 lcd_panel,
@@ -727,6 +799,7 @@ fn read_mgb_submissions() -> Result<Vec<LegacyMgbSubmission>, Error> {
         }
     }
     submissions.sort_by_key(|submission| (submission.sort_group.clone(), submission.slug.clone()));
+    problems.finish("MGB")?;
     Ok(submissions)
 }
 
@@ -736,12 +809,14 @@ fn read_mgl_submissions() -> Result<Vec<LegacyMglSubmission>, Error> {
     use process::to_full_year;
     let walker = WalkDir::new("data/consoles/MGL").min_depth(2).max_depth(2);
     let mut submissions = Vec::new();
+    let mut problems = Problems::default();
     for entry in walker.into_iter().filter_entry(is_metadata_file) {
         let entry = entry?;
         if let Some(root) = entry.path().parent() {
             debug!("{}", entry.path().display());
             let file = File::open(entry.path())?;
             let console: MglConsole = serde_json::from_reader(file)?;
+            problems.start(&console.slug);
             assert_eq!(
                 Some(console.slug.as_str()),
                 root.file_name().and_then(|name| name.to_str())
@@ -750,13 +825,30 @@ fn read_mgl_submissions() -> Result<Vec<LegacyMglSubmission>, Error> {
                 assert_eq!(console.slug, console.shell.serial);
             }
 
-            let year_hint = console.mainboard.year;
-            let cpu = map_part(year_hint, &console.mainboard.u1, parser::mgb_soc_qfp_80());
-            let work_ram = map_part(year_hint, &console.mainboard.u2, parser::sram_sop_28_5v());
-            let amplifier = map_part(year_hint, &console.mainboard.u3, parser::mgb_amp());
-            let regulator = map_part(year_hint, &console.mainboard.u4, parser::dmg_reg());
-            let crystal = map_part(year_hint, &console.mainboard.x1, parser::mgb_crystal());
-            let t1 = map_part(year_hint, &console.mainboard.t1, parser::mgl_transformer());
+            let years = YearResolver::new(Console::Mgl.production_window())
+                .with_hint(console.mainboard.year);
+            let cpu = problems.ok(map_part(
+                years,
+                &console.mainboard.u1,
+                parser::mgb_soc_qfp_80(),
+            ));
+            let work_ram = problems.ok(map_part(
+                years,
+                &console.mainboard.u2,
+                parser::sram_sop_28_5v(),
+            ));
+            let amplifier = problems.ok(map_part(years, &console.mainboard.u3, parser::mgb_amp()));
+            let regulator = problems.ok(map_part(years, &console.mainboard.u4, parser::dmg_reg()));
+            let crystal = problems.ok(map_part(
+                years,
+                &console.mainboard.x1,
+                parser::mgb_crystal(),
+            ));
+            let t1 = problems.ok(map_part(
+                years,
+                &console.mainboard.t1,
+                parser::mgl_transformer(),
+            ));
             let mainboard = LegacyMglMainboard {
                 kind: console.mainboard.label.clone(),
                 circled_letters: Some(&console.mainboard.circled_letters)
@@ -778,15 +870,11 @@ fn read_mgl_submissions() -> Result<Vec<LegacyMglSubmission>, Error> {
                 crystal,
                 t1,
             };
-            let lcd_panel = to_legacy_lcd_panel(year_hint, &console.screen);
+            let lcd_panel = problems.ok(to_legacy_lcd_panel(years, &console.screen));
 
             let stamp = Some(&console.mainboard.stamp)
                 .filter(|stamp| !stamp.is_empty())
-                .map(|stamp| {
-                    gbhwdb_model::parser::cgb_stamp()
-                        .parse(stamp)
-                        .unwrap_or_else(|_| panic!("{}", stamp))
-                });
+                .and_then(|stamp| problems.parsed(gbhwdb_model::parser::cgb_stamp().parse(stamp)));
 
             let metadata = LegacyMglMetadata {
                 color: console.shell.color.map(|c| format!("{:?}", c)),
@@ -795,7 +883,7 @@ fn read_mgl_submissions() -> Result<Vec<LegacyMglSubmission>, Error> {
                     .cloned(),
                 year: stamp
                     .as_ref()
-                    .and_then(|stamp| to_full_year(year_hint, stamp.year)),
+                    .and_then(|stamp| to_full_year(years, stamp.year)),
                 week: stamp.as_ref().and_then(|stamp| stamp.week),
                 mainboard,
                 lcd_panel,
@@ -819,6 +907,7 @@ fn read_mgl_submissions() -> Result<Vec<LegacyMglSubmission>, Error> {
         }
     }
     submissions.sort_by_key(|submission| (submission.sort_group.clone(), submission.slug.clone()));
+    problems.finish("MGL")?;
     Ok(submissions)
 }
 
@@ -827,25 +916,44 @@ fn read_sgb2_submissions() -> Result<Vec<LegacySgb2Submission>, Error> {
     use legacy::console::*;
     let walker = WalkDir::new("data/consoles/SGB2").min_depth(2).max_depth(2);
     let mut submissions = Vec::new();
+    let mut problems = Problems::default();
     for entry in walker.into_iter().filter_entry(is_metadata_file) {
         let entry = entry?;
         if let Some(root) = entry.path().parent() {
             debug!("{}", entry.path().display());
             let file = File::open(entry.path())?;
             let console: Sgb2Console = serde_json::from_reader(file)?;
+            problems.start(&console.slug);
             assert_eq!(
                 Some(console.slug.as_str()),
                 root.file_name().and_then(|name| name.to_str())
             );
 
-            let year_hint = console.mainboard.year;
-            let cpu = map_part(year_hint, &console.mainboard.u1, parser::sgb2_soc_qfp_80());
-            let icd2 = map_part(year_hint, &console.mainboard.u2, parser::icd2());
-            let work_ram = map_part(year_hint, &console.mainboard.u3, parser::sram_sop_28_5v());
-            let rom = map_part(year_hint, &console.mainboard.u4, parser::sgb2_rom());
-            let cic = map_part(year_hint, &console.mainboard.u5, parser::cic());
-            let coil = map_part(year_hint, &console.mainboard.coil1, parser::sgb2_coil());
-            let crystal = map_part(year_hint, &console.mainboard.xtal1, parser::sgb2_crystal());
+            let years = YearResolver::new(Console::Sgb2.production_window())
+                .with_hint(console.mainboard.year);
+            let cpu = problems.ok(map_part(
+                years,
+                &console.mainboard.u1,
+                parser::sgb2_soc_qfp_80(),
+            ));
+            let icd2 = problems.ok(map_part(years, &console.mainboard.u2, parser::icd2()));
+            let work_ram = problems.ok(map_part(
+                years,
+                &console.mainboard.u3,
+                parser::sram_sop_28_5v(),
+            ));
+            let rom = problems.ok(map_part(years, &console.mainboard.u4, parser::sgb2_rom()));
+            let cic = problems.ok(map_part(years, &console.mainboard.u5, parser::cic()));
+            let coil = problems.ok(map_part(
+                years,
+                &console.mainboard.coil1,
+                parser::sgb2_coil(),
+            ));
+            let crystal = problems.ok(map_part(
+                years,
+                &console.mainboard.xtal1,
+                parser::sgb2_crystal(),
+            ));
             let mainboard = LegacySgb2Mainboard {
                 kind: console.mainboard.label.clone(),
                 circled_letters: Some(&console.mainboard.circled_letters)
@@ -890,6 +998,7 @@ fn read_sgb2_submissions() -> Result<Vec<LegacySgb2Submission>, Error> {
         }
     }
     submissions.sort_by_key(|submission| (submission.sort_group.clone(), submission.slug.clone()));
+    problems.finish("SGB2")?;
     Ok(submissions)
 }
 
@@ -899,12 +1008,14 @@ fn read_cgb_submissions() -> Result<Vec<LegacyCgbSubmission>, Error> {
     use process::to_full_year;
     let walker = WalkDir::new("data/consoles/CGB").min_depth(2).max_depth(2);
     let mut submissions = Vec::new();
+    let mut problems = Problems::default();
     for entry in walker.into_iter().filter_entry(is_metadata_file) {
         let entry = entry?;
         if let Some(root) = entry.path().parent() {
             debug!("{}", entry.path().display());
             let file = File::open(entry.path())?;
             let console: CgbConsole = serde_json::from_reader(file)?;
+            problems.start(&console.slug);
             assert_eq!(
                 Some(console.slug.as_str()),
                 root.file_name().and_then(|name| name.to_str())
@@ -913,27 +1024,32 @@ fn read_cgb_submissions() -> Result<Vec<LegacyCgbSubmission>, Error> {
                 assert_eq!(console.slug, console.shell.serial);
             }
 
-            let year_hint = console.mainboard.year.or(Some(1998));
+            let years = YearResolver::new(Console::Cgb.production_window())
+                .with_hint(console.mainboard.year);
             let cpu = match console.mainboard.label.as_str() {
-                "CGB-CPU-06" => map_part(
-                    year_hint,
+                "CGB-CPU-06" => problems.ok(map_part(
+                    years,
                     &console.mainboard.u1,
                     parser::cgb_soc_qfp_128_new(),
-                ),
-                _ => map_part(
-                    year_hint,
+                )),
+                _ => problems.ok(map_part(
+                    years,
                     &console.mainboard.u1,
                     parser::cgb_soc_qfp_128_old(),
-                ),
+                )),
             };
-            let work_ram = map_part(
-                year_hint,
+            let work_ram = problems.ok(map_part(
+                years,
                 &console.mainboard.u2,
                 parser::sram_tsop_i_28_3v3(),
-            );
-            let amplifier = map_part(year_hint, &console.mainboard.u3, parser::mgb_amp());
-            let regulator = map_part(year_hint, &console.mainboard.u4, parser::cgb_reg());
-            let crystal = map_part(year_hint, &console.mainboard.x1, parser::cgb_crystal());
+            ));
+            let amplifier = problems.ok(map_part(years, &console.mainboard.u3, parser::mgb_amp()));
+            let regulator = problems.ok(map_part(years, &console.mainboard.u4, parser::cgb_reg()));
+            let crystal = problems.ok(map_part(
+                years,
+                &console.mainboard.x1,
+                parser::cgb_crystal(),
+            ));
             let mainboard = LegacyCgbMainboard {
                 kind: console.mainboard.label.clone(),
                 circled_letters: Some(&console.mainboard.circled_letters)
@@ -961,21 +1077,15 @@ fn read_cgb_submissions() -> Result<Vec<LegacyCgbSubmission>, Error> {
                 .starts_with(&['6', '7', '8', '9'][..])
             {
                 (
-                    Some(
-                        gbhwdb_model::parser::dmg_stamp()
-                            .parse(&console.mainboard.stamp)
-                            .unwrap_or_else(|_| panic!("{}", console.mainboard.stamp)),
-                    ),
+                    problems
+                        .parsed(gbhwdb_model::parser::dmg_stamp().parse(&console.mainboard.stamp)),
                     None,
                 )
             } else if !console.mainboard.stamp.is_empty() {
                 (
                     None,
-                    Some(
-                        gbhwdb_model::parser::cgb_stamp()
-                            .parse(&console.mainboard.stamp)
-                            .unwrap_or_else(|_| panic!("{}", console.mainboard.stamp)),
-                    ),
+                    problems
+                        .parsed(gbhwdb_model::parser::cgb_stamp().parse(&console.mainboard.stamp)),
                 )
             } else {
                 (None, None)
@@ -990,7 +1100,7 @@ fn read_cgb_submissions() -> Result<Vec<LegacyCgbSubmission>, Error> {
                 release_code: Some(&console.shell.release_code)
                     .filter(|code| !code.is_empty())
                     .cloned(),
-                year: to_full_year(year_hint, stamp_year),
+                year: to_full_year(years, stamp_year),
                 month: old_stamp.as_ref().and_then(|stamp| stamp.month),
                 week: new_stamp.as_ref().and_then(|stamp| stamp.week),
                 mainboard,
@@ -1014,6 +1124,7 @@ fn read_cgb_submissions() -> Result<Vec<LegacyCgbSubmission>, Error> {
         }
     }
     submissions.sort_by_key(|submission| (submission.sort_group.clone(), submission.slug.clone()));
+    problems.finish("CGB")?;
     Ok(submissions)
 }
 
@@ -1023,12 +1134,14 @@ fn read_agb_submissions() -> Result<Vec<LegacyAgbSubmission>, Error> {
     use process::to_full_year;
     let walker = WalkDir::new("data/consoles/AGB").min_depth(2).max_depth(2);
     let mut submissions = Vec::new();
+    let mut problems = Problems::default();
     for entry in walker.into_iter().filter_entry(is_metadata_file) {
         let entry = entry?;
         if let Some(root) = entry.path().parent() {
             debug!("{}", entry.path().display());
             let file = File::open(entry.path())?;
             let console: AgbConsole = serde_json::from_reader(file)?;
+            problems.start(&console.slug);
             assert_eq!(
                 Some(console.slug.as_str()),
                 root.file_name().and_then(|name| name.to_str())
@@ -1037,13 +1150,26 @@ fn read_agb_submissions() -> Result<Vec<LegacyAgbSubmission>, Error> {
                 assert_eq!(console.slug, console.shell.serial);
             }
 
-            let year_hint = console.mainboard.year.or(Some(2001));
-            let cpu = map_part(year_hint, &console.mainboard.u1, parser::agb_soc_qfp_128());
-            let work_ram = map_part(year_hint, &console.mainboard.u2, parser::sram_tsop_i_48());
-            let regulator = map_part(year_hint, &console.mainboard.u3, parser::agb_reg());
-            let u4 = map_part(year_hint, &console.mainboard.u4, parser::agb_pmic());
-            let amplifier = map_part(year_hint, &console.mainboard.u6, parser::agb_amp());
-            let crystal = map_part(year_hint, &console.mainboard.x1, parser::agb_crystal());
+            let years = YearResolver::new(Console::Agb.production_window())
+                .with_hint(console.mainboard.year);
+            let cpu = problems.ok(map_part(
+                years,
+                &console.mainboard.u1,
+                parser::agb_soc_qfp_128(),
+            ));
+            let work_ram = problems.ok(map_part(
+                years,
+                &console.mainboard.u2,
+                parser::sram_tsop_i_48(),
+            ));
+            let regulator = problems.ok(map_part(years, &console.mainboard.u3, parser::agb_reg()));
+            let u4 = problems.ok(map_part(years, &console.mainboard.u4, parser::agb_pmic()));
+            let amplifier = problems.ok(map_part(years, &console.mainboard.u6, parser::agb_amp()));
+            let crystal = problems.ok(map_part(
+                years,
+                &console.mainboard.x1,
+                parser::agb_crystal(),
+            ));
             let mainboard = LegacyAgbMainboard {
                 kind: console.mainboard.label.clone(),
                 circled_letters: Some(&console.mainboard.circled_letters)
@@ -1067,11 +1193,7 @@ fn read_agb_submissions() -> Result<Vec<LegacyAgbSubmission>, Error> {
 
             let stamp = Some(&console.mainboard.stamp)
                 .filter(|stamp| !stamp.is_empty())
-                .map(|stamp| {
-                    gbhwdb_model::parser::cgb_stamp()
-                        .parse(stamp)
-                        .unwrap_or_else(|_| panic!("{}", stamp))
-                });
+                .and_then(|stamp| problems.parsed(gbhwdb_model::parser::cgb_stamp().parse(stamp)));
 
             let metadata = LegacyAgbMetadata {
                 color: console.shell.color.map(|c| format!("{:?}", c)),
@@ -1080,7 +1202,7 @@ fn read_agb_submissions() -> Result<Vec<LegacyAgbSubmission>, Error> {
                     .cloned(),
                 year: stamp
                     .as_ref()
-                    .and_then(|stamp| to_full_year(year_hint, stamp.year)),
+                    .and_then(|stamp| to_full_year(years, stamp.year)),
                 week: stamp.as_ref().and_then(|stamp| stamp.week),
                 mainboard,
             };
@@ -1103,6 +1225,7 @@ fn read_agb_submissions() -> Result<Vec<LegacyAgbSubmission>, Error> {
         }
     }
     submissions.sort_by_key(|submission| (submission.sort_group.clone(), submission.slug.clone()));
+    problems.finish("AGB")?;
     Ok(submissions)
 }
 
@@ -1111,12 +1234,14 @@ fn read_ags_submissions() -> Result<Vec<LegacyAgsSubmission>, Error> {
     use legacy::console::*;
     let walker = WalkDir::new("data/consoles/AGS").min_depth(2).max_depth(2);
     let mut submissions = Vec::new();
+    let mut problems = Problems::default();
     for entry in walker.into_iter().filter_entry(is_metadata_file) {
         let entry = entry?;
         if let Some(root) = entry.path().parent() {
             debug!("{}", entry.path().display());
             let file = File::open(entry.path())?;
             let console: AgsConsole = serde_json::from_reader(file)?;
+            problems.start(&console.slug);
             assert_eq!(
                 Some(console.slug.as_str()),
                 root.file_name().and_then(|name| name.to_str())
@@ -1125,19 +1250,42 @@ fn read_ags_submissions() -> Result<Vec<LegacyAgsSubmission>, Error> {
                 assert_eq!(console.slug, console.shell.serial);
             }
 
-            let year_hint = console.mainboard.year.or(Some(2003));
-            let cpu = map_part(year_hint, &console.mainboard.u1, parser::agb_soc_qfp_156());
-            let work_ram = map_part(year_hint, &console.mainboard.u2, parser::sram_tsop_i_48());
+            let years = YearResolver::new(Console::Ags.production_window())
+                .with_hint(console.mainboard.year);
+            let cpu = problems.ok(map_part(
+                years,
+                &console.mainboard.u1,
+                parser::agb_soc_qfp_156(),
+            ));
+            let work_ram = problems.ok(map_part(
+                years,
+                &console.mainboard.u2,
+                parser::sram_tsop_i_48(),
+            ));
             let amplifier = match console.mainboard.label.as_str() {
                 // FIXME: Not really an amplifier
-                "C/AGS-CPU-30" | "C/AGT-CPU-01" => {
-                    map_part(year_hint, &console.mainboard.u3, parser::ags_pmic_new())
-                }
-                _ => map_part(year_hint, &console.mainboard.u3, parser::agb_amp()),
+                "C/AGS-CPU-30" | "C/AGT-CPU-01" => problems.ok(map_part(
+                    years,
+                    &console.mainboard.u3,
+                    parser::ags_pmic_new(),
+                )),
+                _ => problems.ok(map_part(years, &console.mainboard.u3, parser::agb_amp())),
             };
-            let u4 = map_part(year_hint, &console.mainboard.u4, parser::ags_pmic_old());
-            let u5 = map_part(year_hint, &console.mainboard.u5, parser::ags_charge_ctrl());
-            let crystal = map_part(year_hint, &console.mainboard.x1, parser::ags_crystal());
+            let u4 = problems.ok(map_part(
+                years,
+                &console.mainboard.u4,
+                parser::ags_pmic_old(),
+            ));
+            let u5 = problems.ok(map_part(
+                years,
+                &console.mainboard.u5,
+                parser::ags_charge_ctrl(),
+            ));
+            let crystal = problems.ok(map_part(
+                years,
+                &console.mainboard.x1,
+                parser::ags_crystal(),
+            ));
             let mainboard = LegacyAgsMainboard {
                 kind: console.mainboard.label.clone(),
                 circled_letters: Some(&console.mainboard.circled_letters)
@@ -1186,6 +1334,7 @@ fn read_ags_submissions() -> Result<Vec<LegacyAgsSubmission>, Error> {
         }
     }
     submissions.sort_by_key(|submission| (submission.sort_group.clone(), submission.slug.clone()));
+    problems.finish("AGS")?;
     Ok(submissions)
 }
 
@@ -1195,24 +1344,39 @@ fn read_gbs_submissions() -> Result<Vec<LegacyGbsSubmission>, Error> {
     use process::to_full_year;
     let walker = WalkDir::new("data/consoles/GBS").min_depth(2).max_depth(2);
     let mut submissions = Vec::new();
+    let mut problems = Problems::default();
     for entry in walker.into_iter().filter_entry(is_metadata_file) {
         let entry = entry?;
         if let Some(root) = entry.path().parent() {
             debug!("{}", entry.path().display());
             let file = File::open(entry.path())?;
             let console: GbsConsole = serde_json::from_reader(file)?;
+            problems.start(&console.slug);
             assert_eq!(
                 Some(console.slug.as_str()),
                 root.file_name().and_then(|name| name.to_str())
             );
 
-            let year_hint = console.mainboard.year.or(Some(2003));
-            let cpu = map_part(year_hint, &console.mainboard.u2, parser::agb_soc_qfp_128());
-            let work_ram = map_part(year_hint, &console.mainboard.u3, parser::sram_tsop_i_48());
-            let u4 = map_part(year_hint, &console.mainboard.u4, parser::gbs_dol());
-            let u5 = map_part(year_hint, &console.mainboard.u5, parser::gbs_reg());
-            let u6 = map_part(year_hint, &console.mainboard.u6, parser::gbs_reg());
-            let crystal = map_part(year_hint, &console.mainboard.y1, parser::gbs_crystal());
+            let years = YearResolver::new(Console::Gbs.production_window())
+                .with_hint(console.mainboard.year);
+            let cpu = problems.ok(map_part(
+                years,
+                &console.mainboard.u2,
+                parser::agb_soc_qfp_128(),
+            ));
+            let work_ram = problems.ok(map_part(
+                years,
+                &console.mainboard.u3,
+                parser::sram_tsop_i_48(),
+            ));
+            let u4 = problems.ok(map_part(years, &console.mainboard.u4, parser::gbs_dol()));
+            let u5 = problems.ok(map_part(years, &console.mainboard.u5, parser::gbs_reg()));
+            let u6 = problems.ok(map_part(years, &console.mainboard.u6, parser::gbs_reg()));
+            let crystal = problems.ok(map_part(
+                years,
+                &console.mainboard.y1,
+                parser::gbs_crystal(),
+            ));
             let mainboard = LegacyGbsMainboard {
                 kind: console.mainboard.label.clone(),
                 circled_letters: Some(&console.mainboard.circled_letters)
@@ -1242,11 +1406,7 @@ fn read_gbs_submissions() -> Result<Vec<LegacyGbsSubmission>, Error> {
 
             let stamp = Some(&console.mainboard.stamp)
                 .filter(|stamp| !stamp.is_empty())
-                .map(|stamp| {
-                    gbhwdb_model::parser::cgb_stamp()
-                        .parse(stamp)
-                        .unwrap_or_else(|_| panic!("{}", stamp))
-                });
+                .and_then(|stamp| problems.parsed(gbhwdb_model::parser::cgb_stamp().parse(stamp)));
 
             let metadata = LegacyGbsMetadata {
                 color: console.shell.color.map(|c| format!("{:?}", c)),
@@ -1255,7 +1415,7 @@ fn read_gbs_submissions() -> Result<Vec<LegacyGbsSubmission>, Error> {
                     .cloned(),
                 year: stamp
                     .as_ref()
-                    .and_then(|stamp| to_full_year(year_hint, stamp.year)),
+                    .and_then(|stamp| to_full_year(years, stamp.year)),
                 week: stamp.as_ref().and_then(|stamp| stamp.week),
                 mainboard,
             };
@@ -1278,6 +1438,7 @@ fn read_gbs_submissions() -> Result<Vec<LegacyGbsSubmission>, Error> {
         }
     }
     submissions.sort_by_key(|submission| (submission.sort_group.clone(), submission.slug.clone()));
+    problems.finish("GBS")?;
     Ok(submissions)
 }
 
@@ -1286,12 +1447,14 @@ fn read_oxy_submissions() -> Result<Vec<LegacyOxySubmission>, Error> {
     use legacy::console::*;
     let walker = WalkDir::new("data/consoles/OXY").min_depth(2).max_depth(2);
     let mut submissions = Vec::new();
+    let mut problems = Problems::default();
     for entry in walker.into_iter().filter_entry(is_metadata_file) {
         let entry = entry?;
         if let Some(root) = entry.path().parent() {
             debug!("{}", entry.path().display());
             let file = File::open(entry.path())?;
             let console: OxyConsole = serde_json::from_reader(file)?;
+            problems.start(&console.slug);
             assert_eq!(
                 Some(console.slug.as_str()),
                 root.file_name().and_then(|name| name.to_str())
@@ -1300,11 +1463,16 @@ fn read_oxy_submissions() -> Result<Vec<LegacyOxySubmission>, Error> {
                 assert_eq!(console.slug, console.shell.serial);
             }
 
-            let year_hint = console.mainboard.year.or(Some(2005));
-            let cpu = map_part(year_hint, &console.mainboard.u1, parser::agb_soc_bga());
-            let u2 = map_part(year_hint, &console.mainboard.u2, parser::oxy_pmic());
-            let u4 = map_part(year_hint, &console.mainboard.u4, parser::oxy_u4());
-            let u5 = map_part(year_hint, &console.mainboard.u5, parser::oxy_u5());
+            let years = YearResolver::new(Console::Oxy.production_window())
+                .with_hint(console.mainboard.year);
+            let cpu = problems.ok(map_part(
+                years,
+                &console.mainboard.u1,
+                parser::agb_soc_bga(),
+            ));
+            let u2 = problems.ok(map_part(years, &console.mainboard.u2, parser::oxy_pmic()));
+            let u4 = problems.ok(map_part(years, &console.mainboard.u4, parser::oxy_u4()));
+            let u5 = problems.ok(map_part(years, &console.mainboard.u5, parser::oxy_u5()));
             let mainboard = LegacyOxyMainboard {
                 kind: console.mainboard.label.clone(),
                 circled_letters: Some(&console.mainboard.circled_letters)
@@ -1344,6 +1512,7 @@ fn read_oxy_submissions() -> Result<Vec<LegacyOxySubmission>, Error> {
         }
     }
     submissions.sort_by_key(|submission| (submission.sort_group.clone(), submission.slug.clone()));
+    problems.finish("OXY")?;
     Ok(submissions)
 }
 
@@ -0,0 +1,48 @@
+// SPDX-FileCopyrightText: Joonas Javanainen <joonas.javanainen@gmail.com>
+//
+// SPDX-License-Identifier: MIT
+
+use anyhow::{Error, bail};
+use gbhwdb_model::parser::ParseFailure;
+use itertools::Itertools;
+use std::fmt;
+
+/// Problems found in submissions, such as labels that no parser accepts.
+///
+/// Problems are reported together once all submissions of a kind have been read, instead of
+/// stopping at the first one.
+#[derive(Clone, Debug, Default)]
+pub struct Problems {
+    submission: String,
+    problems: Vec<String>,
+}
+
+impl Problems {
+    /// Attributes the following problems to the given submission
+    pub fn start(&mut self, submission: &str) {
+        submission.clone_into(&mut self.submission);
+    }
+    pub fn push(&mut self, problem: impl fmt::Display) {
+        self.problems
+            .push(format!("{}: {problem}", self.submission));
+    }
+    /// Records a failed parse, and returns None in its place
+    pub fn ok<T>(&mut self, result: Result<Option<T>, ParseFailure>) -> Option<T> {
+        self.parsed(result).flatten()
+    }
+    /// Records a failed parse, and returns None in its place
+    pub fn parsed<T>(&mut self, result: Result<T, ParseFailure>) -> Option<T> {
+        result.map_err(|failure| self.push(failure)).ok()
+    }
+    /// Fails if any problems were found in the submissions of the given kind
+    pub fn finish(self, kind: &str) -> Result<(), Error> {
+        if !self.problems.is_empty() {
+            bail!(
+                "{count} problems in {kind} submissions:\n  {problems}",
+                count = self.problems.len(),
+                problems = self.problems.iter().join("\n  ")
+            );
+        }
+        Ok(())
+    }
+}
@@ -4,10 +4,12 @@
 
 use gbhwdb_model::{
     parser::Year,
-    time::{Jun, Month, Week},
+    time::{Jun, Month, Week, YearConfidence, YearResolver},
 };
+use log::warn;
 
 pub mod part;
+pub mod rom_index;
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Default)]
 pub struct DateCode {
@@ -25,20 +27,16 @@ impl DateCode {
             ..DateCode::default()
         }
     }
-    pub fn loose_year_month(
-        year_hint: Option<u16>,
-        year: Option<Year>,
-        month: Option<Month>,
-    ) -> Self {
+    pub fn loose_year_month(years: YearResolver, year: Option<Year>, month: Option<Month>) -> Self {
         DateCode {
-            year: to_full_year(year_hint, year),
+            year: to_full_year(years, year),
             month,
             ..DateCode::default()
         }
     }
-    pub fn loose_year_week(year_hint: Option<u16>, year: Option<Year>, week: Option<Week>) -> Self {
+    pub fn loose_year_week(years: YearResolver, year: Option<Year>, week: Option<Week>) -> Self {
         DateCode {
-            year: to_full_year(year_hint, year),
+            year: to_full_year(years, year),
             week,
             ..DateCode::default()
         }
@@ -84,36 +82,37 @@ impl From<(Option<u16>, Option<Month>)> for DateCode {
     }
 }
 
-pub fn to_full_year(year_hint: Option<u16>, part_year: Option<Year>) -> Option<u16> {
-    (match (year_hint, part_year) {
-        (_, Some(Year::Full(year))) => Some(year),
-        (Some(year_hint), Some(Year::Partial(year))) => Some(guess_full_year(year_hint, year)),
-        _ => None,
-    })
-    .map(|year| {
-        assert!(
-            (1988..2010).contains(&year),
-            "suspicious year {year} calculated from {year_hint:?}:{part_year:?}"
+/// Resolves a partial year, or returns None if it doesn't fit the production window
+pub fn to_full_year(years: YearResolver, part_year: Option<Year>) -> Option<u16> {
+    let resolved = years.resolve(part_year?)?;
+    if resolved.confidence != YearConfidence::Exact && !years.window.contains(resolved.year) {
+        warn!(
+            "year calculated from {part_year:?} is outside {window}, ignoring {year}",
+            year = resolved.year,
+            window = years.window
         );
-        year
-    })
-}
-
-pub fn guess_full_year(hint: u16, partial_year: u8) -> u16 {
-    let partial_year = u16::from(partial_year);
-    let decades = [1980, 1990, 2000];
-    decades
-        .into_iter()
-        .map(|decade: u16| decade + partial_year)
-        .min_by_key(|&year| hint.abs_diff(year))
-        .unwrap_or(0)
+        return None;
+    }
+    let alternatives = resolved
+        .candidates
+        .iter()
+        .filter(|&&year| years.window.contains(year))
+        .collect::<Vec<_>>();
+    if resolved.confidence == YearConfidence::Low && !alternatives.is_empty() {
+        warn!(
+            "year {year} calculated from {part_year:?} is ambiguous, because {alternatives:?} also fall within {window}",
+            year = resolved.year,
+            window = years.window
+        );
+    }
+    Some(resolved.year)
 }
 
 #[test]
-fn test_guess_full_year() {
-    assert_eq!(1992, guess_full_year(1992, 2));
-    assert_eq!(1989, guess_full_year(1989, 9));
-    assert_eq!(1990, guess_full_year(1990, 0));
-    assert_eq!(1999, guess_full_year(1998, 9));
-    assert_eq!(2000, guess_full_year(2005, 0));
+fn test_to_full_year() {
+    use gbhwdb_model::time::ProductionWindow;
+    let years = YearResolver::new(ProductionWindow::new(1989, 1998)).with_hint(Some(1990));
+    assert_eq!(to_full_year(years, Some(Year::Partial(2))), Some(1992));
+    assert_eq!(to_full_year(years, Some(Year::Partial(8))), None);
+    assert_eq!(to_full_year(years, Some(Year::Full(1988))), Some(1988));
 }
@@ -2,10 +2,13 @@
 //
 // SPDX-License-Identifier: MIT
 
-use anyhow::{Error, anyhow};
 use gbhwdb_model::{
     input::Part,
-    parser::{self, LabelParser, Manufacturer, PartDateCode},
+    parser::{
+        self, Country, DecodedMaskCode, LabelParser, Manufacturer, MaskCode, NormalizedLabel,
+        ParseFailure, PartDateCode,
+    },
+    time::YearResolver,
 };
 
 use crate::process::DateCode;
@@ -14,128 +17,153 @@ use crate::process::DateCode;
 pub struct ProcessedPart {
     pub kind: Option<String>,
     pub label: Option<String>,
+    /// Canonical form of the label, if it differs from the original
+    pub canonical_label: Option<String>,
     pub manufacturer: Option<Manufacturer>,
     pub date_code: DateCode,
     pub rom_id: Option<String>,
+    pub lot_code: Option<String>,
+    pub revision: Option<String>,
+    pub origin_country: Option<Country>,
+    pub mask_code: Option<MaskCode>,
+    pub decoded_mask_code: Option<DecodedMaskCode>,
 }
 
 pub trait ParsedPart {
-    fn process(self, year_hint: Option<u16>, label: String) -> ProcessedPart;
+    fn process(self, years: YearResolver, label: String) -> ProcessedPart;
 }
 
+/// Parses and processes a submission part.
+///
+/// Returns None for an unknown part, and an empty part if the label is empty.
 pub fn map_part<T: ParsedPart, F: LabelParser<T>>(
-    year_hint: Option<u16>,
+    years: YearResolver,
     part: &Part,
     f: &F,
-) -> Option<ProcessedPart> {
+) -> Result<Option<ProcessedPart>, ParseFailure> {
     if part.is_unknown() {
-        None
-    } else {
-        Some(
-            boxed_parser(f)(year_hint, part)
-                .unwrap()
-                .unwrap_or_default(),
-        )
+        return Ok(None);
     }
+    let Some(label) = Some(&part.label).filter(|label| !label.is_empty()) else {
+        return Ok(Some(ProcessedPart::default()));
+    };
+    let (normalized, chip) = f.parse_normalized(label)?;
+    let mut part = chip.process(years, label.clone());
+    part.canonical_label = canonical_label(normalized);
+    Ok(Some(part))
 }
 
-pub type BoxedParser<'a> =
-    Box<dyn Fn(Option<u16>, &Part) -> Result<Option<ProcessedPart>, Error> + 'a>;
-
-pub fn boxed_parser<T: ParsedPart, F: LabelParser<T>>(f: &F) -> BoxedParser<'_> {
-    Box::new(|year_hint, part| {
-        Some(&part.label)
-            .filter(|label| !label.is_empty())
-            .map(|label| {
-                let part = f
-                    .parse(label)
-                    .map_err(|label| anyhow!("Failed to parse {label}"))?;
-                Ok(part.process(year_hint, label.clone()))
-            })
-            .transpose()
-    })
+pub fn canonical_label(label: NormalizedLabel) -> Option<String> {
+    (!label.applied.is_empty()).then_some(label.canonical)
 }
 
 impl ParsedPart for parser::UnknownChip {
-    fn process(self, _: Option<u16>, label: String) -> ProcessedPart {
+    fn process(self, _: YearResolver, label: String) -> ProcessedPart {
         ProcessedPart {
             kind: None,
             label: Some(label),
-            manufacturer: None,
+            canonical_label: None,
+            manufacturer: self.manufacturer,
             date_code: DateCode::default(),
             rom_id: None,
+            lot_code: None,
+            revision: None,
+            origin_country: None,
+            mask_code: None,
+            decoded_mask_code: None,
         }
     }
 }
 
 impl ParsedPart for parser::Crystal {
-    fn process(self, year_hint: Option<u16>, label: String) -> ProcessedPart {
+    fn process(self, years: YearResolver, label: String) -> ProcessedPart {
         ProcessedPart {
             label: Some(label),
             kind: Some(self.format_frequency()),
             manufacturer: self.manufacturer,
-            date_code: loose_datecode(year_hint, self.date_code),
+            date_code: loose_datecode(years, self.date_code),
+            lot_code: self.lot_code,
+            revision: self.revision,
+            origin_country: self.origin_country,
             ..ProcessedPart::default()
         }
     }
 }
 
-pub fn loose_datecode(year_hint: Option<u16>, date_code: Option<PartDateCode>) -> DateCode {
+pub fn loose_datecode(years: YearResolver, date_code: Option<PartDateCode>) -> DateCode {
     match date_code {
-        Some(PartDateCode::Year { year }) => DateCode::loose_year_week(year_hint, Some(year), None),
-        Some(PartDateCode::YearMonth { year, month }) => {
-            DateCode::loose_year_month(year_hint, Some(year), Some(month))
-        }
+        Some(PartDateCode::Year { year }) => DateCode::loose_year_week(years, Some(year), None),
+        Some(
+            PartDateCode::YearMonth { year, month }
+            | PartDateCode::YearMonthDay { year, month, .. },
+        ) => DateCode::loose_year_month(years, Some(year), Some(month)),
+        Some(PartDateCode::YearMonthJun { year, month, jun }) => DateCode {
+            jun: Some(jun),
+            ..DateCode::loose_year_month(years, Some(year), Some(month))
+        },
         Some(PartDateCode::YearWeek { year, week }) => {
-            DateCode::loose_year_week(year_hint, Some(year), Some(week))
+            DateCode::loose_year_week(years, Some(year), Some(week))
         }
-        None => DateCode::loose_year_week(year_hint, None, None),
+        None => DateCode::loose_year_week(years, None, None),
     }
 }
 
 impl ParsedPart for parser::GenericPart {
-    fn process(self, year_hint: Option<u16>, label: String) -> ProcessedPart {
+    fn process(self, years: YearResolver, label: String) -> ProcessedPart {
         ProcessedPart {
             label: Some(label),
             kind: Some(self.kind),
             manufacturer: self.manufacturer,
-            date_code: loose_datecode(year_hint, self.date_code),
+            date_code: loose_datecode(years, self.date_code),
+            lot_code: self.lot_code,
+            revision: self.revision,
+            origin_country: self.origin_country,
             ..ProcessedPart::default()
         }
     }
 }
 
 impl ParsedPart for parser::GameMaskRom {
-    fn process(self, year_hint: Option<u16>, label: String) -> ProcessedPart {
+    fn process(self, years: YearResolver, label: String) -> ProcessedPart {
         ProcessedPart {
             label: Some(label),
+            decoded_mask_code: self.decode_mask_code(),
             kind: self.chip_type,
             manufacturer: self.manufacturer,
-            date_code: loose_datecode(year_hint, self.date_code),
+            date_code: loose_datecode(years, self.date_code),
+            lot_code: self.lot_code,
+            revision: self.revision,
+            origin_country: self.origin_country,
+            mask_code: self.mask_code,
             ..ProcessedPart::default()
         }
     }
 }
 
 impl ParsedPart for parser::MaskRom {
-    fn process(self, year_hint: Option<u16>, label: String) -> ProcessedPart {
+    fn process(self, years: YearResolver, label: String) -> ProcessedPart {
         ProcessedPart {
             label: Some(label),
             kind: self.chip_type,
             manufacturer: self.manufacturer,
-            date_code: loose_datecode(year_hint, self.date_code),
+            date_code: loose_datecode(years, self.date_code),
+            lot_code: self.lot_code,
+            mask_code: self.mask_code,
             ..ProcessedPart::default()
         }
     }
 }
 
 impl ParsedPart for parser::Mapper {
-    fn process(self, year_hint: Option<u16>, label: String) -> ProcessedPart {
+    fn process(self, years: YearResolver, label: String) -> ProcessedPart {
         ProcessedPart {
             label: Some(label),
             kind: Some(self.kind.display_name().to_owned()),
             manufacturer: self.manufacturer,
-            date_code: loose_datecode(year_hint, self.date_code),
+            date_code: loose_datecode(years, self.date_code),
+            lot_code: self.lot_code,
+            revision: self.revision,
+            origin_country: self.origin_country,
             ..ProcessedPart::default()
         }
     }
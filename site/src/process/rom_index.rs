@@ -0,0 +1,98 @@
+// SPDX-FileCopyrightText: Joonas Javanainen <joonas.javanainen@gmail.com>
+//
+// SPDX-License-Identifier: MIT
+
+use gbhwdb_model::parser::{DecodedMaskCode, MaskCode};
+use itertools::Itertools;
+use std::{collections::BTreeMap, fmt};
+
+use crate::legacy::LegacyCartridgeSubmission;
+
+/// Identity of the data stored in a cartridge ROM
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum RomKey {
+    RomId(String),
+    Mask(DecodedMaskCode),
+    /// Mask code that can't be decoded, but still identifies the mask as a whole
+    MaskCode(MaskCode),
+}
+
+impl fmt::Display for RomKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RomKey::RomId(rom_id) => f.write_str(rom_id),
+            RomKey::Mask(mask) => write!(f, "{} {mask}", mask.manufacturer.name()),
+            RomKey::MaskCode(code) => {
+                write!(f, "{} {}", code.manufacturer().name(), code.as_str())
+            }
+        }
+    }
+}
+
+impl RomKey {
+    pub fn of(submission: &LegacyCartridgeSubmission) -> impl Iterator<Item = RomKey> + '_ {
+        let masks = submission.metadata.board.parts.values().filter_map(|part| {
+            match (&part.decoded_mask_code, &part.mask_code) {
+                (Some(mask), _) => Some(RomKey::Mask(mask.clone())),
+                (None, Some(code)) => Some(RomKey::MaskCode(code.clone())),
+                (None, None) => None,
+            }
+        });
+        [RomKey::RomId(submission.metadata.cfg.rom_id.clone())]
+            .into_iter()
+            .chain(masks)
+    }
+}
+
+/// Cartridge submissions grouped by ROM identity.
+///
+/// Only groups that span more than one board type or release are kept, since those are the
+/// ones that tell something a single game page doesn't.
+#[derive(Clone, Debug, Default)]
+pub struct RomIndex<'a> {
+    groups: BTreeMap<RomKey, Vec<&'a LegacyCartridgeSubmission>>,
+}
+
+impl<'a> RomIndex<'a> {
+    pub fn new(submissions: &'a [LegacyCartridgeSubmission]) -> Self {
+        let mut groups = BTreeMap::<_, Vec<_>>::new();
+        for submission in submissions {
+            for key in RomKey::of(submission).unique() {
+                groups.entry(key).or_default().push(submission);
+            }
+        }
+        groups.retain(|_, group| {
+            let boards = group.iter().map(|s| &s.metadata.board.kind).unique();
+            let releases = group.iter().map(|s| &s.metadata.code).unique();
+            boards.count() > 1 || releases.count() > 1
+        });
+        RomIndex { groups }
+    }
+    /// Returns the groups that any of the given submissions belongs to.
+    ///
+    /// A group with exactly the same submissions as an earlier one is left out.
+    pub fn shared_with(
+        &self,
+        submissions: &[&LegacyCartridgeSubmission],
+    ) -> Vec<(&RomKey, &[&'a LegacyCartridgeSubmission])> {
+        let mut result: Vec<(&RomKey, &[&'a LegacyCartridgeSubmission])> = Vec::new();
+        for key in submissions
+            .iter()
+            .flat_map(|&submission| RomKey::of(submission))
+            .unique()
+            .sorted()
+        {
+            let Some((key, group)) = self.groups.get_key_value(&key) else {
+                continue;
+            };
+            let is_duplicate = result.iter().any(|(_, other)| {
+                other.len() == group.len()
+                    && other.iter().zip(group).all(|(a, b)| std::ptr::eq(*a, *b))
+            });
+            if !is_duplicate {
+                result.push((key, group));
+            }
+        }
+        result
+    }
+}
@@ -2,10 +2,11 @@
 //
 // SPDX-License-Identifier: MIT
 
-use crate::template::cartridge_board_page::CartridgeBoardPage;
+use crate::template::cartridge_board_page::{BoardRevision, CartridgeBoardPage};
 use crate::{
     SiteData,
     legacy::{LegacySubmission, cartridge::LegacyBoard},
+    process::rom_index::RomIndex,
     template::{
         cartridge_page::CartridgePage,
         cartridges::Cartridges,
@@ -17,17 +18,20 @@ use crate::{
         game_page::GamePage,
         game_platform_page::GamePlatformPage,
         home::Home,
+        manufacturers::Manufacturers,
         mapper_page::{MapperCfg, MapperPage},
         markdown::Markdown,
         markdown_page::MarkdownPage,
         page,
+        supported_chips::SupportedChips,
     },
 };
 use anyhow::Error;
 use gbhwdb_model::config::cartridge::GameConfig;
+use gbhwdb_model::parser::registry;
 use gbhwdb_model::{
     Console,
-    config::cartridge::{BoardConfig, BoardPart, GamePlatform, PartRole},
+    config::cartridge::{BoardPart, GamePlatform, PartRole},
 };
 use itertools::Itertools;
 use lexical_sort::natural_lexical_cmp;
@@ -105,6 +109,23 @@ pub fn build_site() -> Site {
         SiteSection::Consoles(None),
         "site/content/contribute-cartridges.markdown",
     );
+    site.add_page(["contribute", "chips"], |_| {
+        Ok(Page {
+            title: Cow::Borrowed("Supported chips"),
+            section: SiteSection::Consoles(None),
+            content: SupportedChips {
+                families: registry::families(),
+            }
+            .render(),
+        })
+    });
+    site.add_page(["contribute", "manufacturers"], |_| {
+        Ok(Page {
+            title: Cow::Borrowed("Chip manufacturers"),
+            section: SiteSection::Consoles(None),
+            content: Manufacturers.render(),
+        })
+    });
     for console in Console::ALL {
         site.add_page(["consoles", console.id(), "index"], move |data| {
             let data = &data.submissions;
@@ -216,7 +237,7 @@ pub fn build_site() -> Site {
                 id: "no-mapper",
                 name: "No mapper",
                 parts: &[PartRole::Rom],
-                match_fn: Box::new(|cfg, _| cfg == BoardConfig::Aaac || cfg == BoardConfig::DmgAaa),
+                match_fn: Box::new(|cfg, _| matches!(cfg.label(), "AAAC" | "DMG-AAA")),
             },
             MapperCfg {
                 id: "mbc1",
@@ -226,6 +247,7 @@ pub fn build_site() -> Site {
                     PartRole::Mapper,
                     PartRole::Ram,
                     PartRole::SupervisorReset,
+                    PartRole::Battery,
                 ],
                 match_fn: Box::new(|_, part| {
                     part.and_then(|part| part.kind.as_ref())
@@ -252,6 +274,7 @@ pub fn build_site() -> Site {
                     PartRole::Ram,
                     PartRole::SupervisorReset,
                     PartRole::Crystal,
+                    PartRole::Battery,
                 ],
                 match_fn: Box::new(|_, part| {
                     part.and_then(|part| part.kind.as_ref())
@@ -268,6 +291,7 @@ pub fn build_site() -> Site {
                     PartRole::Ram,
                     PartRole::SupervisorReset,
                     PartRole::Crystal,
+                    PartRole::Battery,
                 ],
                 match_fn: Box::new(|_, part| {
                     part.and_then(|part| part.kind.as_ref())
@@ -283,6 +307,8 @@ pub fn build_site() -> Site {
                     PartRole::Mapper,
                     PartRole::Ram,
                     PartRole::SupervisorReset,
+                    PartRole::Battery,
+                    PartRole::Motor,
                 ],
                 match_fn: Box::new(|_, part| {
                     part.and_then(|part| part.kind.as_ref())
@@ -329,6 +355,7 @@ pub fn build_site() -> Site {
                     PartRole::Mapper,
                     PartRole::Ram,
                     PartRole::SupervisorReset,
+                    PartRole::Battery,
                 ],
                 match_fn: Box::new(|_, part| {
                     part.and_then(|part| part.kind.as_ref())
@@ -344,6 +371,9 @@ pub fn build_site() -> Site {
                     PartRole::Mapper,
                     PartRole::Ram,
                     PartRole::SupervisorReset,
+                    PartRole::Battery,
+                    PartRole::IrLed,
+                    PartRole::Phototransistor,
                 ],
                 match_fn: Box::new(|_, part| {
                     part.and_then(|part| part.kind.as_ref())
@@ -361,6 +391,7 @@ pub fn build_site() -> Site {
                     PartRole::SupervisorReset,
                     PartRole::HexInverter,
                     PartRole::Crystal,
+                    PartRole::Battery,
                 ],
                 match_fn: Box::new(|_, part| {
                     part.and_then(|part| part.kind.as_ref())
@@ -435,6 +466,7 @@ pub fn build_site() -> Site {
         })
     });
     site.page_sets.push(Box::new(move |data| {
+        let rom_index = RomIndex::new(&data.submissions.cartridges);
         data.submissions
             .cartridges
             .iter()
@@ -444,6 +476,7 @@ pub fn build_site() -> Site {
             .map(|(code, group)| {
                 let cfg = data.cfgs[code].clone();
                 let submissions = group.collect::<Vec<_>>();
+                let same_mask = rom_index.shared_with(&submissions);
                 let path = SitePath(vec![
                     Cow::Borrowed("cartridges"),
                     Cow::Owned(cfg.rom_id.clone()),
@@ -478,6 +511,7 @@ pub fn build_site() -> Site {
                         cfg: &cfg,
                         submissions,
                         variants: &variants,
+                        same_mask,
                     }
                     .render(),
                 };
@@ -518,13 +552,13 @@ pub fn build_site() -> Site {
             .map(|submission| {
                 let board = &submission.metadata.board;
                 let mapper = board
-                    .cfg
+                    .label
                     .parts()
-                    .find(|(_, part)| matches!(part, BoardPart::Mapper(_)))
+                    .find(|(_, part)| matches!(part, BoardPart::Mapper(..)))
                     .and_then(|(designator, _)| board.parts.get(&designator));
                 let key = mapper_cfgs
                     .iter()
-                    .position(|cfg| (cfg.match_fn)(board.cfg, mapper));
+                    .position(|cfg| (cfg.match_fn)(board.label.config, mapper));
                 (key, submission)
             })
             .sorted_by_key(|&(key, _)| key)
@@ -564,23 +598,37 @@ pub fn build_site() -> Site {
         data.submissions
             .cartridges
             .iter()
-            .sorted_by_key(|submission| submission.metadata.board.cfg.label())
-            .chunk_by(|submission| &submission.metadata.board.cfg)
+            .sorted_by_key(|submission| submission.metadata.board.label.config.label())
+            .chunk_by(|submission| submission.metadata.board.label.config)
             .into_iter()
             .map(|(cfg, group)| {
-                let submissions = group
+                let revisions = group
                     .sorted_unstable_by(|a, b| {
-                        game_name_cmp(&a.metadata.cfg, &b.metadata.cfg)
+                        let (a_board, b_board) = (&a.metadata.board.label, &b.metadata.board.label);
+                        a_board
+                            .revision
+                            .cmp(&b_board.revision)
+                            .then_with(|| game_name_cmp(&a.metadata.cfg, &b.metadata.cfg))
                             .then_with(|| a.sort_group.as_ref().cmp(&b.sort_group.as_ref()))
                             .then_with(|| natural_lexical_cmp(&a.contributor, &b.contributor))
                             .then_with(|| natural_lexical_cmp(&a.slug, &b.slug))
                     })
-                    .chunk_by(|&s| &s.code);
+                    .chunk_by(|&s| &s.metadata.board.label.revision);
 
-                let submissions = submissions
+                let revisions = revisions
                     .into_iter()
-                    .filter_map(|(code, chunk)| {
-                        Some((data.cfgs.get(code)?, chunk.collect::<Vec<_>>()))
+                    .map(|(revision, chunk)| {
+                        let submissions = chunk
+                            .chunk_by(|&s| &s.code)
+                            .into_iter()
+                            .filter_map(|(code, chunk)| {
+                                Some((data.cfgs.get(code)?, chunk.collect::<Vec<_>>()))
+                            })
+                            .collect::<Vec<_>>();
+                        BoardRevision {
+                            revision: revision.as_deref(),
+                            submissions,
+                        }
                     })
                     .collect::<Vec<_>>();
                 let path = SitePath(vec![
@@ -590,7 +638,7 @@ pub fn build_site() -> Site {
                 let page = Page {
                     title: Cow::Borrowed(cfg.label()),
                     section: SiteSection::Cartridges(None),
-                    content: CartridgeBoardPage { cfg, submissions }.render(),
+                    content: CartridgeBoardPage { cfg, revisions }.render(),
                 };
                 (path, page)
             })
@@ -601,7 +649,7 @@ pub fn build_site() -> Site {
 
         for (contributor, submissions) in data.submissions.by_contributor() {
             if submissions.counts().cartridges > 0 {
-                let contributor_slug = slugify(&contributor);
+                let contributor_slug = slugify(contributor);
                 let path = SitePath(vec![
                     Cow::Borrowed("cartridges"),
                     Cow::Borrowed("contributors"),
@@ -748,9 +796,15 @@ pub enum SiteSection {
 }
 
 pub fn board_kind_link(board: &LegacyBoard) -> Markup {
+    let label = &board.label;
     html! {
-        a href={ "/cartridges/" (board.cfg.label()) ".html" } { (board.cfg.label()) }
-        (board.kind.strip_prefix(&board.cfg.label()).unwrap_or(&board.kind))
+        a href={ "/cartridges/" (label.config.label()) ".html" } { (label.config.label()) }
+        @if let Some(suffix) = &label.suffix {
+            (suffix)
+        }
+        @if let Some(revision) = &label.revision {
+            "-" (revision)
+        }
     }
 }
 
@@ -761,6 +815,6 @@ pub fn game_name_cmp(a: &GameConfig, b: &GameConfig) -> cmp::Ordering {
             .map(|(name, _)| name)
             .unwrap_or(&cfg.name)
     }
-    natural_lexical_cmp(simplified_name(&a), simplified_name(&b))
+    natural_lexical_cmp(simplified_name(a), simplified_name(b))
         .then_with(|| natural_lexical_cmp(&a.rom_id, &b.rom_id))
 }
@@ -23,12 +23,14 @@ pub mod home;
 pub mod listing_entry_cell;
 pub mod listing_part;
 pub mod listing_photos_cell;
+pub mod manufacturers;
 pub mod mapper_page;
 pub mod markdown;
 pub mod markdown_page;
 pub mod site_footer;
 pub mod site_header;
 pub mod submission_part_table;
+pub mod supported_chips;
 
 pub fn page(title: &str, section: SiteSection, content: Markup) -> String {
     html! {
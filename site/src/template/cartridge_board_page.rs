@@ -2,7 +2,7 @@
 //
 // SPDX-License-Identifier: MIT
 
-use gbhwdb_model::config::cartridge::{BoardConfig, GameConfig};
+use gbhwdb_model::config::cartridge::{BoardConfig, BoardPart, GameConfig, PartDesignator};
 use maud::{Markup, Render, html};
 
 use crate::{
@@ -14,7 +14,13 @@ use crate::{
 };
 
 pub struct CartridgeBoardPage<'a> {
-    pub cfg: &'a BoardConfig,
+    pub cfg: BoardConfig,
+    /// Submissions grouped by board revision
+    pub revisions: Vec<BoardRevision<'a>>,
+}
+
+pub struct BoardRevision<'a> {
+    pub revision: Option<&'a str>,
     pub submissions: Vec<(&'a GameConfig, Vec<&'a LegacyCartridgeSubmission>)>,
 }
 
@@ -23,41 +29,56 @@ impl<'a> Render for CartridgeBoardPage<'a> {
         html! {
             article.cartridge-board-page {
                 h2 { "Cartridges by board type: " (self.cfg.label()) }
-                table {
-                    colgroup {
-                        col;
-                        col;
-                        col;
-                        @for _ in self.cfg.parts() {
-                            col;
-                        }
-                        col;
+                @for revision in &self.revisions {
+                    (render_revision(self.cfg, revision))
+                }
+            }
+        }
+    }
+}
+
+fn render_revision(cfg: BoardConfig, revision: &BoardRevision) -> Markup {
+    let parts = cfg.revision_parts(revision.revision).collect::<Vec<_>>();
+    html! {
+        h3 {
+            @match revision.revision {
+                Some(revision) => { "Revision " (revision) }
+                None => "No revision",
+            }
+        }
+        table {
+            colgroup {
+                col;
+                col;
+                col;
+                @for _ in &parts {
+                    col;
+                }
+                col;
+            }
+            thead {
+                tr {
+                    th scope="col" { "Entry" }
+                    th scope="col" { "Release" }
+                    th scope="col" { "Board" }
+                    @for (designator, part) in &parts {
+                        th scope="col" { (part.role().display()) " (" (designator.as_str()) ")" }
                     }
-                    thead {
-                        tr {
-                            th scope="col" { "Entry" }
-                            th scope="col" { "Release" }
-                            th scope="col" { "Board" }
-                            @for (designator, part) in self.cfg.parts() {
-                                th scope="col" { (part.role().display()) " (" (designator.as_str()) ")" }
+                    th scope="col" { "Photos" }
+                }
+            }
+            @for (game, chunk) in &revision.submissions {
+                tbody.mapper-page__game {
+                    tr.mapper-page__game-header {
+                        th colspan=(parts.len() + 4) scope="rowgroup" {
+                            a href={ ("/cartridges/") (game.rom_id) } {
+                                div.mapper-page__game-name { (game.name) }
+                                aside { (game.rom_id) }
                             }
-                            th scope="col" { "Photos" }
                         }
                     }
-                    @for (game, chunk) in &self.submissions {
-                        tbody.mapper-page__game {
-                            tr.mapper-page__game-header {
-                                th colspan=(self.cfg.parts().count() + 4) scope="rowgroup" {
-                                    a href={ ("/cartridges/") (game.rom_id) } {
-                                        div.mapper-page__game-name { (game.name) }
-                                        aside { (game.rom_id) }
-                                    }
-                                }
-                            }
-                            @for submission in chunk {
-                                (render_submission(&self.cfg, submission))
-                            }
-                        }
+                    @for submission in chunk {
+                        (render_submission(&parts, submission))
                     }
                 }
             }
@@ -65,7 +86,10 @@ impl<'a> Render for CartridgeBoardPage<'a> {
     }
 }
 
-fn render_submission(cfg: &BoardConfig, submission: &LegacyCartridgeSubmission) -> Markup {
+fn render_submission(
+    parts: &[(PartDesignator, BoardPart)],
+    submission: &LegacyCartridgeSubmission,
+) -> Markup {
     let metadata = &submission.metadata;
     let board = &metadata.board;
     html! {
@@ -86,9 +110,9 @@ fn render_submission(cfg: &BoardConfig, submission: &LegacyCartridgeSubmission)
                 div { (board.kind) }
                 div { (Optional(board.date_code.calendar())) }
             }
-            @for (designator, _) in cfg.parts() {
+            @for (designator, _) in parts {
                 (ListingPart {
-                    part: board.parts.get(&designator),
+                    part: board.parts.get(designator),
                     hide_type: false,
                 })
             }
@@ -129,7 +129,7 @@ impl<'a, M: LegacyConsoleMetadata, P: LegacyPhotos> Render for Submission<'a, M,
                     })
                 }
                 @for cell in self.extra_cells {
-                    td { (cell(&metadata)) }
+                    td { (cell(metadata)) }
                 }
                 (ListingPhotosCell { submission: self.submission })
             }
@@ -3,11 +3,13 @@
 // SPDX-License-Identifier: MIT
 
 use gbhwdb_model::config::cartridge::{GameConfig, GamePlatform, PartDesignator, PartRole};
+use itertools::Itertools;
 use maud::{Markup, Render, html};
 use std::collections::BTreeSet;
 
 use crate::{
     legacy::LegacyCartridgeSubmission,
+    process::rom_index::RomKey,
     site::board_kind_link,
     template::{
         Optional, listing_entry_cell::ListingEntryCell, listing_part::ListingPart,
@@ -20,6 +22,7 @@ pub struct GamePage<'a> {
     pub cfg: &'a GameConfig,
     pub submissions: Vec<&'a LegacyCartridgeSubmission>,
     pub variants: &'a [(&'a GameConfig, bool)],
+    pub same_mask: Vec<(&'a RomKey, &'a [&'a LegacyCartridgeSubmission])>,
 }
 
 impl<'a> Render for GamePage<'a> {
@@ -30,7 +33,7 @@ impl<'a> Render for GamePage<'a> {
             .flat_map(|s| {
                 s.metadata
                     .board
-                    .cfg
+                    .label
                     .parts()
                     .map(|(designator, part)| (designator, part.role()))
             })
@@ -48,6 +51,29 @@ impl<'a> Render for GamePage<'a> {
                 entry = urlencoding::encode(&self.cfg.no_intro_id),
             ))
         };
+        let cfg = self.cfg;
+        let info = [
+            ("Region", cfg.region.clone()),
+            (
+                "Languages",
+                Some(cfg.languages.iter().join(", ")).filter(|languages| !languages.is_empty()),
+            ),
+            ("Publisher", cfg.publisher.clone()),
+            (
+                "Release date",
+                cfg.release_date.map(|date| date.to_string()),
+            ),
+            ("ROM size", cfg.rom_size.map(format_size)),
+            ("RAM size", cfg.ram_size.map(format_size)),
+            ("Mapper", cfg.mapper.map(|mapper| mapper.name().to_owned())),
+            (
+                "Save type",
+                cfg.save_type.map(|save_type| save_type.name().to_owned()),
+            ),
+        ]
+        .into_iter()
+        .filter_map(|(title, value)| Some((title, value?)))
+        .collect::<Vec<_>>();
         html! {
             article.game-page {
                 h2 { (self.cfg.name) }
@@ -56,6 +82,14 @@ impl<'a> Render for GamePage<'a> {
                         "Game entry in No-Intro Dat-o-Matic"
                     }
                 }
+                @if !info.is_empty() {
+                    dl.game-page__info {
+                        @for (title, value) in &info {
+                            dt { (title) }
+                            dd { (value) }
+                        }
+                    }
+                }
                 @if self.variants.len() > 1 {
                     h3 { "Variants" }
                     ul.game-page__variants {
@@ -92,11 +126,37 @@ impl<'a> Render for GamePage<'a> {
                         }
                     }
                 }
+                @if !self.same_mask.is_empty() {
+                    h3 { "Same mask" }
+                    table.game-page__same-mask {
+                        thead {
+                            tr {
+                                th scope="col" { "ROM" }
+                                th scope="col" { "Boards" }
+                                th scope="col" { "Releases" }
+                                th scope="col" { "Entries" }
+                            }
+                        }
+                        tbody {
+                            @for &(key, group) in &self.same_mask {
+                                (render_same_mask(key, group))
+                            }
+                        }
+                    }
+                }
             }
         }
     }
 }
 
+fn format_size(bytes: u32) -> String {
+    if bytes >= 1024 && bytes.is_multiple_of(1024) {
+        format!("{} KiB", bytes / 1024)
+    } else {
+        format!("{bytes} B")
+    }
+}
+
 fn render_submission(
     submission: &LegacyCartridgeSubmission,
     parts: &BTreeSet<(PartDesignator, PartRole)>,
@@ -122,7 +182,7 @@ fn render_submission(
                 div { (Optional(board.date_code.calendar())) }
             }
             @for &(designator, role) in parts {
-                @if board.cfg.part(designator).map(|p| p.role()) == Some(role) {
+                @if board.label.part(designator).map(|p| p.role()) == Some(role) {
                     (ListingPart {
                         part: board.parts.get(&designator),
                         hide_type: false,
@@ -138,3 +198,40 @@ fn render_submission(
         }
     }
 }
+
+fn render_same_mask(key: &RomKey, group: &[&LegacyCartridgeSubmission]) -> Markup {
+    let boards = group
+        .iter()
+        .map(|s| &s.metadata.board)
+        .unique_by(|board| &board.kind)
+        .sorted_by_key(|board| &board.kind);
+    let releases = group
+        .iter()
+        .filter_map(|s| s.metadata.code.as_deref())
+        .unique()
+        .sorted();
+    html! {
+        tr {
+            td { (key) }
+            td {
+                @for board in boards {
+                    div { (board_kind_link(board)) }
+                }
+            }
+            td {
+                @for release in releases {
+                    div { (release) }
+                }
+            }
+            td {
+                @for submission in group {
+                    div {
+                        a href={ "/cartridges/" (submission.code) "/" (submission.slug) } {
+                            (submission.code) ": " (submission.title) " [" (submission.contributor) "]"
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
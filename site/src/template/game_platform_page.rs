@@ -9,8 +9,10 @@ use std::{borrow::Cow, collections::BTreeMap};
 
 use crate::site::game_name_cmp;
 use crate::{
-    LegacyPhotos, legacy::LegacyCartridgeSubmission, site::board_kind_link,
-    template::mapper_page::MapperCfg,
+    LegacyPhotos,
+    legacy::LegacyCartridgeSubmission,
+    site::board_kind_link,
+    template::{Optional, mapper_page::MapperCfg},
 };
 
 pub struct GamePlatformPage<'a> {
@@ -34,6 +36,11 @@ impl<'a> Render for GamePlatformPage<'a> {
             }
         }
         per_game.sort_unstable_by(|(a, _), (b, _)| game_name_cmp(a, b));
+        let columns = Columns {
+            publisher: per_game.iter().any(|(cfg, _)| cfg.publisher.is_some()),
+            release_date: per_game.iter().any(|(cfg, _)| cfg.release_date.is_some()),
+            mappers: self.platform.has_mappers(),
+        };
         let toggle_js = "\
 var shouldHide = event.currentTarget.innerText.includes('Show only');
 event.currentTarget.innerHTML = (shouldHide)
@@ -63,10 +70,16 @@ document.querySelectorAll('tr.empty').forEach((m) => {
                     colgroup {
                         col;
                         col;
+                        @if columns.publisher {
+                            col;
+                        }
+                        @if columns.release_date {
+                            col;
+                        }
                         col;
                         col;
                         col;
-                        @if self.platform.has_mappers() {
+                        @if columns.mappers {
                             col;
                         }
                         col;
@@ -75,10 +88,16 @@ document.querySelectorAll('tr.empty').forEach((m) => {
                         tr {
                             th scope="col" { "Title" }
                             th scope="col" { "ROM ID" }
+                            @if columns.publisher {
+                                th scope="col" { "Publisher" }
+                            }
+                            @if columns.release_date {
+                                th scope="col" { "Release date" }
+                            }
                             th scope="col" { "Year(s)" }
                             th scope="col" { "Release(s)" }
                             th scope="col" { "Board type(s)" }
-                            @if self.platform.has_mappers() {
+                            @if columns.mappers {
                                 th scope="col" { "Mapper(s)" }
                             }
                             th scope="col" { "Submissions" }
@@ -86,7 +105,7 @@ document.querySelectorAll('tr.empty').forEach((m) => {
                     }
                     tbody {
                         @for (cfg, submissions) in &per_game {
-                            (render_game(cfg, submissions))
+                            (render_game(cfg, submissions, &columns))
                         }
                     }
                 }
@@ -95,7 +114,18 @@ document.querySelectorAll('tr.empty').forEach((m) => {
     }
 }
 
-fn render_game(cfg: &GameConfig, submissions: &[&LegacyCartridgeSubmission]) -> Markup {
+/// Optional table columns
+struct Columns {
+    publisher: bool,
+    release_date: bool,
+    mappers: bool,
+}
+
+fn render_game(
+    cfg: &GameConfig,
+    submissions: &[&LegacyCartridgeSubmission],
+    columns: &Columns,
+) -> Markup {
     let years = submissions.iter().filter_map(|submission| {
         submission
             .metadata
@@ -120,13 +150,13 @@ fn render_game(cfg: &GameConfig, submissions: &[&LegacyCartridgeSubmission]) ->
             .and_then(|part| part.kind.as_deref().map(Cow::Borrowed))
     });
     let photo_submissions = submissions
-        .into_iter()
+        .iter()
         .filter(|s| s.photos.front().is_some())
         .collect::<Vec<_>>();
     html! {
-        tr .submission-list-item .empty[submissions.len() == 0] {
+        tr .submission-list-item .empty[submissions.is_empty()] {
             td {
-                @if submissions.len() > 0 {
+                @if !submissions.is_empty() {
                     a.submission-list-item__link href={ "/cartridges/" (cfg.rom_id) } {
                         div.submission-list-item__photos {
                             @for submission in &photo_submissions {
@@ -147,12 +177,18 @@ fn render_game(cfg: &GameConfig, submissions: &[&LegacyCartridgeSubmission]) ->
                 }
             }
             td.submission-list-item__rom-id {
-                @if submissions.len() > 0 {
+                @if !submissions.is_empty() {
                     a href={ "/cartridges/" (cfg.rom_id) } { (cfg.rom_id) }
                 } @else {
                     (cfg.rom_id)
                 }
             }
+            @if columns.publisher {
+                td { (Optional(cfg.publisher.as_ref())) }
+            }
+            @if columns.release_date {
+                td { (Optional(cfg.release_date.map(|date| date.to_string()))) }
+            }
             td { (multiline(years)) }
             td { (multiline(releases)) }
             td {
@@ -161,7 +197,7 @@ fn render_game(cfg: &GameConfig, submissions: &[&LegacyCartridgeSubmission]) ->
                     br;
                 }
             }
-            @if cfg.platform.has_mappers() {
+            @if columns.mappers {
                 td { (multiline(mappers)) }
             }
             td { (submissions.len()) }
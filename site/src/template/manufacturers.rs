@@ -0,0 +1,70 @@
+// SPDX-FileCopyrightText: Joonas Javanainen <joonas.javanainen@gmail.com>
+//
+// SPDX-License-Identifier: MIT
+
+use gbhwdb_model::parser::Manufacturer;
+use itertools::Itertools;
+use maud::{Markup, Render, html};
+use strum::VariantArray;
+
+pub struct Manufacturers;
+
+impl Render for Manufacturers {
+    fn render(&self) -> Markup {
+        let manufacturers = Manufacturer::VARIANTS
+            .iter()
+            .sorted_by_key(|manufacturer| manufacturer.name().to_lowercase());
+        html! {
+            article.manufacturers {
+                h2 { "Chip manufacturers" }
+                p {
+                    "Manufacturers of recognized chips, with the markings and date code formats "
+                    "found on their labels."
+                }
+                table {
+                    thead {
+                        tr {
+                            th scope="col" { "Manufacturer" }
+                            th scope="col" { "Country" }
+                            th scope="col" { "Also known as" }
+                            th scope="col" { "Markings" }
+                            th scope="col" { "Date codes" }
+                            th scope="col" { "History" }
+                        }
+                    }
+                    tbody {
+                        @for manufacturer in manufacturers {
+                            (render_manufacturer(*manufacturer))
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn render_manufacturer(manufacturer: Manufacturer) -> Markup {
+    let info = manufacturer.info();
+    html! {
+        tr {
+            td { (manufacturer.name()) }
+            td { (info.country.map(|country| country.name()).unwrap_or_default()) }
+            td { (info.aliases.join(", ")) }
+            td {
+                @for marking in info.markings {
+                    div.manufacturers__marking { (marking) }
+                }
+            }
+            td {
+                @for format in info.date_code_formats {
+                    div.manufacturers__marking { (format.name()) }
+                }
+            }
+            td {
+                @for link in manufacturer.lineage() {
+                    div { (link) }
+                }
+            }
+        }
+    }
+}
@@ -22,6 +22,7 @@ pub fn submission_part_table<'a>(parts: impl Iterator<Item = SubmissionPart<'a>>
                     th { "Type" }
                     th { "Manufacturer" }
                     th { "Date" }
+                    th { "Markings" }
                     th { "Label" }
                 }
             }
@@ -34,12 +35,29 @@ pub fn submission_part_table<'a>(parts: impl Iterator<Item = SubmissionPart<'a>>
                             td { (Optional(part.kind.as_ref())) }
                             td { (Optional(part.manufacturer.as_ref().map(|m| m.name()))) }
                             td { (Optional(part.date_code.calendar())) }
-                            td { (Optional(part.label.as_ref())) }
+                            td {
+                                @if let Some(revision) = &part.revision {
+                                    div { "Revision " (revision) }
+                                }
+                                @if let Some(lot_code) = &part.lot_code {
+                                    div { "Lot " (lot_code) }
+                                }
+                                @if let Some(country) = part.origin_country {
+                                    div { "Made in " (country.name()) }
+                                }
+                            }
+                            td {
+                                (Optional(part.label.as_ref()))
+                                @if let Some(canonical) = &part.canonical_label {
+                                    div.submission-part__canonical { "Canonical: " (canonical) }
+                                }
+                            }
                         } @else {
                             td;
                             td;
                             td;
                             td;
+                            td;
                         }
                     }
                 }
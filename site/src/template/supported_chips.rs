@@ -0,0 +1,82 @@
+// SPDX-FileCopyrightText: 2017-2025 Joonas Javanainen <joonas.javanainen@gmail.com>
+//
+// SPDX-License-Identifier: MIT
+
+use gbhwdb_model::parser::registry::ParserFamily;
+use itertools::Itertools;
+use maud::{Markup, Render, html};
+
+pub struct SupportedChips<'a> {
+    pub families: &'a [ParserFamily],
+}
+
+impl<'a> Render for SupportedChips<'a> {
+    fn render(&self) -> Markup {
+        let families = self
+            .families
+            .iter()
+            .filter(|family| !family.parsers.is_empty())
+            .sorted_by_key(|family| family.role)
+            .chunk_by(|family| family.role);
+        html! {
+            article.supported-chips {
+                h2 { "Supported chips" }
+                p {
+                    "Chips that can be recognized from their labels. "
+                    "If your chip is not listed here, please mention it when contributing."
+                }
+                @for (role, families) in &families {
+                    h3 { (role.display()) }
+                    @for family in families {
+                        (render_family(family))
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn render_family(family: &ParserFamily) -> Markup {
+    let details = [
+        family.package.map(|package| package.to_string()),
+        family.voltage.map(|voltage| voltage.to_string()),
+    ]
+    .into_iter()
+    .flatten()
+    .join(", ");
+    html! {
+        section.supported-chips__family {
+            h4 {
+                @if let Some(console) = family.console {
+                    (console.code()) " "
+                }
+                (family.role.display())
+                @if !details.is_empty() {
+                    " (" (details) ")"
+                }
+            }
+            table {
+                thead {
+                    tr {
+                        th scope="col" { "Chip" }
+                        th scope="col" { "Manufacturer" }
+                        th scope="col" { "Example labels" }
+                    }
+                }
+                tbody {
+                    @for parser in &family.parsers {
+                        tr {
+                            td { (parser.name) }
+                            td { (parser.manufacturer.map(|m| m.name()).unwrap_or_default()) }
+                            td {
+                                @for example in parser.examples {
+                                    div.supported-chips__example { (example) }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
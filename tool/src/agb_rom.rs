@@ -0,0 +1,73 @@
+// SPDX-FileCopyrightText: Joonas Javanainen <joonas.javanainen@gmail.com>
+//
+// SPDX-License-Identifier: MIT
+
+use anyhow::{Context as _, Error, anyhow, bail};
+use gbhwdb_model::{
+    config::cartridge::{BoardConfig, BoardLabel, SaveType, load_cfgs},
+    rom_header::agb::{AgbHeader, detect_save_type},
+};
+use std::{fs, process::ExitCode};
+
+pub fn run(args: &[String]) -> Result<ExitCode, Error> {
+    let (path, board) = match args {
+        [path] => (path, None),
+        [path, board] => (path, Some(board)),
+        _ => bail!("usage: agb-rom <rom file> [board label]"),
+    };
+    BoardConfig::validate()?;
+    let board = board
+        .map(|label| BoardLabel::parse(label).ok_or_else(|| anyhow!("unknown board {label}")))
+        .transpose()?;
+    let rom = fs::read(path).with_context(|| format!("failed to read {path}"))?;
+    let header = AgbHeader::from_rom(&rom).map_err(|err| anyhow!("{path}: {err}"))?;
+    let save_type = detect_save_type(&rom);
+    let cfgs = load_cfgs("config/games.json")?;
+    let games = cfgs
+        .values()
+        .filter(|cfg| header.matches_game(cfg))
+        .collect::<Vec<_>>();
+
+    println!("Title:        {}", header.title);
+    println!("Game code:    {}", header.game_code);
+    println!("Maker code:   {}", header.maker_code);
+    println!("Version:      {}", header.version);
+    println!(
+        "Save type:    {}",
+        save_type.map_or("none", |save_type| save_type.name())
+    );
+    for game in &games {
+        println!("Game:         {} {}", game.rom_id, game.name);
+    }
+
+    let mut problems = Vec::new();
+    if !header.has_valid_complement_check() {
+        problems.push(format!(
+            "invalid header complement check {:02X}",
+            header.complement_check
+        ));
+    }
+    if games.is_empty() {
+        problems.push(format!(
+            "no game in config/games.json has ROM ID {}",
+            header.rom_id()
+        ));
+    }
+    if let Some(board) = board
+        && !SaveType::fits_board(save_type, &board)
+    {
+        problems.push(format!(
+            "save type {save_type} doesn't match the parts on {board}",
+            save_type = save_type.map_or("none", |save_type| save_type.name()),
+            board = board.config.label(),
+        ));
+    }
+    for problem in &problems {
+        eprintln!("error: {problem}");
+    }
+    Ok(if problems.is_empty() {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    })
+}
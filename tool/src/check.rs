@@ -0,0 +1,70 @@
+// SPDX-FileCopyrightText: Joonas Javanainen <joonas.javanainen@gmail.com>
+//
+// SPDX-License-Identifier: MIT
+
+use anyhow::{Context as _, Error, anyhow, bail};
+use gbhwdb_model::{
+    config::cartridge::{
+        BoardConfig, BoardLabel, Severity, check_consistency, check_roms, load_cfgs,
+        parse_board_chips,
+    },
+    input::cartridge::Cartridge,
+};
+use glob::glob;
+use std::{fs::File, process::ExitCode};
+
+/// Checks the ROM chips, mapper and board of every cartridge submission against its game
+pub fn run(args: &[String]) -> Result<ExitCode, Error> {
+    if !args.is_empty() {
+        bail!("usage: check");
+    }
+    BoardConfig::validate()?;
+    let cfgs = load_cfgs("config/games.json")?;
+    let mut warnings = 0;
+    let mut errors = 0;
+    for path in glob("data/cartridges/*/*/metadata.json")? {
+        let path = path?;
+        let file =
+            File::open(&path).with_context(|| format!("failed to open {}", path.display()))?;
+        let cartridge: Cartridge = serde_json::from_reader(file)
+            .with_context(|| format!("failed to parse {}", path.display()))?;
+        let cfg = cfgs
+            .get(&cartridge.code)
+            .ok_or_else(|| anyhow!("{}: unknown ROM code {}", path.display(), cartridge.code))?;
+        let board = BoardLabel::parse(&cartridge.board.label).ok_or_else(|| {
+            anyhow!(
+                "{}: unknown board {}",
+                path.display(),
+                cartridge.board.label
+            )
+        })?;
+        let chips = parse_board_chips(&board, &cartridge.board);
+        for mismatch in check_roms(&board, &chips.roms, cfg) {
+            errors += 1;
+            eprintln!(
+                "{severity}: {code} {slug}: {mismatch}",
+                severity = Severity::Error,
+                code = cartridge.code,
+                slug = cartridge.slug,
+            );
+        }
+        for inconsistency in check_consistency(cfg, &board, chips.mapper.as_ref()) {
+            match inconsistency.severity() {
+                Severity::Warning => warnings += 1,
+                Severity::Error => errors += 1,
+            }
+            eprintln!(
+                "{severity}: {code} {slug}: {inconsistency}",
+                severity = inconsistency.severity(),
+                code = cartridge.code,
+                slug = cartridge.slug,
+            );
+        }
+    }
+    println!("{errors} errors, {warnings} warnings");
+    Ok(if errors == 0 {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    })
+}
@@ -0,0 +1,174 @@
+// SPDX-FileCopyrightText: Joonas Javanainen <joonas.javanainen@gmail.com>
+//
+// SPDX-License-Identifier: MIT
+
+//! Parser coverage report: which parsers match part labels in submissions, and which labels fail.
+//!
+//! Every part label is tried against every parser family, so the report doesn't depend on which
+//! parser the site uses for a part. Labels of parts that the site keeps as-is, such as accelerometers
+//! and TAMA chips, show up as failures. Board labels and stamps aren't parsed by the registered
+//! parsers, and are skipped.
+
+use anyhow::{Context as _, Error, bail};
+use gbhwdb_model::parser::registry;
+use glob::glob;
+use serde::Serialize;
+use serde_json::Value;
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fmt::Write as _,
+    fs::{self, File},
+    path::Path,
+    process::ExitCode,
+};
+
+#[derive(Clone, Debug, Serialize)]
+struct Match {
+    label: String,
+    parsers: BTreeSet<&'static str>,
+}
+
+#[derive(Clone, Debug, Serialize)]
+struct Failure {
+    label: String,
+    error: String,
+}
+
+#[derive(Clone, Debug, Default, Serialize)]
+struct Report {
+    labels: usize,
+    hits: BTreeMap<&'static str, usize>,
+    zero_hits: BTreeSet<&'static str>,
+    failures: Vec<Failure>,
+    /// Every parsed label with the parsers that matched it, only included with `--matches`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    matches: Option<Vec<Match>>,
+}
+
+/// Writes a parser coverage report of all submissions as `coverage.json` and `coverage.md`
+pub fn run(args: &[String]) -> Result<ExitCode, Error> {
+    let mut dir = None;
+    let mut with_matches = false;
+    for arg in args {
+        match arg.as_str() {
+            "--matches" => with_matches = true,
+            _ if dir.is_none() && !arg.starts_with("--") => dir = Some(arg.as_str()),
+            _ => bail!("usage: coverage [output dir] [--matches]"),
+        }
+    }
+    let dir = Path::new(dir.unwrap_or("build"));
+
+    let mut labels = Vec::new();
+    for pattern in [
+        "data/consoles/*/*/metadata.json",
+        "data/cartridges/*/*/metadata.json",
+    ] {
+        for path in glob(pattern)? {
+            let path = path?;
+            let file =
+                File::open(&path).with_context(|| format!("failed to open {}", path.display()))?;
+            let metadata: Value = serde_json::from_reader(file)
+                .with_context(|| format!("failed to parse {}", path.display()))?;
+            collect_part_labels(&metadata, 0, &mut labels);
+        }
+    }
+
+    let families = registry::families();
+    let mut report = Report {
+        labels: labels.len(),
+        ..Report::default()
+    };
+    let mut matches = Vec::new();
+    for label in labels {
+        let parsers = families
+            .iter()
+            .filter_map(|family| family.parse_all(&label).first().map(|(name, _)| *name))
+            .collect::<BTreeSet<_>>();
+        if parsers.is_empty() {
+            report.failures.push(Failure {
+                label,
+                error: String::from("no parser matched"),
+            });
+            continue;
+        }
+        for name in &parsers {
+            *report.hits.entry(name).or_default() += 1;
+        }
+        matches.push(Match { label, parsers });
+    }
+    report.zero_hits = families
+        .iter()
+        .flat_map(|family| &family.parsers)
+        .map(|parser| parser.name)
+        .filter(|name| !report.hits.contains_key(name))
+        .collect();
+    if with_matches {
+        report.matches = Some(matches);
+    }
+
+    fs::create_dir_all(dir)?;
+    fs::write(
+        dir.join("coverage.json"),
+        serde_json::to_string_pretty(&report)?,
+    )?;
+    fs::write(dir.join("coverage.md"), render_markdown(&report))?;
+    println!(
+        "{} labels, {} failed, {} parsers without hits",
+        report.labels,
+        report.failures.len(),
+        report.zero_hits.len()
+    );
+    Ok(ExitCode::SUCCESS)
+}
+
+/// Collects non-empty part labels, skipping the labels of the boards directly under the root
+fn collect_part_labels(value: &Value, depth: usize, labels: &mut Vec<String>) {
+    match value {
+        Value::Object(object) => {
+            for (key, value) in object {
+                match value {
+                    Value::String(label) if key == "label" && depth > 1 && !label.is_empty() => {
+                        labels.push(label.clone())
+                    }
+                    Value::Object(_) | Value::Array(_) => {
+                        collect_part_labels(value, depth + 1, labels)
+                    }
+                    _ => (),
+                }
+            }
+        }
+        Value::Array(values) => {
+            for value in values {
+                collect_part_labels(value, depth + 1, labels);
+            }
+        }
+        _ => (),
+    }
+}
+
+fn render_markdown(report: &Report) -> String {
+    let mut md = String::new();
+    let _ = writeln!(md, "# Parser coverage\n");
+    let _ = writeln!(
+        md,
+        "{} labels, {} failed, {} parsers without hits\n",
+        report.labels,
+        report.failures.len(),
+        report.zero_hits.len()
+    );
+    let _ = writeln!(md, "## Failed labels\n");
+    for failure in &report.failures {
+        let _ = writeln!(md, "- `{}`: {}", failure.label, failure.error);
+    }
+    let _ = writeln!(md, "\n## Parsers without hits\n");
+    for name in &report.zero_hits {
+        let _ = writeln!(md, "- {name}");
+    }
+    let _ = writeln!(md, "\n## Hits per parser\n");
+    let _ = writeln!(md, "| Parser | Hits |");
+    let _ = writeln!(md, "| --- | ---: |");
+    for (name, count) in &report.hits {
+        let _ = writeln!(md, "| {name} | {count} |");
+    }
+    md
+}
@@ -0,0 +1,85 @@
+// SPDX-FileCopyrightText: Joonas Javanainen <joonas.javanainen@gmail.com>
+//
+// SPDX-License-Identifier: MIT
+
+use anyhow::{Context as _, Error, anyhow, bail};
+use gbhwdb_model::{
+    config::cartridge::{BoardConfig, BoardLabel},
+    rom_header::RomHeader,
+};
+use std::{fs, process::ExitCode};
+
+pub fn run(args: &[String]) -> Result<ExitCode, Error> {
+    let (path, board) = match args {
+        [path] => (path, None),
+        [path, board] => (path, Some(board)),
+        _ => bail!("usage: gb-rom <rom file> [board label]"),
+    };
+    BoardConfig::validate()?;
+    let board = board
+        .map(|label| BoardLabel::parse(label).ok_or_else(|| anyhow!("unknown board {label}")))
+        .transpose()?;
+    let rom = fs::read(path).with_context(|| format!("failed to read {path}"))?;
+    let header = RomHeader::from_rom(&rom).map_err(|err| anyhow!("{path}: {err}"))?;
+    let features = header.cartridge_type.features();
+
+    println!("Title:        {}", header.title);
+    println!(
+        "Type:         {:02X} {}",
+        header.cartridge_type.0,
+        features.map_or("unknown", |features| features.name)
+    );
+    println!("Licensee:     {}", header.licensee);
+    println!("Version:      {}", header.version);
+    println!(
+        "ROM size:     {}",
+        header
+            .rom_size()
+            .map_or(String::from("unknown"), |size| size.to_string())
+    );
+    println!(
+        "RAM size:     {}",
+        header
+            .ram_size()
+            .map_or(String::from("none"), |size| size.to_string())
+    );
+
+    let mut problems = Vec::new();
+    if !header.has_valid_header_checksum() {
+        problems.push(format!(
+            "invalid header checksum {:02X}",
+            header.header_checksum
+        ));
+    }
+    if !header.has_valid_global_checksum(&rom) {
+        problems.push(format!(
+            "invalid global checksum {:04X}",
+            header.global_checksum
+        ));
+    }
+    if let Some(size) = header.rom_size()
+        && usize::try_from(size) != Ok(rom.len())
+    {
+        problems.push(format!(
+            "header declares {size} bytes of ROM, but the file has {}",
+            rom.len()
+        ));
+    }
+    if let Some(board) = board {
+        // The mapper chip label isn't known here, so only its presence is checked
+        for mismatch in header.check_board(&board, None) {
+            problems.push(format!(
+                "{mismatch} ({board})",
+                board = board.config.label()
+            ));
+        }
+    }
+    for problem in &problems {
+        eprintln!("error: {problem}");
+    }
+    Ok(if problems.is_empty() {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    })
+}
@@ -0,0 +1,96 @@
+// SPDX-FileCopyrightText: Joonas Javanainen <joonas.javanainen@gmail.com>
+//
+// SPDX-License-Identifier: MIT
+
+use anyhow::{Context as _, Error, bail};
+use gbhwdb_model::{
+    config::cartridge::{RomIdentity, identify_rom, load_cfgs},
+    hash::Hashes,
+    input::cartridge::CartridgeDump,
+    rom_header::agb::AgbHeader,
+};
+use std::{
+    fs::File,
+    io::{BufReader, Read as _},
+    process::ExitCode,
+};
+use time::OffsetDateTime;
+
+const USAGE: &str = "usage: identify <rom file> [expected rom id] [--tool <dumping tool>]";
+
+pub fn run(args: &[String]) -> Result<ExitCode, Error> {
+    let mut tool = None;
+    let mut positional = Vec::new();
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--tool" => match args.next() {
+                Some(name) => tool = Some(name.clone()),
+                None => bail!(USAGE),
+            },
+            _ => positional.push(arg),
+        }
+    }
+    let (path, expected) = match positional[..] {
+        [path] => (path, None),
+        [path, rom_id] => (path, Some(rom_id.clone())),
+        _ => bail!(USAGE),
+    };
+    let open = || File::open(path).with_context(|| format!("failed to open {path}"));
+    let hashes = Hashes::from_reader(BufReader::new(open()?))
+        .with_context(|| format!("failed to read {path}"))?;
+    // GBA headers include the game code, so the expected game can be guessed from the ROM
+    let expected = match expected {
+        Some(expected) => Some(expected),
+        None => {
+            let mut header = Vec::new();
+            open()?.take(0x200).read_to_end(&mut header)?;
+            AgbHeader::from_rom(&header)
+                .ok()
+                .map(|header| header.rom_id())
+        }
+    };
+    let cfgs = load_cfgs("config/games.json")?;
+
+    println!("CRC-32:       {}", hashes.crc32);
+    println!("MD5:          {}", hashes.md5);
+    println!("SHA-1:        {}", hashes.sha1);
+    println!("SHA-256:      {}", hashes.sha256);
+    let identity = identify_rom(&cfgs, &hashes, expected.as_deref());
+    match &identity {
+        RomIdentity::Game(game) => {
+            println!("Game:         {} {}", game.rom_id, game.name);
+            if let Some(expected) = expected.as_ref().filter(|&rom_id| *rom_id != game.rom_id) {
+                eprintln!(
+                    "warning: expected {expected}, but the hashes match {}",
+                    game.rom_id
+                );
+            }
+        }
+        RomIdentity::Variants(variants) => {
+            eprintln!("error: no game in config/games.json has these hashes");
+            for variant in variants {
+                println!("Variant:      {} {}", variant.rom_id, variant.name);
+            }
+        }
+        RomIdentity::Unknown => {
+            eprintln!("error: no game in config/games.json has these hashes");
+        }
+    }
+
+    let dump = CartridgeDump::new(
+        tool.clone().unwrap_or_default(),
+        OffsetDateTime::now_utc().date(),
+        &hashes,
+    );
+    let json = serde_json::to_string_pretty(&dump)?;
+    println!();
+    println!("  \"dump\": {}", json.replace('\n', "\n  "));
+    if tool.is_none() {
+        eprintln!("note: fill in the dumping tool, or pass it with --tool");
+    }
+    Ok(match identity {
+        RomIdentity::Game(_) => ExitCode::SUCCESS,
+        RomIdentity::Variants(_) | RomIdentity::Unknown => ExitCode::FAILURE,
+    })
+}
@@ -0,0 +1,49 @@
+// SPDX-FileCopyrightText: Joonas Javanainen <joonas.javanainen@gmail.com>
+//
+// SPDX-License-Identifier: MIT
+
+use anyhow::Error;
+use std::process::ExitCode;
+
+mod agb_rom;
+mod check;
+mod coverage;
+mod gb_rom;
+mod identify;
+mod no_intro;
+
+const USAGE: &str = "\
+Usage: gbhwdb-tool <command> [args...]
+
+Commands:
+  agb-rom <rom file> [board label]  Decode a GBA ROM header and check the save type against the
+                                    board and config/games.json
+  check                             Check cartridge submissions for inconsistencies between the
+                                    game platform, board and mapper
+  coverage [output dir] [--matches] Write a parser coverage report of all part labels in
+                                    submissions, optionally listing every matched label
+  gb-rom <rom file> [board label]   Decode a DMG/CGB ROM header, verify its checksums and check
+                                    the cartridge type against the board
+  identify <rom file> [rom id] [--tool <dumping tool>]
+                                    Look up a ROM dump in config/games.json by its hashes, and
+                                    print a dump block for the submission metadata
+  no-intro <dat file> [--dry-run]   Fill in missing No-Intro IDs, clone-of links and hashes in
+                                    config/games.json from a No-Intro DAT file, and report name
+                                    changes and conflicts
+";
+
+fn main() -> Result<ExitCode, Error> {
+    let args = std::env::args().skip(1).collect::<Vec<_>>();
+    match args.split_first() {
+        Some((command, args)) if command == "agb-rom" => agb_rom::run(args),
+        Some((command, args)) if command == "check" => check::run(args),
+        Some((command, args)) if command == "coverage" => coverage::run(args),
+        Some((command, args)) if command == "gb-rom" => gb_rom::run(args),
+        Some((command, args)) if command == "identify" => identify::run(args),
+        Some((command, args)) if command == "no-intro" => no_intro::run(args),
+        _ => {
+            eprint!("{USAGE}");
+            Ok(ExitCode::from(2))
+        }
+    }
+}
@@ -0,0 +1,400 @@
+// SPDX-FileCopyrightText: Joonas Javanainen <joonas.javanainen@gmail.com>
+//
+// SPDX-License-Identifier: MIT
+
+//! Synchronizes `config/games.json` with a No-Intro DAT file in Logiqx XML format
+
+use anyhow::{Context as _, Error, anyhow, bail};
+use gbhwdb_model::{
+    config::cartridge::{GameConfig, GamePlatform, load_cfgs, write_cfgs},
+    hash::{Crc32, Md5, Sha1, Sha256},
+};
+use itertools::Itertools as _;
+use roxmltree::{Document, Node};
+use std::{collections::BTreeMap, fmt, fs, process::ExitCode};
+
+const GAMES_JSON: &str = "config/games.json";
+
+pub fn run(args: &[String]) -> Result<ExitCode, Error> {
+    let (path, dry_run) = match args {
+        [path] => (path, false),
+        [path, flag] if flag == "--dry-run" => (path, true),
+        _ => bail!("usage: no-intro <dat file> [--dry-run]"),
+    };
+    let xml = fs::read_to_string(path).with_context(|| format!("failed to read {path}"))?;
+    let dat = Dat::parse(&xml).with_context(|| format!("failed to parse {path}"))?;
+    let mut cfgs = load_cfgs(GAMES_JSON)?;
+    let report = sync(&mut cfgs, &dat);
+
+    for (rom_id, changes) in &report.changes {
+        println!("{rom_id} {name}", name = cfgs[rom_id].name);
+        for change in changes {
+            println!("-   {}: {}", change.field, change.old);
+            println!("+   {}: {}", change.field, change.new);
+        }
+    }
+    for uncertain in &report.uncertain {
+        eprintln!("uncertain: {uncertain}");
+    }
+    for conflict in &report.conflicts {
+        eprintln!("conflict: {conflict}");
+    }
+    println!(
+        "{platform}: {changed} games changed, {uncertain} uncertain matches, {conflicts} conflicts, {unmatched} DAT entries without a game",
+        platform = dat.platform,
+        changed = report.changes.len(),
+        uncertain = report.uncertain.len(),
+        conflicts = report.conflicts.len(),
+        unmatched = report.unmatched,
+    );
+    if !dry_run && !report.changes.is_empty() {
+        write_cfgs(GAMES_JSON, &cfgs)?;
+    }
+    Ok(if report.conflicts.is_empty() {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    })
+}
+
+/// Parsed No-Intro DAT file
+#[derive(Clone, Debug)]
+struct Dat {
+    platform: GamePlatform,
+    entries: Vec<DatEntry>,
+}
+
+#[derive(Clone, Debug, Default)]
+struct DatEntry {
+    id: String,
+    clone_of: String,
+    name: String,
+    /// Serial numbers, e.g. "DMG-APSJ-JPN"
+    serials: Vec<String>,
+    size: Option<u32>,
+    crc32: Option<Crc32>,
+    md5: Option<Md5>,
+    sha1: Option<Sha1>,
+    sha256: Option<Sha256>,
+}
+
+impl Dat {
+    fn parse(xml: &str) -> Result<Dat, Error> {
+        let document = Document::parse(xml)?;
+        let root = document.root_element();
+        let header = child(root, "header").ok_or_else(|| anyhow!("missing header"))?;
+        let name = child(header, "name")
+            .and_then(|node| node.text())
+            .ok_or_else(|| anyhow!("missing header name"))?;
+        // Check GBA first, because the other names are its prefixes
+        let platform = [
+            ("Nintendo - Game Boy Advance", GamePlatform::Gba),
+            ("Nintendo - Game Boy Color", GamePlatform::Gbc),
+            ("Nintendo - Game Boy", GamePlatform::Gb),
+        ]
+        .into_iter()
+        .find(|(prefix, _)| name.starts_with(prefix))
+        .map(|(_, platform)| platform)
+        .ok_or_else(|| anyhow!("unsupported DAT {name:?}"))?;
+        let entries = root
+            .children()
+            .filter(|node| node.has_tag_name("game"))
+            .map(DatEntry::parse)
+            .collect::<Result<Vec<_>, Error>>()?;
+        Ok(Dat { platform, entries })
+    }
+}
+
+impl DatEntry {
+    fn parse(game: Node) -> Result<DatEntry, Error> {
+        let name = game.attribute("name").unwrap_or_default();
+        let rom = child(game, "rom").ok_or_else(|| anyhow!("{name}: missing rom"))?;
+        let hash = |attribute: &str| rom.attribute(attribute).filter(|text| !text.is_empty());
+        let parse_error = |err| anyhow!("{name}: {err}");
+        Ok(DatEntry {
+            id: game.attribute("id").unwrap_or_default().to_owned(),
+            clone_of: game.attribute("cloneofid").unwrap_or_default().to_owned(),
+            name: name.to_owned(),
+            serials: rom
+                .attribute("serial")
+                .unwrap_or_default()
+                .split(',')
+                .map(str::trim)
+                .filter(|serial| !serial.is_empty())
+                .map(str::to_owned)
+                .collect(),
+            size: rom
+                .attribute("size")
+                .map(str::parse)
+                .transpose()
+                .with_context(|| format!("{name}: invalid size"))?,
+            crc32: hash("crc")
+                .map(Crc32::parse)
+                .transpose()
+                .map_err(parse_error)?,
+            md5: hash("md5")
+                .map(Md5::parse)
+                .transpose()
+                .map_err(parse_error)?,
+            sha1: hash("sha1")
+                .map(Sha1::parse)
+                .transpose()
+                .map_err(parse_error)?,
+            sha256: hash("sha256")
+                .map(Sha256::parse)
+                .transpose()
+                .map_err(parse_error)?,
+        })
+    }
+    fn has_hash_of(&self, cfg: &GameConfig) -> bool {
+        fn same<T: PartialEq>(a: Option<T>, b: Option<T>) -> bool {
+            a.is_some() && a == b
+        }
+        same(self.crc32, cfg.crc32)
+            || same(self.md5, cfg.md5)
+            || same(self.sha1, cfg.sha1)
+            || same(self.sha256, cfg.sha256)
+    }
+    fn has_serial(&self, serial: &str) -> bool {
+        self.serials.iter().any(|candidate| candidate == serial)
+    }
+    /// Returns true if any serial number matches the ROM ID when the region and revision
+    /// suffixes are ignored (e.g. "DMG-APSJ-JPN" and "DMG-APSJ-0")
+    fn has_game_code_of(&self, rom_id: &str) -> bool {
+        let code = game_code(rom_id);
+        code.is_some() && self.serials.iter().any(|serial| game_code(serial) == code)
+    }
+}
+
+/// Returns the platform and game code part of a serial number or ROM ID, e.g. "DMG-APSJ"
+fn game_code(text: &str) -> Option<&str> {
+    let (platform, rest) = text.split_once('-')?;
+    let game = rest.split('-').next()?;
+    Some(&text[..platform.len() + 1 + game.len()])
+}
+
+fn child<'a, 'input>(node: Node<'a, 'input>, name: &str) -> Option<Node<'a, 'input>> {
+    node.children().find(|child| child.has_tag_name(name))
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+struct Change {
+    field: &'static str,
+    old: String,
+    new: String,
+}
+
+#[derive(Clone, Debug, Default)]
+struct SyncReport {
+    changes: BTreeMap<String, Vec<Change>>,
+    /// Games that only matched a DAT entry by game code, and were left as is
+    uncertain: Vec<String>,
+    conflicts: Vec<String>,
+    /// Number of DAT entries that didn't match any game
+    unmatched: usize,
+}
+
+/// Fills in missing No-Intro data of games on the DAT's platform, and updates changed names.
+///
+/// Games are matched by No-Intro ID, then by any hash, and finally by serial number if exactly
+/// one entry matches. Values that differ from the DAT are reported as conflicts and left as is,
+/// and so are games matching an entry that an earlier game already matched. Games whose serial
+/// number only matches without the revision are reported as uncertain and left as is.
+fn sync(cfgs: &mut BTreeMap<String, GameConfig>, dat: &Dat) -> SyncReport {
+    let mut report = SyncReport::default();
+    let mut matched: Vec<Option<&str>> = vec![None; dat.entries.len()];
+    for (rom_id, cfg) in cfgs.iter_mut() {
+        if cfg.platform != dat.platform {
+            continue;
+        }
+        let by_id = || {
+            dat.entries
+                .iter()
+                .position(|entry| !cfg.no_intro_id.is_empty() && entry.id == cfg.no_intro_id)
+        };
+        let by_hash = || dat.entries.iter().position(|entry| entry.has_hash_of(cfg));
+        // Serial numbers often don't include the revision, so only unique matches are used
+        let unique = |f: &dyn Fn(&DatEntry) -> bool| {
+            let mut candidates = dat.entries.iter().positions(f);
+            match (candidates.next(), candidates.next()) {
+                (Some(index), None) => Some(index),
+                _ => None,
+            }
+        };
+        let by_serial = || unique(&|entry| entry.has_serial(rom_id));
+        let Some(index) = by_id().or_else(by_hash).or_else(by_serial) else {
+            if let Some(index) = unique(&|entry| entry.has_game_code_of(rom_id)) {
+                let entry = &dat.entries[index];
+                report.uncertain.push(format!(
+                    "{rom_id}: only the game code matches {name:?} ({id}) in the DAT",
+                    name = entry.name,
+                    id = entry.id,
+                ));
+            }
+            continue;
+        };
+        let entry = &dat.entries[index];
+        if let Some(other) = matched[index] {
+            report.conflicts.push(format!(
+                "{rom_id}: {name:?} ({id}) in the DAT already matched {other}",
+                name = entry.name,
+                id = entry.id,
+            ));
+            continue;
+        }
+        matched[index] = Some(rom_id);
+        let mut sync = Sync {
+            rom_id: &cfg.rom_id,
+            changes: Vec::new(),
+            conflicts: &mut report.conflicts,
+        };
+        if cfg.name != entry.name {
+            sync.changes.push(Change {
+                field: "name",
+                old: format!("{:?}", cfg.name),
+                new: format!("{:?}", entry.name),
+            });
+            cfg.name.clone_from(&entry.name);
+        }
+        sync.string("no_intro_id", &mut cfg.no_intro_id, &entry.id);
+        sync.string(
+            "no_intro_clone_of",
+            &mut cfg.no_intro_clone_of,
+            &entry.clone_of,
+        );
+        sync.value("rom_size", &mut cfg.rom_size, entry.size);
+        sync.value("crc32", &mut cfg.crc32, entry.crc32);
+        sync.value("md5", &mut cfg.md5, entry.md5);
+        sync.value("sha1", &mut cfg.sha1, entry.sha1);
+        sync.value("sha256", &mut cfg.sha256, entry.sha256);
+        let changes = sync.changes;
+        if !changes.is_empty() {
+            report.changes.insert(rom_id.clone(), changes);
+        }
+    }
+    report.unmatched = matched.iter().filter(|matched| matched.is_none()).count();
+    report
+}
+
+struct Sync<'a> {
+    rom_id: &'a str,
+    changes: Vec<Change>,
+    conflicts: &'a mut Vec<String>,
+}
+
+impl Sync<'_> {
+    fn value<T: Copy + PartialEq + fmt::Display>(
+        &mut self,
+        field: &'static str,
+        current: &mut Option<T>,
+        value: Option<T>,
+    ) {
+        match (*current, value) {
+            (None, Some(value)) => {
+                self.changes.push(Change {
+                    field,
+                    old: "(none)".to_owned(),
+                    new: value.to_string(),
+                });
+                *current = Some(value);
+            }
+            (Some(current), Some(value)) if current != value => self.conflicts.push(format!(
+                "{rom_id}: {field} is {current} in {GAMES_JSON}, but {value} in the DAT",
+                rom_id = self.rom_id,
+            )),
+            _ => (),
+        }
+    }
+    fn string(&mut self, field: &'static str, current: &mut String, value: &str) {
+        if value.is_empty() || current == value {
+            return;
+        }
+        if current.is_empty() {
+            self.changes.push(Change {
+                field,
+                old: "(none)".to_owned(),
+                new: value.to_owned(),
+            });
+            value.clone_into(current);
+        } else {
+            self.conflicts.push(format!(
+                "{rom_id}: {field} is {current} in {GAMES_JSON}, but {value} in the DAT",
+                rom_id = self.rom_id,
+            ));
+        }
+    }
+}
+
+#[test]
+fn test_sync() {
+    let dat = Dat::parse(
+        r#"<?xml version="1.0"?>
+        <datafile>
+            <header><name>Nintendo - Game Boy</name></header>
+            <game name="Tetris (World) (Rev 1)" id="0863">
+                <description>Tetris (World) (Rev 1)</description>
+                <rom name="Tetris (World) (Rev 1).gb" size="32768" crc="46DF91AD" sha1="74591CC9501AF93873F9A5D3EB12DA12C0723BBC" serial="DMG-TRA-1"/>
+            </game>
+            <game name="Alleyway (World)" id="0012" cloneofid="0011">
+                <rom name="Alleyway (World).gb" size="32768" crc="9E8A3A8B" serial="DMG-AWA"/>
+            </game>
+            <game name="Unknown (Japan)" id="9999">
+                <rom name="Unknown (Japan).gb" size="32768" crc="00000000"/>
+            </game>
+        </datafile>"#,
+    )
+    .unwrap();
+    assert_eq!(dat.platform, GamePlatform::Gb);
+    let mut cfgs = serde_json::from_value::<BTreeMap<String, GameConfig>>(serde_json::json!({
+        "DMG-TRA-1": {
+            "name": "Tetris (World) (Rev A)", "rom_verified": true, "platform": "gb",
+            "no_intro_id": "", "crc32": "46df91ad",
+        },
+        "DMG-AWA-0": {
+            "name": "Alleyway (World)", "rom_verified": true, "platform": "gb",
+            "no_intro_id": "0012", "rom_size": 65536,
+        },
+        "DMG-AWA-1": {
+            "name": "Alleyway (World) (Rev 1)", "rom_verified": true, "platform": "gb",
+            "no_intro_id": "0012",
+        },
+        "DMG-TRA-0": {
+            "name": "Tetris (World)", "rom_verified": true, "platform": "gb",
+            "no_intro_id": "",
+        },
+        "AGB-AXVE-0": {
+            "name": "Pokemon - Ruby Version (USA)", "rom_verified": true, "platform": "gba",
+            "no_intro_id": "",
+        },
+    }))
+    .unwrap();
+    for (rom_id, cfg) in cfgs.iter_mut() {
+        cfg.rom_id.clone_from(rom_id);
+    }
+    let report = sync(&mut cfgs, &dat);
+    let tetris = &cfgs["DMG-TRA-1"];
+    assert_eq!(tetris.name, "Tetris (World) (Rev 1)");
+    assert_eq!(tetris.no_intro_id, "0863");
+    assert_eq!(tetris.rom_size, Some(32768));
+    assert!(tetris.sha1.is_some());
+    assert_eq!(report.changes["DMG-TRA-1"].len(), 4);
+    let alleyway = &cfgs["DMG-AWA-0"];
+    assert_eq!(alleyway.no_intro_clone_of, "0011");
+    assert_eq!(alleyway.rom_size, Some(65536));
+    assert_eq!(
+        report.conflicts,
+        [
+            "DMG-AWA-0: rom_size is 65536 in config/games.json, but 32768 in the DAT",
+            "DMG-AWA-1: \"Alleyway (World)\" (0012) in the DAT already matched DMG-AWA-0",
+        ]
+    );
+    assert!(!report.changes.contains_key("DMG-AWA-1"));
+    assert_eq!(cfgs["DMG-TRA-0"].name, "Tetris (World)");
+    assert_eq!(
+        report.uncertain,
+        ["DMG-TRA-0: only the game code matches \"Tetris (World) (Rev 1)\" (0863) in the DAT"]
+    );
+    assert!(!report.changes.contains_key("DMG-TRA-0"));
+    assert_eq!(report.unmatched, 1);
+    assert!(!report.changes.contains_key("AGB-AXVE-0"));
+}